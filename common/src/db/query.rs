@@ -241,6 +241,7 @@ pub(crate) mod tests {
             pub published: Option<OffsetDateTime>,
             pub severity: Severity,
             pub score: f64,
+            pub withdrawn: bool,
             #[sea_orm(column_type = "JsonBinary")]
             pub purl: CanonicalPurl,
         }
@@ -163,6 +163,7 @@ impl Arg {
         Ok(match ct {
             ColumnType::Uuid => Arg::Value(SeaValue::from(s.parse::<Uuid>().map_err(err)?)),
             ColumnType::Integer => Arg::Value(SeaValue::from(s.parse::<i32>().map_err(err)?)),
+            ColumnType::Boolean => Arg::Value(SeaValue::from(s.parse::<bool>().map_err(err)?)),
             ColumnType::Decimal(_) | ColumnType::Float | ColumnType::Double => {
                 Arg::Value(SeaValue::from(s.parse::<f64>().map_err(err)?))
             }
@@ -404,6 +405,11 @@ pub(crate) mod tests {
             where_clause("severity>low")?,
             r#""advisory"."severity" > (CAST('low' AS Severity))"#
         );
+        assert_eq!(
+            where_clause("withdrawn=true")?,
+            r#""advisory"."withdrawn" = TRUE"#
+        );
+        assert!(where_clause("withdrawn=maybe").is_err());
 
         Ok(())
     }
@@ -0,0 +1,221 @@
+use crate::cvss2::vector::Cvss2Vector;
+use crate::cvss3::score::Score;
+use crate::cvss3::severity::Severity;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub mod vector;
+
+/// The base metric group of a CVSS v2 vector, e.g. `AV:N/AC:L/Au:N/C:P/I:P/A:P`.
+///
+/// Described in the CVSS v2 Complete Documentation: Section 2.1:
+/// <https://www.first.org/cvss/v2/guide#2-1-Base-Metrics>
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cvss2Base {
+    pub av: AccessVector,
+    pub ac: AccessComplexity,
+    pub au: Authentication,
+    pub c: ConfidentialityImpact,
+    pub i: IntegrityImpact,
+    pub a: AvailabilityImpact,
+}
+
+impl Cvss2Base {
+    /// Calculate the Base score.
+    ///
+    /// Described in the CVSS v2 Complete Documentation: Section 3.2.1:
+    /// <https://www.first.org/cvss/v2/guide#3-2-1-Base-Equation>
+    pub fn score(&self) -> Score {
+        Score::new(base_score(self.impact(), self.exploitability()))
+    }
+
+    /// Calculate the Exploitability sub-score.
+    pub fn exploitability(&self) -> Score {
+        Score::new(20.0 * self.av.score() * self.ac.score() * self.au.score())
+    }
+
+    /// Calculate the Impact sub-score.
+    pub fn impact(&self) -> Score {
+        Score::new(
+            10.41
+                * (1.0 - (1.0 - self.c.score()) * (1.0 - self.i.score()) * (1.0 - self.a.score())),
+        )
+    }
+
+    /// Evaluate the severity of the base score.
+    ///
+    /// CVSS v2 doesn't define a qualitative rating scale, so this follows the one the NVD uses:
+    /// Low (0.0 - 3.9), Medium (4.0 - 6.9), and High (7.0 - 10.0).
+    pub fn severity(&self) -> Severity {
+        severity(self.score())
+    }
+}
+
+/// The base equation, also used with an adjusted impact for the environmental score.
+pub(crate) fn base_score(impact: Score, exploitability: Score) -> f64 {
+    let impact = impact.value();
+    let f = if impact == 0.0 { 0.0 } else { 1.176 };
+    round_to_1_decimal(((0.6 * impact) + (0.4 * exploitability.value()) - 1.5) * f)
+}
+
+pub(crate) fn round_to_1_decimal(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+/// Map a CVSS v2 score to the NVD severity rating.
+pub fn severity(score: Score) -> Severity {
+    match score.value() {
+        x if x < 4.0 => Severity::Low,
+        x if x < 7.0 => Severity::Medium,
+        _ => Severity::High,
+    }
+}
+
+impl Display for Cvss2Base {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AV:{}/AC:{}/Au:{}/C:{}/I:{}/A:{}",
+            self.av, self.ac, self.au, self.c, self.i, self.a
+        )
+    }
+}
+
+/// Parses the base metrics of a vector, temporal and environmental metrics must be valid, but
+/// are ignored. Use [`Cvss2Vector`] to retain them.
+impl FromStr for Cvss2Base {
+    type Err = Cvss2Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Cvss2Vector::from_str(s)?.base)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Cvss2Error {
+    Invalid,
+    AccessVector,
+    AccessComplexity,
+    Authentication,
+    ConfidentialityImpact,
+    IntegrityImpact,
+    AvailabilityImpact,
+    Exploitability,
+    RemediationLevel,
+    ReportConfidence,
+    CollateralDamagePotential,
+    TargetDistribution,
+    SecurityRequirement,
+}
+
+impl Display for Cvss2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Define a metric, with its values in the vector and their numeric values in the equations.
+macro_rules! metric {
+    ($(#[$meta:meta])* $name:ident, $prefix:literal, $error:ident, { $($variant:ident => ($value:literal, $score:literal)),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl $name {
+            pub(crate) fn score(self) -> f64 {
+                match self {
+                    $(Self::$variant => $score),+
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => $value),+
+                })
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Cvss2Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.strip_prefix(concat!($prefix, ":")) {
+                    $(Some($value) => Ok(Self::$variant),)+
+                    _ => Err(Cvss2Error::$error),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use metric;
+
+metric!(AccessVector, "AV", AccessVector, {
+    Local => ("L", 0.395),
+    AdjacentNetwork => ("A", 0.646),
+    Network => ("N", 1.0),
+});
+
+metric!(AccessComplexity, "AC", AccessComplexity, {
+    High => ("H", 0.35),
+    Medium => ("M", 0.61),
+    Low => ("L", 0.71),
+});
+
+metric!(Authentication, "Au", Authentication, {
+    Multiple => ("M", 0.45),
+    Single => ("S", 0.56),
+    None => ("N", 0.704),
+});
+
+metric!(ConfidentialityImpact, "C", ConfidentialityImpact, {
+    None => ("N", 0.0),
+    Partial => ("P", 0.275),
+    Complete => ("C", 0.660),
+});
+
+metric!(IntegrityImpact, "I", IntegrityImpact, {
+    None => ("N", 0.0),
+    Partial => ("P", 0.275),
+    Complete => ("C", 0.660),
+});
+
+metric!(AvailabilityImpact, "A", AvailabilityImpact, {
+    None => ("N", 0.0),
+    Partial => ("P", 0.275),
+    Complete => ("C", 0.660),
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scores() {
+        for (vector, score) in [
+            ("AV:N/AC:L/Au:N/C:P/I:P/A:P", 7.5),
+            ("AV:N/AC:L/Au:N/C:C/I:C/A:C", 10.0),
+            ("AV:N/AC:M/Au:N/C:N/I:P/A:N", 4.3),
+            ("AV:L/AC:L/Au:N/C:C/I:C/A:C", 7.2),
+            ("AV:N/AC:L/Au:N/C:N/I:N/A:C", 7.8),
+            ("AV:N/AC:L/Au:N/C:N/I:N/A:N", 0.0),
+        ] {
+            let base = Cvss2Base::from_str(vector).unwrap();
+            assert_eq!(base.score(), Score::new(score), "{vector}");
+            assert_eq!(base.to_string(), vector);
+        }
+    }
+
+    #[test]
+    fn severity() {
+        let severity = |s: &str| Cvss2Base::from_str(s).unwrap().severity();
+        assert_eq!(severity("AV:N/AC:L/Au:N/C:N/I:N/A:N"), Severity::Low);
+        assert_eq!(severity("AV:N/AC:M/Au:N/C:N/I:P/A:N"), Severity::Medium);
+        assert_eq!(severity("AV:N/AC:L/Au:N/C:P/I:P/A:P"), Severity::High);
+        assert_eq!(severity("AV:N/AC:L/Au:N/C:C/I:C/A:C"), Severity::High);
+    }
+}
@@ -0,0 +1,339 @@
+use super::*;
+
+/// A full CVSS v2 vector, consisting of the base, temporal, and environmental metric groups.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cvss2Vector {
+    pub base: Cvss2Base,
+    pub temporal: Cvss2Temporal,
+    pub environmental: Cvss2Environmental,
+}
+
+/// The temporal metric group. A metric which is `None` is "Not Defined" (`ND`).
+///
+/// Described in the CVSS v2 Complete Documentation: Section 2.2:
+/// <https://www.first.org/cvss/v2/guide#2-2-Temporal-Metrics>
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Cvss2Temporal {
+    pub e: Option<Exploitability>,
+    pub rl: Option<RemediationLevel>,
+    pub rc: Option<ReportConfidence>,
+}
+
+impl Cvss2Temporal {
+    /// Check if any of the temporal metrics is defined.
+    pub fn is_defined(&self) -> bool {
+        self.e.is_some() || self.rl.is_some() || self.rc.is_some()
+    }
+
+    fn factor(&self) -> f64 {
+        self.e.map(Exploitability::score).unwrap_or(1.0)
+            * self.rl.map(RemediationLevel::score).unwrap_or(1.0)
+            * self.rc.map(ReportConfidence::score).unwrap_or(1.0)
+    }
+}
+
+/// The environmental metric group. A metric which is `None` is "Not Defined" (`ND`).
+///
+/// Described in the CVSS v2 Complete Documentation: Section 2.3:
+/// <https://www.first.org/cvss/v2/guide#2-3-Environmental-Metrics>
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Cvss2Environmental {
+    pub cdp: Option<CollateralDamagePotential>,
+    pub td: Option<TargetDistribution>,
+    pub cr: Option<SecurityRequirement>,
+    pub ir: Option<SecurityRequirement>,
+    pub ar: Option<SecurityRequirement>,
+}
+
+impl Cvss2Environmental {
+    /// Check if any of the environmental metrics is defined.
+    pub fn is_defined(&self) -> bool {
+        *self != Self::default()
+    }
+}
+
+impl Cvss2Vector {
+    /// Calculate the Base score.
+    pub fn score(&self) -> Score {
+        self.base.score()
+    }
+
+    /// Calculate the Temporal score, `None` if no temporal metric is defined.
+    ///
+    /// Described in the CVSS v2 Complete Documentation: Section 3.2.2:
+    /// <https://www.first.org/cvss/v2/guide#3-2-2-Temporal-Equation>
+    pub fn temporal_score(&self) -> Option<Score> {
+        self.temporal.is_defined().then(|| {
+            Score::new(round_to_1_decimal(
+                self.base.score().value() * self.temporal.factor(),
+            ))
+        })
+    }
+
+    /// Calculate the Environmental score, `None` if no environmental metric is defined.
+    ///
+    /// Described in the CVSS v2 Complete Documentation: Section 3.2.3:
+    /// <https://www.first.org/cvss/v2/guide#3-2-3-Environmental-Equation>
+    pub fn environmental_score(&self) -> Option<Score> {
+        if !self.environmental.is_defined() {
+            return None;
+        }
+
+        let base = &self.base;
+        let env = &self.environmental;
+
+        let requirement = |r: Option<SecurityRequirement>| r.map(|r| r.score()).unwrap_or(1.0);
+
+        let adjusted_impact = (10.41
+            * (1.0
+                - (1.0 - base.c.score() * requirement(env.cr))
+                    * (1.0 - base.i.score() * requirement(env.ir))
+                    * (1.0 - base.a.score() * requirement(env.ar))))
+        .min(10.0);
+        let adjusted_base = base_score(Score::new(adjusted_impact), base.exploitability());
+        let adjusted_temporal = round_to_1_decimal(adjusted_base * self.temporal.factor());
+
+        let cdp = env.cdp.map(CollateralDamagePotential::score).unwrap_or(0.0);
+        let td = env.td.map(TargetDistribution::score).unwrap_or(1.0);
+
+        Some(Score::new(round_to_1_decimal(
+            (adjusted_temporal + (10.0 - adjusted_temporal) * cdp) * td,
+        )))
+    }
+}
+
+impl From<Cvss2Base> for Cvss2Vector {
+    fn from(base: Cvss2Base) -> Self {
+        Self {
+            base,
+            temporal: Default::default(),
+            environmental: Default::default(),
+        }
+    }
+}
+
+/// Serializes the base metrics, followed by all defined temporal and environmental metrics.
+impl Display for Cvss2Vector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.base)?;
+
+        let temporal = &self.temporal;
+        if let Some(e) = temporal.e {
+            write!(f, "/E:{e}")?;
+        }
+        if let Some(rl) = temporal.rl {
+            write!(f, "/RL:{rl}")?;
+        }
+        if let Some(rc) = temporal.rc {
+            write!(f, "/RC:{rc}")?;
+        }
+
+        let environmental = &self.environmental;
+        if let Some(cdp) = environmental.cdp {
+            write!(f, "/CDP:{cdp}")?;
+        }
+        if let Some(td) = environmental.td {
+            write!(f, "/TD:{td}")?;
+        }
+        if let Some(cr) = environmental.cr {
+            write!(f, "/CR:{cr}")?;
+        }
+        if let Some(ir) = environmental.ir {
+            write!(f, "/IR:{ir}")?;
+        }
+        if let Some(ar) = environmental.ar {
+            write!(f, "/AR:{ar}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a vector in the NVD notation (`AV:N/AC:L/...`), optionally wrapped in parentheses, or
+/// prefixed with `CVSS:2.0/`.
+impl FromStr for Cvss2Vector {
+    type Err = Cvss2Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+        let s = s.strip_prefix("CVSS:2.0/").unwrap_or(s);
+
+        let parts = s.split('/').collect::<Vec<_>>();
+        if parts.len() < 6 {
+            return Err(Self::Err::Invalid);
+        }
+
+        let base = Cvss2Base {
+            av: AccessVector::from_str(parts[0])?,
+            ac: AccessComplexity::from_str(parts[1])?,
+            au: Authentication::from_str(parts[2])?,
+            c: ConfidentialityImpact::from_str(parts[3])?,
+            i: IntegrityImpact::from_str(parts[4])?,
+            a: AvailabilityImpact::from_str(parts[5])?,
+        };
+
+        let mut temporal = Cvss2Temporal::default();
+        let mut environmental = Cvss2Environmental::default();
+
+        for part in &parts[6..] {
+            let (metric, value) = part.split_once(':').ok_or(Self::Err::Invalid)?;
+            let defined = value != "ND";
+
+            match metric {
+                "E" => temporal.e = parse(defined, part)?,
+                "RL" => temporal.rl = parse(defined, part)?,
+                "RC" => temporal.rc = parse(defined, part)?,
+                "CDP" => environmental.cdp = parse(defined, part)?,
+                "TD" => environmental.td = parse(defined, part)?,
+                "CR" => environmental.cr = parse(defined, value)?,
+                "IR" => environmental.ir = parse(defined, value)?,
+                "AR" => environmental.ar = parse(defined, value)?,
+                _ => return Err(Self::Err::Invalid),
+            }
+        }
+
+        Ok(Self {
+            base,
+            temporal,
+            environmental,
+        })
+    }
+}
+
+/// Parse a metric, unless it is "Not Defined".
+fn parse<T: FromStr<Err = Cvss2Error>>(defined: bool, s: &str) -> Result<Option<T>, Cvss2Error> {
+    defined.then(|| T::from_str(s)).transpose()
+}
+
+metric!(Exploitability, "E", Exploitability, {
+    Unproven => ("U", 0.85),
+    ProofOfConcept => ("POC", 0.9),
+    Functional => ("F", 0.95),
+    High => ("H", 1.0),
+});
+
+metric!(RemediationLevel, "RL", RemediationLevel, {
+    OfficialFix => ("OF", 0.87),
+    TemporaryFix => ("TF", 0.9),
+    Workaround => ("W", 0.95),
+    Unavailable => ("U", 1.0),
+});
+
+metric!(ReportConfidence, "RC", ReportConfidence, {
+    Unconfirmed => ("UC", 0.9),
+    Uncorroborated => ("UR", 0.95),
+    Confirmed => ("C", 1.0),
+});
+
+metric!(CollateralDamagePotential, "CDP", CollateralDamagePotential, {
+    None => ("N", 0.0),
+    Low => ("L", 0.1),
+    LowMedium => ("LM", 0.3),
+    MediumHigh => ("MH", 0.4),
+    High => ("H", 0.5),
+});
+
+metric!(TargetDistribution, "TD", TargetDistribution, {
+    None => ("N", 0.0),
+    Low => ("L", 0.25),
+    Medium => ("M", 0.75),
+    High => ("H", 1.0),
+});
+
+/// Confidentiality (`CR`), Integrity (`IR`), or Availability (`AR`) Requirement.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SecurityRequirement {
+    Low,
+    Medium,
+    High,
+}
+
+impl SecurityRequirement {
+    fn score(self) -> f64 {
+        match self {
+            Self::Low => 0.5,
+            Self::Medium => 1.0,
+            Self::High => 1.51,
+        }
+    }
+}
+
+impl Display for SecurityRequirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Low => "L",
+            Self::Medium => "M",
+            Self::High => "H",
+        })
+    }
+}
+
+/// Parses the value only (e.g. `H`), as the same values are used for `CR`, `IR` and `AR`.
+impl FromStr for SecurityRequirement {
+    type Err = Cvss2Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Low),
+            "M" => Ok(Self::Medium),
+            "H" => Ok(Self::High),
+            _ => Err(Self::Err::SecurityRequirement),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The example of CVE-2002-0392 from the CVSS v2 documentation, section 3.3.1
+    #[test]
+    fn example() {
+        let vector = Cvss2Vector::from_str(
+            "AV:N/AC:L/Au:N/C:N/I:N/A:C/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:H",
+        )
+        .unwrap();
+        assert_eq!(vector.score(), Score::new(7.8));
+        assert_eq!(vector.temporal_score(), Some(Score::new(6.4)));
+        assert_eq!(vector.environmental_score(), Some(Score::new(9.2)));
+    }
+
+    #[test]
+    fn notations() {
+        for s in [
+            "AV:N/AC:L/Au:N/C:P/I:P/A:P",
+            "(AV:N/AC:L/Au:N/C:P/I:P/A:P)",
+            "CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P",
+            "AV:N/AC:L/Au:N/C:P/I:P/A:P/E:ND/RL:ND/RC:ND/CDP:ND/TD:ND/CR:ND/IR:ND/AR:ND",
+        ] {
+            let vector = Cvss2Vector::from_str(s).unwrap();
+            assert_eq!(vector.to_string(), "AV:N/AC:L/Au:N/C:P/I:P/A:P", "{s}");
+            assert_eq!(vector.temporal_score(), None);
+            assert_eq!(vector.environmental_score(), None);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let s = "AV:A/AC:M/Au:S/C:C/I:N/A:P/E:POC/RL:TF/RC:UR/CDP:LM/TD:M/CR:L/IR:H/AR:M";
+        let vector = Cvss2Vector::from_str(s).unwrap();
+        assert_eq!(vector.to_string(), s);
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [
+            "AV:N/AC:L/Au:N/C:P/I:P",
+            "AV:N/AC:L/Au:X/C:P/I:P/A:P",
+            "AV:N/AC:L/Au:N/C:P/I:P/A:P/E:X",
+            "AV:N/AC:L/Au:N/C:P/I:P/A:P/CR:X",
+            "AV:N/AC:L/Au:N/C:P/I:P/A:P/FOO:ND",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        ] {
+            assert!(Cvss2Vector::from_str(s).is_err(), "{s}");
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::{
+    AttackComplexity, AttackVector, Availability, Confidentiality, Cvss3Error, Integrity,
+    PrivilegesRequired, Scope, UserInteraction,
+};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The environmental metric group. A metric which is `None` is "Not Defined" (`X`), in which
+/// case the modified metrics fall back to the value of their base metric.
+///
+/// Described in CVSS v3.1 Specification: Section 4:
+/// <https://www.first.org/cvss/specification-document#Environmental-Metrics>
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Cvss3Environmental {
+    pub cr: Option<SecurityRequirement>,
+    pub ir: Option<SecurityRequirement>,
+    pub ar: Option<SecurityRequirement>,
+    pub mav: Option<AttackVector>,
+    pub mac: Option<AttackComplexity>,
+    pub mpr: Option<PrivilegesRequired>,
+    pub mui: Option<UserInteraction>,
+    pub ms: Option<Scope>,
+    pub mc: Option<Confidentiality>,
+    pub mi: Option<Integrity>,
+    pub ma: Option<Availability>,
+}
+
+impl Cvss3Environmental {
+    /// Check if any of the environmental metrics is defined.
+    pub fn is_defined(&self) -> bool {
+        *self != Self::default()
+    }
+}
+
+impl Display for Cvss3Environmental {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(cr) = self.cr {
+            write!(f, "/CR:{cr}")?;
+        }
+        if let Some(ir) = self.ir {
+            write!(f, "/IR:{ir}")?;
+        }
+        if let Some(ar) = self.ar {
+            write!(f, "/AR:{ar}")?;
+        }
+        if let Some(mav) = self.mav {
+            write!(f, "/MAV:{mav}")?;
+        }
+        if let Some(mac) = self.mac {
+            write!(f, "/MAC:{mac}")?;
+        }
+        if let Some(mpr) = self.mpr {
+            write!(f, "/MPR:{mpr}")?;
+        }
+        if let Some(mui) = self.mui {
+            write!(f, "/MUI:{mui}")?;
+        }
+        if let Some(ms) = self.ms {
+            write!(f, "/MS:{ms}")?;
+        }
+        if let Some(mc) = self.mc {
+            write!(f, "/MC:{mc}")?;
+        }
+        if let Some(mi) = self.mi {
+            write!(f, "/MI:{mi}")?;
+        }
+        if let Some(ma) = self.ma {
+            write!(f, "/MA:{ma}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Confidentiality (`CR`), Integrity (`IR`), or Availability (`AR`) Requirement.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SecurityRequirement {
+    Low,
+    Medium,
+    High,
+}
+
+impl SecurityRequirement {
+    pub(crate) fn score(self) -> f64 {
+        match self {
+            Self::Low => 0.5,
+            Self::Medium => 1.0,
+            Self::High => 1.5,
+        }
+    }
+}
+
+impl Display for SecurityRequirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Low => 'L',
+                Self::Medium => 'M',
+                Self::High => 'H',
+            }
+        )
+    }
+}
+
+/// Parses the value only (e.g. `H`), as the same values are used for `CR`, `IR` and `AR`.
+impl FromStr for SecurityRequirement {
+    type Err = Cvss3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Low),
+            "M" => Ok(Self::Medium),
+            "H" => Ok(Self::High),
+            _ => Err(Self::Err::SecurityRequirement),
+        }
+    }
+}
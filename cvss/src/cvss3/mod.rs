@@ -1,12 +1,16 @@
 use crate::cvss3::score::Score;
 use crate::cvss3::severity::Severity;
+use crate::cvss3::vector::Cvss3Vector;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+pub mod environmental;
 pub mod score;
 pub mod severity;
+pub mod temporal;
+pub mod vector;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Cvss3Base {
     pub minor_version: u8,
     pub av: AttackVector,
@@ -130,6 +134,10 @@ pub enum Cvss3Error {
     Confidentiality,
     Integrity,
     Availability,
+    ExploitCodeMaturity,
+    RemediationLevel,
+    ReportConfidence,
+    SecurityRequirement,
     InvalidSeverity { name: String },
 }
 
@@ -139,47 +147,17 @@ impl Display for Cvss3Error {
     }
 }
 
+/// Parses the base metrics of a vector, temporal and environmental metrics must be valid, but
+/// are ignored. Use [`Cvss3Vector`] to retain them.
 impl FromStr for Cvss3Base {
     type Err = Cvss3Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split('/').collect::<Vec<_>>();
-        if parts.len() == 9 && parts[0].starts_with("CVSS:") {
-            let minor_version = if parts[0] == "CVSS:3.1" {
-                1
-            } else if parts[0].starts_with("CVSS:3") {
-                0
-            } else {
-                return Err(Self::Err::MinorVersion);
-            };
-
-            let av = parts[1];
-            let ac = parts[2];
-            let pr = parts[3];
-            let ui = parts[4];
-            let s = parts[5];
-            let c = parts[6];
-            let i = parts[7];
-            let a = parts[8];
-
-            Ok(Cvss3Base {
-                minor_version,
-                av: AttackVector::from_str(av)?,
-                ac: AttackComplexity::from_str(ac)?,
-                pr: PrivilegesRequired::from_str(pr)?,
-                ui: UserInteraction::from_str(ui)?,
-                s: Scope::from_str(s)?,
-                c: Confidentiality::from_str(c)?,
-                i: Integrity::from_str(i)?,
-                a: Availability::from_str(a)?,
-            })
-        } else {
-            Err(Self::Err::Invalid)
-        }
+        Ok(Cvss3Vector::from_str(s)?.base)
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum AttackVector {
     Network,
     Adjacent,
@@ -230,7 +208,7 @@ impl AttackVector {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum AttackComplexity {
     Low,
     High,
@@ -273,7 +251,7 @@ impl FromStr for AttackComplexity {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum PrivilegesRequired {
     None,
     Low,
@@ -332,7 +310,7 @@ impl FromStr for PrivilegesRequired {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum UserInteraction {
     None,
     Required,
@@ -375,7 +353,7 @@ impl FromStr for UserInteraction {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Scope {
     Unchanged,
     Changed,
@@ -415,7 +393,7 @@ impl FromStr for Scope {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Confidentiality {
     None,
     Low,
@@ -462,7 +440,7 @@ impl FromStr for Confidentiality {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Integrity {
     None,
     Low,
@@ -501,7 +479,7 @@ impl FromStr for Integrity {
             return Err(Self::Err::Integrity);
         }
         match s.chars().nth(2) {
-            Some('N') => Ok(Self::Low),
+            Some('N') => Ok(Self::None),
             Some('L') => Ok(Self::Low),
             Some('H') => Ok(Self::High),
             _ => Err(Self::Err::Integrity),
@@ -509,7 +487,7 @@ impl FromStr for Integrity {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Availability {
     None,
     Low,
@@ -549,7 +527,7 @@ impl FromStr for Availability {
         }
 
         match s.chars().nth(2) {
-            Some('N') => Ok(Self::Low),
+            Some('N') => Ok(Self::None),
             Some('L') => Ok(Self::Low),
             Some('H') => Ok(Self::High),
             _ => Err(Self::Err::Availability),
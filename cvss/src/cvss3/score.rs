@@ -67,10 +67,17 @@ impl From<Score> for Severity {
 
 impl FromIterator<Cvss3Base> for Score {
     fn from_iter<I: IntoIterator<Item = Cvss3Base>>(iter: I) -> Self {
+        iter.into_iter().map(|v| v.score()).collect()
+    }
+}
+
+/// The arithmetic mean of the scores, e.g. to aggregate CVSS v3 and v4 scores.
+impl FromIterator<Score> for Score {
+    fn from_iter<I: IntoIterator<Item = Score>>(iter: I) -> Self {
         let mut count: usize = 0;
         let mut sum = 0.0;
         for v in iter {
-            sum += v.score().value();
+            sum += v.value();
             count += 1;
         }
         if count > 0 {
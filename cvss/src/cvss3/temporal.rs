@@ -0,0 +1,183 @@
+use super::Cvss3Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The temporal metric group. A metric which is `None` is "Not Defined" (`X`).
+///
+/// Described in CVSS v3.1 Specification: Section 3:
+/// <https://www.first.org/cvss/specification-document#Temporal-Metrics>
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Cvss3Temporal {
+    pub e: Option<ExploitCodeMaturity>,
+    pub rl: Option<RemediationLevel>,
+    pub rc: Option<ReportConfidence>,
+}
+
+impl Cvss3Temporal {
+    /// Check if any of the temporal metrics is defined.
+    pub fn is_defined(&self) -> bool {
+        self.e.is_some() || self.rl.is_some() || self.rc.is_some()
+    }
+
+    /// The factor to apply to a base score, `1.0` if nothing is defined.
+    pub(crate) fn factor(&self) -> f64 {
+        self.e.map(ExploitCodeMaturity::score).unwrap_or(1.0)
+            * self.rl.map(RemediationLevel::score).unwrap_or(1.0)
+            * self.rc.map(ReportConfidence::score).unwrap_or(1.0)
+    }
+}
+
+impl Display for Cvss3Temporal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(e) = self.e {
+            write!(f, "/E:{e}")?;
+        }
+        if let Some(rl) = self.rl {
+            write!(f, "/RL:{rl}")?;
+        }
+        if let Some(rc) = self.rc {
+            write!(f, "/RC:{rc}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExploitCodeMaturity {
+    Unproven,
+    ProofOfConcept,
+    Functional,
+    High,
+}
+
+impl ExploitCodeMaturity {
+    fn score(self) -> f64 {
+        match self {
+            Self::Unproven => 0.91,
+            Self::ProofOfConcept => 0.94,
+            Self::Functional => 0.97,
+            Self::High => 1.0,
+        }
+    }
+}
+
+impl Display for ExploitCodeMaturity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Unproven => 'U',
+                Self::ProofOfConcept => 'P',
+                Self::Functional => 'F',
+                Self::High => 'H',
+            }
+        )
+    }
+}
+
+impl FromStr for ExploitCodeMaturity {
+    type Err = Cvss3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("E:") {
+            Some("U") => Ok(Self::Unproven),
+            Some("P") => Ok(Self::ProofOfConcept),
+            Some("F") => Ok(Self::Functional),
+            Some("H") => Ok(Self::High),
+            _ => Err(Self::Err::ExploitCodeMaturity),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RemediationLevel {
+    OfficialFix,
+    TemporaryFix,
+    Workaround,
+    Unavailable,
+}
+
+impl RemediationLevel {
+    fn score(self) -> f64 {
+        match self {
+            Self::OfficialFix => 0.95,
+            Self::TemporaryFix => 0.96,
+            Self::Workaround => 0.97,
+            Self::Unavailable => 1.0,
+        }
+    }
+}
+
+impl Display for RemediationLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::OfficialFix => 'O',
+                Self::TemporaryFix => 'T',
+                Self::Workaround => 'W',
+                Self::Unavailable => 'U',
+            }
+        )
+    }
+}
+
+impl FromStr for RemediationLevel {
+    type Err = Cvss3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("RL:") {
+            Some("O") => Ok(Self::OfficialFix),
+            Some("T") => Ok(Self::TemporaryFix),
+            Some("W") => Ok(Self::Workaround),
+            Some("U") => Ok(Self::Unavailable),
+            _ => Err(Self::Err::RemediationLevel),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReportConfidence {
+    Unknown,
+    Reasonable,
+    Confirmed,
+}
+
+impl ReportConfidence {
+    fn score(self) -> f64 {
+        match self {
+            Self::Unknown => 0.92,
+            Self::Reasonable => 0.96,
+            Self::Confirmed => 1.0,
+        }
+    }
+}
+
+impl Display for ReportConfidence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Unknown => 'U',
+                Self::Reasonable => 'R',
+                Self::Confirmed => 'C',
+            }
+        )
+    }
+}
+
+impl FromStr for ReportConfidence {
+    type Err = Cvss3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("RC:") {
+            Some("U") => Ok(Self::Unknown),
+            Some("R") => Ok(Self::Reasonable),
+            Some("C") => Ok(Self::Confirmed),
+            _ => Err(Self::Err::ReportConfidence),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use super::environmental::{Cvss3Environmental, SecurityRequirement};
+use super::score::Score;
+use super::temporal::Cvss3Temporal;
+use super::*;
+
+/// A full CVSS v3 vector, consisting of the base, temporal, and environmental metric groups.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cvss3Vector {
+    pub base: Cvss3Base,
+    pub temporal: Cvss3Temporal,
+    pub environmental: Cvss3Environmental,
+}
+
+impl Cvss3Vector {
+    /// Calculate the Base CVSS score.
+    pub fn score(&self) -> Score {
+        self.base.score()
+    }
+
+    /// Calculate the Temporal score, `None` if no temporal metric is defined.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.2:
+    /// <https://www.first.org/cvss/specification-document#7-2-Temporal-Metrics-Equations>
+    pub fn temporal_score(&self) -> Option<Score> {
+        self.temporal
+            .is_defined()
+            .then(|| Score::new(self.base.score().value() * self.temporal.factor()).roundup())
+    }
+
+    /// Calculate the Environmental score, `None` if no environmental metric is defined.
+    ///
+    /// Described in CVSS v3.1 Specification: Section 7.3:
+    /// <https://www.first.org/cvss/specification-document#7-3-Environmental-Metrics-Equations>
+    pub fn environmental_score(&self) -> Option<Score> {
+        if !self.environmental.is_defined() {
+            return None;
+        }
+
+        let base = &self.base;
+        let env = &self.environmental;
+
+        let requirement = |r: Option<SecurityRequirement>| r.map(|r| r.score()).unwrap_or(1.0);
+        let cr = requirement(env.cr);
+        let ir = requirement(env.ir);
+        let ar = requirement(env.ar);
+
+        let scope_changed = env.ms.unwrap_or(base.s).is_changed();
+
+        let mc = env.mc.unwrap_or(base.c).score();
+        let mi = env.mi.unwrap_or(base.i).score();
+        let ma = env.ma.unwrap_or(base.a).score();
+        let miss = (1.0 - ((1.0 - cr * mc) * (1.0 - ir * mi) * (1.0 - ar * ma))).min(0.915);
+
+        let impact = if !scope_changed {
+            6.42 * miss
+        } else if base.minor_version == 1 {
+            (7.52 * (miss - 0.029)) - (3.25 * (miss * 0.9731 - 0.02).powf(13.0))
+        } else {
+            (7.52 * (miss - 0.029)) - (3.25 * (miss - 0.02).powf(15.0))
+        };
+
+        let exploitability = 8.22
+            * env.mav.unwrap_or(base.av).score()
+            * env.mac.unwrap_or(base.ac).score()
+            * env.mpr.unwrap_or(base.pr).scoped_score(scope_changed)
+            * env.mui.unwrap_or(base.ui).score();
+
+        if impact <= 0.0 {
+            return Some(Score::new(0.0));
+        }
+
+        let score = if !scope_changed {
+            (impact + exploitability).min(10.0)
+        } else {
+            (1.08 * (impact + exploitability)).min(10.0)
+        };
+
+        Some(Score::new(Score::new(score).roundup().value() * self.temporal.factor()).roundup())
+    }
+}
+
+impl From<Cvss3Base> for Cvss3Vector {
+    fn from(base: Cvss3Base) -> Self {
+        Self {
+            base,
+            temporal: Default::default(),
+            environmental: Default::default(),
+        }
+    }
+}
+
+/// Serializes the base metrics, followed by all defined temporal and environmental metrics.
+impl Display for Cvss3Vector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.base, self.temporal, self.environmental)
+    }
+}
+
+impl FromStr for Cvss3Vector {
+    type Err = Cvss3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split('/').collect::<Vec<_>>();
+        if parts.len() < 9 || !parts[0].starts_with("CVSS:") {
+            return Err(Self::Err::Invalid);
+        }
+
+        let minor_version = if parts[0] == "CVSS:3.1" {
+            1
+        } else if parts[0].starts_with("CVSS:3") {
+            0
+        } else {
+            return Err(Self::Err::MinorVersion);
+        };
+
+        let base = Cvss3Base {
+            minor_version,
+            av: AttackVector::from_str(parts[1])?,
+            ac: AttackComplexity::from_str(parts[2])?,
+            pr: PrivilegesRequired::from_str(parts[3])?,
+            ui: UserInteraction::from_str(parts[4])?,
+            s: Scope::from_str(parts[5])?,
+            c: Confidentiality::from_str(parts[6])?,
+            i: Integrity::from_str(parts[7])?,
+            a: Availability::from_str(parts[8])?,
+        };
+
+        let mut temporal = Cvss3Temporal::default();
+        let mut environmental = Cvss3Environmental::default();
+
+        for part in &parts[9..] {
+            let (metric, value) = part.split_once(':').ok_or(Self::Err::Invalid)?;
+            let defined = value != "X";
+            // modified metrics use the same values as their base metric
+            let modified = &part[1..];
+
+            match metric {
+                "E" => temporal.e = parse(defined, part)?,
+                "RL" => temporal.rl = parse(defined, part)?,
+                "RC" => temporal.rc = parse(defined, part)?,
+                "CR" => environmental.cr = parse(defined, value)?,
+                "IR" => environmental.ir = parse(defined, value)?,
+                "AR" => environmental.ar = parse(defined, value)?,
+                "MAV" => environmental.mav = parse(defined, modified)?,
+                "MAC" => environmental.mac = parse(defined, modified)?,
+                "MPR" => environmental.mpr = parse(defined, modified)?,
+                "MUI" => environmental.mui = parse(defined, modified)?,
+                "MS" => environmental.ms = parse(defined, modified)?,
+                "MC" => environmental.mc = parse(defined, modified)?,
+                "MI" => environmental.mi = parse(defined, modified)?,
+                "MA" => environmental.ma = parse(defined, modified)?,
+                _ => return Err(Self::Err::Invalid),
+            }
+        }
+
+        Ok(Self {
+            base,
+            temporal,
+            environmental,
+        })
+    }
+}
+
+/// Parse a metric, unless it is "Not Defined".
+fn parse<T: FromStr<Err = Cvss3Error>>(defined: bool, s: &str) -> Result<Option<T>, Cvss3Error> {
+    defined.then(|| T::from_str(s)).transpose()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base_only() {
+        let vector = Cvss3Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+        assert_eq!(vector.score(), Score::new(9.8));
+        assert_eq!(vector.temporal_score(), None);
+        assert_eq!(vector.environmental_score(), None);
+        assert_eq!(
+            vector.to_string(),
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+        );
+    }
+
+    #[test]
+    fn temporal() {
+        let vector =
+            Cvss3Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C")
+                .unwrap();
+        assert_eq!(vector.score(), Score::new(9.8));
+        assert_eq!(vector.temporal_score(), Some(Score::new(8.8)));
+        assert_eq!(vector.environmental_score(), None);
+
+        // the base vector ignores the temporal metrics
+        let base = Cvss3Base::from_str(&vector.to_string()).unwrap();
+        assert_eq!(base, vector.base);
+    }
+
+    #[test]
+    fn environmental() {
+        let vector = Cvss3Vector::from_str(
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C/CR:H/IR:H/AR:H/MAV:L",
+        )
+        .unwrap();
+        assert_eq!(vector.environmental_score(), Some(Score::new(7.6)));
+
+        // scope changed through the modified scope
+        let vector =
+            Cvss3Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:U/C:L/I:L/A:N/MS:C").unwrap();
+        assert_eq!(vector.score(), Score::new(5.4));
+        assert_eq!(vector.environmental_score(), Some(Score::new(6.4)));
+
+        // no impact
+        let vector =
+            Cvss3Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:L/A:L/MC:N/MI:N/MA:N")
+                .unwrap();
+        assert_eq!(vector.environmental_score(), Some(Score::new(0.0)));
+    }
+
+    #[test]
+    fn no_integrity_availability() {
+        let vector = Cvss3Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N").unwrap();
+        assert_eq!(vector.base.i, Integrity::None);
+        assert_eq!(vector.base.a, Availability::None);
+        assert_eq!(vector.score(), Score::new(7.5));
+        assert_eq!(
+            vector.to_string(),
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N"
+        );
+    }
+
+    #[test]
+    fn not_defined() {
+        let vector =
+            Cvss3Vector::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X/CR:X/MAV:X")
+                .unwrap();
+        assert_eq!(vector.temporal, Cvss3Temporal::default());
+        assert_eq!(vector.environmental, Cvss3Environmental::default());
+        assert_eq!(
+            vector.to_string(),
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let s = "CVSS:3.0/AV:L/AC:H/PR:H/UI:R/S:C/C:L/I:H/A:H/E:U/RL:W/RC:R/CR:L/IR:M/AR:H/MAV:P/MAC:L/MPR:N/MUI:N/MS:U/MC:N/MI:L/MA:H";
+        let vector = Cvss3Vector::from_str(s).unwrap();
+        assert_eq!(vector.to_string(), s);
+    }
+
+    #[test]
+    fn invalid() {
+        for s in [
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:Q",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/CR:N",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:Q",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/FOO:X",
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E",
+        ] {
+            assert!(Cvss3Vector::from_str(s).is_err(), "{s}");
+        }
+    }
+}
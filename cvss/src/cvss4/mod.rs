@@ -1,6 +1,8 @@
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+pub mod score;
+
 #[derive(Debug, Copy, Clone)]
 pub struct Cvss4Base {
     pub minor_version: u8,
@@ -0,0 +1,791 @@
+use super::*;
+use crate::cvss3::score::Score;
+use crate::cvss3::severity::Severity;
+
+/// The distance between two adjacent metric values.
+const STEP: f64 = 0.1;
+
+/// Compensate for floating point errors when rounding to one decimal.
+const EPSILON: f64 = 0.000_001;
+
+impl Cvss4Base {
+    /// Calculate the CVSS-B score (base metrics only).
+    ///
+    /// Described in CVSS v4.0 Specification: Section 8:
+    /// <https://www.first.org/cvss/v4.0/specification-document#CVSS-v4-0-Scoring>
+    ///
+    /// > CVSS v4.0 scores are computed by first assigning the vector to a MacroVector,
+    /// > looking up the score of that MacroVector, and then interpolating between the
+    /// > MacroVector score and the scores of the next lower MacroVectors, based on the
+    /// > severity distance of the vector within its MacroVector.
+    ///
+    /// This follows the FIRST reference implementation:
+    /// <https://github.com/FIRSTdotorg/cvss-v4-calculator>
+    pub fn score(&self) -> Score {
+        if matches!(self.vc, VulnerableConfidentiality::None)
+            && matches!(self.vi, VulnerableIntegrity::None)
+            && matches!(self.va, VulnerableAvailability::None)
+            && matches!(self.sc, SubsequentConfidentiality::Negligible)
+            && matches!(self.si, SubsequentIntegrity::None)
+            && matches!(self.sa, SubsequentAvailability::None)
+        {
+            return Score::new(0.0);
+        }
+
+        let macro_vector = self.macro_vector();
+        let Some(value) = macro_vector.lookup() else {
+            // all macro vectors of base metrics are part of the lookup table
+            return Score::new(0.0);
+        };
+
+        let MacroVector {
+            eq1,
+            eq2,
+            eq3,
+            eq4,
+            eq5,
+            eq6,
+        } = macro_vector;
+
+        let lower_eq1 = MacroVector {
+            eq1: eq1 + 1,
+            ..macro_vector
+        }
+        .lookup();
+        let lower_eq2 = MacroVector {
+            eq2: eq2 + 1,
+            ..macro_vector
+        }
+        .lookup();
+        let lower_eq3eq6 = match (eq3, eq6) {
+            (0, 1) | (1, 1) => MacroVector {
+                eq3: eq3 + 1,
+                ..macro_vector
+            }
+            .lookup(),
+            (1, 0) => MacroVector {
+                eq6: eq6 + 1,
+                ..macro_vector
+            }
+            .lookup(),
+            (0, 0) => {
+                // there are two paths, take the one with the higher score
+                let left = MacroVector {
+                    eq6: eq6 + 1,
+                    ..macro_vector
+                }
+                .lookup();
+                let right = MacroVector {
+                    eq3: eq3 + 1,
+                    ..macro_vector
+                }
+                .lookup();
+                match (left, right) {
+                    (Some(left), Some(right)) if left > right => Some(left),
+                    _ => right,
+                }
+            }
+            _ => MacroVector {
+                eq3: eq3 + 1,
+                eq6: eq6 + 1,
+                ..macro_vector
+            }
+            .lookup(),
+        };
+        let lower_eq4 = MacroVector {
+            eq4: eq4 + 1,
+            ..macro_vector
+        }
+        .lookup();
+        let lower_eq5 = MacroVector {
+            eq5: eq5 + 1,
+            ..macro_vector
+        }
+        .lookup();
+
+        let levels = self.levels();
+        let max = macro_vector
+            .max_vectors()
+            .into_iter()
+            .find(|max| {
+                levels
+                    .iter()
+                    .zip(max)
+                    .all(|(level, max)| level - max >= 0.0)
+            })
+            .unwrap_or(levels);
+        let distance =
+            |range: std::ops::Range<usize>| -> f64 { range.map(|i| levels[i] - max[i]).sum() };
+
+        let mut existing_lower = 0;
+        let mut normalized = 0.0;
+
+        for (lower, distance, max_severity) in [
+            (lower_eq1, distance(0..3), macro_vector.max_severity_eq1()),
+            (lower_eq2, distance(3..5), macro_vector.max_severity_eq2()),
+            (
+                lower_eq3eq6,
+                distance(5..8),
+                macro_vector.max_severity_eq3eq6(),
+            ),
+            (lower_eq4, distance(8..11), macro_vector.max_severity_eq4()),
+        ] {
+            if let Some(lower) = lower {
+                existing_lower += 1;
+                normalized += (value - lower) * (distance / (max_severity * STEP));
+            }
+        }
+
+        // the severity distance of the exploit maturity is always zero for the base metrics,
+        // but an existing lower macro vector still counts
+        if lower_eq5.is_some() {
+            existing_lower += 1;
+        }
+
+        let mean_distance = if existing_lower == 0 {
+            0.0
+        } else {
+            normalized / existing_lower as f64
+        };
+
+        let value = (value - mean_distance).clamp(0.0, 10.0);
+
+        Score::new(((value + EPSILON) * 10.0).round() / 10.0)
+    }
+
+    /// Calculate Base CVSS `Severity` according to the
+    /// Qualitative Severity Rating Scale (i.e. Low / Medium / High / Critical)
+    ///
+    /// Described in CVSS v4.0 Specification: Section 6:
+    /// <https://www.first.org/cvss/v4.0/specification-document#Qualitative-Severity-Rating-Scale>
+    pub fn severity(&self) -> Severity {
+        self.score().severity()
+    }
+
+    /// Evaluate the MacroVector of the base metrics.
+    ///
+    /// The security requirements (`CR`, `IR`, `AR`) are not part of the base metrics and default
+    /// to `High`, the exploit maturity (`E`) defaults to `Attacked`.
+    pub fn macro_vector(&self) -> MacroVector {
+        use AttackVector as AV;
+        use PrivilegesRequired as PR;
+        use UserInteraction as UI;
+
+        let eq1 = match (self.av, self.pr, self.ui) {
+            (AV::Network, PR::None, UI::None) => 0,
+            (AV::Physical, _, _) => 2,
+            (AV::Network, _, _) | (_, PR::None, _) | (_, _, UI::None) => 1,
+            _ => 2,
+        };
+
+        let eq2 = match (self.ac, self.at) {
+            (AttackComplexity::Low, AttackRequirements::None) => 0,
+            _ => 1,
+        };
+
+        let vc = matches!(self.vc, VulnerableConfidentiality::High);
+        let vi = matches!(self.vi, VulnerableIntegrity::High);
+        let va = matches!(self.va, VulnerableAvailability::High);
+
+        let eq3 = if vc && vi {
+            0
+        } else if vc || vi || va {
+            1
+        } else {
+            2
+        };
+
+        let eq4 = if matches!(self.sc, SubsequentConfidentiality::High)
+            || matches!(self.si, SubsequentIntegrity::High)
+            || matches!(self.sa, SubsequentAvailability::High)
+        {
+            1
+        } else {
+            2
+        };
+
+        let eq5 = 0;
+
+        let eq6 = if vc || vi || va { 0 } else { 1 };
+
+        MacroVector {
+            eq1,
+            eq2,
+            eq3,
+            eq4,
+            eq5,
+            eq6,
+        }
+    }
+
+    /// The levels of the metrics, in the order: AV, PR, UI, AC, AT, VC, VI, VA, SC, SI, SA
+    fn levels(&self) -> [f64; 11] {
+        [
+            self.av.level(),
+            self.pr.level(),
+            self.ui.level(),
+            self.ac.level(),
+            self.at.level(),
+            self.vc.level(),
+            self.vi.level(),
+            self.va.level(),
+            self.sc.level(),
+            self.si.level(),
+            self.sa.level(),
+        ]
+    }
+}
+
+/// A group of vectors with a similar severity, identified by the values of the six
+/// equivalence sets (EQ1 - EQ6).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MacroVector {
+    pub eq1: u8,
+    pub eq2: u8,
+    pub eq3: u8,
+    pub eq4: u8,
+    pub eq5: u8,
+    pub eq6: u8,
+}
+
+impl Display for MacroVector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}{}",
+            self.eq1, self.eq2, self.eq3, self.eq4, self.eq5, self.eq6
+        )
+    }
+}
+
+impl MacroVector {
+    /// Look up the score of the macro vector, if it exists.
+    pub fn lookup(&self) -> Option<f64> {
+        let key = self.to_string();
+        LOOKUP
+            .binary_search_by_key(&key.as_str(), |(key, _)| key)
+            .ok()
+            .map(|index| LOOKUP[index].1)
+    }
+
+    /// The highest severity vectors of the macro vector, as levels of the base metrics.
+    ///
+    /// Max vectors which require non-default security requirements can never be reached by the
+    /// base metrics and are left out.
+    fn max_vectors(&self) -> Vec<[f64; 11]> {
+        let eq1: &[[f64; 3]] = match self.eq1 {
+            0 => &[[0.0, 0.0, 0.0]],
+            1 => &[[0.1, 0.0, 0.0], [0.0, 0.1, 0.0], [0.0, 0.0, 0.1]],
+            _ => &[[0.3, 0.0, 0.0], [0.1, 0.1, 0.1]],
+        };
+        let eq2: &[[f64; 2]] = match self.eq2 {
+            0 => &[[0.0, 0.0]],
+            _ => &[[0.1, 0.0], [0.0, 0.1]],
+        };
+        let eq3eq6: &[[f64; 3]] = match (self.eq3, self.eq6) {
+            (0, 0) => &[[0.0, 0.0, 0.0]],
+            (1, 0) => &[[0.1, 0.0, 0.0], [0.0, 0.1, 0.0]],
+            (2, 1) => &[[0.1, 0.1, 0.1]],
+            _ => &[],
+        };
+        let eq4: &[[f64; 3]] = match self.eq4 {
+            0 => &[[0.1, 0.0, 0.0]],
+            1 => &[[0.1, 0.1, 0.1]],
+            _ => &[[0.2, 0.2, 0.2]],
+        };
+
+        let mut result = Vec::new();
+        for eq1 in eq1 {
+            for eq2 in eq2 {
+                for eq3eq6 in eq3eq6 {
+                    for eq4 in eq4 {
+                        result.push([
+                            eq1[0], eq1[1], eq1[2], eq2[0], eq2[1], eq3eq6[0], eq3eq6[1],
+                            eq3eq6[2], eq4[0], eq4[1], eq4[2],
+                        ]);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn max_severity_eq1(&self) -> f64 {
+        match self.eq1 {
+            0 => 1.0,
+            1 => 4.0,
+            _ => 5.0,
+        }
+    }
+
+    fn max_severity_eq2(&self) -> f64 {
+        match self.eq2 {
+            0 => 1.0,
+            _ => 2.0,
+        }
+    }
+
+    fn max_severity_eq3eq6(&self) -> f64 {
+        match (self.eq3, self.eq6) {
+            (0, 0) => 7.0,
+            (0, _) => 6.0,
+            (1, _) => 8.0,
+            _ => 10.0,
+        }
+    }
+
+    fn max_severity_eq4(&self) -> f64 {
+        match self.eq4 {
+            0 => 6.0,
+            1 => 5.0,
+            _ => 4.0,
+        }
+    }
+}
+
+impl AttackVector {
+    fn level(self) -> f64 {
+        match self {
+            Self::Network => 0.0,
+            Self::Adjacent => 0.1,
+            Self::Local => 0.2,
+            Self::Physical => 0.3,
+        }
+    }
+}
+
+impl PrivilegesRequired {
+    fn level(self) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::Low => 0.1,
+            Self::High => 0.2,
+        }
+    }
+}
+
+impl UserInteraction {
+    fn level(self) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::Passive => 0.1,
+            Self::Active => 0.2,
+        }
+    }
+}
+
+impl AttackComplexity {
+    fn level(self) -> f64 {
+        match self {
+            Self::Low => 0.0,
+            Self::High => 0.1,
+        }
+    }
+}
+
+impl AttackRequirements {
+    fn level(self) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::Present => 0.1,
+        }
+    }
+}
+
+macro_rules! impact_level {
+    ($($t:ty),*) => {
+        $(
+            impl $t {
+                fn level(self) -> f64 {
+                    match self {
+                        Self::High => 0.0,
+                        Self::Low => 0.1,
+                        Self::None => 0.2,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impact_level!(
+    VulnerableConfidentiality,
+    VulnerableIntegrity,
+    VulnerableAvailability
+);
+
+impl SubsequentConfidentiality {
+    fn level(self) -> f64 {
+        match self {
+            Self::High => 0.1,
+            Self::Low => 0.2,
+            Self::Negligible => 0.3,
+        }
+    }
+}
+
+macro_rules! subsequent_impact_level {
+    ($($t:ty),*) => {
+        $(
+            impl $t {
+                fn level(self) -> f64 {
+                    match self {
+                        Self::High => 0.1,
+                        Self::Low => 0.2,
+                        Self::None => 0.3,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+subsequent_impact_level!(SubsequentIntegrity, SubsequentAvailability);
+
+/// MacroVector scores, sorted by key.
+///
+/// Taken from the FIRST reference implementation:
+/// <https://github.com/FIRSTdotorg/cvss-v4-calculator/blob/main/cvss_lookup.js>
+const LOOKUP: [(&str, f64); 270] = [
+    ("000000", 10.0),
+    ("000001", 9.9),
+    ("000010", 9.8),
+    ("000011", 9.5),
+    ("000020", 9.5),
+    ("000021", 9.2),
+    ("000100", 10.0),
+    ("000101", 9.6),
+    ("000110", 9.3),
+    ("000111", 8.7),
+    ("000120", 9.1),
+    ("000121", 8.1),
+    ("000200", 9.3),
+    ("000201", 9.0),
+    ("000210", 8.9),
+    ("000211", 8.0),
+    ("000220", 8.1),
+    ("000221", 6.8),
+    ("001000", 9.8),
+    ("001001", 9.5),
+    ("001010", 9.5),
+    ("001011", 9.2),
+    ("001020", 9.0),
+    ("001021", 8.4),
+    ("001100", 9.3),
+    ("001101", 9.2),
+    ("001110", 8.9),
+    ("001111", 8.1),
+    ("001120", 8.1),
+    ("001121", 6.5),
+    ("001200", 8.8),
+    ("001201", 8.0),
+    ("001210", 7.8),
+    ("001211", 7.0),
+    ("001220", 6.9),
+    ("001221", 4.8),
+    ("002001", 9.2),
+    ("002011", 8.2),
+    ("002021", 7.2),
+    ("002101", 7.9),
+    ("002111", 6.9),
+    ("002121", 5.0),
+    ("002201", 6.9),
+    ("002211", 5.5),
+    ("002221", 2.7),
+    ("010000", 9.9),
+    ("010001", 9.7),
+    ("010010", 9.5),
+    ("010011", 9.2),
+    ("010020", 9.2),
+    ("010021", 8.5),
+    ("010100", 9.5),
+    ("010101", 9.1),
+    ("010110", 9.0),
+    ("010111", 8.3),
+    ("010120", 8.4),
+    ("010121", 7.1),
+    ("010200", 9.2),
+    ("010201", 8.1),
+    ("010210", 8.2),
+    ("010211", 7.1),
+    ("010220", 7.2),
+    ("010221", 5.3),
+    ("011000", 9.5),
+    ("011001", 9.3),
+    ("011010", 9.2),
+    ("011011", 8.5),
+    ("011020", 8.5),
+    ("011021", 7.3),
+    ("011100", 9.2),
+    ("011101", 8.2),
+    ("011110", 8.0),
+    ("011111", 7.2),
+    ("011120", 7.0),
+    ("011121", 5.9),
+    ("011200", 8.4),
+    ("011201", 7.0),
+    ("011210", 7.1),
+    ("011211", 5.2),
+    ("011220", 5.0),
+    ("011221", 3.0),
+    ("012001", 8.6),
+    ("012011", 7.5),
+    ("012021", 5.2),
+    ("012101", 7.1),
+    ("012111", 5.2),
+    ("012121", 2.9),
+    ("012201", 6.3),
+    ("012211", 2.9),
+    ("012221", 1.7),
+    ("100000", 9.8),
+    ("100001", 9.5),
+    ("100010", 9.4),
+    ("100011", 8.7),
+    ("100020", 9.1),
+    ("100021", 8.1),
+    ("100100", 9.4),
+    ("100101", 8.9),
+    ("100110", 8.6),
+    ("100111", 7.4),
+    ("100120", 7.7),
+    ("100121", 6.4),
+    ("100200", 8.7),
+    ("100201", 7.5),
+    ("100210", 7.4),
+    ("100211", 6.3),
+    ("100220", 6.3),
+    ("100221", 4.9),
+    ("101000", 9.4),
+    ("101001", 8.9),
+    ("101010", 8.8),
+    ("101011", 7.7),
+    ("101020", 7.6),
+    ("101021", 6.7),
+    ("101100", 8.6),
+    ("101101", 7.6),
+    ("101110", 7.4),
+    ("101111", 5.8),
+    ("101120", 5.9),
+    ("101121", 5.0),
+    ("101200", 7.2),
+    ("101201", 5.7),
+    ("101210", 5.7),
+    ("101211", 5.2),
+    ("101220", 5.2),
+    ("101221", 2.5),
+    ("102001", 8.3),
+    ("102011", 7.0),
+    ("102021", 5.4),
+    ("102101", 6.5),
+    ("102111", 5.8),
+    ("102121", 2.6),
+    ("102201", 5.3),
+    ("102211", 2.1),
+    ("102221", 1.3),
+    ("110000", 9.5),
+    ("110001", 9.0),
+    ("110010", 8.8),
+    ("110011", 7.6),
+    ("110020", 7.6),
+    ("110021", 7.0),
+    ("110100", 9.0),
+    ("110101", 7.7),
+    ("110110", 7.5),
+    ("110111", 6.2),
+    ("110120", 6.1),
+    ("110121", 5.3),
+    ("110200", 7.7),
+    ("110201", 6.6),
+    ("110210", 6.8),
+    ("110211", 5.9),
+    ("110220", 5.2),
+    ("110221", 3.0),
+    ("111000", 8.9),
+    ("111001", 7.8),
+    ("111010", 7.6),
+    ("111011", 6.7),
+    ("111020", 6.2),
+    ("111021", 5.8),
+    ("111100", 7.4),
+    ("111101", 5.9),
+    ("111110", 5.7),
+    ("111111", 5.7),
+    ("111120", 4.7),
+    ("111121", 2.3),
+    ("111200", 6.1),
+    ("111201", 5.2),
+    ("111210", 5.7),
+    ("111211", 2.9),
+    ("111220", 2.4),
+    ("111221", 1.6),
+    ("112001", 7.1),
+    ("112011", 5.9),
+    ("112021", 3.0),
+    ("112101", 5.8),
+    ("112111", 2.6),
+    ("112121", 1.5),
+    ("112201", 2.3),
+    ("112211", 1.3),
+    ("112221", 0.6),
+    ("200000", 9.3),
+    ("200001", 8.7),
+    ("200010", 8.6),
+    ("200011", 7.2),
+    ("200020", 7.5),
+    ("200021", 5.8),
+    ("200100", 8.6),
+    ("200101", 7.4),
+    ("200110", 7.4),
+    ("200111", 6.1),
+    ("200120", 5.6),
+    ("200121", 3.4),
+    ("200200", 7.0),
+    ("200201", 5.4),
+    ("200210", 5.2),
+    ("200211", 4.0),
+    ("200220", 4.0),
+    ("200221", 2.2),
+    ("201000", 8.5),
+    ("201001", 7.5),
+    ("201010", 7.4),
+    ("201011", 5.5),
+    ("201020", 6.2),
+    ("201021", 5.1),
+    ("201100", 7.2),
+    ("201101", 5.7),
+    ("201110", 5.5),
+    ("201111", 4.1),
+    ("201120", 4.6),
+    ("201121", 1.9),
+    ("201200", 5.3),
+    ("201201", 3.6),
+    ("201210", 3.4),
+    ("201211", 1.9),
+    ("201220", 1.9),
+    ("201221", 0.8),
+    ("202001", 6.4),
+    ("202011", 5.1),
+    ("202021", 2.0),
+    ("202101", 4.7),
+    ("202111", 2.1),
+    ("202121", 1.1),
+    ("202201", 2.4),
+    ("202211", 0.9),
+    ("202221", 0.4),
+    ("210000", 8.8),
+    ("210001", 7.5),
+    ("210010", 7.3),
+    ("210011", 5.3),
+    ("210020", 6.0),
+    ("210021", 5.0),
+    ("210100", 7.3),
+    ("210101", 5.5),
+    ("210110", 5.9),
+    ("210111", 4.0),
+    ("210120", 4.1),
+    ("210121", 2.0),
+    ("210200", 5.4),
+    ("210201", 4.3),
+    ("210210", 4.5),
+    ("210211", 2.2),
+    ("210220", 2.0),
+    ("210221", 1.1),
+    ("211000", 7.5),
+    ("211001", 5.5),
+    ("211010", 5.8),
+    ("211011", 4.5),
+    ("211020", 4.0),
+    ("211021", 2.1),
+    ("211100", 6.1),
+    ("211101", 5.1),
+    ("211110", 4.8),
+    ("211111", 1.8),
+    ("211120", 2.0),
+    ("211121", 0.9),
+    ("211200", 4.6),
+    ("211201", 1.8),
+    ("211210", 1.7),
+    ("211211", 0.7),
+    ("211220", 0.8),
+    ("211221", 0.2),
+    ("212001", 5.3),
+    ("212011", 2.4),
+    ("212021", 1.4),
+    ("212101", 2.4),
+    ("212111", 1.2),
+    ("212121", 0.5),
+    ("212201", 1.0),
+    ("212211", 0.3),
+    ("212221", 0.1),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    fn score(vector: &str) -> f64 {
+        Cvss4Base::from_str(vector).unwrap().score().value()
+    }
+
+    #[test]
+    fn lookup_sorted() {
+        assert!(LOOKUP.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn macro_vector() {
+        let cvss4 =
+            Cvss4Base::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:A/VC:N/VI:N/VA:N/SC:L/SI:L/SA:N")
+                .unwrap();
+        assert_eq!(cvss4.macro_vector().to_string(), "102201");
+    }
+
+    #[test]
+    fn scores() {
+        for (vector, expected) in [
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H",
+                10.0,
+            ),
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+                9.3,
+            ),
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:L/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+                8.7,
+            ),
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:H/SC:N/SI:N/SA:N",
+                8.7,
+            ),
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N",
+                6.9,
+            ),
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:P/VC:N/VI:N/VA:N/SC:L/SI:L/SA:N",
+                5.3,
+            ),
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:A/VC:N/VI:N/VA:N/SC:L/SI:L/SA:N",
+                5.1,
+            ),
+            (
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+                0.0,
+            ),
+        ] {
+            assert_eq!(score(vector), expected, "{vector}");
+        }
+    }
+
+    #[test]
+    fn severity() {
+        let cvss4 =
+            Cvss4Base::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N")
+                .unwrap();
+        assert_eq!(cvss4.severity(), Severity::Critical);
+    }
+}
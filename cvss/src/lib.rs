@@ -1,12 +1,15 @@
+use crate::cvss2::{Cvss2Base, Cvss2Error};
 use crate::cvss3::{Cvss3Base, Cvss3Error};
 use crate::cvss4::{Cvss4Base, Cvss4Error};
 use std::str::FromStr;
 
+pub mod cvss2;
 pub mod cvss3;
 pub mod cvss4;
 
 #[derive(Debug, Copy, Clone)]
 pub enum CvssBase {
+    Cvss2(Cvss2Base),
     Cvss3(Cvss3Base),
     Cvss4(Cvss4Base),
 }
@@ -14,10 +17,17 @@ pub enum CvssBase {
 #[derive(Debug, Clone)]
 pub enum CvssError {
     MajorVersion,
+    Cvss2(Cvss2Error),
     Cvss3(Cvss3Error),
     Cvss4(Cvss4Error),
 }
 
+impl From<Cvss2Error> for CvssError {
+    fn from(value: Cvss2Error) -> Self {
+        Self::Cvss2(value)
+    }
+}
+
 impl From<Cvss3Error> for CvssError {
     fn from(value: Cvss3Error) -> Self {
         Self::Cvss3(value)
@@ -34,7 +44,10 @@ impl FromStr for CvssBase {
     type Err = CvssError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.starts_with("CVSS:3") {
+        if s.starts_with("CVSS:2") || s.starts_with("AV:") || s.starts_with("(AV:") {
+            // CVSS v2 vectors usually come without a version prefix
+            Ok(CvssBase::Cvss2(Cvss2Base::from_str(s)?))
+        } else if s.starts_with("CVSS:3") {
             Ok(CvssBase::Cvss3(Cvss3Base::from_str(s)?))
         } else if s.starts_with("CVSS:4") {
             Ok(CvssBase::Cvss4(Cvss4Base::from_str(s)?))
@@ -1,4 +1,6 @@
-use crate::{advisory_vulnerability, cvss3, labels::Labels, organization, vulnerability};
+use crate::{
+    advisory_vulnerability, cvss2, cvss3, cvss4, labels::Labels, organization, vulnerability,
+};
 use async_graphql::*;
 use sea_orm::{entity::prelude::*, sea_query::IntoCondition, Condition};
 use std::sync::Arc;
@@ -70,9 +72,15 @@ pub enum Relation {
         to = "super::organization::Column::Id")]
     Issuer,
 
+    #[sea_orm(has_many = "super::cvss2::Entity")]
+    Cvss2,
+
     #[sea_orm(has_many = "super::cvss3::Entity")]
     Cvss3,
 
+    #[sea_orm(has_many = "super::cvss4::Entity")]
+    Cvss4,
+
     #[sea_orm(has_many = "super::advisory_vulnerability::Entity")]
     AdvisoryVulnerability,
 }
@@ -105,12 +113,24 @@ impl Related<advisory_vulnerability::Entity> for Entity {
     }
 }
 
+impl Related<cvss2::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Cvss2.def()
+    }
+}
+
 impl Related<cvss3::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Cvss3.def()
     }
 }
 
+impl Related<cvss4::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Cvss4.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 
 impl TryFilterForId for Entity {
@@ -1,4 +1,4 @@
-use crate::{advisory, cvss3, purl_status, vulnerability};
+use crate::{advisory, cvss2, cvss3, purl_status, vulnerability};
 use sea_orm::{entity::prelude::*, LinkDef};
 use time::OffsetDateTime;
 
@@ -32,6 +32,9 @@ pub enum Relation {
         to = "super::vulnerability::Column::Id")]
     Vulnerability,
 
+    #[sea_orm(has_many = "super::cvss2::Entity")]
+    Cvss2,
+
     #[sea_orm(has_many = "super::cvss3::Entity")]
     Cvss3,
 
@@ -51,6 +54,12 @@ impl Related<vulnerability::Entity> for Entity {
     }
 }
 
+impl Related<cvss2::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Cvss2.def()
+    }
+}
+
 impl Related<cvss3::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Cvss3.def()
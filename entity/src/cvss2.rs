@@ -0,0 +1,510 @@
+use crate::{advisory, advisory_vulnerability, cvss3::Severity, vulnerability};
+use sea_orm::entity::prelude::*;
+use trustify_cvss::cvss2::{
+    self,
+    vector::{Cvss2Environmental, Cvss2Temporal, Cvss2Vector},
+};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "cvss2")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub advisory_id: Uuid,
+
+    #[sea_orm(primary_key)]
+    pub vulnerability_id: String,
+
+    #[sea_orm(primary_key)]
+    pub minor_version: i32,
+
+    #[sea_orm(primary_key)]
+    pub idx: i32,
+
+    pub av: AccessVector,
+    pub ac: AccessComplexity,
+    pub au: Authentication,
+    pub c: ConfidentialityImpact,
+    pub i: IntegrityImpact,
+    pub a: AvailabilityImpact,
+
+    pub e: Option<Exploitability>,
+    pub rl: Option<RemediationLevel>,
+    pub rc: Option<ReportConfidence>,
+
+    pub cdp: Option<CollateralDamagePotential>,
+    pub td: Option<TargetDistribution>,
+    pub cr: Option<SecurityRequirement>,
+    pub ir: Option<SecurityRequirement>,
+    pub ar: Option<SecurityRequirement>,
+
+    pub score: f64,
+    pub severity: Severity,
+    pub temporal_score: Option<f64>,
+    pub environmental_score: Option<f64>,
+}
+
+impl From<&Model> for Cvss2Vector {
+    fn from(value: &Model) -> Self {
+        Self {
+            base: cvss2::Cvss2Base {
+                av: value.av.into(),
+                ac: value.ac.into(),
+                au: value.au.into(),
+                c: value.c.into(),
+                i: value.i.into(),
+                a: value.a.into(),
+            },
+            temporal: Cvss2Temporal {
+                e: value.e.map(Into::into),
+                rl: value.rl.map(Into::into),
+                rc: value.rc.map(Into::into),
+            },
+            environmental: Cvss2Environmental {
+                cdp: value.cdp.map(Into::into),
+                td: value.td.map(Into::into),
+                cr: value.cr.map(Into::into),
+                ir: value.ir.map(Into::into),
+                ar: value.ar.map(Into::into),
+            },
+        }
+    }
+}
+
+impl From<Model> for Cvss2Vector {
+    fn from(value: Model) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::advisory::Entity",
+        from = "super::cvss2::Column::AdvisoryId"
+        to = "super::advisory::Column::Id")]
+    Advisory,
+
+    #[sea_orm(
+        belongs_to = "super::vulnerability::Entity",
+        from = "super::cvss2::Column::VulnerabilityId"
+        to = "super::vulnerability::Column::Id")]
+    Vulnerability,
+
+    #[sea_orm(
+        belongs_to = "super::advisory_vulnerability::Entity",
+        from = "(super::cvss2::Column::AdvisoryId, super::cvss2::Column::VulnerabilityId)"
+        to = "(super::advisory_vulnerability::Column::AdvisoryId, super::advisory_vulnerability::Column::VulnerabilityId)")]
+    AdvisoryVulnerability,
+}
+
+impl Related<advisory::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Advisory.def()
+    }
+}
+
+impl Related<vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Vulnerability.def()
+    }
+}
+
+impl Related<advisory_vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AdvisoryVulnerability.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_av")]
+pub enum AccessVector {
+    #[sea_orm(string_value = "l")]
+    Local,
+    #[sea_orm(string_value = "a")]
+    AdjacentNetwork,
+    #[sea_orm(string_value = "n")]
+    Network,
+}
+
+impl From<AccessVector> for cvss2::AccessVector {
+    fn from(value: AccessVector) -> Self {
+        match value {
+            AccessVector::Local => Self::Local,
+            AccessVector::AdjacentNetwork => Self::AdjacentNetwork,
+            AccessVector::Network => Self::Network,
+        }
+    }
+}
+
+impl From<cvss2::AccessVector> for AccessVector {
+    fn from(value: cvss2::AccessVector) -> Self {
+        match value {
+            cvss2::AccessVector::Local => Self::Local,
+            cvss2::AccessVector::AdjacentNetwork => Self::AdjacentNetwork,
+            cvss2::AccessVector::Network => Self::Network,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_ac")]
+pub enum AccessComplexity {
+    #[sea_orm(string_value = "h")]
+    High,
+    #[sea_orm(string_value = "m")]
+    Medium,
+    #[sea_orm(string_value = "l")]
+    Low,
+}
+
+impl From<AccessComplexity> for cvss2::AccessComplexity {
+    fn from(value: AccessComplexity) -> Self {
+        match value {
+            AccessComplexity::High => Self::High,
+            AccessComplexity::Medium => Self::Medium,
+            AccessComplexity::Low => Self::Low,
+        }
+    }
+}
+
+impl From<cvss2::AccessComplexity> for AccessComplexity {
+    fn from(value: cvss2::AccessComplexity) -> Self {
+        match value {
+            cvss2::AccessComplexity::High => Self::High,
+            cvss2::AccessComplexity::Medium => Self::Medium,
+            cvss2::AccessComplexity::Low => Self::Low,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_au")]
+pub enum Authentication {
+    #[sea_orm(string_value = "m")]
+    Multiple,
+    #[sea_orm(string_value = "s")]
+    Single,
+    #[sea_orm(string_value = "n")]
+    None,
+}
+
+impl From<Authentication> for cvss2::Authentication {
+    fn from(value: Authentication) -> Self {
+        match value {
+            Authentication::Multiple => Self::Multiple,
+            Authentication::Single => Self::Single,
+            Authentication::None => Self::None,
+        }
+    }
+}
+
+impl From<cvss2::Authentication> for Authentication {
+    fn from(value: cvss2::Authentication) -> Self {
+        match value {
+            cvss2::Authentication::Multiple => Self::Multiple,
+            cvss2::Authentication::Single => Self::Single,
+            cvss2::Authentication::None => Self::None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_c")]
+pub enum ConfidentialityImpact {
+    #[sea_orm(string_value = "n")]
+    None,
+    #[sea_orm(string_value = "p")]
+    Partial,
+    #[sea_orm(string_value = "c")]
+    Complete,
+}
+
+impl From<ConfidentialityImpact> for cvss2::ConfidentialityImpact {
+    fn from(value: ConfidentialityImpact) -> Self {
+        match value {
+            ConfidentialityImpact::None => Self::None,
+            ConfidentialityImpact::Partial => Self::Partial,
+            ConfidentialityImpact::Complete => Self::Complete,
+        }
+    }
+}
+
+impl From<cvss2::ConfidentialityImpact> for ConfidentialityImpact {
+    fn from(value: cvss2::ConfidentialityImpact) -> Self {
+        match value {
+            cvss2::ConfidentialityImpact::None => Self::None,
+            cvss2::ConfidentialityImpact::Partial => Self::Partial,
+            cvss2::ConfidentialityImpact::Complete => Self::Complete,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_i")]
+pub enum IntegrityImpact {
+    #[sea_orm(string_value = "n")]
+    None,
+    #[sea_orm(string_value = "p")]
+    Partial,
+    #[sea_orm(string_value = "c")]
+    Complete,
+}
+
+impl From<IntegrityImpact> for cvss2::IntegrityImpact {
+    fn from(value: IntegrityImpact) -> Self {
+        match value {
+            IntegrityImpact::None => Self::None,
+            IntegrityImpact::Partial => Self::Partial,
+            IntegrityImpact::Complete => Self::Complete,
+        }
+    }
+}
+
+impl From<cvss2::IntegrityImpact> for IntegrityImpact {
+    fn from(value: cvss2::IntegrityImpact) -> Self {
+        match value {
+            cvss2::IntegrityImpact::None => Self::None,
+            cvss2::IntegrityImpact::Partial => Self::Partial,
+            cvss2::IntegrityImpact::Complete => Self::Complete,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_a")]
+pub enum AvailabilityImpact {
+    #[sea_orm(string_value = "n")]
+    None,
+    #[sea_orm(string_value = "p")]
+    Partial,
+    #[sea_orm(string_value = "c")]
+    Complete,
+}
+
+impl From<AvailabilityImpact> for cvss2::AvailabilityImpact {
+    fn from(value: AvailabilityImpact) -> Self {
+        match value {
+            AvailabilityImpact::None => Self::None,
+            AvailabilityImpact::Partial => Self::Partial,
+            AvailabilityImpact::Complete => Self::Complete,
+        }
+    }
+}
+
+impl From<cvss2::AvailabilityImpact> for AvailabilityImpact {
+    fn from(value: cvss2::AvailabilityImpact) -> Self {
+        match value {
+            cvss2::AvailabilityImpact::None => Self::None,
+            cvss2::AvailabilityImpact::Partial => Self::Partial,
+            cvss2::AvailabilityImpact::Complete => Self::Complete,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_e")]
+pub enum Exploitability {
+    #[sea_orm(string_value = "u")]
+    Unproven,
+    #[sea_orm(string_value = "poc")]
+    ProofOfConcept,
+    #[sea_orm(string_value = "f")]
+    Functional,
+    #[sea_orm(string_value = "h")]
+    High,
+}
+
+impl From<Exploitability> for cvss2::vector::Exploitability {
+    fn from(value: Exploitability) -> Self {
+        match value {
+            Exploitability::Unproven => Self::Unproven,
+            Exploitability::ProofOfConcept => Self::ProofOfConcept,
+            Exploitability::Functional => Self::Functional,
+            Exploitability::High => Self::High,
+        }
+    }
+}
+
+impl From<cvss2::vector::Exploitability> for Exploitability {
+    fn from(value: cvss2::vector::Exploitability) -> Self {
+        match value {
+            cvss2::vector::Exploitability::Unproven => Self::Unproven,
+            cvss2::vector::Exploitability::ProofOfConcept => Self::ProofOfConcept,
+            cvss2::vector::Exploitability::Functional => Self::Functional,
+            cvss2::vector::Exploitability::High => Self::High,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_rl")]
+pub enum RemediationLevel {
+    #[sea_orm(string_value = "of")]
+    OfficialFix,
+    #[sea_orm(string_value = "tf")]
+    TemporaryFix,
+    #[sea_orm(string_value = "w")]
+    Workaround,
+    #[sea_orm(string_value = "u")]
+    Unavailable,
+}
+
+impl From<RemediationLevel> for cvss2::vector::RemediationLevel {
+    fn from(value: RemediationLevel) -> Self {
+        match value {
+            RemediationLevel::OfficialFix => Self::OfficialFix,
+            RemediationLevel::TemporaryFix => Self::TemporaryFix,
+            RemediationLevel::Workaround => Self::Workaround,
+            RemediationLevel::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+impl From<cvss2::vector::RemediationLevel> for RemediationLevel {
+    fn from(value: cvss2::vector::RemediationLevel) -> Self {
+        match value {
+            cvss2::vector::RemediationLevel::OfficialFix => Self::OfficialFix,
+            cvss2::vector::RemediationLevel::TemporaryFix => Self::TemporaryFix,
+            cvss2::vector::RemediationLevel::Workaround => Self::Workaround,
+            cvss2::vector::RemediationLevel::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_rc")]
+pub enum ReportConfidence {
+    #[sea_orm(string_value = "uc")]
+    Unconfirmed,
+    #[sea_orm(string_value = "ur")]
+    Uncorroborated,
+    #[sea_orm(string_value = "c")]
+    Confirmed,
+}
+
+impl From<ReportConfidence> for cvss2::vector::ReportConfidence {
+    fn from(value: ReportConfidence) -> Self {
+        match value {
+            ReportConfidence::Unconfirmed => Self::Unconfirmed,
+            ReportConfidence::Uncorroborated => Self::Uncorroborated,
+            ReportConfidence::Confirmed => Self::Confirmed,
+        }
+    }
+}
+
+impl From<cvss2::vector::ReportConfidence> for ReportConfidence {
+    fn from(value: cvss2::vector::ReportConfidence) -> Self {
+        match value {
+            cvss2::vector::ReportConfidence::Unconfirmed => Self::Unconfirmed,
+            cvss2::vector::ReportConfidence::Uncorroborated => Self::Uncorroborated,
+            cvss2::vector::ReportConfidence::Confirmed => Self::Confirmed,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_cdp")]
+pub enum CollateralDamagePotential {
+    #[sea_orm(string_value = "n")]
+    None,
+    #[sea_orm(string_value = "l")]
+    Low,
+    #[sea_orm(string_value = "lm")]
+    LowMedium,
+    #[sea_orm(string_value = "mh")]
+    MediumHigh,
+    #[sea_orm(string_value = "h")]
+    High,
+}
+
+impl From<CollateralDamagePotential> for cvss2::vector::CollateralDamagePotential {
+    fn from(value: CollateralDamagePotential) -> Self {
+        match value {
+            CollateralDamagePotential::None => Self::None,
+            CollateralDamagePotential::Low => Self::Low,
+            CollateralDamagePotential::LowMedium => Self::LowMedium,
+            CollateralDamagePotential::MediumHigh => Self::MediumHigh,
+            CollateralDamagePotential::High => Self::High,
+        }
+    }
+}
+
+impl From<cvss2::vector::CollateralDamagePotential> for CollateralDamagePotential {
+    fn from(value: cvss2::vector::CollateralDamagePotential) -> Self {
+        match value {
+            cvss2::vector::CollateralDamagePotential::None => Self::None,
+            cvss2::vector::CollateralDamagePotential::Low => Self::Low,
+            cvss2::vector::CollateralDamagePotential::LowMedium => Self::LowMedium,
+            cvss2::vector::CollateralDamagePotential::MediumHigh => Self::MediumHigh,
+            cvss2::vector::CollateralDamagePotential::High => Self::High,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_td")]
+pub enum TargetDistribution {
+    #[sea_orm(string_value = "n")]
+    None,
+    #[sea_orm(string_value = "l")]
+    Low,
+    #[sea_orm(string_value = "m")]
+    Medium,
+    #[sea_orm(string_value = "h")]
+    High,
+}
+
+impl From<TargetDistribution> for cvss2::vector::TargetDistribution {
+    fn from(value: TargetDistribution) -> Self {
+        match value {
+            TargetDistribution::None => Self::None,
+            TargetDistribution::Low => Self::Low,
+            TargetDistribution::Medium => Self::Medium,
+            TargetDistribution::High => Self::High,
+        }
+    }
+}
+
+impl From<cvss2::vector::TargetDistribution> for TargetDistribution {
+    fn from(value: cvss2::vector::TargetDistribution) -> Self {
+        match value {
+            cvss2::vector::TargetDistribution::None => Self::None,
+            cvss2::vector::TargetDistribution::Low => Self::Low,
+            cvss2::vector::TargetDistribution::Medium => Self::Medium,
+            cvss2::vector::TargetDistribution::High => Self::High,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss2_req")]
+pub enum SecurityRequirement {
+    #[sea_orm(string_value = "l")]
+    Low,
+    #[sea_orm(string_value = "m")]
+    Medium,
+    #[sea_orm(string_value = "h")]
+    High,
+}
+
+impl From<SecurityRequirement> for cvss2::vector::SecurityRequirement {
+    fn from(value: SecurityRequirement) -> Self {
+        match value {
+            SecurityRequirement::Low => Self::Low,
+            SecurityRequirement::Medium => Self::Medium,
+            SecurityRequirement::High => Self::High,
+        }
+    }
+}
+
+impl From<cvss2::vector::SecurityRequirement> for SecurityRequirement {
+    fn from(value: cvss2::vector::SecurityRequirement) -> Self {
+        match value {
+            cvss2::vector::SecurityRequirement::Low => Self::Low,
+            cvss2::vector::SecurityRequirement::Medium => Self::Medium,
+            cvss2::vector::SecurityRequirement::High => Self::High,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{advisory, advisory_vulnerability, vulnerability};
 use sea_orm::entity::prelude::*;
 use std::fmt::{Display, Formatter};
-use trustify_cvss::cvss3;
+use trustify_cvss::cvss3::{
+    self, environmental::Cvss3Environmental, temporal::Cvss3Temporal, vector::Cvss3Vector,
+};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "cvss3")]
@@ -26,6 +28,25 @@ pub struct Model {
 
     pub score: f64,
     pub severity: Severity,
+
+    pub e: Option<ExploitCodeMaturity>,
+    pub rl: Option<RemediationLevel>,
+    pub rc: Option<ReportConfidence>,
+
+    pub cr: Option<SecurityRequirement>,
+    pub ir: Option<SecurityRequirement>,
+    pub ar: Option<SecurityRequirement>,
+    pub mav: Option<AttackVector>,
+    pub mac: Option<AttackComplexity>,
+    pub mpr: Option<PrivilegesRequired>,
+    pub mui: Option<UserInteraction>,
+    pub ms: Option<Scope>,
+    pub mc: Option<Confidentiality>,
+    pub mi: Option<Integrity>,
+    pub ma: Option<Availability>,
+
+    pub temporal_score: Option<f64>,
+    pub environmental_score: Option<f64>,
 }
 
 impl From<&Model> for cvss3::Cvss3Base {
@@ -50,6 +71,38 @@ impl From<Model> for cvss3::Cvss3Base {
     }
 }
 
+impl From<&Model> for Cvss3Vector {
+    fn from(value: &Model) -> Self {
+        Self {
+            base: value.into(),
+            temporal: Cvss3Temporal {
+                e: value.e.map(Into::into),
+                rl: value.rl.map(Into::into),
+                rc: value.rc.map(Into::into),
+            },
+            environmental: Cvss3Environmental {
+                cr: value.cr.map(Into::into),
+                ir: value.ir.map(Into::into),
+                ar: value.ar.map(Into::into),
+                mav: value.mav.map(Into::into),
+                mac: value.mac.map(Into::into),
+                mpr: value.mpr.map(Into::into),
+                mui: value.mui.map(Into::into),
+                ms: value.ms.map(Into::into),
+                mc: value.mc.map(Into::into),
+                mi: value.mi.map(Into::into),
+                ma: value.ma.map(Into::into),
+            },
+        }
+    }
+}
+
+impl From<Model> for Cvss3Vector {
+    fn from(value: Model) -> Self {
+        Self::from(&value)
+    }
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
@@ -391,3 +444,135 @@ impl From<Severity> for cvss3::severity::Severity {
         }
     }
 }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss3_e")]
+pub enum ExploitCodeMaturity {
+    #[sea_orm(string_value = "u")]
+    Unproven,
+    #[sea_orm(string_value = "p")]
+    ProofOfConcept,
+    #[sea_orm(string_value = "f")]
+    Functional,
+    #[sea_orm(string_value = "h")]
+    High,
+}
+
+impl From<ExploitCodeMaturity> for cvss3::temporal::ExploitCodeMaturity {
+    fn from(value: ExploitCodeMaturity) -> Self {
+        match value {
+            ExploitCodeMaturity::Unproven => Self::Unproven,
+            ExploitCodeMaturity::ProofOfConcept => Self::ProofOfConcept,
+            ExploitCodeMaturity::Functional => Self::Functional,
+            ExploitCodeMaturity::High => Self::High,
+        }
+    }
+}
+
+impl From<cvss3::temporal::ExploitCodeMaturity> for ExploitCodeMaturity {
+    fn from(value: cvss3::temporal::ExploitCodeMaturity) -> Self {
+        match value {
+            cvss3::temporal::ExploitCodeMaturity::Unproven => Self::Unproven,
+            cvss3::temporal::ExploitCodeMaturity::ProofOfConcept => Self::ProofOfConcept,
+            cvss3::temporal::ExploitCodeMaturity::Functional => Self::Functional,
+            cvss3::temporal::ExploitCodeMaturity::High => Self::High,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss3_rl")]
+pub enum RemediationLevel {
+    #[sea_orm(string_value = "o")]
+    OfficialFix,
+    #[sea_orm(string_value = "t")]
+    TemporaryFix,
+    #[sea_orm(string_value = "w")]
+    Workaround,
+    #[sea_orm(string_value = "u")]
+    Unavailable,
+}
+
+impl From<RemediationLevel> for cvss3::temporal::RemediationLevel {
+    fn from(value: RemediationLevel) -> Self {
+        match value {
+            RemediationLevel::OfficialFix => Self::OfficialFix,
+            RemediationLevel::TemporaryFix => Self::TemporaryFix,
+            RemediationLevel::Workaround => Self::Workaround,
+            RemediationLevel::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+impl From<cvss3::temporal::RemediationLevel> for RemediationLevel {
+    fn from(value: cvss3::temporal::RemediationLevel) -> Self {
+        match value {
+            cvss3::temporal::RemediationLevel::OfficialFix => Self::OfficialFix,
+            cvss3::temporal::RemediationLevel::TemporaryFix => Self::TemporaryFix,
+            cvss3::temporal::RemediationLevel::Workaround => Self::Workaround,
+            cvss3::temporal::RemediationLevel::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss3_rc")]
+pub enum ReportConfidence {
+    #[sea_orm(string_value = "u")]
+    Unknown,
+    #[sea_orm(string_value = "r")]
+    Reasonable,
+    #[sea_orm(string_value = "c")]
+    Confirmed,
+}
+
+impl From<ReportConfidence> for cvss3::temporal::ReportConfidence {
+    fn from(value: ReportConfidence) -> Self {
+        match value {
+            ReportConfidence::Unknown => Self::Unknown,
+            ReportConfidence::Reasonable => Self::Reasonable,
+            ReportConfidence::Confirmed => Self::Confirmed,
+        }
+    }
+}
+
+impl From<cvss3::temporal::ReportConfidence> for ReportConfidence {
+    fn from(value: cvss3::temporal::ReportConfidence) -> Self {
+        match value {
+            cvss3::temporal::ReportConfidence::Unknown => Self::Unknown,
+            cvss3::temporal::ReportConfidence::Reasonable => Self::Reasonable,
+            cvss3::temporal::ReportConfidence::Confirmed => Self::Confirmed,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss3_req")]
+pub enum SecurityRequirement {
+    #[sea_orm(string_value = "l")]
+    Low,
+    #[sea_orm(string_value = "m")]
+    Medium,
+    #[sea_orm(string_value = "h")]
+    High,
+}
+
+impl From<SecurityRequirement> for cvss3::environmental::SecurityRequirement {
+    fn from(value: SecurityRequirement) -> Self {
+        match value {
+            SecurityRequirement::Low => Self::Low,
+            SecurityRequirement::Medium => Self::Medium,
+            SecurityRequirement::High => Self::High,
+        }
+    }
+}
+
+impl From<cvss3::environmental::SecurityRequirement> for SecurityRequirement {
+    fn from(value: cvss3::environmental::SecurityRequirement) -> Self {
+        match value {
+            cvss3::environmental::SecurityRequirement::Low => Self::Low,
+            cvss3::environmental::SecurityRequirement::Medium => Self::Medium,
+            cvss3::environmental::SecurityRequirement::High => Self::High,
+        }
+    }
+}
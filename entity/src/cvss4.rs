@@ -1,8 +1,8 @@
-use crate::{advisory, vulnerability};
+use crate::{advisory, cvss3::Severity, vulnerability};
 use sea_orm::entity::prelude::*;
 use trustify_cvss::cvss4;
 
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "cvss4")]
 pub struct Model {
     #[sea_orm(primary_key)]
@@ -25,10 +25,13 @@ pub struct Model {
     pub sc: SubsequentConfidentiality,
     pub si: SubsequentIntegrity,
     pub sa: SubsequentAvailability,
+
+    pub score: f64,
+    pub severity: Severity,
 }
 
-impl From<Model> for cvss4::Cvss4Base {
-    fn from(value: Model) -> Self {
+impl From<&Model> for cvss4::Cvss4Base {
+    fn from(value: &Model) -> Self {
         Self {
             minor_version: value.minor_version as u8,
             av: value.av.into(),
@@ -46,6 +49,12 @@ impl From<Model> for cvss4::Cvss4Base {
     }
 }
 
+impl From<Model> for cvss4::Cvss4Base {
+    fn from(value: Model) -> Self {
+        Self::from(&value)
+    }
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
@@ -75,7 +84,7 @@ impl Related<vulnerability::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_av")]
 pub enum AttackVector {
     #[sea_orm(string_value = "n")]
@@ -110,7 +119,7 @@ impl From<cvss4::AttackVector> for AttackVector {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_ac")]
 pub enum AttackComplexity {
     #[sea_orm(string_value = "l")]
@@ -137,7 +146,7 @@ impl From<cvss4::AttackComplexity> for AttackComplexity {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_at")]
 pub enum AttackRequirements {
     #[sea_orm(string_value = "n")]
@@ -164,7 +173,7 @@ impl From<cvss4::AttackRequirements> for AttackRequirements {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_pr")]
 pub enum PrivilegesRequired {
     #[sea_orm(string_value = "n")]
@@ -195,7 +204,7 @@ impl From<cvss4::PrivilegesRequired> for PrivilegesRequired {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_ui")]
 pub enum UserInteraction {
     #[sea_orm(string_value = "n")]
@@ -228,7 +237,7 @@ impl From<cvss4::UserInteraction> for UserInteraction {
 
 // ----
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_vc")]
 pub enum VulnerableConfidentiality {
     #[sea_orm(string_value = "n")]
@@ -261,7 +270,7 @@ impl From<cvss4::VulnerableConfidentiality> for VulnerableConfidentiality {
 
 // ---
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_vi")]
 pub enum VulnerableIntegrity {
     #[sea_orm(string_value = "n")]
@@ -294,7 +303,7 @@ impl From<cvss4::VulnerableIntegrity> for VulnerableIntegrity {
 
 // ---
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_va")]
 pub enum VulnerableAvailability {
     #[sea_orm(string_value = "n")]
@@ -330,7 +339,7 @@ impl From<cvss4::VulnerableAvailability> for VulnerableAvailability {
 // ---
 // ---
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_sc")]
 pub enum SubsequentConfidentiality {
     #[sea_orm(string_value = "n")]
@@ -363,7 +372,7 @@ impl From<cvss4::SubsequentConfidentiality> for SubsequentConfidentiality {
 
 // ---
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_si")]
 pub enum SubsequentIntegrity {
     #[sea_orm(string_value = "n")]
@@ -396,7 +405,7 @@ impl From<cvss4::SubsequentIntegrity> for SubsequentIntegrity {
 
 // ---
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "cvss4_sa")]
 pub enum SubsequentAvailability {
     #[sea_orm(string_value = "n")]
@@ -0,0 +1,31 @@
+use crate::vulnerability;
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+
+/// The latest EPSS score of a vulnerability.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "epss")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub vulnerability_id: String,
+    pub date: OffsetDateTime,
+    pub score: f64,
+    pub percentile: f64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+    belongs_to = "super::vulnerability::Entity",
+    from = "super::epss::Column::VulnerabilityId"
+    to = "super::vulnerability::Column::Id")]
+    Vulnerability,
+}
+
+impl Related<vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Vulnerability.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,32 @@
+use crate::vulnerability;
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+
+/// An EPSS score of a vulnerability, as published on a specific day.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "epss_history")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub vulnerability_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub date: OffsetDateTime,
+    pub score: f64,
+    pub percentile: f64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+    belongs_to = "super::vulnerability::Entity",
+    from = "super::epss_history::Column::VulnerabilityId"
+    to = "super::vulnerability::Column::Id")]
+    Vulnerability,
+}
+
+impl Related<vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Vulnerability.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,104 @@
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+
+/// The justification of why a product is not affected by a vulnerability.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "flag")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub advisory_id: Uuid,
+    pub vulnerability_id: String,
+    pub label: FlagLabel,
+    pub date: Option<OffsetDateTime>,
+}
+
+/// The label of a flag, the justification of why a product is not affected.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    Hash,
+    Debug,
+    PartialEq,
+    EnumIter,
+    DeriveActiveEnum,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "String(StringLen::None)",
+    rename_all = "snake_case"
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FlagLabel {
+    /// The vulnerable component is not part of the product
+    ComponentNotPresent,
+    /// The vulnerable code is not part of the product
+    VulnerableCodeNotPresent,
+    /// The vulnerable code is never executed
+    VulnerableCodeNotInExecutePath,
+    /// The vulnerable code can't be controlled by an attacker
+    VulnerableCodeCannotBeControlledByAdversary,
+    /// The product already contains mitigations preventing an exploitation
+    InlineMitigationsAlreadyExist,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(belongs_to = "super::advisory_vulnerability::Entity",
+        from = "(Column::AdvisoryId, Column::VulnerabilityId)"
+        to = "(super::advisory_vulnerability::Column::AdvisoryId, super::advisory_vulnerability::Column::VulnerabilityId)"
+    )]
+    AdvisoryVulnerability,
+
+    #[sea_orm(has_many = "super::flag_product_status::Entity")]
+    FlagProductStatus,
+
+    #[sea_orm(has_many = "super::flag_purl_status::Entity")]
+    FlagPurlStatus,
+}
+
+impl Related<super::advisory_vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AdvisoryVulnerability.def()
+    }
+}
+
+impl Related<super::flag_product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FlagProductStatus.def()
+    }
+}
+
+impl Related<super::flag_purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FlagPurlStatus.def()
+    }
+}
+
+impl Related<super::product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::flag_product_status::Relation::ProductStatus.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::flag_product_status::Relation::Flag.def().rev())
+    }
+}
+
+impl Related<super::purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::flag_purl_status::Relation::PurlStatus.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::flag_purl_status::Relation::Flag.def().rev())
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "flag_product_status")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub flag_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub product_status_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(belongs_to = "super::flag::Entity",
+        from = "Column::FlagId"
+        to = "super::flag::Column::Id"
+    )]
+    Flag,
+
+    #[sea_orm(belongs_to = "super::product_status::Entity",
+        from = "Column::ProductStatusId"
+        to = "super::product_status::Column::Id"
+    )]
+    ProductStatus,
+}
+
+impl Related<super::flag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flag.def()
+    }
+}
+
+impl Related<super::product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProductStatus.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "flag_purl_status")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub flag_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub purl_status_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(belongs_to = "super::flag::Entity",
+        from = "Column::FlagId"
+        to = "super::flag::Column::Id"
+    )]
+    Flag,
+
+    #[sea_orm(belongs_to = "super::purl_status::Entity",
+        from = "Column::PurlStatusId"
+        to = "super::purl_status::Column::Id"
+    )]
+    PurlStatus,
+}
+
+impl Related<super::flag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flag.def()
+    }
+}
+
+impl Related<super::purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurlStatus.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -18,6 +18,13 @@ pub struct Model {
     pub progress_total: Option<i32>,
     pub progress_message: Option<String>,
 
+    /// the node currently holding the lease of a run
+    pub lease_owner: Option<String>,
+    /// the point in time the lease expires, unless renewed
+    pub lease_expires: Option<time::OffsetDateTime>,
+    /// a request to cancel the current run
+    pub cancel_requested: bool,
+
     /// an importer specific continuation token
     pub continuation: Option<serde_json::Value>,
 
@@ -10,10 +10,19 @@ pub struct Model {
 
     pub creation: time::OffsetDateTime,
     pub error: Option<String>,
+    pub outcome: Outcome,
 
     pub report: serde_json::Value,
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "i32", db_type = "Integer")]
+pub enum Outcome {
+    Succeeded = 0,
+    Failed = 1,
+    Cancelled = 2,
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
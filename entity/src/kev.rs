@@ -0,0 +1,38 @@
+use crate::vulnerability;
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+
+/// An entry of the CISA Known Exploited Vulnerabilities catalog.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "kev")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub vulnerability_id: String,
+    pub vendor_project: Option<String>,
+    pub product: Option<String>,
+    pub name: Option<String>,
+    pub short_description: Option<String>,
+    pub required_action: Option<String>,
+    pub notes: Option<String>,
+    pub date_added: OffsetDateTime,
+    pub due_date: Option<OffsetDateTime>,
+    pub known_ransomware_campaign_use: bool,
+    pub cwes: Option<Vec<String>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+    belongs_to = "super::vulnerability::Entity",
+    from = "super::kev::Column::VulnerabilityId"
+    to = "super::vulnerability::Column::Id")]
+    Vulnerability,
+}
+
+impl Related<vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Vulnerability.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
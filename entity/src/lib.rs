@@ -4,10 +4,17 @@ pub mod base_purl;
 pub mod conversation;
 pub mod cpe;
 pub mod cpe_license_assertion;
+pub mod cvss2;
 pub mod cvss3;
 pub mod cvss4;
+pub mod epss;
+pub mod epss_history;
+pub mod flag;
+pub mod flag_product_status;
+pub mod flag_purl_status;
 pub mod importer;
 pub mod importer_report;
+pub mod kev;
 pub mod labels;
 pub mod license;
 pub mod organization;
@@ -21,14 +28,23 @@ pub mod purl_license_assertion;
 pub mod purl_status;
 pub mod qualified_purl;
 pub mod relationship;
+pub mod remediation;
+pub mod remediation_product_status;
+pub mod remediation_purl_status;
 pub mod sbom;
 pub mod sbom_file;
 pub mod sbom_node;
+pub mod sbom_node_checksum;
 pub mod sbom_package;
 pub mod sbom_package_cpe_ref;
+pub mod sbom_package_external_reference;
+pub mod sbom_package_property;
 pub mod sbom_package_purl_ref;
 pub mod source_document;
 pub mod status;
+pub mod threat;
+pub mod threat_product_status;
+pub mod threat_purl_status;
 pub mod user_preferences;
 pub mod version_range;
 pub mod version_scheme;
@@ -12,6 +12,9 @@ pub struct Model {
     pub base_purl_id: Uuid,
     pub version_range_id: Uuid,
     pub context_cpe_id: Option<Uuid>,
+    pub justification: Option<String>,
+    pub impact_statement: Option<String>,
+    pub action_statement: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,114 @@
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+
+/// How to fix a vulnerability, or deal with it.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "remediation")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub advisory_id: Uuid,
+    pub vulnerability_id: String,
+    pub category: RemediationCategory,
+    pub details: String,
+    pub url: Option<String>,
+    pub date: Option<OffsetDateTime>,
+}
+
+/// The category of a remediation.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    Hash,
+    Debug,
+    PartialEq,
+    EnumIter,
+    DeriveActiveEnum,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "String(StringLen::None)",
+    rename_all = "snake_case"
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RemediationCategory {
+    /// A fix was released by the vendor
+    VendorFix,
+    /// A configuration change or deployment scenario which avoids the vulnerability
+    Workaround,
+    /// A configuration change or deployment scenario which reduces the risk
+    Mitigation,
+    /// There is no fix available
+    NoneAvailable,
+    /// There is no fix, and none will be provided
+    NoFixPlanned,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(belongs_to = "super::advisory_vulnerability::Entity",
+        from = "(Column::AdvisoryId, Column::VulnerabilityId)"
+        to = "(super::advisory_vulnerability::Column::AdvisoryId, super::advisory_vulnerability::Column::VulnerabilityId)"
+    )]
+    AdvisoryVulnerability,
+
+    #[sea_orm(has_many = "super::remediation_product_status::Entity")]
+    RemediationProductStatus,
+
+    #[sea_orm(has_many = "super::remediation_purl_status::Entity")]
+    RemediationPurlStatus,
+}
+
+impl Related<super::advisory_vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AdvisoryVulnerability.def()
+    }
+}
+
+impl Related<super::remediation_product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RemediationProductStatus.def()
+    }
+}
+
+impl Related<super::remediation_purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RemediationPurlStatus.def()
+    }
+}
+
+impl Related<super::product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::remediation_product_status::Relation::ProductStatus.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(
+            super::remediation_product_status::Relation::Remediation
+                .def()
+                .rev(),
+        )
+    }
+}
+
+impl Related<super::purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::remediation_purl_status::Relation::PurlStatus.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(
+            super::remediation_purl_status::Relation::Remediation
+                .def()
+                .rev(),
+        )
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -8,6 +8,10 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub node_id: String,
     pub version: Option<String>,
+    pub supplier: Option<String>,
+    pub originator: Option<String>,
+    pub download_location: Option<String>,
+    pub copyright_text: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
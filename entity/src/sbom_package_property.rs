@@ -0,0 +1,48 @@
+use sea_orm::entity::prelude::*;
+
+/// A name/value property of an SBOM package
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "sbom_package_property")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub sbom_id: Uuid,
+
+    #[sea_orm(primary_key)]
+    pub node_id: String,
+
+    #[sea_orm(primary_key)]
+    pub name: String,
+
+    #[sea_orm(primary_key)]
+    pub value: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::sbom::Entity",
+        from = "Column::SbomId",
+        to = "super::sbom::Column::SbomId"
+    )]
+    Sbom,
+    #[sea_orm(
+        belongs_to = "super::sbom_node::Entity",
+        from = "(Column::SbomId, Column::NodeId)",
+        to = "(super::sbom_node::Column::SbomId, super::sbom_node::Column::NodeId)"
+    )]
+    Node,
+}
+
+impl Related<super::sbom::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Sbom.def()
+    }
+}
+
+impl Related<super::sbom_node::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Node.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,6 +1,11 @@
 use crate::purl_status;
 use sea_orm::entity::prelude::*;
 
+/// The slugs of the statuses meaning a vulnerability affects a version.
+///
+/// CSAF documents may also report the first or last affected version of a range.
+pub const AFFECTED: [&str; 3] = ["affected", "first_affected", "last_affected"];
+
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "status")]
 pub struct Model {
@@ -0,0 +1,101 @@
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+
+/// The impact, exploit status or target set of a vulnerability.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "threat")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub advisory_id: Uuid,
+    pub vulnerability_id: String,
+    pub category: ThreatCategory,
+    pub details: String,
+    pub date: Option<OffsetDateTime>,
+}
+
+/// The category of a threat.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    Hash,
+    Debug,
+    PartialEq,
+    EnumIter,
+    DeriveActiveEnum,
+    strum::Display,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "String(StringLen::None)",
+    rename_all = "snake_case"
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ThreatCategory {
+    /// The impact on the product
+    Impact,
+    /// The status of known exploits
+    ExploitStatus,
+    /// The expected targets
+    TargetSet,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(belongs_to = "super::advisory_vulnerability::Entity",
+        from = "(Column::AdvisoryId, Column::VulnerabilityId)"
+        to = "(super::advisory_vulnerability::Column::AdvisoryId, super::advisory_vulnerability::Column::VulnerabilityId)"
+    )]
+    AdvisoryVulnerability,
+
+    #[sea_orm(has_many = "super::threat_product_status::Entity")]
+    ThreatProductStatus,
+
+    #[sea_orm(has_many = "super::threat_purl_status::Entity")]
+    ThreatPurlStatus,
+}
+
+impl Related<super::advisory_vulnerability::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AdvisoryVulnerability.def()
+    }
+}
+
+impl Related<super::threat_product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ThreatProductStatus.def()
+    }
+}
+
+impl Related<super::threat_purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ThreatPurlStatus.def()
+    }
+}
+
+impl Related<super::product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::threat_product_status::Relation::ProductStatus.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::threat_product_status::Relation::Threat.def().rev())
+    }
+}
+
+impl Related<super::purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::threat_purl_status::Relation::PurlStatus.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::threat_purl_status::Relation::Threat.def().rev())
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "threat_product_status")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub threat_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub product_status_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(belongs_to = "super::threat::Entity",
+        from = "Column::ThreatId"
+        to = "super::threat::Column::Id"
+    )]
+    Threat,
+
+    #[sea_orm(belongs_to = "super::product_status::Entity",
+        from = "Column::ProductStatusId"
+        to = "super::product_status::Column::Id"
+    )]
+    ProductStatus,
+}
+
+impl Related<super::threat::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Threat.def()
+    }
+}
+
+impl Related<super::product_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProductStatus.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "threat_purl_status")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub threat_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub purl_status_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(belongs_to = "super::threat::Entity",
+        from = "Column::ThreatId"
+        to = "super::threat::Column::Id"
+    )]
+    Threat,
+
+    #[sea_orm(belongs_to = "super::purl_status::Entity",
+        from = "Column::PurlStatusId"
+        to = "super::purl_status::Column::Id"
+    )]
+    PurlStatus,
+}
+
+impl Related<super::threat::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Threat.def()
+    }
+}
+
+impl Related<super::purl_status::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurlStatus.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
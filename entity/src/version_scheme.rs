@@ -27,4 +27,8 @@ pub enum VersionScheme {
     Rpm,
     Python,
     Maven,
+    /// Debian and its derivatives (dpkg)
+    Deb,
+    /// Alpine Linux (apk)
+    Alpine,
 }
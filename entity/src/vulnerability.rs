@@ -1,4 +1,7 @@
-use crate::{advisory, advisory_vulnerability, cvss3, vulnerability_description};
+use crate::{
+    advisory, advisory_vulnerability, cvss2, cvss3, cvss4, epss, epss_history, kev,
+    vulnerability_description,
+};
 use async_graphql::SimpleObject;
 use sea_orm::entity::prelude::*;
 use time::OffsetDateTime;
@@ -28,11 +31,26 @@ pub enum Relation {
     #[sea_orm(has_many = "super::vulnerability_description::Entity")]
     Descriptions,
 
+    #[sea_orm(has_many = "super::cvss2::Entity")]
+    Cvss2,
+
     #[sea_orm(has_many = "super::cvss3::Entity")]
     Cvss3,
 
+    #[sea_orm(has_many = "super::cvss4::Entity")]
+    Cvss4,
+
     #[sea_orm(has_many = "super::purl_status::Entity")]
     PurlStatuses,
+
+    #[sea_orm(has_one = "super::epss::Entity")]
+    Epss,
+
+    #[sea_orm(has_many = "super::epss_history::Entity")]
+    EpssHistory,
+
+    #[sea_orm(has_one = "super::kev::Entity")]
+    Kev,
 }
 
 impl Related<super::purl_status::Entity> for Entity {
@@ -61,12 +79,42 @@ impl Related<advisory_vulnerability::Entity> for Entity {
     }
 }
 
+impl Related<cvss2::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Cvss2.def()
+    }
+}
+
 impl Related<cvss3::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Cvss3.def()
     }
 }
 
+impl Related<cvss4::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Cvss4.def()
+    }
+}
+
+impl Related<epss::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Epss.def()
+    }
+}
+
+impl Related<epss_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EpssHistory.def()
+    }
+}
+
+impl Related<kev::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Kev.def()
+    }
+}
+
 impl Related<vulnerability_description::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Descriptions.def()
@@ -99,6 +99,17 @@ mod m0000790_alter_sbom_alter_document_id;
 mod m0000800_alter_product_version_range_scheme;
 mod m0000810_fix_get_purl;
 mod m0000820_create_conversation;
+mod m0000830_alter_importer_add_lease;
+mod m0000840_alter_importer_add_cancel;
+mod m0000850_python_version_cmp;
+mod m0000860_deb_apk_version_cmp;
+mod m0000870_alter_cvss4_add_score;
+mod m0000880_create_cvss2;
+mod m0000890_alter_cvss3_add_temporal_environmental;
+mod m0000900_create_epss_kev;
+mod m0000910_create_remediation;
+mod m0000920_status_justification;
+mod m0000930_sbom_package_details;
 
 pub struct Migrator;
 
@@ -205,6 +216,17 @@ impl MigratorTrait for Migrator {
             Box::new(m0000800_alter_product_version_range_scheme::Migration),
             Box::new(m0000810_fix_get_purl::Migration),
             Box::new(m0000820_create_conversation::Migration),
+            Box::new(m0000830_alter_importer_add_lease::Migration),
+            Box::new(m0000840_alter_importer_add_cancel::Migration),
+            Box::new(m0000850_python_version_cmp::Migration),
+            Box::new(m0000860_deb_apk_version_cmp::Migration),
+            Box::new(m0000870_alter_cvss4_add_score::Migration),
+            Box::new(m0000880_create_cvss2::Migration),
+            Box::new(m0000890_alter_cvss3_add_temporal_environmental::Migration),
+            Box::new(m0000900_create_epss_kev::Migration),
+            Box::new(m0000910_create_remediation::Migration),
+            Box::new(m0000920_status_justification::Migration),
+            Box::new(m0000930_sbom_package_details::Migration),
         ]
     }
 }
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Importer::Table)
+                    .add_column(
+                        ColumnDef::new(Importer::LeaseOwner)
+                            .string()
+                            .null()
+                            .to_owned(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Importer::LeaseExpires)
+                            .timestamp_with_time_zone()
+                            .null()
+                            .to_owned(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Importer::Table)
+                    .drop_column(Importer::LeaseExpires)
+                    .drop_column(Importer::LeaseOwner)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Importer {
+    Table,
+    LeaseOwner,
+    LeaseExpires,
+}
@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Importer::Table)
+                    .add_column(
+                        ColumnDef::new(Importer::CancelRequested)
+                            .boolean()
+                            .not_null()
+                            .default(false)
+                            .to_owned(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 0 = succeeded, 1 = failed, 2 = cancelled
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ImporterReport::Table)
+                    .add_column(
+                        ColumnDef::new(ImporterReport::Outcome)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .to_owned(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(ImporterReport::Table)
+                    .value(ImporterReport::Outcome, 1)
+                    .and_where(Expr::col(ImporterReport::Error).is_not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ImporterReport::Table)
+                    .drop_column(ImporterReport::Outcome)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Importer::Table)
+                    .drop_column(Importer::CancelRequested)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Importer {
+    Table,
+    CancelRequested,
+}
+
+#[derive(DeriveIden)]
+enum ImporterReport {
+    Table,
+    Error,
+    Outcome,
+}
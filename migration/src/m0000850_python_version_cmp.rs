@@ -0,0 +1,82 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `VersionScheme::Python` is stored as `python`, the initial set only knows `pypi`
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+INSERT INTO version_scheme (id, name, description)
+VALUES ('python', 'Python', 'https://peps.python.org/pep-0440/')
+ON CONFLICT DO NOTHING
+"#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000850_python_version_cmp/pythonver_parse.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000850_python_version_cmp/pythonver_cmp.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000850_python_version_cmp/python_version_matches.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000850_python_version_cmp/version_matches.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!("m0000670_version_cmp/version_matches.sql"))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function python_version_matches")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function pythonver_cmp")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function pythonver_parse")
+            .await?;
+
+        // the `python` version scheme is kept, as version ranges might still reference it
+
+        Ok(())
+    }
+}
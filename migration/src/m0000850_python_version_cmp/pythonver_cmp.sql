@@ -0,0 +1,75 @@
+create or replace function pythonver_cmp(left_p text, right_p text)
+    returns integer
+as
+$$
+declare
+    left_v record;
+    right_v record;
+    left_seg text;
+    right_seg text;
+begin
+    select * into left_v from pythonver_parse(left_p);
+    select * into right_v from pythonver_parse(right_p);
+
+    if left_v.release is null or right_v.release is null then
+        return null;
+    end if;
+
+    if left_v.epoch <> right_v.epoch then
+        return case when left_v.epoch < right_v.epoch then -1 else 1 end;
+    end if;
+
+    if left_v.release <> right_v.release then
+        return case when left_v.release < right_v.release then -1 else 1 end;
+    end if;
+
+    if left_v.pre <> right_v.pre then
+        return case when left_v.pre < right_v.pre then -1 else 1 end;
+    end if;
+
+    if left_v.post <> right_v.post then
+        return case when left_v.post < right_v.post then -1 else 1 end;
+    end if;
+
+    if left_v.dev <> right_v.dev then
+        return case when left_v.dev < right_v.dev then -1 else 1 end;
+    end if;
+
+    -- a version without local segment sorts before one with
+    if left_v.local is null and right_v.local is null then
+        return 0;
+    elsif left_v.local is null then
+        return -1;
+    elsif right_v.local is null then
+        return +1;
+    end if;
+
+    -- numeric segments sort after alphanumeric ones, and compare numerically
+    for i in 1..least(cardinality(left_v.local), cardinality(right_v.local)) loop
+        left_seg := left_v.local[i];
+        right_seg := right_v.local[i];
+        if left_seg ~ '^\d+$' then
+            if right_seg ~ '^\d+$' then
+                if left_seg::numeric <> right_seg::numeric then
+                    return case when left_seg::numeric < right_seg::numeric then -1 else 1 end;
+                end if;
+            else
+                return +1;
+            end if;
+        elsif right_seg ~ '^\d+$' then
+            return -1;
+        elsif left_seg <> right_seg then
+            return case when left_seg collate "C" < right_seg collate "C" then -1 else 1 end;
+        end if;
+    end loop;
+
+    if cardinality(left_v.local) > cardinality(right_v.local) then
+        return +1;
+    elsif cardinality(left_v.local) < cardinality(right_v.local) then
+        return -1;
+    end if;
+
+    return 0;
+end
+$$
+    language plpgsql immutable;
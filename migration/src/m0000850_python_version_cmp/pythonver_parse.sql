@@ -0,0 +1,69 @@
+create or replace function pythonver_parse(
+    version_p text,
+    out epoch numeric,
+    out release numeric[],
+    out pre numeric[],
+    out post numeric[],
+    out dev numeric[],
+    out local text[]
+)
+as
+$$
+declare
+    parts text[];
+begin
+    -- see: https://packaging.python.org/en/latest/specifications/version-specifiers/#appendix-parsing-version-strings-with-regular-expressions
+    parts := regexp_match(
+        lower(trim(version_p)),
+        '^v?(?:(\d+)!)?(\d+(?:\.\d+)*)(?:[-_.]?(alpha|a|beta|b|preview|pre|c|rc)[-_.]?(\d+)?)?(?:-(\d+)|[-_.]?(post|rev|r)[-_.]?(\d+)?)?(?:[-_.]?(dev)[-_.]?(\d+)?)?(?:\+([a-z0-9]+(?:[-_.][a-z0-9]+)*))?$'
+    );
+
+    if parts is null then
+        -- not a valid version, all fields are null
+        return;
+    end if;
+
+    epoch := coalesce(parts[1], '0')::numeric;
+
+    -- trailing zeros are not significant
+    release := string_to_array(parts[2], '.')::numeric[];
+    while cardinality(release) > 1 and release[cardinality(release)] = 0 loop
+        release := release[1:cardinality(release) - 1];
+    end loop;
+
+    if parts[3] is not null then
+        pre := array [
+            case parts[3]
+                when 'a' then 0
+                when 'alpha' then 0
+                when 'b' then 1
+                when 'beta' then 1
+                else 2
+            end,
+            coalesce(parts[4], '0')::numeric
+        ];
+    elsif parts[5] is null and parts[6] is null and parts[8] is not null then
+        -- the development release of a final release sorts before its pre-releases
+        pre := array [-1, 0];
+    else
+        pre := array [3, 0];
+    end if;
+
+    if parts[5] is not null then
+        post := array [1, parts[5]::numeric];
+    elsif parts[6] is not null then
+        post := array [1, coalesce(parts[7], '0')::numeric];
+    else
+        post := array [0, 0];
+    end if;
+
+    if parts[8] is not null then
+        dev := array [0, coalesce(parts[9], '0')::numeric];
+    else
+        dev := array [1, 0];
+    end if;
+
+    local := regexp_split_to_array(parts[10], '[-_.]');
+end
+$$
+    language plpgsql immutable;
@@ -0,0 +1,118 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000860_deb_apk_version_cmp/debver_order.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000860_deb_apk_version_cmp/debver_cmp_part.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!("m0000860_deb_apk_version_cmp/debver_cmp.sql"))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000860_deb_apk_version_cmp/debver_version_matches.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000860_deb_apk_version_cmp/apkver_suffix_rank.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!("m0000860_deb_apk_version_cmp/apkver_cmp.sql"))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000860_deb_apk_version_cmp/apkver_version_matches.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000860_deb_apk_version_cmp/version_matches.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000850_python_version_cmp/version_matches.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function apkver_version_matches")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function apkver_cmp")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function apkver_suffix_rank")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function debver_version_matches")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function debver_cmp")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function debver_cmp_part")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("drop function debver_order")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+create or replace function apkver_cmp(left_p text, right_p text)
+    returns integer
+as
+$$
+declare
+    left_parts text[];
+    right_parts text[];
+    left_numbers text[];
+    right_numbers text[];
+    left_ranks integer[];
+    right_ranks integer[];
+    left_suffix_numbers numeric[];
+    right_suffix_numbers numeric[];
+    left_num text;
+    right_num text;
+    left_rank integer;
+    right_rank integer;
+    left_revision numeric;
+    right_revision numeric;
+begin
+    -- see: https://gitlab.alpinelinux.org/alpine/apk-tools/-/blob/master/src/version.c
+    -- number{.number}...{letter}{_suffix{number}}...{~hash}{-r#}
+    left_parts := regexp_match(left_p, '^(\d+(?:\.\d+)*)([a-z]?)((?:_(?:alpha|beta|pre|rc|cvs|svn|git|hg|p)\d*)*)(?:~[0-9a-f]+)?(?:-r(\d+))?$');
+    right_parts := regexp_match(right_p, '^(\d+(?:\.\d+)*)([a-z]?)((?:_(?:alpha|beta|pre|rc|cvs|svn|git|hg|p)\d*)*)(?:~[0-9a-f]+)?(?:-r(\d+))?$');
+
+    if left_parts is null or right_parts is null then
+        return null;
+    end if;
+
+    -- numbers, the one with more components is greater
+    left_numbers := string_to_array(left_parts[1], '.');
+    right_numbers := string_to_array(right_parts[1], '.');
+
+    for i in 1..greatest(cardinality(left_numbers), cardinality(right_numbers)) loop
+        left_num := left_numbers[i];
+        right_num := right_numbers[i];
+        if left_num is null then
+            return -1;
+        elsif right_num is null then
+            return +1;
+        end if;
+
+        if i > 1 and (left_num ~ '^0' or right_num ~ '^0') then
+            -- components with a leading zero compare as fractions
+            if left_num collate "C" < right_num collate "C" then
+                return -1;
+            elsif left_num collate "C" > right_num collate "C" then
+                return +1;
+            end if;
+        elsif left_num::numeric < right_num::numeric then
+            return -1;
+        elsif left_num::numeric > right_num::numeric then
+            return +1;
+        end if;
+    end loop;
+
+    -- letter, no letter is lower than any
+    if left_parts[2] collate "C" < right_parts[2] collate "C" then
+        return -1;
+    elsif left_parts[2] collate "C" > right_parts[2] collate "C" then
+        return +1;
+    end if;
+
+    -- suffixes
+    left_ranks := array(select apkver_suffix_rank(m[1]) from regexp_matches(left_parts[3], '_([a-z]+)(\d*)', 'g') as m);
+    left_suffix_numbers := array(select coalesce(nullif(m[2], ''), '0')::numeric from regexp_matches(left_parts[3], '_([a-z]+)(\d*)', 'g') as m);
+    right_ranks := array(select apkver_suffix_rank(m[1]) from regexp_matches(right_parts[3], '_([a-z]+)(\d*)', 'g') as m);
+    right_suffix_numbers := array(select coalesce(nullif(m[2], ''), '0')::numeric from regexp_matches(right_parts[3], '_([a-z]+)(\d*)', 'g') as m);
+
+    for i in 1..greatest(cardinality(left_ranks), cardinality(right_ranks)) loop
+        left_rank := coalesce(left_ranks[i], apkver_suffix_rank(null));
+        right_rank := coalesce(right_ranks[i], apkver_suffix_rank(null));
+        if left_rank < right_rank then
+            return -1;
+        elsif left_rank > right_rank then
+            return +1;
+        end if;
+
+        if coalesce(left_suffix_numbers[i], 0) < coalesce(right_suffix_numbers[i], 0) then
+            return -1;
+        elsif coalesce(left_suffix_numbers[i], 0) > coalesce(right_suffix_numbers[i], 0) then
+            return +1;
+        end if;
+    end loop;
+
+    -- revision, no revision is lower than any
+    left_revision := coalesce(left_parts[4]::numeric, -1);
+    right_revision := coalesce(right_parts[4]::numeric, -1);
+
+    if left_revision < right_revision then
+        return -1;
+    elsif left_revision > right_revision then
+        return +1;
+    end if;
+
+    return 0;
+end
+$$
+    language plpgsql immutable;
@@ -0,0 +1,22 @@
+create or replace function apkver_suffix_rank(suffix_p text)
+    returns integer
+as
+$$
+begin
+    -- pre-release suffixes sort before the end of a version, all others after it
+    return case suffix_p
+        when 'alpha' then 0
+        when 'beta' then 1
+        when 'pre' then 2
+        when 'rc' then 3
+        when 'cvs' then 5
+        when 'svn' then 6
+        when 'git' then 7
+        when 'hg' then 8
+        when 'p' then 9
+        -- no suffix
+        else 4
+    end;
+end
+$$
+    language plpgsql immutable;
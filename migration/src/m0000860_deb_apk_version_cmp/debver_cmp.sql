@@ -0,0 +1,44 @@
+create or replace function debver_cmp(left_p text, right_p text)
+    returns integer
+as
+$$
+declare
+    left_epoch numeric;
+    right_epoch numeric;
+    left_upstream text;
+    right_upstream text;
+    left_revision text;
+    right_revision text;
+    cur integer;
+begin
+    -- [epoch:]upstream_version[-debian_revision]
+    left_epoch := coalesce(substring(left_p from '^(\d+):'), '0')::numeric;
+    left_upstream := regexp_replace(left_p, '^\d+:', '');
+    left_revision := coalesce(substring(left_upstream from '-([^-]*)$'), '');
+    left_upstream := regexp_replace(left_upstream, '-[^-]*$', '');
+
+    right_epoch := coalesce(substring(right_p from '^(\d+):'), '0')::numeric;
+    right_upstream := regexp_replace(right_p, '^\d+:', '');
+    right_revision := coalesce(substring(right_upstream from '-([^-]*)$'), '');
+    right_upstream := regexp_replace(right_upstream, '-[^-]*$', '');
+
+    if left_upstream !~ '^\d' or right_upstream !~ '^\d' then
+        -- the upstream version must start with a digit
+        return null;
+    end if;
+
+    if left_epoch < right_epoch then
+        return -1;
+    elsif left_epoch > right_epoch then
+        return +1;
+    end if;
+
+    cur := debver_cmp_part(left_upstream, right_upstream);
+    if cur <> 0 then
+        return cur;
+    end if;
+
+    return debver_cmp_part(left_revision, right_revision);
+end
+$$
+    language plpgsql immutable;
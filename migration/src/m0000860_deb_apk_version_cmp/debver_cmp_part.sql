@@ -0,0 +1,47 @@
+create or replace function debver_cmp_part(left_p text, right_p text)
+    returns integer
+as
+$$
+declare
+    left_chars text;
+    right_chars text;
+    left_digits text;
+    right_digits text;
+    left_order integer;
+    right_order integer;
+begin
+    -- see: https://www.debian.org/doc/debian-policy/ch-controlfields.html#version
+    while left_p <> '' or right_p <> '' loop
+        -- the non-digit prefix, compared char by char
+        left_chars := substring(left_p from '^[^0-9]*');
+        right_chars := substring(right_p from '^[^0-9]*');
+        left_p := substr(left_p, length(left_chars) + 1);
+        right_p := substr(right_p, length(right_chars) + 1);
+
+        for i in 1..greatest(length(left_chars), length(right_chars)) loop
+            left_order := debver_order(substr(left_chars, i, 1));
+            right_order := debver_order(substr(right_chars, i, 1));
+            if left_order < right_order then
+                return -1;
+            elsif left_order > right_order then
+                return +1;
+            end if;
+        end loop;
+
+        -- the digit prefix, compared numerically
+        left_digits := substring(left_p from '^[0-9]*');
+        right_digits := substring(right_p from '^[0-9]*');
+        left_p := substr(left_p, length(left_digits) + 1);
+        right_p := substr(right_p, length(right_digits) + 1);
+
+        if coalesce(nullif(left_digits, ''), '0')::numeric < coalesce(nullif(right_digits, ''), '0')::numeric then
+            return -1;
+        elsif coalesce(nullif(left_digits, ''), '0')::numeric > coalesce(nullif(right_digits, ''), '0')::numeric then
+            return +1;
+        end if;
+    end loop;
+
+    return 0;
+end
+$$
+    language plpgsql immutable;
@@ -0,0 +1,15 @@
+create or replace function debver_order(char_p text)
+    returns integer
+as
+$$
+begin
+    -- the tilde sorts before anything, even the end of a part, letters sort before non-letters
+    return case
+        when char_p = '~' then -1
+        when char_p = '' then 0
+        when char_p ~ '^[A-Za-z]$' then ascii(char_p)
+        else ascii(char_p) + 256
+    end;
+end
+$$
+    language plpgsql immutable;
@@ -0,0 +1,54 @@
+create or replace function version_matches(version_p text, range_p version_range)
+    returns bool
+as
+$$
+declare
+begin
+    -- for an authoritative list of support schemes, see the enum
+    -- `trustify_entity::version_scheme::VersionScheme`
+    return case
+        when range_p.version_scheme_id = 'git'
+            -- Git is git, and hard.
+            then gitver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'semver'
+            -- Semver is semver
+            then semver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'gem'
+            -- RubyGems claims to be semver
+            then semver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'npm'
+            -- NPM claims to be semver
+            then semver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'golang'
+            -- Golang claims to be semver
+            then semver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'nuget'
+            -- NuGet claims to be semver
+            then semver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'generic'
+            -- Just check if it is equal
+            then generic_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'rpm'
+            -- Look at me! I'm an RPM! I'm special!
+            then rpmver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'maven'
+            -- Look at me! I'm a Maven! I'm kinda special!
+            then maven_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'python'
+            -- Python follows PEP 440
+            then python_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'pypi'
+            -- PyPI is Python
+            then python_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'deb'
+            -- Debian, and its derivatives, follow dpkg
+            then debver_version_matches(version_p, range_p)
+        when range_p.version_scheme_id = 'alpine'
+            -- Alpine follows apk
+            then apkver_version_matches(version_p, range_p)
+        else
+            false
+    end;
+end
+$$
+    language plpgsql immutable;
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The `cvss4` table was never populated before, so there is nothing to backfill. CVSS v4
+        // uses the same qualitative severity rating scale as v3, so we re-use its enum.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cvss4::Table)
+                    .add_column(ColumnDef::new(Cvss4::Score).double().not_null())
+                    .add_column(
+                        ColumnDef::new(Cvss4::Severity)
+                            .enumeration(
+                                Cvss3Severity::Cvss3Severity,
+                                [
+                                    Cvss3Severity::None,
+                                    Cvss3Severity::Low,
+                                    Cvss3Severity::Medium,
+                                    Cvss3Severity::High,
+                                    Cvss3Severity::Critical,
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cvss4::Table)
+                    .drop_column(Cvss4::Severity)
+                    .drop_column(Cvss4::Score)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Cvss4 {
+    Table,
+    Score,
+    Severity,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(DeriveIden)]
+pub enum Cvss3Severity {
+    Cvss3Severity,
+    None,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
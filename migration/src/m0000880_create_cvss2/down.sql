@@ -0,0 +1,14 @@
+DROP TABLE IF EXISTS cvss2;
+
+DROP TYPE IF EXISTS cvss2_req;
+DROP TYPE IF EXISTS cvss2_td;
+DROP TYPE IF EXISTS cvss2_cdp;
+DROP TYPE IF EXISTS cvss2_rc;
+DROP TYPE IF EXISTS cvss2_rl;
+DROP TYPE IF EXISTS cvss2_e;
+DROP TYPE IF EXISTS cvss2_a;
+DROP TYPE IF EXISTS cvss2_i;
+DROP TYPE IF EXISTS cvss2_c;
+DROP TYPE IF EXISTS cvss2_au;
+DROP TYPE IF EXISTS cvss2_ac;
+DROP TYPE IF EXISTS cvss2_av;
@@ -0,0 +1,47 @@
+CREATE TYPE cvss2_av AS ENUM ('l', 'a', 'n');
+CREATE TYPE cvss2_ac AS ENUM ('h', 'm', 'l');
+CREATE TYPE cvss2_au AS ENUM ('m', 's', 'n');
+CREATE TYPE cvss2_c AS ENUM ('n', 'p', 'c');
+CREATE TYPE cvss2_i AS ENUM ('n', 'p', 'c');
+CREATE TYPE cvss2_a AS ENUM ('n', 'p', 'c');
+CREATE TYPE cvss2_e AS ENUM ('u', 'poc', 'f', 'h');
+CREATE TYPE cvss2_rl AS ENUM ('of', 'tf', 'w', 'u');
+CREATE TYPE cvss2_rc AS ENUM ('uc', 'ur', 'c');
+CREATE TYPE cvss2_cdp AS ENUM ('n', 'l', 'lm', 'mh', 'h');
+CREATE TYPE cvss2_td AS ENUM ('n', 'l', 'm', 'h');
+CREATE TYPE cvss2_req AS ENUM ('l', 'm', 'h');
+
+CREATE TABLE cvss2
+(
+    advisory_id         UUID           NOT NULL,
+    vulnerability_id    VARCHAR        NOT NULL,
+    minor_version       INTEGER        NOT NULL,
+    idx                 INTEGER        NOT NULL,
+
+    av                  cvss2_av       NOT NULL,
+    ac                  cvss2_ac       NOT NULL,
+    au                  cvss2_au       NOT NULL,
+    c                   cvss2_c        NOT NULL,
+    i                   cvss2_i        NOT NULL,
+    a                   cvss2_a        NOT NULL,
+
+    e                   cvss2_e,
+    rl                  cvss2_rl,
+    rc                  cvss2_rc,
+
+    cdp                 cvss2_cdp,
+    td                  cvss2_td,
+    cr                  cvss2_req,
+    ir                  cvss2_req,
+    ar                  cvss2_req,
+
+    score               DOUBLE PRECISION NOT NULL,
+    severity            cvss3_severity NOT NULL,
+    temporal_score      DOUBLE PRECISION,
+    environmental_score DOUBLE PRECISION,
+
+    PRIMARY KEY (advisory_id, vulnerability_id, minor_version, idx),
+    FOREIGN KEY (advisory_id, vulnerability_id)
+        REFERENCES advisory_vulnerability (advisory_id, vulnerability_id)
+        ON DELETE CASCADE
+);
@@ -0,0 +1,32 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // All new columns are nullable, a missing value means the metric is not defined.
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000890_alter_cvss3_add_temporal_environmental/up.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(include_str!(
+                "m0000890_alter_cvss3_add_temporal_environmental/down.sql"
+            ))
+            .await
+            .map(|_| ())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+ALTER TABLE cvss3
+    DROP COLUMN IF EXISTS environmental_score,
+    DROP COLUMN IF EXISTS temporal_score,
+    DROP COLUMN IF EXISTS ma,
+    DROP COLUMN IF EXISTS mi,
+    DROP COLUMN IF EXISTS mc,
+    DROP COLUMN IF EXISTS ms,
+    DROP COLUMN IF EXISTS mui,
+    DROP COLUMN IF EXISTS mpr,
+    DROP COLUMN IF EXISTS mac,
+    DROP COLUMN IF EXISTS mav,
+    DROP COLUMN IF EXISTS ar,
+    DROP COLUMN IF EXISTS ir,
+    DROP COLUMN IF EXISTS cr,
+    DROP COLUMN IF EXISTS rc,
+    DROP COLUMN IF EXISTS rl,
+    DROP COLUMN IF EXISTS e;
+
+DROP TYPE IF EXISTS cvss3_req;
+DROP TYPE IF EXISTS cvss3_rc;
+DROP TYPE IF EXISTS cvss3_rl;
+DROP TYPE IF EXISTS cvss3_e;
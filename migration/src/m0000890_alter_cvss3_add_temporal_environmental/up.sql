@@ -0,0 +1,23 @@
+CREATE TYPE cvss3_e AS ENUM ('u', 'p', 'f', 'h');
+CREATE TYPE cvss3_rl AS ENUM ('o', 't', 'w', 'u');
+CREATE TYPE cvss3_rc AS ENUM ('u', 'r', 'c');
+CREATE TYPE cvss3_req AS ENUM ('l', 'm', 'h');
+
+-- the modified base metrics re-use the types of their base metric
+ALTER TABLE cvss3
+    ADD COLUMN e                   cvss3_e,
+    ADD COLUMN rl                  cvss3_rl,
+    ADD COLUMN rc                  cvss3_rc,
+    ADD COLUMN cr                  cvss3_req,
+    ADD COLUMN ir                  cvss3_req,
+    ADD COLUMN ar                  cvss3_req,
+    ADD COLUMN mav                 cvss3_av,
+    ADD COLUMN mac                 cvss3_ac,
+    ADD COLUMN mpr                 cvss3_pr,
+    ADD COLUMN mui                 cvss3_ui,
+    ADD COLUMN ms                  cvss3_s,
+    ADD COLUMN mc                  cvss3_c,
+    ADD COLUMN mi                  cvss3_i,
+    ADD COLUMN ma                  cvss3_a,
+    ADD COLUMN temporal_score      DOUBLE PRECISION,
+    ADD COLUMN environmental_score DOUBLE PRECISION;
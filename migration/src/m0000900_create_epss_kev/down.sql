@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS kev;
+DROP TABLE IF EXISTS epss_history;
+DROP TABLE IF EXISTS epss;
@@ -0,0 +1,40 @@
+-- the latest EPSS score of a vulnerability
+CREATE TABLE epss
+(
+    vulnerability_id VARCHAR          NOT NULL PRIMARY KEY
+        REFERENCES vulnerability (id) ON DELETE CASCADE,
+    date             TIMESTAMPTZ      NOT NULL,
+    score            DOUBLE PRECISION NOT NULL,
+    percentile       DOUBLE PRECISION NOT NULL
+);
+
+CREATE INDEX epss_score_idx ON epss (score);
+
+-- all EPSS scores of a vulnerability, one per day
+CREATE TABLE epss_history
+(
+    vulnerability_id VARCHAR          NOT NULL
+        REFERENCES vulnerability (id) ON DELETE CASCADE,
+    date             TIMESTAMPTZ      NOT NULL,
+    score            DOUBLE PRECISION NOT NULL,
+    percentile       DOUBLE PRECISION NOT NULL,
+
+    PRIMARY KEY (vulnerability_id, date)
+);
+
+-- an entry of the CISA Known Exploited Vulnerabilities catalog
+CREATE TABLE kev
+(
+    vulnerability_id              VARCHAR     NOT NULL PRIMARY KEY
+        REFERENCES vulnerability (id) ON DELETE CASCADE,
+    vendor_project                VARCHAR,
+    product                       VARCHAR,
+    name                          VARCHAR,
+    short_description             VARCHAR,
+    required_action               VARCHAR,
+    notes                         VARCHAR,
+    date_added                    TIMESTAMPTZ NOT NULL,
+    due_date                      TIMESTAMPTZ,
+    known_ransomware_campaign_use BOOLEAN     NOT NULL DEFAULT FALSE,
+    cwes                          VARCHAR[]
+);
@@ -0,0 +1,11 @@
+DROP TABLE IF EXISTS flag_purl_status;
+DROP TABLE IF EXISTS flag_product_status;
+DROP TABLE IF EXISTS threat_purl_status;
+DROP TABLE IF EXISTS threat_product_status;
+DROP TABLE IF EXISTS remediation_purl_status;
+DROP TABLE IF EXISTS remediation_product_status;
+DROP TABLE IF EXISTS flag;
+DROP TABLE IF EXISTS threat;
+DROP TABLE IF EXISTS remediation;
+
+DELETE FROM status WHERE slug IN ('first_affected', 'first_fixed', 'last_affected');
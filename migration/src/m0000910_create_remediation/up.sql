@@ -0,0 +1,100 @@
+-- the remaining CSAF product status categories
+INSERT INTO status (slug, name, description)
+VALUES ('first_affected', 'First Affected', 'Vulnerabililty affects, starting with this version'),
+       ('first_fixed', 'First Fixed', 'Vulnerabililty is fixed, starting with this version'),
+       ('last_affected', 'Last Affected', 'Vulnerabililty affects, up to this version');
+
+-- how to fix a vulnerability, or deal with it
+CREATE TABLE remediation
+(
+    id               UUID        NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+    advisory_id      UUID        NOT NULL,
+    vulnerability_id VARCHAR     NOT NULL,
+    category         VARCHAR     NOT NULL,
+    details          VARCHAR     NOT NULL,
+    url              VARCHAR,
+    date             TIMESTAMPTZ,
+
+    FOREIGN KEY (advisory_id, vulnerability_id)
+        REFERENCES advisory_vulnerability (advisory_id, vulnerability_id) ON DELETE CASCADE
+);
+
+-- the impact, exploit status or target set of a vulnerability
+CREATE TABLE threat
+(
+    id               UUID        NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+    advisory_id      UUID        NOT NULL,
+    vulnerability_id VARCHAR     NOT NULL,
+    category         VARCHAR     NOT NULL,
+    details          VARCHAR     NOT NULL,
+    date             TIMESTAMPTZ,
+
+    FOREIGN KEY (advisory_id, vulnerability_id)
+        REFERENCES advisory_vulnerability (advisory_id, vulnerability_id) ON DELETE CASCADE
+);
+
+-- the justification of why a product is not affected by a vulnerability
+CREATE TABLE flag
+(
+    id               UUID        NOT NULL PRIMARY KEY DEFAULT gen_random_uuid(),
+    advisory_id      UUID        NOT NULL,
+    vulnerability_id VARCHAR     NOT NULL,
+    label            VARCHAR     NOT NULL,
+    date             TIMESTAMPTZ,
+
+    FOREIGN KEY (advisory_id, vulnerability_id)
+        REFERENCES advisory_vulnerability (advisory_id, vulnerability_id) ON DELETE CASCADE
+);
+
+-- the product and purl statuses the entries above apply to
+CREATE TABLE remediation_product_status
+(
+    remediation_id    UUID NOT NULL REFERENCES remediation (id) ON DELETE CASCADE,
+    product_status_id UUID NOT NULL REFERENCES product_status (id) ON DELETE CASCADE,
+    PRIMARY KEY (remediation_id, product_status_id)
+);
+
+CREATE TABLE remediation_purl_status
+(
+    remediation_id UUID NOT NULL REFERENCES remediation (id) ON DELETE CASCADE,
+    purl_status_id UUID NOT NULL REFERENCES purl_status (id) ON DELETE CASCADE,
+    PRIMARY KEY (remediation_id, purl_status_id)
+);
+
+CREATE TABLE threat_product_status
+(
+    threat_id         UUID NOT NULL REFERENCES threat (id) ON DELETE CASCADE,
+    product_status_id UUID NOT NULL REFERENCES product_status (id) ON DELETE CASCADE,
+    PRIMARY KEY (threat_id, product_status_id)
+);
+
+CREATE TABLE threat_purl_status
+(
+    threat_id      UUID NOT NULL REFERENCES threat (id) ON DELETE CASCADE,
+    purl_status_id UUID NOT NULL REFERENCES purl_status (id) ON DELETE CASCADE,
+    PRIMARY KEY (threat_id, purl_status_id)
+);
+
+CREATE TABLE flag_product_status
+(
+    flag_id           UUID NOT NULL REFERENCES flag (id) ON DELETE CASCADE,
+    product_status_id UUID NOT NULL REFERENCES product_status (id) ON DELETE CASCADE,
+    PRIMARY KEY (flag_id, product_status_id)
+);
+
+CREATE TABLE flag_purl_status
+(
+    flag_id        UUID NOT NULL REFERENCES flag (id) ON DELETE CASCADE,
+    purl_status_id UUID NOT NULL REFERENCES purl_status (id) ON DELETE CASCADE,
+    PRIMARY KEY (flag_id, purl_status_id)
+);
+
+CREATE INDEX remediation_advisory_vulnerability_idx ON remediation (advisory_id, vulnerability_id);
+CREATE INDEX threat_advisory_vulnerability_idx ON threat (advisory_id, vulnerability_id);
+CREATE INDEX flag_advisory_vulnerability_idx ON flag (advisory_id, vulnerability_id);
+CREATE INDEX remediation_purl_status_purl_status_idx ON remediation_purl_status (purl_status_id);
+CREATE INDEX remediation_product_status_product_status_idx ON remediation_product_status (product_status_id);
+CREATE INDEX threat_purl_status_purl_status_idx ON threat_purl_status (purl_status_id);
+CREATE INDEX threat_product_status_product_status_idx ON threat_product_status (product_status_id);
+CREATE INDEX flag_purl_status_purl_status_idx ON flag_purl_status (purl_status_id);
+CREATE INDEX flag_product_status_product_status_idx ON flag_product_status (product_status_id);
@@ -0,0 +1,9 @@
+ALTER TABLE product_status
+    DROP COLUMN IF EXISTS action_statement,
+    DROP COLUMN IF EXISTS impact_statement,
+    DROP COLUMN IF EXISTS justification;
+
+ALTER TABLE purl_status
+    DROP COLUMN IF EXISTS action_statement,
+    DROP COLUMN IF EXISTS impact_statement,
+    DROP COLUMN IF EXISTS justification;
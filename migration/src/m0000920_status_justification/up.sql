@@ -0,0 +1,9 @@
+ALTER TABLE purl_status
+    ADD COLUMN justification VARCHAR,
+    ADD COLUMN impact_statement VARCHAR,
+    ADD COLUMN action_statement VARCHAR;
+
+ALTER TABLE product_status
+    ADD COLUMN justification VARCHAR,
+    ADD COLUMN impact_statement VARCHAR,
+    ADD COLUMN action_statement VARCHAR;
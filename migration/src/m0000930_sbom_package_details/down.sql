@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS sbom_package_property;
+DROP TABLE IF EXISTS sbom_package_external_reference;
+DROP TABLE IF EXISTS sbom_node_checksum;
+
+ALTER TABLE sbom_package
+    DROP COLUMN IF EXISTS copyright_text,
+    DROP COLUMN IF EXISTS download_location,
+    DROP COLUMN IF EXISTS originator,
+    DROP COLUMN IF EXISTS supplier;
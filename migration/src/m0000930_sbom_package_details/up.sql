@@ -0,0 +1,47 @@
+-- the origin of a package
+ALTER TABLE sbom_package
+    ADD COLUMN supplier VARCHAR,
+    ADD COLUMN originator VARCHAR,
+    ADD COLUMN download_location VARCHAR,
+    ADD COLUMN copyright_text VARCHAR;
+
+-- the checksums of packages and files, e.g. a SHA-256 digest
+CREATE TABLE sbom_node_checksum
+(
+    sbom_id UUID    NOT NULL,
+    node_id VARCHAR NOT NULL,
+    type    VARCHAR NOT NULL,
+    value   VARCHAR NOT NULL,
+    PRIMARY KEY (sbom_id, node_id, type, value),
+
+    FOREIGN KEY (sbom_id, node_id)
+        REFERENCES sbom_node (sbom_id, node_id) ON DELETE CASCADE
+);
+
+-- external references of packages, other than purls and CPEs
+CREATE TABLE sbom_package_external_reference
+(
+    sbom_id UUID    NOT NULL,
+    node_id VARCHAR NOT NULL,
+    type    VARCHAR NOT NULL,
+    url     VARCHAR NOT NULL,
+    PRIMARY KEY (sbom_id, node_id, type, url),
+
+    FOREIGN KEY (sbom_id, node_id)
+        REFERENCES sbom_node (sbom_id, node_id) ON DELETE CASCADE
+);
+
+-- arbitrary name/value properties of packages
+CREATE TABLE sbom_package_property
+(
+    sbom_id UUID    NOT NULL,
+    node_id VARCHAR NOT NULL,
+    name    VARCHAR NOT NULL,
+    value   VARCHAR NOT NULL,
+    PRIMARY KEY (sbom_id, node_id, name, value),
+
+    FOREIGN KEY (sbom_id, node_id)
+        REFERENCES sbom_node (sbom_id, node_id) ON DELETE CASCADE
+);
+
+CREATE INDEX sbom_node_checksum_value_idx ON sbom_node_checksum (value);
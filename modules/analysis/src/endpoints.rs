@@ -1,19 +1,38 @@
 use super::service::AnalysisService;
 use crate::{
-    model::{AnalysisStatus, AncestorSummary, DepSummary},
+    export::{render, ExportFormat},
+    model::{
+        AnalysisStatus, AncestorSummary, DepSummary, GraphTraversal, VulnerableComponentSummary,
+    },
     Error,
 };
 use actix_web::{get, web, HttpResponse, Responder};
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 use trustify_auth::{
     authenticator::user::UserInformation,
     authorizer::{Authorizer, Require},
     Permission, ReadSbom,
 };
-use trustify_common::{db::query::Query, db::Database, model::Paginated, purl::Purl};
+use trustify_common::{
+    db::query::Query,
+    db::Database,
+    model::{Paginated, PaginatedResults},
+    purl::Purl,
+};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct Config {
+    /// Directory to persist analysis graph snapshots to, so they survive a restart.
+    pub snapshot_dir: Option<PathBuf>,
+}
 
-pub fn configure(config: &mut utoipa_actix_web::service_config::ServiceConfig, db: Database) {
-    let analysis = AnalysisService::new();
+pub fn configure(
+    config: &mut utoipa_actix_web::service_config::ServiceConfig,
+    analysis_config: Config,
+    db: Database,
+) {
+    let analysis = AnalysisService::with_config(analysis_config);
 
     config
         .app_data(web::Data::new(analysis))
@@ -22,7 +41,9 @@ pub fn configure(config: &mut utoipa_actix_web::service_config::ServiceConfig, d
         .service(get_component_root_components)
         .service(analysis_status)
         .service(search_component_deps)
-        .service(get_component_deps);
+        .service(get_component_deps)
+        .service(get_vulnerability_root_components)
+        .service(export_sbom_graph);
 }
 
 #[utoipa::path(
@@ -49,6 +70,7 @@ pub async fn analysis_status(
     operation_id = "searchComponentRootComponents",
     params(
         Query,
+        GraphTraversal,
         Paginated,
     ),
     responses(
@@ -60,12 +82,13 @@ pub async fn search_component_root_components(
     service: web::Data<AnalysisService>,
     db: web::Data<Database>,
     web::Query(search): web::Query<Query>,
+    web::Query(traversal): web::Query<GraphTraversal>,
     web::Query(paginated): web::Query<Paginated>,
     _: Require<ReadSbom>,
 ) -> actix_web::Result<impl Responder> {
     Ok(HttpResponse::Ok().json(
         service
-            .retrieve_root_components(search, paginated, db.as_ref())
+            .retrieve_root_components(search, traversal, paginated, db.as_ref())
             .await?,
     ))
 }
@@ -74,7 +97,8 @@ pub async fn search_component_root_components(
     tag = "analysis",
     operation_id = "getComponentRootComponents",
     params(
-        ("key" = String, Path, description = "provide component name or URL-encoded pURL itself")
+        ("key" = String, Path, description = "provide component name or URL-encoded pURL itself"),
+        GraphTraversal,
     ),
     responses(
         (status = 200, description = "Retrieve component(s) root components by name or pURL.", body = AncestorSummary),
@@ -85,6 +109,7 @@ pub async fn get_component_root_components(
     service: web::Data<AnalysisService>,
     db: web::Data<Database>,
     key: web::Path<String>,
+    web::Query(traversal): web::Query<GraphTraversal>,
     web::Query(paginated): web::Query<Paginated>,
     _: Require<ReadSbom>,
 ) -> actix_web::Result<impl Responder> {
@@ -92,13 +117,18 @@ pub async fn get_component_root_components(
         let purl: Purl = Purl::from_str(&key).map_err(Error::Purl)?;
         Ok(HttpResponse::Ok().json(
             service
-                .retrieve_root_components_by_purl(purl, paginated, db.as_ref())
+                .retrieve_root_components_by_purl(purl, traversal, paginated, db.as_ref())
                 .await?,
         ))
     } else {
         Ok(HttpResponse::Ok().json(
             service
-                .retrieve_root_components_by_name(key.to_string(), paginated, db.as_ref())
+                .retrieve_root_components_by_name(
+                    key.to_string(),
+                    traversal,
+                    paginated,
+                    db.as_ref(),
+                )
                 .await?,
         ))
     }
@@ -109,6 +139,7 @@ pub async fn get_component_root_components(
     operation_id = "searchComponentDeps",
     params(
         Query,
+        GraphTraversal,
         Paginated,
     ),
     responses(
@@ -120,12 +151,13 @@ pub async fn search_component_deps(
     service: web::Data<AnalysisService>,
     db: web::Data<Database>,
     web::Query(search): web::Query<Query>,
+    web::Query(traversal): web::Query<GraphTraversal>,
     web::Query(paginated): web::Query<Paginated>,
     _: Require<ReadSbom>,
 ) -> actix_web::Result<impl Responder> {
     Ok(HttpResponse::Ok().json(
         service
-            .retrieve_deps(search, paginated, db.as_ref())
+            .retrieve_deps(search, traversal, paginated, db.as_ref())
             .await?,
     ))
 }
@@ -134,7 +166,8 @@ pub async fn search_component_deps(
     tag = "analysis",
     operation_id = "getComponentDeps",
     params(
-        ("key" = String, Path, description = "provide component name or URL-encoded pURL itself")
+        ("key" = String, Path, description = "provide component name or URL-encoded pURL itself"),
+        GraphTraversal,
     ),
     responses(
         (status = 200, description = "Retrieve component(s) dep components by name or pURL.", body = DepSummary),
@@ -145,6 +178,7 @@ pub async fn get_component_deps(
     service: web::Data<AnalysisService>,
     db: web::Data<Database>,
     key: web::Path<String>,
+    web::Query(traversal): web::Query<GraphTraversal>,
     web::Query(paginated): web::Query<Paginated>,
     _: Require<ReadSbom>,
 ) -> actix_web::Result<impl Responder> {
@@ -152,27 +186,93 @@ pub async fn get_component_deps(
         let purl: Purl = Purl::from_str(&key).map_err(Error::Purl)?;
         Ok(HttpResponse::Ok().json(
             service
-                .retrieve_deps_by_purl(purl, paginated, db.as_ref())
+                .retrieve_deps_by_purl(purl, traversal, paginated, db.as_ref())
                 .await?,
         ))
     } else {
         Ok(HttpResponse::Ok().json(
             service
-                .retrieve_deps_by_name(key.to_string(), paginated, db.as_ref())
+                .retrieve_deps_by_name(key.to_string(), traversal, paginated, db.as_ref())
                 .await?,
         ))
     }
 }
 
+#[utoipa::path(
+    tag = "analysis",
+    operation_id = "getVulnerabilityRootComponents",
+    params(
+        ("id" = String, Path, description = "ID of the vulnerability, e.g. a CVE id"),
+        Paginated,
+    ),
+    responses(
+        (status = 200, description = "Retrieve the components affected by a vulnerability, with their paths to root components.", body = PaginatedResults<VulnerableComponentSummary>),
+    ),
+)]
+#[get("/v1/analysis/vulnerability/{id}")]
+pub async fn get_vulnerability_root_components(
+    service: web::Data<AnalysisService>,
+    db: web::Data<Database>,
+    id: web::Path<String>,
+    web::Query(paginated): web::Query<Paginated>,
+    _: Require<ReadSbom>,
+) -> actix_web::Result<impl Responder> {
+    Ok(HttpResponse::Ok().json(
+        service
+            .retrieve_by_vulnerability(&id, paginated, db.as_ref())
+            .await?,
+    ))
+}
+
+#[derive(Clone, Debug, serde::Deserialize, utoipa::IntoParams)]
+struct ExportQuery {
+    /// The format to render the graph in
+    #[serde(default)]
+    #[param(inline)]
+    format: ExportFormat,
+    /// Only export the sub-graph rooted at the component with this node id
+    #[serde(default)]
+    node_id: Option<String>,
+}
+
+#[utoipa::path(
+    tag = "analysis",
+    operation_id = "exportSbomGraph",
+    params(
+        ("id" = Uuid, Path, description = "ID of the SBOM"),
+        ExportQuery,
+    ),
+    responses(
+        (status = 200, description = "The dependency graph of the SBOM, annotated with the vulnerabilities of its components.", body = String),
+        (status = 404, description = "The SBOM or the node could not be found"),
+    ),
+)]
+#[get("/v1/analysis/sbom/{id}/graph")]
+pub async fn export_sbom_graph(
+    service: web::Data<AnalysisService>,
+    db: web::Data<Database>,
+    id: web::Path<Uuid>,
+    web::Query(ExportQuery { format, node_id }): web::Query<ExportQuery>,
+    _: Require<ReadSbom>,
+) -> actix_web::Result<impl Responder> {
+    let graph = service.export_graph(*id, node_id, db.as_ref()).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .body(render(&graph, &id.to_string(), format)))
+}
+
 #[cfg(test)]
 mod test {
     use crate::test::{caller, CallService};
-    use actix_http::Request;
-    use actix_web::test::TestRequest;
+    use actix_http::{Request, StatusCode};
+    use actix_web::{http::header, test::TestRequest};
     use serde_json::Value;
     use test_context::test_context;
     use test_log::test;
+    use trustify_common::id::Id;
     use trustify_test_context::TrustifyContext;
+    use uuid::Uuid;
 
     #[test_context(TrustifyContext)]
     #[test(actix_web::test)]
@@ -233,6 +333,82 @@ mod test {
         Ok(())
     }
 
+    #[test_context(TrustifyContext)]
+    #[test(actix_web::test)]
+    async fn test_traversal_analysis_endpoint(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let app = caller(ctx).await?;
+        ctx.ingest_documents(["spdx/simple.json"]).await?;
+
+        let uri = "/api/v1/analysis/root-component/DD?depth=1";
+        let request: Request = TestRequest::get().uri(uri).to_request();
+        let response: Value = app.call_and_read_body_json(request).await;
+        assert_eq!(
+            response["items"][0]["ancestors"].as_array().unwrap().len(),
+            1
+        );
+        assert_eq!(
+            response["items"][0]["ancestors"][0]["node_id"],
+            "SPDXRef-BB"
+        );
+
+        let uri =
+            "/api/v1/analysis/root-component/DD?relationships=dependency_of,dev_dependency_of";
+        let request: Request = TestRequest::get().uri(uri).to_request();
+        let response: Value = app.call_and_read_body_json(request).await;
+        assert!(response["items"][0]["ancestors"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+
+        let uri = "/api/v1/analysis/dep/AA?depth=1";
+        let request: Request = TestRequest::get().uri(uri).to_request();
+        let response: Value = app.call_and_read_body_json(request).await;
+        assert_eq!(response["items"][0]["deps"][0]["node_id"], "SPDXRef-BB");
+        assert!(response["items"][0]["deps"][0]["deps"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+
+        // unknown relationships are rejected
+        let uri = "/api/v1/analysis/root-component/DD?relationships=foo";
+        let request: Request = TestRequest::get().uri(uri).to_request();
+        let response = app.call_service(request).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(actix_web::test)]
+    async fn test_stitched_retrieve_by_name_analysis_endpoint(
+        ctx: &TrustifyContext,
+    ) -> Result<(), anyhow::Error> {
+        let app = caller(ctx).await?;
+        ctx.ingest_documents(["spdx/stitched-product.json", "spdx/stitched-image.json"])
+            .await?;
+
+        let uri = "/api/v1/analysis/root-component/stitched-library";
+
+        let request: Request = TestRequest::get().uri(uri).to_request();
+
+        let response: Value = app.call_and_read_body_json(request).await;
+
+        assert_eq!(
+            response["items"][0]["purl"],
+            "pkg:rpm/redhat/stitched-library@1.0"
+        );
+        assert_eq!(
+            response["items"][0]["ancestors"][0]["purl"],
+            "pkg:oci/stitched-image@1.0"
+        );
+        assert_eq!(
+            response["items"][0]["ancestors"][1]["purl"],
+            "pkg:generic/redhat/stitched-product@1.0"
+        );
+        assert_eq!(&response["total"], 1);
+        Ok(())
+    }
+
     #[test_context(TrustifyContext)]
     #[test(actix_web::test)]
     async fn test_simple_retrieve_by_purl_analysis_endpoint(
@@ -426,6 +602,32 @@ mod test {
         Ok(())
     }
 
+    #[test_context(TrustifyContext)]
+    #[test(actix_web::test)]
+    async fn test_vulnerability_endpoint(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let app = caller(ctx).await?;
+        ctx.ingest_documents(["cve/CVE-2024-26308.json", "spdx/SATELLITE-6.15-RHEL-8.json"])
+            .await?;
+
+        let uri = "/api/v1/analysis/vulnerability/CVE-2024-26308";
+        let request: Request = TestRequest::get().uri(uri).to_request();
+        let response: Value = app.call_and_read_body_json(request).await;
+
+        log::debug!("{response:#?}");
+
+        assert_eq!(
+            response["items"][0]["purl"],
+            "pkg:maven/org.apache.commons/commons-compress@1.21"
+        );
+        assert_eq!(response["items"][0]["status"], "affected");
+        assert_eq!(
+            response["items"][0]["document_id"],
+            "https://access.redhat.com/security/data/sbom/spdx/SATELLITE-6.15-RHEL-8"
+        );
+        assert_eq!(&response["total"], 1);
+        Ok(())
+    }
+
     #[test_context(TrustifyContext)]
     #[test(actix_web::test)]
     async fn test_retrieve_query_params_endpoint(
@@ -476,4 +678,102 @@ mod test {
         assert_eq!(&response["total"], 0);
         Ok(())
     }
+
+    #[test_context(TrustifyContext)]
+    #[test(actix_web::test)]
+    async fn test_export_graph_endpoint(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let app = caller(ctx).await?;
+        let result = ctx.ingest_document("spdx/simple.json").await?;
+        let Id::Uuid(sbom_id) = result.id else {
+            panic!("must be a UUID");
+        };
+
+        // the full graph
+        let uri = format!("/api/v1/analysis/sbom/{sbom_id}/graph");
+        let request: Request = TestRequest::get().uri(&uri).to_request();
+        let response: Value = app.call_and_read_body_json(request).await;
+        let nodes = response["elements"]["nodes"].as_array().unwrap();
+        assert!(nodes
+            .iter()
+            .any(|node| node["data"]["node_id"] == "SPDXRef-A"));
+        assert!(!response["elements"]["edges"].as_array().unwrap().is_empty());
+
+        // a sub-graph, as DOT
+        let uri = format!("/api/v1/analysis/sbom/{sbom_id}/graph?format=dot&node_id=SPDXRef-BB");
+        let request: Request = TestRequest::get().uri(&uri).to_request();
+        let response = app.call_service(request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/vnd.graphviz"
+        );
+        let body = String::from_utf8(actix_web::test::read_body(response).await.to_vec())?;
+        assert!(body.starts_with("digraph {"));
+        assert!(body.contains("pkg:rpm/redhat/DD@0.0.0"));
+        assert!(!body.contains("pkg:rpm/redhat/AA@0.0.0"));
+
+        // as GraphML
+        let uri = format!("/api/v1/analysis/sbom/{sbom_id}/graph?format=graphml");
+        let request: Request = TestRequest::get().uri(&uri).to_request();
+        let body = String::from_utf8(app.call_and_read_body(request).await.to_vec())?;
+        assert!(body.contains("<graphml"));
+        assert!(body.contains(r#"<data key="node_id">SPDXRef-DD</data>"#));
+
+        // unknown node
+        let uri = format!("/api/v1/analysis/sbom/{sbom_id}/graph?node_id=SPDXRef-XX");
+        let request: Request = TestRequest::get().uri(&uri).to_request();
+        let response = app.call_service(request).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // unknown SBOM
+        let uri = format!("/api/v1/analysis/sbom/{}/graph", Uuid::new_v4());
+        let request: Request = TestRequest::get().uri(&uri).to_request();
+        let response = app.call_service(request).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(actix_web::test)]
+    async fn test_export_vulnerable_graph_endpoint(
+        ctx: &TrustifyContext,
+    ) -> Result<(), anyhow::Error> {
+        let app = caller(ctx).await?;
+        let result = ctx
+            .ingest_documents(["cve/CVE-2024-26308.json", "spdx/SATELLITE-6.15-RHEL-8.json"])
+            .await?;
+        let Id::Uuid(sbom_id) = result[1].id else {
+            panic!("must be a UUID");
+        };
+
+        let uri = format!("/api/v1/analysis/sbom/{sbom_id}/graph?format=cytoscape");
+        let request: Request = TestRequest::get().uri(&uri).to_request();
+        let response: Value = app.call_and_read_body_json(request).await;
+
+        let nodes = response["elements"]["nodes"].as_array().unwrap();
+        let node = nodes
+            .iter()
+            .find(|node| {
+                node["data"]["purl"] == "pkg:maven/org.apache.commons/commons-compress@1.21"
+            })
+            .unwrap();
+        assert_eq!(node["data"]["affected"], true);
+        assert_eq!(
+            node["data"]["vulnerabilities"][0]["vulnerability_id"],
+            "CVE-2024-26308"
+        );
+        assert_eq!(node["data"]["vulnerabilities"][0]["status"], "affected");
+
+        // only the affected components are flagged
+        assert_eq!(
+            nodes
+                .iter()
+                .filter(|node| node["data"]["affected"] == true)
+                .count(),
+            1
+        );
+
+        Ok(())
+    }
 }
@@ -20,6 +20,8 @@ pub enum Error {
     Actix(#[from] actix_web::Error),
     #[error("Invalid request {msg}")]
     BadRequest { msg: String, status: StatusCode },
+    #[error("Not found: {0}")]
+    NotFound(String),
     #[error(transparent)]
     Any(#[from] anyhow::Error),
     #[error("Unsupported hash algorithm")]
@@ -49,6 +51,9 @@ impl ResponseError for Error {
             Self::BadRequest { msg, status } => {
                 HttpResponse::build(*status).json(ErrorInformation::new("Bad request", msg))
             }
+            Self::NotFound(msg) => {
+                HttpResponse::NotFound().json(ErrorInformation::new("Not Found", msg))
+            }
             Error::Query(err) => {
                 HttpResponse::BadRequest().json(ErrorInformation::new("Query error", err))
             }
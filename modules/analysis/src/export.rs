@@ -0,0 +1,231 @@
+use crate::model::PackageNode;
+use petgraph::{
+    dot::Dot,
+    graph::Graph,
+    visit::{EdgeRef, IntoNodeReferences},
+};
+use serde_json::json;
+use std::fmt::{self, Write};
+use trustify_entity::{relationship::Relationship, status};
+use utoipa::ToSchema;
+
+/// Formats an analysis graph can be exported to.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Graphviz DOT
+    Dot,
+    /// GraphML
+    GraphMl,
+    /// Cytoscape.js JSON
+    #[default]
+    Cytoscape,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Dot => "text/vnd.graphviz",
+            Self::GraphMl => "application/graphml+xml",
+            Self::Cytoscape => "application/json",
+        }
+    }
+}
+
+/// The status of a vulnerability for a component.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct NodeVulnerability {
+    pub vulnerability_id: String,
+    pub status: String,
+}
+
+/// A component of an exported graph, annotated with its vulnerabilities.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportNode {
+    pub package: PackageNode,
+    pub vulnerabilities: Vec<NodeVulnerability>,
+}
+
+impl ExportNode {
+    /// Check if any vulnerability affects the component.
+    pub fn is_affected(&self) -> bool {
+        self.vulnerabilities
+            .iter()
+            .any(|vulnerability| status::AFFECTED.contains(&vulnerability.status.as_str()))
+    }
+}
+
+impl fmt::Display for ExportNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.package)
+    }
+}
+
+pub type ExportGraph = Graph<ExportNode, Relationship, petgraph::Directed>;
+
+pub fn render(graph: &ExportGraph, sbom_id: &str, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Dot => render_dot(graph),
+        ExportFormat::GraphMl => render_graphml(graph, sbom_id),
+        ExportFormat::Cytoscape => render_cytoscape(graph).to_string(),
+    }
+}
+
+fn render_dot(graph: &ExportGraph) -> String {
+    format!(
+        "{}",
+        Dot::with_attr_getters(graph, &[], &|_, _| String::new(), &|_, (_, node)| {
+            let mut attributes = format!("tooltip = \"{}\"", escape_dot(&tooltip(node)));
+            if node.is_affected() {
+                attributes.push_str(" color = red fontcolor = red");
+            }
+            attributes
+        })
+    )
+}
+
+fn tooltip(node: &ExportNode) -> String {
+    let mut tooltip = format!("{} {}", node.package.name, node.package.version);
+    for vulnerability in &node.vulnerabilities {
+        let _ = write!(
+            tooltip,
+            "\n{}: {}",
+            vulnerability.vulnerability_id, vulnerability.status
+        );
+    }
+    tooltip
+}
+
+fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn render_graphml(graph: &ExportGraph, sbom_id: &str) -> String {
+    let mut result = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="node_id" for="node" attr.name="node_id" attr.type="string"/>
+  <key id="purl" for="node" attr.name="purl" attr.type="string"/>
+  <key id="name" for="node" attr.name="name" attr.type="string"/>
+  <key id="version" for="node" attr.name="version" attr.type="string"/>
+  <key id="affected" for="node" attr.name="affected" attr.type="boolean"/>
+  <key id="vulnerabilities" for="node" attr.name="vulnerabilities" attr.type="string"/>
+  <key id="relationship" for="edge" attr.name="relationship" attr.type="string"/>
+"#,
+    );
+
+    let _ = writeln!(
+        result,
+        r#"  <graph id="{}" edgedefault="directed">"#,
+        escape_xml(sbom_id)
+    );
+
+    for (index, node) in graph.node_references() {
+        let vulnerabilities = node
+            .vulnerabilities
+            .iter()
+            .map(|vulnerability| {
+                format!(
+                    "{}:{}",
+                    vulnerability.vulnerability_id, vulnerability.status
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let _ = writeln!(result, r#"    <node id="n{}">"#, index.index());
+        for (key, value) in [
+            ("node_id", node.package.node_id.as_str()),
+            ("purl", &node.package.purl),
+            ("name", &node.package.name),
+            ("version", &node.package.version),
+            (
+                "affected",
+                if node.is_affected() { "true" } else { "false" },
+            ),
+            ("vulnerabilities", &vulnerabilities),
+        ] {
+            let _ = writeln!(
+                result,
+                r#"      <data key="{key}">{}</data>"#,
+                escape_xml(value)
+            );
+        }
+        result.push_str("    </node>\n");
+    }
+
+    for edge in graph.edge_references() {
+        let _ = writeln!(
+            result,
+            r#"    <edge id="e{}" source="n{}" target="n{}">"#,
+            edge.id().index(),
+            edge.source().index(),
+            edge.target().index()
+        );
+        let _ = writeln!(
+            result,
+            r#"      <data key="relationship">{}</data>"#,
+            escape_xml(&edge.weight().to_string())
+        );
+        result.push_str("    </edge>\n");
+    }
+
+    result.push_str("  </graph>\n</graphml>\n");
+    result
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn render_cytoscape(graph: &ExportGraph) -> serde_json::Value {
+    let nodes = graph
+        .node_references()
+        .map(|(index, node)| {
+            json!({
+                "data": {
+                    "id": format!("n{}", index.index()),
+                    "label": node.package.name,
+                    "sbom_id": node.package.sbom_id,
+                    "node_id": node.package.node_id,
+                    "purl": node.package.purl,
+                    "name": node.package.name,
+                    "version": node.package.version,
+                    "affected": node.is_affected(),
+                    "vulnerabilities": node.vulnerabilities,
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let edges = graph
+        .edge_references()
+        .map(|edge| {
+            json!({
+                "data": {
+                    "id": format!("e{}", edge.id().index()),
+                    "source": format!("n{}", edge.source().index()),
+                    "target": format!("n{}", edge.target().index()),
+                    "relationship": edge.weight().to_string(),
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "elements": {
+            "nodes": nodes,
+            "edges": edges,
+        }
+    })
+}
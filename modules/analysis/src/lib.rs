@@ -1,5 +1,7 @@
 pub mod endpoints;
 
+pub mod export;
+
 pub mod service;
 
 pub mod error;
@@ -1,18 +1,31 @@
 use parking_lot::RwLock;
-use petgraph::Graph;
-use serde::Serialize;
+use petgraph::{
+    graph::{Edge, Node},
+    Graph,
+};
+use serde::{
+    de::{value, Error as _, IntoDeserializer},
+    Deserialize, Deserializer, Serialize,
+};
 use std::{
     collections::HashMap,
     fmt,
+    mem::size_of,
+    path::{Path, PathBuf},
     sync::{Arc, OnceLock},
+    time::Duration,
 };
 use trustify_entity::relationship::Relationship;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Clone, PartialEq, Eq, ToSchema, serde::Serialize)]
 pub struct AnalysisStatus {
     pub sbom_count: i32,
     pub graph_count: i32,
+    /// estimated number of bytes held by all loaded graphs
+    pub graph_memory: u64,
+    /// details of each loaded graph
+    pub graphs: Vec<GraphStatus>,
 }
 
 impl fmt::Display for AnalysisStatus {
@@ -21,7 +34,84 @@ impl fmt::Display for AnalysisStatus {
     }
 }
 
+/// Options restricting how the analysis graph is walked.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, IntoParams)]
+pub struct GraphTraversal {
+    /// Maximum number of relationships to walk from the component, unlimited if not set
+    #[serde(default)]
+    pub depth: Option<u32>,
+    /// Comma separated list of relationships to walk (e.g. `dependency_of,contained_by`), all if not set
+    #[serde(default, deserialize_with = "deserialize_relationships")]
+    #[param(value_type = Option<String>)]
+    pub relationships: Option<Vec<Relationship>>,
+    /// List the dependencies of a component only the first time it is encountered
+    #[serde(default = "default_dedup")]
+    pub dedup: bool,
+}
+
+const fn default_dedup() -> bool {
+    true
+}
+
+impl Default for GraphTraversal {
+    fn default() -> Self {
+        Self {
+            depth: None,
+            relationships: None,
+            dedup: default_dedup(),
+        }
+    }
+}
+
+impl GraphTraversal {
+    /// Check if an edge of the provided relationship should be walked
+    pub fn follows(&self, relationship: &Relationship) -> bool {
+        self.relationships
+            .as_ref()
+            .map(|relationships| relationships.contains(relationship))
+            .unwrap_or(true)
+    }
+
+    /// Check if the provided depth reached the limit
+    pub fn exceeds(&self, depth: u32) -> bool {
+        self.depth.is_some_and(|max| depth >= max)
+    }
+}
+
+fn deserialize_relationships<'de, D>(deserializer: D) -> Result<Option<Vec<Relationship>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(relationships) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    relationships
+        .split(',')
+        .map(str::trim)
+        .filter(|relationship| !relationship.is_empty())
+        .map(|relationship| {
+            Relationship::deserialize(IntoDeserializer::<value::Error>::into_deserializer(
+                relationship,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+        .map_err(D::Error::custom)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ToSchema, serde::Serialize)]
+pub struct GraphStatus {
+    pub sbom_id: String,
+    pub node_count: u64,
+    pub edge_count: u64,
+    /// estimated number of bytes held by the graph
+    pub memory: u64,
+    /// time it took to load the graph, in milliseconds
+    pub load_time_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ToSchema, serde::Serialize, serde::Deserialize)]
 pub struct PackageNode {
     pub sbom_id: String,
     pub node_id: String,
@@ -98,9 +188,64 @@ pub struct DepSummary {
     pub product_version: String,
     pub deps: Vec<DepNode>,
 }
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VulnerableComponentSummary {
+    pub vulnerability_id: String,
+    pub advisory_id: String,
+    pub status: String,
+    pub sbom_id: String,
+    pub node_id: String,
+    pub purl: String,
+    pub name: String,
+    pub version: String,
+    pub published: String,
+    pub document_id: String,
+    pub product_name: String,
+    pub product_version: String,
+    /// distinct root components reachable from the affected component
+    pub roots: Vec<AncNode>,
+    /// the dependency paths from the affected component up to one of its roots, continued into
+    /// the SBOMs including the root
+    pub paths: Vec<Vec<AncNode>>,
+    /// if the paths are incomplete, as there were too many or too long ones
+    pub truncated: bool,
+}
+
+impl PackageNode {
+    fn heap_size(&self) -> usize {
+        self.sbom_id.capacity()
+            + self.node_id.capacity()
+            + self.purl.capacity()
+            + self.name.capacity()
+            + self.version.capacity()
+            + self.published.capacity()
+            + self.document_id.capacity()
+            + self.product_name.capacity()
+            + self.product_version.capacity()
+    }
+}
+
+/// Estimate the number of bytes a graph occupies in memory.
+pub fn graph_memory(graph: &Graph<PackageNode, Relationship, petgraph::Directed>) -> u64 {
+    let (node_capacity, edge_capacity) = graph.capacity();
+    let nodes = node_capacity * size_of::<Node<PackageNode>>();
+    let edges = edge_capacity * size_of::<Edge<Relationship>>();
+    let strings: usize = graph.node_weights().map(PackageNode::heap_size).sum();
+    (nodes + edges + strings) as u64
+}
+
+/// A graph as persisted in a snapshot file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GraphSnapshot {
+    pub graph: Graph<PackageNode, Relationship, petgraph::Directed>,
+}
+
 #[derive(Debug)]
 pub struct GraphMap {
     map: HashMap<String, Graph<PackageNode, Relationship, petgraph::Directed>>,
+    load_times: HashMap<String, Duration>,
+    snapshot_dir: Option<PathBuf>,
 }
 
 static G: OnceLock<Arc<RwLock<GraphMap>>> = OnceLock::new();
@@ -110,6 +255,8 @@ impl GraphMap {
     pub fn new() -> Self {
         GraphMap {
             map: HashMap::new(),
+            load_times: HashMap::new(),
+            snapshot_dir: None,
         }
     }
 
@@ -128,15 +275,26 @@ impl GraphMap {
         self.map.is_empty()
     }
 
-    // Add a new graph with the given key (write access)
+    // Add a new graph with the given key and the time it took to load it (write access)
     pub fn insert(
         &mut self,
         key: String,
         graph: Graph<PackageNode, Relationship, petgraph::Directed>,
+        load_time: Duration,
     ) {
+        self.load_times.insert(key.clone(), load_time);
         self.map.insert(key, graph);
     }
 
+    // Remove a graph by its key (write access)
+    pub fn remove(
+        &mut self,
+        key: &str,
+    ) -> Option<Graph<PackageNode, Relationship, petgraph::Directed>> {
+        self.load_times.remove(key);
+        self.map.remove(key)
+    }
+
     // Retrieve a reference to a graph by its key (read access)
     pub fn get(&self, key: &str) -> Option<&Graph<PackageNode, Relationship, petgraph::Directed>> {
         self.map.get(key)
@@ -147,6 +305,37 @@ impl GraphMap {
         self.map.keys().cloned().collect()
     }
 
+    // Retrieve the status of all graphs, ordered by sbom id (read access)
+    pub fn graph_status(&self) -> Vec<GraphStatus> {
+        let mut result: Vec<_> = self
+            .map
+            .iter()
+            .map(|(sbom_id, graph)| GraphStatus {
+                sbom_id: sbom_id.clone(),
+                node_count: graph.node_count() as u64,
+                edge_count: graph.edge_count() as u64,
+                memory: graph_memory(graph),
+                load_time_ms: self
+                    .load_times
+                    .get(sbom_id)
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or_default(),
+            })
+            .collect();
+        result.sort_by(|a, b| a.sbom_id.cmp(&b.sbom_id));
+        result
+    }
+
+    // Directory graph snapshots are persisted to, if any
+    pub fn snapshot_dir(&self) -> Option<&Path> {
+        self.snapshot_dir.as_deref()
+    }
+
+    // Set the directory graph snapshots are persisted to
+    pub fn set_snapshot_dir(&mut self, snapshot_dir: Option<PathBuf>) {
+        self.snapshot_dir = snapshot_dir;
+    }
+
     // Get the singleton instance of GraphMap
     pub fn get_instance() -> Arc<RwLock<GraphMap>> {
         G.get_or_init(|| Arc::new(RwLock::new(GraphMap::new())))
@@ -156,6 +345,7 @@ impl GraphMap {
     // Clear all graphs from the map
     pub fn clear(&mut self) {
         self.map.clear();
+        self.load_times.clear();
     }
 }
 
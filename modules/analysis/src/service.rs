@@ -1,11 +1,16 @@
 use crate::{
-    model::{AnalysisStatus, AncNode, AncestorSummary, DepNode, DepSummary, GraphMap, PackageNode},
+    endpoints::Config,
+    export::{ExportGraph, ExportNode, NodeVulnerability},
+    model::{
+        AnalysisStatus, AncNode, AncestorSummary, DepNode, DepSummary, GraphMap, GraphSnapshot,
+        GraphTraversal, PackageNode, VulnerableComponentSummary,
+    },
     Error,
 };
 use petgraph::{
     algo::is_cyclic_directed,
     graph::{Graph, NodeIndex},
-    visit::{NodeIndexable, VisitMap, Visitable},
+    visit::{Dfs, EdgeRef, NodeIndexable, Reversed, VisitMap, Visitable},
     Direction,
 };
 use sea_orm::{
@@ -14,8 +19,11 @@ use sea_orm::{
 };
 use sea_query::Order;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
+    io::ErrorKind,
+    path::{Path, PathBuf},
     str::FromStr,
+    time::Instant,
 };
 use tracing::instrument;
 use trustify_common::{
@@ -29,41 +37,68 @@ use uuid::Uuid;
 #[derive(Default)]
 pub struct AnalysisService {}
 
+/// An SBOM component which is affected by a vulnerability, according to an advisory.
+#[derive(Clone, Debug)]
+pub struct AffectedNode {
+    pub sbom_id: String,
+    pub node_id: String,
+    pub advisory_id: Uuid,
+    pub status: String,
+}
+
+/// A package of another SBOM, which is the package described by an SBOM.
+///
+/// This links the root of one SBOM graph (e.g. a container image) to the place it is used in
+/// another SBOM graph (e.g. the product shipping the image).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SbomLink {
+    pub sbom_id: String,
+    pub node_id: String,
+}
+
 pub fn dep_nodes(
     graph: &Graph<PackageNode, Relationship, petgraph::Directed>,
     node: NodeIndex,
     visited: &mut HashSet<NodeIndex>,
+    traversal: &GraphTraversal,
 ) -> Vec<DepNode> {
-    let mut depnodes = Vec::new();
-    fn dfs(
+    fn walk(
         graph: &Graph<PackageNode, Relationship, petgraph::Directed>,
         node: NodeIndex,
-        depnodes: &mut Vec<DepNode>,
+        depth: u32,
         visited: &mut HashSet<NodeIndex>,
-    ) {
-        if visited.contains(&node) {
-            return;
+        on_path: &mut HashSet<NodeIndex>,
+        traversal: &GraphTraversal,
+    ) -> Vec<DepNode> {
+        let mut depnodes = Vec::new();
+        if traversal.exceeds(depth) {
+            return depnodes;
         }
-        visited.insert(node);
-        for neighbor in graph.neighbors_directed(node, Direction::Incoming) {
-            if let Some(dep_packagenode) = graph.node_weight(neighbor).cloned() {
-                // Attempt to find the edge and get the relationship in a more elegant way
-                if let Some(relationship) = graph
-                    .find_edge(neighbor, node)
-                    .and_then(|edge_index| graph.edge_weight(edge_index))
-                {
-                    let dep_node = DepNode {
-                        sbom_id: dep_packagenode.sbom_id,
-                        node_id: dep_packagenode.node_id,
-                        relationship: relationship.to_string(),
-                        purl: dep_packagenode.purl.to_string(),
-                        name: dep_packagenode.name.to_string(),
-                        version: dep_packagenode.version.to_string(),
-                        deps: dep_nodes(graph, neighbor, visited),
-                    };
-                    depnodes.push(dep_node);
-                    dfs(graph, neighbor, depnodes, visited);
-                }
+
+        on_path.insert(node);
+        for edge in graph.edges_directed(node, Direction::Incoming) {
+            let neighbor = edge.source();
+            if !traversal.follows(edge.weight()) {
+                continue;
+            }
+            if let Some(dep_packagenode) = graph.node_weight(neighbor) {
+                // never expand a circular reference, and when de-duplicating, only expand the
+                // subtree the first time we encounter it
+                let expand =
+                    !on_path.contains(&neighbor) && (visited.insert(neighbor) || !traversal.dedup);
+                depnodes.push(DepNode {
+                    sbom_id: dep_packagenode.sbom_id.to_string(),
+                    node_id: dep_packagenode.node_id.to_string(),
+                    relationship: edge.weight().to_string(),
+                    purl: dep_packagenode.purl.to_string(),
+                    name: dep_packagenode.name.to_string(),
+                    version: dep_packagenode.version.to_string(),
+                    deps: if expand {
+                        walk(graph, neighbor, depth + 1, visited, on_path, traversal)
+                    } else {
+                        Vec::new()
+                    },
+                });
             } else {
                 log::warn!(
                     "Processing descendants node weight for neighbor {:?} not found",
@@ -71,59 +106,472 @@ pub fn dep_nodes(
                 );
             }
         }
+        on_path.remove(&node);
+
+        depnodes
     }
-    dfs(graph, node, &mut depnodes, visited);
-    depnodes
+
+    visited.insert(node);
+    walk(graph, node, 0, visited, &mut HashSet::new(), traversal)
 }
 
 pub fn ancestor_nodes(
     graph: &Graph<PackageNode, Relationship, petgraph::Directed>,
     node: NodeIndex,
+    traversal: &GraphTraversal,
 ) -> Vec<AncNode> {
+    walk_ancestors(graph, node, traversal, traversal.depth).0
+}
+
+/// Walk the ancestors of a node, up to the provided depth.
+///
+/// Returns the ancestors, as well as the depth at which the root of the graph was reached. If
+/// the root wasn't reached, the depth will be `None`.
+fn walk_ancestors(
+    graph: &Graph<PackageNode, Relationship, petgraph::Directed>,
+    node: NodeIndex,
+    traversal: &GraphTraversal,
+    depth_limit: Option<u32>,
+) -> (Vec<AncNode>, Option<u32>) {
     let mut discovered = graph.visit_map();
     let mut ancestor_nodes = Vec::new();
+    let mut root_depth = None;
     let mut stack = Vec::new();
 
-    stack.push(graph.from_index(node.index()));
+    stack.push((graph.from_index(node.index()), 0));
 
-    while let Some(node) = stack.pop() {
+    while let Some((node, depth)) = stack.pop() {
         if discovered.visit(node) {
-            for succ in graph.neighbors_directed(node, Direction::Outgoing) {
-                if !discovered.is_visited(&succ) {
-                    if let Some(anc_packagenode) = graph.node_weight(succ).cloned() {
-                        if let Some(edge) = graph.find_edge(node, succ) {
-                            if let Some(relationship) = graph.edge_weight(edge) {
-                                let anc_node = AncNode {
-                                    sbom_id: anc_packagenode.sbom_id,
-                                    node_id: anc_packagenode.node_id,
-                                    relationship: relationship.to_string(),
-                                    purl: anc_packagenode.purl,
-                                    name: anc_packagenode.name,
-                                    version: anc_packagenode.version,
-                                };
-                                ancestor_nodes.push(anc_node);
-                                stack.push(succ);
-                            } else {
-                                log::warn!(
-                                    "Edge weight not found for edge between {:?} and {:?}",
-                                    node,
-                                    succ
-                                );
-                            }
-                        } else {
-                            log::warn!("Edge not found between {:?} and {:?}", node, succ);
-                        }
-                    } else {
-                        log::warn!("Processing ancestors, node value for {:?} not found", succ);
-                    }
+            if graph.neighbors_directed(node, Direction::Outgoing).count() == 0 {
+                // we are at the root
+                root_depth =
+                    Some(root_depth.map_or(depth, |root_depth: u32| root_depth.min(depth)));
+                continue;
+            }
+            if depth_limit.is_some_and(|max| depth >= max) {
+                continue;
+            }
+            for edge in graph.edges_directed(node, Direction::Outgoing) {
+                let succ = edge.target();
+                if !traversal.follows(edge.weight()) || discovered.is_visited(&succ) {
+                    continue;
+                }
+                if let Some(anc_packagenode) = graph.node_weight(succ).cloned() {
+                    ancestor_nodes.push(AncNode {
+                        sbom_id: anc_packagenode.sbom_id,
+                        node_id: anc_packagenode.node_id,
+                        relationship: edge.weight().to_string(),
+                        purl: anc_packagenode.purl,
+                        name: anc_packagenode.name,
+                        version: anc_packagenode.version,
+                    });
+                    stack.push((succ, depth + 1));
+                } else {
+                    log::warn!("Processing ancestors, node value for {:?} not found", succ);
                 }
             }
-            if graph.neighbors_directed(node, Direction::Outgoing).count() == 0 {
-                continue; // we are at the root
+        }
+    }
+
+    (ancestor_nodes, root_depth)
+}
+
+/// The maximum number of paths returned by [`ancestor_paths`].
+///
+/// The number of paths may grow exponentially with the size of the graph.
+pub const MAX_ANCESTOR_PATHS: usize = 100;
+
+/// The maximum number of relationships of a path returned by [`ancestor_paths`].
+pub const MAX_ANCESTOR_PATH_LENGTH: usize = 64;
+
+/// The maximum number of nodes visited by [`ancestor_paths`], bounding the time spent on graphs
+/// with lots of paths which don't end up in the result.
+const MAX_ANCESTOR_PATH_STEPS: usize = 100_000;
+
+/// Find the paths from a node up to its roots, continuing into the SBOMs which include the root.
+///
+/// Returns the paths, and if they are incomplete. Paths longer than [`MAX_ANCESTOR_PATH_LENGTH`]
+/// are skipped, and the walk stops after [`MAX_ANCESTOR_PATHS`] paths or too many steps.
+pub fn ancestor_paths(
+    graph_map: &GraphMap,
+    links: &HashMap<String, Vec<SbomLink>>,
+    sbom_id: &str,
+    node: NodeIndex,
+) -> (Vec<Vec<AncNode>>, bool) {
+    struct Paths<'a> {
+        graph_map: &'a GraphMap,
+        links: &'a HashMap<String, Vec<SbomLink>>,
+        paths: Vec<Vec<AncNode>>,
+        /// a path exceeded the maximum length
+        truncated: bool,
+        /// there were more paths than the maximum number of paths, or steps
+        exhausted: bool,
+        steps: usize,
+    }
+
+    impl<'a> Paths<'a> {
+        fn walk(
+            &mut self,
+            graph: &Graph<PackageNode, Relationship, petgraph::Directed>,
+            sbom_id: &str,
+            node: NodeIndex,
+            path: &mut Vec<AncNode>,
+            on_path: &mut HashSet<NodeIndex>,
+            sboms: &mut HashSet<String>,
+        ) {
+            self.steps += 1;
+            if self.steps > MAX_ANCESTOR_PATH_STEPS {
+                self.exhausted = true;
+                return;
+            }
+
+            on_path.insert(node);
+            let mut is_root = true;
+            for edge in graph.edges_directed(node, Direction::Outgoing) {
+                if self.exhausted {
+                    break;
+                }
+                let succ = edge.target();
+                if on_path.contains(&succ) {
+                    // circular reference, don't walk into it again
+                    continue;
+                }
+                is_root = false;
+                if path.len() >= MAX_ANCESTOR_PATH_LENGTH {
+                    self.truncated = true;
+                    continue;
+                }
+                if let Some(anc_packagenode) = graph.node_weight(succ) {
+                    path.push(AncNode {
+                        sbom_id: anc_packagenode.sbom_id.clone(),
+                        node_id: anc_packagenode.node_id.clone(),
+                        relationship: edge.weight().to_string(),
+                        purl: anc_packagenode.purl.clone(),
+                        name: anc_packagenode.name.clone(),
+                        version: anc_packagenode.version.clone(),
+                    });
+                    self.walk(graph, sbom_id, succ, path, on_path, sboms);
+                    path.pop();
+                } else {
+                    log::warn!(
+                        "Processing ancestor paths, node value for {:?} not found",
+                        succ
+                    );
+                }
+            }
+            if is_root {
+                self.root(sbom_id, path, sboms);
+            }
+            on_path.remove(&node);
+        }
+
+        /// Continue a path which reached the root of an SBOM in all SBOMs including it.
+        fn root(&mut self, sbom_id: &str, path: &mut Vec<AncNode>, sboms: &mut HashSet<String>) {
+            let graph_map = self.graph_map;
+            let links = self.links;
+
+            sboms.insert(sbom_id.to_string());
+            let mut continued = false;
+            for link in links.get(sbom_id).into_iter().flatten() {
+                if self.exhausted {
+                    break;
+                }
+                if sboms.contains(&link.sbom_id) {
+                    // circular reference between SBOMs
+                    continue;
+                }
+                let Some(graph) = graph_map.get(&link.sbom_id) else {
+                    log::warn!("Graph of linked sbom {} is not loaded", link.sbom_id);
+                    continue;
+                };
+                let Some(node_index) = graph
+                    .node_indices()
+                    .find(|&i| graph[i].node_id == link.node_id)
+                else {
+                    log::warn!(
+                        "Linked node {} not found in graph of sbom {}",
+                        link.node_id,
+                        link.sbom_id
+                    );
+                    continue;
+                };
+
+                continued = true;
+                self.walk(
+                    graph,
+                    &link.sbom_id,
+                    node_index,
+                    path,
+                    &mut HashSet::new(),
+                    sboms,
+                );
             }
+            sboms.remove(sbom_id);
+
+            if !continued && !path.is_empty() && !self.exhausted {
+                if self.paths.len() >= MAX_ANCESTOR_PATHS {
+                    self.exhausted = true;
+                } else {
+                    self.paths.push(path.clone());
+                }
+            }
+        }
+    }
+
+    let mut paths = Paths {
+        graph_map,
+        links,
+        paths: Vec::new(),
+        truncated: false,
+        exhausted: false,
+        steps: 0,
+    };
+    if let Some(graph) = graph_map.get(sbom_id) {
+        paths.walk(
+            graph,
+            sbom_id,
+            node,
+            &mut Vec::new(),
+            &mut HashSet::new(),
+            &mut HashSet::new(),
+        );
+    }
+
+    (paths.paths, paths.truncated || paths.exhausted)
+}
+
+/// Continue the ancestors of an SBOM's root in all the SBOMs which include it.
+///
+/// The remaining depth is the number of relationships which may still be walked, `None` if unlimited.
+pub fn stitched_ancestor_nodes(
+    graph_map: &GraphMap,
+    links: &HashMap<String, Vec<SbomLink>>,
+    sbom_id: &str,
+    traversal: &GraphTraversal,
+    remaining_depth: Option<u32>,
+    visited: &mut HashSet<String>,
+) -> Vec<AncNode> {
+    let mut ancestors = Vec::new();
+    visited.insert(sbom_id.to_string());
+
+    for link in links.get(sbom_id).into_iter().flatten() {
+        if visited.contains(&link.sbom_id) {
+            // circular reference between SBOMs
+            continue;
+        }
+        let Some(graph) = graph_map.get(&link.sbom_id) else {
+            log::warn!("Graph of linked sbom {} is not loaded", link.sbom_id);
+            continue;
+        };
+        let Some(node_index) = graph
+            .node_indices()
+            .find(|&i| graph[i].node_id == link.node_id)
+        else {
+            log::warn!(
+                "Linked node {} not found in graph of sbom {}",
+                link.node_id,
+                link.sbom_id
+            );
+            continue;
+        };
+
+        let (linked_ancestors, root_depth) =
+            walk_ancestors(graph, node_index, traversal, remaining_depth);
+        ancestors.extend(linked_ancestors);
+
+        // only continue if we made it all the way to the root of the linked SBOM
+        if let Some(root_depth) = root_depth {
+            ancestors.extend(stitched_ancestor_nodes(
+                graph_map,
+                links,
+                &link.sbom_id,
+                traversal,
+                remaining_depth.map(|depth| depth - root_depth),
+                visited,
+            ));
+        }
+    }
+
+    visited.remove(sbom_id);
+    ancestors
+}
+
+pub async fn get_sbom_links<C: ConnectionTrait>(
+    connection: &C,
+    distinct_sbom_id: &str,
+) -> Result<Vec<QueryResult>, DbErr> {
+    // Retrieve leaf components of other SBOMs, matching the purl or CPE of a package this SBOM describes
+    let sql = r#"
+        SELECT
+            other.sbom_id,
+            other.node_id
+        FROM
+            package_relates_to_package describes
+        JOIN
+            sbom_package_purl_ref described ON described.sbom_id = describes.sbom_id AND described.node_id = describes.left_node_id
+        JOIN
+            qualified_purl described_purl ON described_purl.id = described.qualified_purl_id
+        JOIN
+            qualified_purl other_purl ON other_purl.versioned_purl_id = described_purl.versioned_purl_id
+        JOIN
+            sbom_package_purl_ref other ON other.qualified_purl_id = other_purl.id AND other.sbom_id <> describes.sbom_id
+        WHERE
+            describes.sbom_id = $1
+          AND
+            describes.relationship = 13
+          AND NOT EXISTS (
+            SELECT 1 FROM package_relates_to_package prtp
+            WHERE prtp.sbom_id = other.sbom_id
+              AND prtp.right_node_id = other.node_id
+              AND prtp.relationship IN (0, 1, 8, 13, 14, 15)
+          )
+        UNION
+        SELECT
+            other.sbom_id,
+            other.node_id
+        FROM
+            package_relates_to_package describes
+        JOIN
+            sbom_package_cpe_ref described ON described.sbom_id = describes.sbom_id AND described.node_id = describes.left_node_id
+        JOIN
+            sbom_package_cpe_ref other ON other.cpe_id = described.cpe_id AND other.sbom_id <> describes.sbom_id
+        WHERE
+            describes.sbom_id = $1
+          AND
+            describes.relationship = 13
+          AND NOT EXISTS (
+            SELECT 1 FROM package_relates_to_package prtp
+            WHERE prtp.sbom_id = other.sbom_id
+              AND prtp.right_node_id = other.node_id
+              AND prtp.relationship IN (0, 1, 8, 13, 14, 15)
+          )
+        "#;
+
+    let uuid = match Uuid::parse_str(distinct_sbom_id) {
+        Ok(uuid) => uuid,
+        Err(_) => return Err(sea_orm::DbErr::Custom("Invalid SBOM ID".to_string())),
+    };
+    let stmt = Statement::from_sql_and_values(DatabaseBackend::Postgres, sql, [uuid.into()]);
+    let results: Vec<QueryResult> = connection.query_all(stmt).await?;
+
+    Ok(results)
+}
+
+/// Find the SBOMs including the packages described by the provided SBOMs, transitively, and
+/// load their graphs.
+pub async fn load_sbom_links<C: ConnectionTrait>(
+    connection: &C,
+    distinct_sbom_ids: &[String],
+) -> Result<HashMap<String, Vec<SbomLink>>, Error> {
+    let mut links = HashMap::new();
+    let mut queue: VecDeque<String> = distinct_sbom_ids.iter().cloned().collect();
+
+    while let Some(distinct_sbom_id) = queue.pop_front() {
+        if links.contains_key(&distinct_sbom_id) {
+            continue;
+        }
+
+        let mut sbom_links = Vec::new();
+        for row in get_sbom_links(connection, &distinct_sbom_id).await? {
+            let link = SbomLink {
+                sbom_id: row.try_get::<Uuid>("", "sbom_id")?.to_string(),
+                node_id: row.try_get("", "node_id")?,
+            };
+            queue.push_back(link.sbom_id.clone());
+            sbom_links.push(link);
         }
+        links.insert(distinct_sbom_id, sbom_links);
     }
-    ancestor_nodes
+
+    let linked_sbom_ids = links
+        .values()
+        .flatten()
+        .map(|link| link.sbom_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    load_graphs(connection, &linked_sbom_ids).await?;
+
+    Ok(links)
+}
+
+pub async fn get_affected_nodes<C: ConnectionTrait>(
+    connection: &C,
+    vulnerability_id: &str,
+) -> Result<Vec<QueryResult>, DbErr> {
+    // Retrieve all SBOM components which have a version matching an 'affected' purl status,
+    // including the first and last affected versions reported by CSAF
+    let sql = r#"
+        SELECT DISTINCT
+            sbom_package_purl_ref.sbom_id,
+            sbom_package_purl_ref.node_id,
+            purl_status.advisory_id,
+            status.slug AS status
+        FROM
+            purl_status
+        JOIN
+            status ON purl_status.status_id = status.id
+        JOIN
+            version_range ON purl_status.version_range_id = version_range.id
+        JOIN
+            versioned_purl ON versioned_purl.base_purl_id = purl_status.base_purl_id
+                AND version_matches(versioned_purl.version, version_range.*)
+        JOIN
+            qualified_purl ON qualified_purl.versioned_purl_id = versioned_purl.id
+        JOIN
+            sbom_package_purl_ref ON sbom_package_purl_ref.qualified_purl_id = qualified_purl.id
+        WHERE
+            purl_status.vulnerability_id = $1
+          AND
+            status.slug IN ('affected', 'first_affected', 'last_affected')
+        ORDER BY
+            sbom_package_purl_ref.sbom_id, sbom_package_purl_ref.node_id
+        "#;
+
+    let stmt =
+        Statement::from_sql_and_values(DatabaseBackend::Postgres, sql, [vulnerability_id.into()]);
+    let results: Vec<QueryResult> = connection.query_all(stmt).await?;
+
+    Ok(results)
+}
+
+pub async fn get_sbom_vulnerabilities<C: ConnectionTrait>(
+    connection: &C,
+    distinct_sbom_id: &str,
+) -> Result<Vec<QueryResult>, DbErr> {
+    // Retrieve the status of all vulnerabilities for SBOM components which have a version matching a purl status
+    let sql = r#"
+        SELECT DISTINCT
+            sbom_package_purl_ref.node_id,
+            purl_status.vulnerability_id,
+            status.slug AS status
+        FROM
+            sbom_package_purl_ref
+        JOIN
+            qualified_purl ON qualified_purl.id = sbom_package_purl_ref.qualified_purl_id
+        JOIN
+            versioned_purl ON versioned_purl.id = qualified_purl.versioned_purl_id
+        JOIN
+            purl_status ON purl_status.base_purl_id = versioned_purl.base_purl_id
+        JOIN
+            version_range ON version_range.id = purl_status.version_range_id
+                AND version_matches(versioned_purl.version, version_range.*)
+        JOIN
+            status ON status.id = purl_status.status_id
+        WHERE
+            sbom_package_purl_ref.sbom_id = $1
+        ORDER BY
+            sbom_package_purl_ref.node_id, purl_status.vulnerability_id, status
+        "#;
+
+    let uuid = match Uuid::parse_str(distinct_sbom_id) {
+        Ok(uuid) => uuid,
+        Err(_) => return Err(sea_orm::DbErr::Custom("Invalid SBOM ID".to_string())),
+    };
+    let stmt = Statement::from_sql_and_values(DatabaseBackend::Postgres, sql, [uuid.into()]);
+    let results: Vec<QueryResult> = connection.query_all(stmt).await?;
+
+    Ok(results)
 }
 
 pub async fn get_implicit_relationships<C: ConnectionTrait>(
@@ -227,188 +675,301 @@ pub async fn get_relationships<C: ConnectionTrait>(
     Ok(results)
 }
 
-pub async fn load_graphs<C: ConnectionTrait>(connection: &C, distinct_sbom_ids: &Vec<String>) {
+fn snapshot_path(snapshot_dir: &Path, distinct_sbom_id: &str) -> PathBuf {
+    snapshot_dir.join(format!("{distinct_sbom_id}.json"))
+}
+
+async fn read_snapshot(
+    snapshot_dir: &Path,
+    distinct_sbom_id: &str,
+) -> Option<Graph<PackageNode, Relationship, petgraph::Directed>> {
+    let path = snapshot_path(snapshot_dir, distinct_sbom_id);
+    let data = match tokio::fs::read(&path).await {
+        Ok(data) => data,
+        Err(err) if err.kind() == ErrorKind::NotFound => return None,
+        Err(err) => {
+            log::warn!(
+                "Unable to read analysis graph snapshot {}: {err}",
+                path.display()
+            );
+            return None;
+        }
+    };
+
+    match serde_json::from_slice::<GraphSnapshot>(&data) {
+        Ok(snapshot) => Some(snapshot.graph),
+        Err(err) => {
+            // drop the broken snapshot, it will be re-created from the database
+            log::warn!(
+                "Discarding invalid analysis graph snapshot {}: {err}",
+                path.display()
+            );
+            remove_snapshot(snapshot_dir, distinct_sbom_id).await;
+            None
+        }
+    }
+}
+
+async fn write_snapshot(
+    snapshot_dir: &Path,
+    distinct_sbom_id: &str,
+    graph: Graph<PackageNode, Relationship, petgraph::Directed>,
+) -> Graph<PackageNode, Relationship, petgraph::Directed> {
+    let path = snapshot_path(snapshot_dir, distinct_sbom_id);
+    let snapshot = GraphSnapshot { graph };
+    let result = match serde_json::to_vec(&snapshot) {
+        Ok(data) => match tokio::fs::create_dir_all(snapshot_dir).await {
+            Ok(()) => tokio::fs::write(&path, data).await,
+            Err(err) => Err(err),
+        },
+        Err(err) => Err(err.into()),
+    };
+    if let Err(err) = result {
+        log::warn!(
+            "Unable to write analysis graph snapshot {}: {err}",
+            path.display()
+        );
+    }
+    snapshot.graph
+}
+
+async fn remove_snapshot(snapshot_dir: &Path, distinct_sbom_id: &str) {
+    let path = snapshot_path(snapshot_dir, distinct_sbom_id);
+    match tokio::fs::remove_file(&path).await {
+        Ok(()) => {}
+        Err(err) if err.kind() == ErrorKind::NotFound => {}
+        Err(err) => {
+            log::warn!(
+                "Unable to remove analysis graph snapshot {}: {err}",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Load graphs into the cache, if not already present.
+///
+/// A graph failing to load is neither cached nor persisted as a snapshot.
+pub async fn load_graphs<C: ConnectionTrait>(
+    connection: &C,
+    distinct_sbom_ids: &Vec<String>,
+) -> Result<(), Error> {
     let graph_map = GraphMap::get_instance();
-    {
+    for distinct_sbom_id in distinct_sbom_ids {
+        if graph_map.read().contains_key(distinct_sbom_id) {
+            continue;
+        }
+
+        // lazy load graphs, preferring a persisted snapshot over the database
+        let start = Instant::now();
+        let snapshot_dir = graph_map.read().snapshot_dir().map(Path::to_path_buf);
+
+        let snapshot = match &snapshot_dir {
+            Some(snapshot_dir) => read_snapshot(snapshot_dir, distinct_sbom_id).await,
+            None => None,
+        };
+
+        let g = match (snapshot, &snapshot_dir) {
+            (Some(g), _) => g,
+            (None, Some(snapshot_dir)) => {
+                let g = build_graph(connection, distinct_sbom_id).await?;
+                write_snapshot(snapshot_dir, distinct_sbom_id, g).await
+            }
+            (None, None) => build_graph(connection, distinct_sbom_id).await?,
+        };
+
+        graph_map
+            .write()
+            .insert(distinct_sbom_id.to_string(), g, start.elapsed());
+    }
+
+    Ok(())
+}
+
+/// Remove graphs from the cache, along with their persisted snapshots.
+pub async fn invalidate_graphs(distinct_sbom_ids: &[String]) {
+    let graph_map = GraphMap::get_instance();
+    let snapshot_dir = {
+        let mut graph_map = graph_map.write();
         for distinct_sbom_id in distinct_sbom_ids {
-            if !graph_map.read().contains_key(distinct_sbom_id) {
-                // lazy load graphs
-                let mut g: Graph<PackageNode, Relationship, petgraph::Directed> = Graph::new();
-                let mut nodes = HashMap::new();
-
-                let mut describedby_purl: String = Default::default();
-
-                // Set relationships explicitly defined in SBOM
-                match get_relationships(connection, &distinct_sbom_id.to_string()).await {
-                    Ok(results) => {
-                        for row in results {
-                            let (
-                                sbom_published,
-                                document_id,
-                                product_name,
-                                product_version,
-                                left_node_id,
-                                left_purl_string,
-                                left_node_name,
-                                left_node_version,
-                                right_node_id,
-                                right_purl_string,
-                                right_node_name,
-                                right_node_version,
-                                relationship,
-                            ) = {
-                                let default_value = "NOVALUE".to_string(); // TODO: this eventually will have different defaults.
-                                (
-                                    row.try_get("", "published")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "document_id")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "product_name")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "product_version")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "left_node_id")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "left_qualified_purl")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "left_node_name")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "left_node_version")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "right_node_id")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "right_qualified_purl")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "right_node_name")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "right_node_version")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "relationship")
-                                        .unwrap_or(Relationship::ContainedBy),
-                                )
-                            };
-
-                            if relationship == Relationship::DescribedBy {
-                                // Save for implicit relationships performed later
-                                describedby_purl = left_purl_string.clone();
-                            } else {
-                                let p1 = match nodes.get(&left_purl_string) {
-                                    Some(&node_index) => node_index, // already exists
-                                    None => {
-                                        let new_node = PackageNode {
-                                            sbom_id: distinct_sbom_id.clone(),
-                                            node_id: left_node_id.clone(),
-                                            purl: left_purl_string.clone(),
-                                            name: left_node_name.clone(),
-                                            version: left_node_version.clone(),
-                                            published: sbom_published.clone(),
-                                            document_id: document_id.clone(),
-                                            product_name: product_name.clone(),
-                                            product_version: product_version.clone(),
-                                        };
-                                        let i = g.add_node(new_node);
-                                        nodes.insert(left_purl_string.clone(), i);
-                                        i
-                                    }
-                                };
-
-                                let p2 = match nodes.get(&right_purl_string) {
-                                    Some(&node_index) => node_index, // already exists
-                                    None => {
-                                        let new_node = PackageNode {
-                                            sbom_id: distinct_sbom_id.clone(),
-                                            node_id: right_node_id.clone(),
-                                            purl: right_purl_string.clone(),
-                                            name: right_node_name.clone(),
-                                            version: right_node_version.clone(),
-                                            published: sbom_published.clone(),
-                                            document_id: document_id.clone(),
-                                            product_name: product_name.clone(),
-                                            product_version: product_version.clone(),
-                                        };
-                                        let i = g.add_node(new_node);
-                                        nodes.insert(right_purl_string.clone(), i);
-                                        i
-                                    }
-                                };
+            graph_map.remove(distinct_sbom_id);
+        }
+        graph_map.snapshot_dir().map(Path::to_path_buf)
+    };
 
-                                g.add_edge(p1, p2, relationship);
-                            }
-                        }
-                    }
-                    Err(err) => {
-                        log::error!("Error fetching graph relationships: {}", err);
-                    }
-                }
+    if let Some(snapshot_dir) = snapshot_dir {
+        for distinct_sbom_id in distinct_sbom_ids {
+            remove_snapshot(&snapshot_dir, distinct_sbom_id).await;
+        }
+    }
+}
 
-                // Set relationships implicitly defined in SBOM
-                match get_implicit_relationships(connection, &distinct_sbom_id.to_string()).await {
-                    Ok(results) => {
-                        for row in results {
-                            let (
-                                sbom_published,
-                                document_id,
-                                product_name,
-                                product_version,
-                                node_id,
-                                purl,
-                                node_name,
-                                node_version,
-                            ) = {
-                                let default_value = "NOVALUE".to_string(); // TODO: this eventually will have different defaults.
-                                (
-                                    row.try_get("", "published")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "document_id")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "product_name")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "product_version")
-                                        .unwrap_or_else(|_| default_value.clone()),
-                                    row.try_get("", "node_id").unwrap_or(default_value.clone()),
-                                    row.try_get("", "purl").unwrap_or(default_value.clone()),
-                                    row.try_get("", "node_name")
-                                        .unwrap_or(default_value.clone()),
-                                    row.try_get("", "node_version")
-                                        .unwrap_or(default_value.clone()),
-                                )
-                            };
-
-                            let p1 = match nodes.get(&purl) {
-                                Some(&node_index) => node_index, // already exists
-                                None => {
-                                    let new_node = PackageNode {
-                                        sbom_id: distinct_sbom_id.clone(),
-                                        node_id: node_id.clone(),
-                                        purl: purl.clone(),
-                                        name: node_name.clone(),
-                                        version: node_version.clone(),
-                                        published: sbom_published.clone(),
-                                        document_id: document_id.clone(),
-                                        product_name: product_name.clone(),
-                                        product_version: product_version.clone(),
-                                    };
-                                    let i = g.add_node(new_node);
-                                    nodes.insert(purl.clone(), i);
-                                    i
-                                }
-                            };
-                            if let Some(describedby_node_index) = nodes.get(&describedby_purl) {
-                                g.add_edge(p1, *describedby_node_index, Relationship::Undefined);
-                            } else {
-                                log::warn!("No 'describes' relationship found in {} SBOM, no implicit relationship set.", distinct_sbom_id);
-                            }
-                        }
-                    }
-                    Err(err) => {
-                        log::error!("Error fetching graph relationships: {}", err);
-                    }
+async fn build_graph<C: ConnectionTrait>(
+    connection: &C,
+    distinct_sbom_id: &str,
+) -> Result<Graph<PackageNode, Relationship, petgraph::Directed>, DbErr> {
+    let mut g: Graph<PackageNode, Relationship, petgraph::Directed> = Graph::new();
+    let mut nodes = HashMap::new();
+
+    let mut describedby_purl: String = Default::default();
+
+    // Set relationships explicitly defined in SBOM
+    for row in get_relationships(connection, &distinct_sbom_id.to_string()).await? {
+        let (
+            sbom_published,
+            document_id,
+            product_name,
+            product_version,
+            left_node_id,
+            left_purl_string,
+            left_node_name,
+            left_node_version,
+            right_node_id,
+            right_purl_string,
+            right_node_name,
+            right_node_version,
+            relationship,
+        ) = {
+            let default_value = "NOVALUE".to_string(); // TODO: this eventually will have different defaults.
+            (
+                row.try_get("", "published")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "document_id")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "product_name")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "product_version")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "left_node_id")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "left_qualified_purl")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "left_node_name")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "left_node_version")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "right_node_id")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "right_qualified_purl")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "right_node_name")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "right_node_version")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "relationship")
+                    .unwrap_or(Relationship::ContainedBy),
+            )
+        };
+
+        if relationship == Relationship::DescribedBy {
+            // Save for implicit relationships performed later
+            describedby_purl = left_purl_string.clone();
+        } else {
+            let p1 = match nodes.get(&left_purl_string) {
+                Some(&node_index) => node_index, // already exists
+                None => {
+                    let new_node = PackageNode {
+                        sbom_id: distinct_sbom_id.to_string(),
+                        node_id: left_node_id.clone(),
+                        purl: left_purl_string.clone(),
+                        name: left_node_name.clone(),
+                        version: left_node_version.clone(),
+                        published: sbom_published.clone(),
+                        document_id: document_id.clone(),
+                        product_name: product_name.clone(),
+                        product_version: product_version.clone(),
+                    };
+                    let i = g.add_node(new_node);
+                    nodes.insert(left_purl_string.clone(), i);
+                    i
                 }
+            };
+
+            let p2 = match nodes.get(&right_purl_string) {
+                Some(&node_index) => node_index, // already exists
+                None => {
+                    let new_node = PackageNode {
+                        sbom_id: distinct_sbom_id.to_string(),
+                        node_id: right_node_id.clone(),
+                        purl: right_purl_string.clone(),
+                        name: right_node_name.clone(),
+                        version: right_node_version.clone(),
+                        published: sbom_published.clone(),
+                        document_id: document_id.clone(),
+                        product_name: product_name.clone(),
+                        product_version: product_version.clone(),
+                    };
+                    let i = g.add_node(new_node);
+                    nodes.insert(right_purl_string.clone(), i);
+                    i
+                }
+            };
 
-                graph_map.write().insert(distinct_sbom_id.to_string(), g);
+            g.add_edge(p1, p2, relationship);
+        }
+    }
+
+    // Set relationships implicitly defined in SBOM
+    for row in get_implicit_relationships(connection, &distinct_sbom_id.to_string()).await? {
+        let (
+            sbom_published,
+            document_id,
+            product_name,
+            product_version,
+            node_id,
+            purl,
+            node_name,
+            node_version,
+        ) = {
+            let default_value = "NOVALUE".to_string(); // TODO: this eventually will have different defaults.
+            (
+                row.try_get("", "published")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "document_id")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "product_name")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "product_version")
+                    .unwrap_or_else(|_| default_value.clone()),
+                row.try_get("", "node_id").unwrap_or(default_value.clone()),
+                row.try_get("", "purl").unwrap_or(default_value.clone()),
+                row.try_get("", "node_name")
+                    .unwrap_or(default_value.clone()),
+                row.try_get("", "node_version")
+                    .unwrap_or(default_value.clone()),
+            )
+        };
+
+        let p1 = match nodes.get(&purl) {
+            Some(&node_index) => node_index, // already exists
+            None => {
+                let new_node = PackageNode {
+                    sbom_id: distinct_sbom_id.to_string(),
+                    node_id: node_id.clone(),
+                    purl: purl.clone(),
+                    name: node_name.clone(),
+                    version: node_version.clone(),
+                    published: sbom_published.clone(),
+                    document_id: document_id.clone(),
+                    product_name: product_name.clone(),
+                    product_version: product_version.clone(),
+                };
+                let i = g.add_node(new_node);
+                nodes.insert(purl.clone(), i);
+                i
             }
+        };
+        if let Some(describedby_node_index) = nodes.get(&describedby_purl) {
+            g.add_edge(p1, *describedby_node_index, Relationship::Undefined);
+        } else {
+            log::warn!(
+                "No 'describes' relationship found in {} SBOM, no implicit relationship set.",
+                distinct_sbom_id
+            );
         }
     }
+
+    Ok(g)
 }
 
 impl AnalysisService {
@@ -417,12 +978,20 @@ impl AnalysisService {
         Self {}
     }
 
+    /// Create a new instance, applying the configuration to the shared graph cache.
+    pub fn with_config(config: Config) -> Self {
+        GraphMap::get_instance()
+            .write()
+            .set_snapshot_dir(config.snapshot_dir);
+        Self {}
+    }
+
     pub async fn load_graphs<C: ConnectionTrait>(
         &self,
         distinct_sbom_ids: Vec<String>,
         connection: &C,
     ) -> Result<(), Error> {
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
 
         Ok(())
     }
@@ -438,7 +1007,26 @@ impl AnalysisService {
             .map(|record| record.sbom_id.to_string()) // Assuming sbom_id is of type String
             .collect();
 
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
+
+        Ok(())
+    }
+
+    /// Drop the cached graphs of the provided SBOMs, so that they get rebuilt on next use.
+    pub async fn invalidate_graphs(&self, distinct_sbom_ids: Vec<String>) -> Result<(), Error> {
+        invalidate_graphs(&distinct_sbom_ids).await;
+
+        Ok(())
+    }
+
+    /// Rebuild the graphs of the provided SBOMs, replacing any cached version.
+    pub async fn reload_graphs<C: ConnectionTrait>(
+        &self,
+        distinct_sbom_ids: Vec<String>,
+        connection: &C,
+    ) -> Result<(), Error> {
+        invalidate_graphs(&distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
 
         Ok(())
     }
@@ -463,9 +1051,12 @@ impl AnalysisService {
 
         let graph_manager = GraphMap::get_instance();
         let manager = graph_manager.read();
+        let graphs = manager.graph_status();
         Ok(AnalysisStatus {
             sbom_count: distinct_sbom_ids.len() as i32,
             graph_count: manager.len() as i32,
+            graph_memory: graphs.iter().map(|graph| graph.memory).sum(),
+            graphs,
         })
     }
 
@@ -474,6 +1065,8 @@ impl AnalysisService {
         component_purl: Option<Purl>,
         query: Option<Query>,
         distinct_sbom_ids: Vec<String>,
+        links: &HashMap<String, Vec<SbomLink>>,
+        traversal: GraphTraversal,
     ) -> Vec<AncestorSummary> {
         let mut components = Vec::new();
         let graph_manager = GraphMap::get_instance();
@@ -554,7 +1147,26 @@ impl AnalysisService {
                                         product_version: find_match_package_node
                                             .product_version
                                             .to_string(),
-                                        ancestors: ancestor_nodes(graph, node_index),
+                                        ancestors: {
+                                            let (mut ancestors, root_depth) = walk_ancestors(
+                                                graph,
+                                                node_index,
+                                                &traversal,
+                                                traversal.depth,
+                                            );
+                                            // continue into the SBOMs which include this one
+                                            if let Some(root_depth) = root_depth {
+                                                ancestors.extend(stitched_ancestor_nodes(
+                                                    &graph_read_guard,
+                                                    links,
+                                                    distinct_sbom_id,
+                                                    &traversal,
+                                                    traversal.depth.map(|depth| depth - root_depth),
+                                                    &mut HashSet::new(),
+                                                ));
+                                            }
+                                            ancestors
+                                        },
                                     });
                                 }
                             }
@@ -571,6 +1183,7 @@ impl AnalysisService {
         component_purl: Option<Purl>,
         query: Option<Query>,
         distinct_sbom_ids: Vec<String>,
+        traversal: GraphTraversal,
     ) -> Vec<DepSummary> {
         let mut components = Vec::new();
         let graph_manager = GraphMap::get_instance();
@@ -651,7 +1264,12 @@ impl AnalysisService {
                                         product_version: find_match_package_node
                                             .product_version
                                             .to_string(),
-                                        deps: dep_nodes(graph, node_index, &mut HashSet::new()),
+                                        deps: dep_nodes(
+                                            graph,
+                                            node_index,
+                                            &mut HashSet::new(),
+                                            &traversal,
+                                        ),
                                     });
                                 }
                             }
@@ -663,10 +1281,187 @@ impl AnalysisService {
         components
     }
 
+    pub fn query_vulnerable_graph(
+        vulnerability_id: &str,
+        affected_nodes: &[AffectedNode],
+        links: &HashMap<String, Vec<SbomLink>>,
+    ) -> Vec<VulnerableComponentSummary> {
+        let mut components = Vec::new();
+        let graph_manager = GraphMap::get_instance();
+        {
+            // RwLock for reading hashmap<graph>
+            let graph_read_guard = graph_manager.read();
+            for affected in affected_nodes {
+                if let Some(graph) = graph_read_guard.get(&affected.sbom_id) {
+                    graph
+                        .node_indices()
+                        .filter(|&i| {
+                            graph
+                                .node_weight(i)
+                                .map(|node| node.node_id == affected.node_id)
+                                .unwrap_or(false)
+                        })
+                        .for_each(|node_index| {
+                            if let Some(affected_package_node) = graph.node_weight(node_index) {
+                                let (paths, truncated) = ancestor_paths(
+                                    &graph_read_guard,
+                                    links,
+                                    &affected.sbom_id,
+                                    node_index,
+                                );
+
+                                let mut roots: Vec<AncNode> = Vec::new();
+                                for path in &paths {
+                                    if let Some(root) = path.last() {
+                                        if !roots.contains(root) {
+                                            // we want distinct list
+                                            roots.push(root.clone());
+                                        }
+                                    }
+                                }
+
+                                components.push(VulnerableComponentSummary {
+                                    vulnerability_id: vulnerability_id.to_string(),
+                                    advisory_id: affected.advisory_id.to_string(),
+                                    status: affected.status.to_string(),
+                                    sbom_id: affected_package_node.sbom_id.to_string(),
+                                    node_id: affected_package_node.node_id.to_string(),
+                                    purl: affected_package_node.purl.to_string(),
+                                    name: affected_package_node.name.to_string(),
+                                    version: affected_package_node.version.to_string(),
+                                    published: affected_package_node.published.to_string(),
+                                    document_id: affected_package_node.document_id.to_string(),
+                                    product_name: affected_package_node.product_name.to_string(),
+                                    product_version: affected_package_node
+                                        .product_version
+                                        .to_string(),
+                                    roots,
+                                    paths,
+                                    truncated,
+                                });
+                            }
+                        });
+                }
+            }
+        }
+
+        components
+    }
+
+    #[instrument(skip(self, connection), err)]
+    pub async fn retrieve_by_vulnerability<C: ConnectionTrait>(
+        &self,
+        vulnerability_id: &str,
+        paginated: Paginated,
+        connection: &C,
+    ) -> Result<PaginatedResults<VulnerableComponentSummary>, Error> {
+        let affected_nodes = get_affected_nodes(connection, vulnerability_id)
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok(AffectedNode {
+                    sbom_id: row.try_get::<Uuid>("", "sbom_id")?.to_string(),
+                    node_id: row.try_get("", "node_id")?,
+                    advisory_id: row.try_get("", "advisory_id")?,
+                    status: row.try_get("", "status")?,
+                })
+            })
+            .collect::<Result<Vec<_>, DbErr>>()?;
+
+        let mut distinct_sbom_ids: Vec<String> = Vec::new();
+        for affected in &affected_nodes {
+            if !distinct_sbom_ids.contains(&affected.sbom_id) {
+                distinct_sbom_ids.push(affected.sbom_id.clone());
+            }
+        }
+
+        load_graphs(connection, &distinct_sbom_ids).await?;
+
+        let links = load_sbom_links(connection, &distinct_sbom_ids).await?;
+
+        let components =
+            AnalysisService::query_vulnerable_graph(vulnerability_id, &affected_nodes, &links);
+
+        Ok(paginated.paginate_array(&components))
+    }
+
+    /// Get the graph of an SBOM, or the sub-graph rooted at one of its nodes, annotated with the
+    /// vulnerabilities of its components.
+    #[instrument(skip(self, connection), err)]
+    pub async fn export_graph<C: ConnectionTrait>(
+        &self,
+        sbom_id: Uuid,
+        node_id: Option<String>,
+        connection: &C,
+    ) -> Result<ExportGraph, Error> {
+        if sbom::Entity::find_by_id(sbom_id)
+            .one(connection)
+            .await?
+            .is_none()
+        {
+            return Err(Error::NotFound(format!("SBOM {sbom_id}")));
+        }
+
+        let distinct_sbom_ids = vec![sbom_id.to_string()];
+        load_graphs(connection, &distinct_sbom_ids).await?;
+
+        let mut vulnerabilities: HashMap<String, Vec<NodeVulnerability>> = HashMap::new();
+        for row in get_sbom_vulnerabilities(connection, &distinct_sbom_ids[0]).await? {
+            vulnerabilities
+                .entry(row.try_get("", "node_id")?)
+                .or_default()
+                .push(NodeVulnerability {
+                    vulnerability_id: row.try_get("", "vulnerability_id")?,
+                    status: row.try_get("", "status")?,
+                });
+        }
+
+        let graph_manager = GraphMap::get_instance();
+        let graph_read_guard = graph_manager.read();
+        let Some(graph) = graph_read_guard.get(&distinct_sbom_ids[0]) else {
+            return Err(Error::NotFound(format!("Graph of SBOM {sbom_id}")));
+        };
+
+        let included = match node_id {
+            Some(node_id) => {
+                let Some(root) = graph.node_indices().find(|&i| graph[i].node_id == node_id) else {
+                    return Err(Error::NotFound(format!("Node {node_id} of SBOM {sbom_id}")));
+                };
+
+                // dependencies point towards the component they belong to, walk them in reverse
+                let reversed = Reversed(graph);
+                let mut dfs = Dfs::new(reversed, root);
+                let mut included = HashSet::new();
+                while let Some(node) = dfs.next(reversed) {
+                    included.insert(node);
+                }
+                Some(included)
+            }
+            None => None,
+        };
+
+        Ok(graph.filter_map(
+            |index, package| {
+                included
+                    .as_ref()
+                    .map_or(true, |included| included.contains(&index))
+                    .then(|| ExportNode {
+                        package: package.clone(),
+                        vulnerabilities: vulnerabilities
+                            .get(&package.node_id)
+                            .cloned()
+                            .unwrap_or_default(),
+                    })
+            },
+            |_, relationship| Some(*relationship),
+        ))
+    }
+
     #[instrument(skip(self, connection), err)]
     pub async fn retrieve_root_components<C: ConnectionTrait>(
         &self,
         query: Query,
+        traversal: GraphTraversal,
         paginated: Paginated,
         connection: &C,
     ) -> Result<PaginatedResults<AncestorSummary>, Error> {
@@ -687,13 +1482,17 @@ impl AnalysisService {
             .map(|record| record.sbom_id.to_string()) // Assuming sbom_id is of type String
             .collect();
 
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
+
+        let links = load_sbom_links(connection, &distinct_sbom_ids).await?;
 
         let components = AnalysisService::query_ancestor_graph(
             None,
             None,
             Option::from(query),
             distinct_sbom_ids,
+            &links,
+            traversal,
         );
 
         Ok(paginated.paginate_array(&components))
@@ -709,13 +1508,17 @@ impl AnalysisService {
         // root components.
 
         let distinct_sbom_ids = vec![sbom_id.to_string()];
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
+
+        let links = load_sbom_links(connection, &distinct_sbom_ids).await?;
 
         let components = AnalysisService::query_ancestor_graph(
             Option::from(component_name),
             None,
             None,
             distinct_sbom_ids,
+            &links,
+            GraphTraversal::default(),
         );
 
         let mut root_components = Vec::new();
@@ -735,6 +1538,7 @@ impl AnalysisService {
     pub async fn retrieve_root_components_by_name<C: ConnectionTrait>(
         &self,
         component_name: String,
+        traversal: GraphTraversal,
         paginated: Paginated,
         connection: &C,
     ) -> Result<PaginatedResults<AncestorSummary>, Error> {
@@ -755,13 +1559,17 @@ impl AnalysisService {
             .map(|record| record.sbom_id.to_string()) // Assuming sbom_id is of type String
             .collect();
 
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
+
+        let links = load_sbom_links(connection, &distinct_sbom_ids).await?;
 
         let components = AnalysisService::query_ancestor_graph(
             Option::from(component_name),
             None,
             None,
             distinct_sbom_ids,
+            &links,
+            traversal,
         );
 
         Ok(paginated.paginate_array(&components))
@@ -771,6 +1579,7 @@ impl AnalysisService {
     pub async fn retrieve_root_components_by_purl<C: ConnectionTrait>(
         &self,
         component_purl: Purl,
+        traversal: GraphTraversal,
         paginated: Paginated,
         connection: &C,
     ) -> Result<PaginatedResults<AncestorSummary>, Error> {
@@ -791,13 +1600,17 @@ impl AnalysisService {
             .map(|record| record.sbom_id.to_string()) // Assuming sbom_id is of type String
             .collect();
 
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
+
+        let links = load_sbom_links(connection, &distinct_sbom_ids).await?;
 
         let components = AnalysisService::query_ancestor_graph(
             None,
             Option::from(component_purl),
             None,
             distinct_sbom_ids,
+            &links,
+            traversal,
         );
 
         Ok(paginated.paginate_array(&components))
@@ -807,6 +1620,7 @@ impl AnalysisService {
     pub async fn retrieve_deps<C: ConnectionTrait>(
         &self,
         query: Query,
+        traversal: GraphTraversal,
         paginated: Paginated,
         connection: &C,
     ) -> Result<PaginatedResults<DepSummary>, Error> {
@@ -827,11 +1641,16 @@ impl AnalysisService {
             .map(|record| record.sbom_id.to_string()) // Assuming sbom_id is of type String
             .collect();
 
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
 
-        let components =
-            AnalysisService::query_deps_graph(None, None, Option::from(query), distinct_sbom_ids)
-                .await;
+        let components = AnalysisService::query_deps_graph(
+            None,
+            None,
+            Option::from(query),
+            distinct_sbom_ids,
+            traversal,
+        )
+        .await;
 
         Ok(paginated.paginate_array(&components))
     }
@@ -839,6 +1658,7 @@ impl AnalysisService {
     pub async fn retrieve_deps_by_name<C: ConnectionTrait>(
         &self,
         component_name: String,
+        traversal: GraphTraversal,
         paginated: Paginated,
         connection: &C,
     ) -> Result<PaginatedResults<DepSummary>, Error> {
@@ -859,13 +1679,14 @@ impl AnalysisService {
             .map(|record| record.sbom_id.to_string()) // Assuming sbom_id is of type String
             .collect();
 
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
 
         let components = AnalysisService::query_deps_graph(
             Option::from(component_name),
             None,
             None,
             distinct_sbom_ids,
+            traversal,
         )
         .await;
 
@@ -875,6 +1696,7 @@ impl AnalysisService {
     pub async fn retrieve_deps_by_purl<C: ConnectionTrait>(
         &self,
         component_purl: Purl,
+        traversal: GraphTraversal,
         paginated: Paginated,
         connection: &C,
     ) -> Result<PaginatedResults<DepSummary>, Error> {
@@ -895,13 +1717,14 @@ impl AnalysisService {
             .map(|record| record.sbom_id.to_string()) // Assuming sbom_id is of type String
             .collect();
 
-        load_graphs(connection, &distinct_sbom_ids).await;
+        load_graphs(connection, &distinct_sbom_ids).await?;
 
         let components = AnalysisService::query_deps_graph(
             None,
             Option::from(component_purl),
             None,
             distinct_sbom_ids,
+            traversal,
         )
         .await;
 
@@ -915,7 +1738,7 @@ mod test {
 
     use test_context::test_context;
     use test_log::test;
-    use trustify_common::model::Paginated;
+    use trustify_common::{id::Id, model::Paginated};
     use trustify_test_context::TrustifyContext;
 
     #[test_context(TrustifyContext)]
@@ -927,7 +1750,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_root_components(Query::q("DD"), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q("DD"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -957,7 +1785,12 @@ mod test {
 
         // ensure we set implicit relationship on component with no defined relationships
         let analysis_graph = service
-            .retrieve_root_components(Query::q("EE"), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q("EE"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
         assert_eq!(analysis_graph.total, 1);
@@ -975,7 +1808,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_root_components(Query::q("DD"), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q("DD"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1003,7 +1841,12 @@ mod test {
 
         // ensure we set implicit relationship on component with no defined relationships
         let analysis_graph = service
-            .retrieve_root_components(Query::q("EE"), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q("EE"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
         assert_eq!(analysis_graph.total, 1);
@@ -1020,7 +1863,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_root_components_by_name("B".to_string(), Paginated::default(), &ctx.db)
+            .retrieve_root_components_by_name(
+                "B".to_string(),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1062,7 +1910,12 @@ mod test {
         let component_purl: Purl = Purl::from_str("pkg:rpm/redhat/B@0.0.0").map_err(Error::Purl)?;
 
         let analysis_graph = service
-            .retrieve_root_components_by_purl(component_purl, Paginated::default(), &ctx.db)
+            .retrieve_root_components_by_purl(
+                component_purl,
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1092,6 +1945,122 @@ mod test {
         Ok(())
     }
 
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn test_traversal_analysis_service(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        ctx.ingest_documents(["spdx/simple.json"]).await?;
+
+        let service = AnalysisService::new();
+
+        // without limits, we walk all the way up to the root
+        let analysis_graph = service
+            .retrieve_root_components_by_name(
+                "DD".to_string(),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
+            .await?;
+        let ancestors = &analysis_graph.items[0].ancestors;
+        assert_eq!(ancestors.len(), 2);
+        assert_eq!(ancestors[1].node_id, "SPDXRef-AA");
+
+        // limit the depth
+        let analysis_graph = service
+            .retrieve_root_components_by_name(
+                "DD".to_string(),
+                GraphTraversal {
+                    depth: Some(1),
+                    ..Default::default()
+                },
+                Paginated::default(),
+                &ctx.db,
+            )
+            .await?;
+        let ancestors = &analysis_graph.items[0].ancestors;
+        assert_eq!(ancestors.len(), 1);
+        assert_eq!(ancestors[0].node_id, "SPDXRef-BB");
+
+        // only walk relationships the SBOM doesn't have
+        let analysis_graph = service
+            .retrieve_root_components_by_name(
+                "DD".to_string(),
+                GraphTraversal {
+                    relationships: Some(vec![Relationship::DependencyOf]),
+                    ..Default::default()
+                },
+                Paginated::default(),
+                &ctx.db,
+            )
+            .await?;
+        assert!(analysis_graph.items[0].ancestors.is_empty());
+
+        // limit the depth of dependencies
+        let analysis_graph = service
+            .retrieve_deps_by_name(
+                "AA".to_string(),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
+            .await?;
+        let deps = &analysis_graph.items[0].deps;
+        assert_eq!(deps[0].node_id, "SPDXRef-BB");
+        assert_eq!(deps[0].deps.len(), 2);
+
+        let analysis_graph = service
+            .retrieve_deps_by_name(
+                "AA".to_string(),
+                GraphTraversal {
+                    depth: Some(1),
+                    ..Default::default()
+                },
+                Paginated::default(),
+                &ctx.db,
+            )
+            .await?;
+        let deps = &analysis_graph.items[0].deps;
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].node_id, "SPDXRef-BB");
+        assert!(deps[0].deps.is_empty());
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn test_stitched_analysis_service(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        // ingest the product before the image, links must not depend on the order
+        ctx.ingest_documents(["spdx/stitched-product.json", "spdx/stitched-image.json"])
+            .await?;
+
+        let service = AnalysisService::new();
+
+        let analysis_graph = service
+            .retrieve_root_components_by_name(
+                "stitched-library".to_string(),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
+            .await?;
+
+        assert_eq!(analysis_graph.total, 1);
+
+        let ancestors = &analysis_graph.items[0].ancestors;
+        assert_eq!(ancestors.len(), 2);
+
+        // the image, as described by its own SBOM
+        assert_eq!(ancestors[0].purl, "pkg:oci/stitched-image@1.0");
+        assert_eq!(ancestors[0].sbom_id, analysis_graph.items[0].sbom_id);
+
+        // the product, shipping the image
+        assert_eq!(ancestors[1].purl, "pkg:generic/redhat/stitched-product@1.0");
+        assert_ne!(ancestors[1].sbom_id, analysis_graph.items[0].sbom_id);
+
+        Ok(())
+    }
+
     #[test_context(TrustifyContext)]
     #[test(tokio::test)]
     async fn test_quarkus_analysis_service(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
@@ -1104,7 +2073,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_root_components(Query::q("spymemcached"), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q("spymemcached"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1157,6 +2131,77 @@ mod test {
         Ok(())
     }
 
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn test_invalidate_graphs(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let result = ctx.ingest_document("spdx/simple.json").await?;
+        let Id::Uuid(sbom_id) = result.id else {
+            panic!("must be a UUID");
+        };
+        let sbom_id = sbom_id.to_string();
+
+        // ingesting loads the graph
+        let graph_map = GraphMap::get_instance();
+        assert!(graph_map.read().contains_key(&sbom_id));
+
+        let status = graph_map.read().graph_status();
+        let graph_status = status.iter().find(|g| g.sbom_id == sbom_id).unwrap();
+        assert!(graph_status.node_count > 0);
+        assert!(graph_status.memory > 0);
+
+        let service = AnalysisService::new();
+        service.invalidate_graphs(vec![sbom_id.clone()]).await?;
+        assert!(!graph_map.read().contains_key(&sbom_id));
+
+        // it is rebuilt on demand
+        let analysis_graph = service
+            .retrieve_root_components(
+                Query::q("DD"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
+            .await?;
+        assert_eq!(analysis_graph.total, 1);
+        assert!(graph_map.read().contains_key(&sbom_id));
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn test_graph_snapshot(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let result = ctx.ingest_document("spdx/simple.json").await?;
+        let Id::Uuid(sbom_id) = result.id else {
+            panic!("must be a UUID");
+        };
+        let sbom_id = sbom_id.to_string();
+
+        let snapshot_dir = std::env::temp_dir().join(format!("analysis-{}", Uuid::new_v4()));
+
+        let graph = build_graph(&ctx.db, &sbom_id).await;
+        let graph = write_snapshot(&snapshot_dir, &sbom_id, graph).await;
+
+        let snapshot = read_snapshot(&snapshot_dir, &sbom_id)
+            .await
+            .expect("snapshot must exist");
+        assert_eq!(snapshot.node_count(), graph.node_count());
+        assert_eq!(snapshot.edge_count(), graph.edge_count());
+        assert!(snapshot
+            .node_weights()
+            .zip(graph.node_weights())
+            .all(|(a, b)| a == b));
+
+        // a broken snapshot gets discarded
+        tokio::fs::write(snapshot_path(&snapshot_dir, &sbom_id), b"{").await?;
+        assert!(read_snapshot(&snapshot_dir, &sbom_id).await.is_none());
+        assert!(!snapshot_path(&snapshot_dir, &sbom_id).exists());
+
+        tokio::fs::remove_dir_all(&snapshot_dir).await?;
+
+        Ok(())
+    }
+
     #[test_context(TrustifyContext)]
     #[test(tokio::test)]
     async fn test_simple_deps_service(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
@@ -1165,7 +2210,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_deps(Query::q("AA"), Paginated::default(), &ctx.db)
+            .retrieve_deps(
+                Query::q("AA"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1173,7 +2223,12 @@ mod test {
 
         // ensure we set implicit relationship on component with no defined relationships
         let analysis_graph = service
-            .retrieve_root_components(Query::q("EE"), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q("EE"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
         assert_eq!(analysis_graph.total, 1);
@@ -1190,7 +2245,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_deps(Query::q("AA"), Paginated::default(), &ctx.db)
+            .retrieve_deps(
+                Query::q("AA"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1198,7 +2258,12 @@ mod test {
 
         // ensure we set implicit relationship on component with no defined relationships
         let analysis_graph = service
-            .retrieve_root_components(Query::q("EE"), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q("EE"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
         assert_eq!(analysis_graph.total, 1);
@@ -1213,7 +2278,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_deps_by_name("A".to_string(), Paginated::default(), &ctx.db)
+            .retrieve_deps_by_name(
+                "A".to_string(),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1236,7 +2306,12 @@ mod test {
             Purl::from_str("pkg:rpm/redhat/AA@0.0.0?arch=src").map_err(Error::Purl)?;
 
         let analysis_graph = service
-            .retrieve_deps_by_purl(component_purl, Paginated::default(), &ctx.db)
+            .retrieve_deps_by_purl(
+                component_purl,
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1261,7 +2336,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_deps(Query::q("spymemcached"), Paginated::default(), &ctx.db)
+            .retrieve_deps(
+                Query::q("spymemcached"),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1280,7 +2360,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_deps_by_name("junit-bom".to_string(), Paginated::default(), &ctx.db)
+            .retrieve_deps_by_name(
+                "junit-bom".to_string(),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1296,7 +2381,12 @@ mod test {
         let service = AnalysisService::new();
 
         let analysis_graph = service
-            .retrieve_deps_by_name("A".to_string(), Paginated::default(), &ctx.db)
+            .retrieve_deps_by_name(
+                "A".to_string(),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await
             .unwrap();
 
@@ -1304,6 +2394,45 @@ mod test {
         Ok(())
     }
 
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn test_vulnerability_analysis_service(
+        ctx: &TrustifyContext,
+    ) -> Result<(), anyhow::Error> {
+        ctx.ingest_documents(["cve/CVE-2024-26308.json", "spdx/SATELLITE-6.15-RHEL-8.json"])
+            .await?;
+
+        let service = AnalysisService::new();
+
+        let analysis_graph = service
+            .retrieve_by_vulnerability("CVE-2024-26308", Paginated::default(), &ctx.db)
+            .await?;
+
+        assert_eq!(analysis_graph.total, 1);
+
+        let component = &analysis_graph.items[0];
+        assert_eq!(component.vulnerability_id, "CVE-2024-26308");
+        assert_eq!(component.status, "affected");
+        assert_eq!(component.name, "commons-compress");
+        assert_eq!(component.version, "1.21");
+
+        // every path must end in one of the root components
+        assert!(!component.paths.is_empty());
+        assert!(!component.roots.is_empty());
+        for path in &component.paths {
+            assert!(component.roots.contains(path.last().unwrap()));
+        }
+        assert!(!component.truncated);
+
+        // an unknown vulnerability doesn't match anything
+        let analysis_graph = service
+            .retrieve_by_vulnerability("CVE-0000-0000", Paginated::default(), &ctx.db)
+            .await?;
+        assert_eq!(analysis_graph.total, 0);
+
+        Ok(())
+    }
+
     #[test_context(TrustifyContext)]
     #[test(tokio::test)]
     async fn test_retrieve_all_sbom_roots_by_name1(
@@ -1316,7 +2445,12 @@ mod test {
         let component_name = "quarkus-vertx-http".to_string();
 
         let analysis_graph = service
-            .retrieve_root_components(Query::q(&component_name), Paginated::default(), &ctx.db)
+            .retrieve_root_components(
+                Query::q(&component_name),
+                GraphTraversal::default(),
+                Paginated::default(),
+                &ctx.db,
+            )
             .await?;
 
         let sbom_id = analysis_graph
@@ -1334,4 +2468,86 @@ mod test {
 
         Ok(())
     }
+
+    fn package_node(sbom_id: &str, node_id: &str) -> PackageNode {
+        PackageNode {
+            sbom_id: sbom_id.to_string(),
+            node_id: node_id.to_string(),
+            purl: format!("pkg:generic/{node_id}@1.0"),
+            name: node_id.to_string(),
+            version: "1.0".to_string(),
+            published: String::new(),
+            document_id: String::new(),
+            product_name: String::new(),
+            product_version: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_ancestor_paths_limit() {
+        // a ladder of two nodes per level, each depending on both nodes of the next level,
+        // which has 2^32 paths
+        let mut graph = Graph::new();
+        let leaf = graph.add_node(package_node("a", "leaf"));
+        let mut level = vec![leaf];
+        for i in 0..32 {
+            let next = vec![
+                graph.add_node(package_node("a", &format!("l{i}"))),
+                graph.add_node(package_node("a", &format!("r{i}"))),
+            ];
+            for &node in &level {
+                for &parent in &next {
+                    graph.add_edge(node, parent, Relationship::DependencyOf);
+                }
+            }
+            level = next;
+        }
+
+        let mut graph_map = GraphMap::new();
+        graph_map.insert("a".to_string(), graph, Default::default());
+
+        let (paths, truncated) = ancestor_paths(&graph_map, &HashMap::new(), "a", leaf);
+
+        assert!(truncated);
+        assert_eq!(paths.len(), MAX_ANCESTOR_PATHS);
+    }
+
+    #[test]
+    fn test_stitched_ancestor_paths() {
+        // the image, containing the library
+        let mut image = Graph::new();
+        let library = image.add_node(package_node("image", "library"));
+        let image_root = image.add_node(package_node("image", "image"));
+        image.add_edge(library, image_root, Relationship::ContainedBy);
+
+        // the product, shipping the image
+        let mut product = Graph::new();
+        let product_image = product.add_node(package_node("product", "image"));
+        let product_root = product.add_node(package_node("product", "product"));
+        product.add_edge(product_image, product_root, Relationship::ContainedBy);
+
+        let mut graph_map = GraphMap::new();
+        graph_map.insert("image".to_string(), image, Default::default());
+        graph_map.insert("product".to_string(), product, Default::default());
+
+        let links = HashMap::from([(
+            "image".to_string(),
+            vec![SbomLink {
+                sbom_id: "product".to_string(),
+                node_id: "image".to_string(),
+            }],
+        )]);
+
+        let (paths, truncated) = ancestor_paths(&graph_map, &links, "image", library);
+
+        assert!(!truncated);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(
+            paths[0]
+                .iter()
+                .map(|node| (node.sbom_id.as_str(), node.name.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("image", "image"), ("product", "product")]
+        );
+    }
 }
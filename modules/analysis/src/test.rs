@@ -1,4 +1,4 @@
-use crate::endpoints::configure;
+use crate::endpoints::{configure, Config};
 use actix_http::Request;
 use actix_web::{
     dev::{Service, ServiceResponse},
@@ -42,7 +42,8 @@ pub async fn caller(ctx: &TrustifyContext) -> anyhow::Result<impl CallService> {
             .app_data(web::PayloadConfig::default().limit(5 * 1024 * 1024))
             .app_data(web::Data::new(Authorizer::new(None)))
             .service(
-                utoipa_actix_web::scope("/api").configure(|svc| configure(svc, ctx.db.clone())),
+                utoipa_actix_web::scope("/api")
+                    .configure(|svc| configure(svc, Config::default(), ctx.db.clone())),
             )
             .into_app(),
     )
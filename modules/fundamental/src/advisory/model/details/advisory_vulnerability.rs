@@ -1,10 +1,13 @@
 use crate::{vulnerability::model::VulnerabilityHead, Error};
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, LoaderTrait, QueryFilter};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, LoaderTrait, QueryFilter, QueryOrder};
 use serde::{Deserialize, Serialize};
 use trustify_common::memo::Memo;
 use trustify_cvss::cvss3::severity::Severity;
-use trustify_cvss::{cvss3::score::Score, cvss3::Cvss3Base};
-use trustify_entity::{advisory, advisory_vulnerability, cvss3, vulnerability};
+use trustify_cvss::{
+    cvss2::vector::Cvss2Vector,
+    cvss3::{score::Score, vector::Cvss3Vector, Cvss3Base},
+};
+use trustify_entity::{advisory, advisory_vulnerability, cvss2, cvss3, vulnerability};
 use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
@@ -114,6 +117,10 @@ pub struct AdvisoryVulnerabilitySummary {
     #[serde(flatten)]
     pub head: AdvisoryVulnerabilityHead,
 
+    /// The CVSS2 score from the advisory for the given vulnerability, if any.
+    #[schema(default, value_type = Vec<String>)]
+    pub cvss2_scores: Vec<String>,
+
     /// All CVSS3 scores from the advisory for the given vulnerability.
     /// May include several, varying by minor version of the CVSS3 vector.
     #[schema(default, value_type = Vec<String>)]
@@ -126,6 +133,15 @@ impl AdvisoryVulnerabilitySummary {
         vulnerabilities: &[vulnerability::Model],
         tx: &C,
     ) -> Result<Vec<Self>, Error> {
+        let mut cvss2s = vulnerabilities
+            .load_many(
+                cvss2::Entity::find()
+                    .filter(cvss2::Column::AdvisoryId.eq(advisory.id))
+                    .order_by_asc(cvss2::Column::Idx),
+                tx,
+            )
+            .await?;
+
         let mut cvss3s = vulnerabilities
             .load_many(
                 cvss3::Entity::find().filter(cvss3::Column::AdvisoryId.eq(advisory.id)),
@@ -135,14 +151,24 @@ impl AdvisoryVulnerabilitySummary {
 
         let mut summaries = Vec::new();
 
-        for (vuln, mut cvss3) in vulnerabilities.iter().zip(cvss3s.drain(..)) {
+        for ((vuln, mut cvss2), mut cvss3) in vulnerabilities
+            .iter()
+            .zip(cvss2s.drain(..))
+            .zip(cvss3s.drain(..))
+        {
+            let cvss2_scores = cvss2
+                .drain(..)
+                .map(|e| Cvss2Vector::from(e).to_string())
+                .collect();
+
             let cvss3_scores = cvss3
                 .drain(..)
-                .map(|e| Cvss3Base::from(e).to_string())
+                .map(|e| Cvss3Vector::from(e).to_string())
                 .collect();
 
             summaries.push(AdvisoryVulnerabilitySummary {
                 head: AdvisoryVulnerabilityHead::from_entity(advisory, vuln, tx).await?,
+                cvss2_scores,
                 cvss3_scores,
             });
         }
@@ -10,7 +10,7 @@ use sea_orm::{prelude::Uuid, ConnectionTrait, LoaderTrait, ModelTrait};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use trustify_common::memo::Memo;
-use trustify_entity::{advisory, labels::Labels, organization};
+use trustify_entity::{advisory, labels::Labels, organization, product_status, purl_status};
 use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, Debug, Clone, ToSchema, PartialEq, Eq)]
@@ -115,3 +115,37 @@ impl AdvisoryHead {
         Ok(heads)
     }
 }
+
+/// Additional information an advisory provides for a status, e.g. from a VEX document.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, ToSchema, PartialEq, Eq)]
+pub struct StatusInformation {
+    /// The justification of a "not affected" status, if any (e.g. `component_not_present`).
+    #[schema(required)]
+    pub justification: Option<String>,
+    /// A statement on the impact of the vulnerability, if any.
+    #[schema(required)]
+    pub impact_statement: Option<String>,
+    /// A statement on the actions to take for the vulnerability, if any.
+    #[schema(required)]
+    pub action_statement: Option<String>,
+}
+
+impl From<&purl_status::Model> for StatusInformation {
+    fn from(value: &purl_status::Model) -> Self {
+        Self {
+            justification: value.justification.clone(),
+            impact_statement: value.impact_statement.clone(),
+            action_statement: value.action_statement.clone(),
+        }
+    }
+}
+
+impl From<product_status::Model> for StatusInformation {
+    fn from(value: product_status::Model) -> Self {
+        Self {
+            justification: value.justification,
+            impact_statement: value.impact_statement,
+            action_statement: value.action_statement,
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use crate::{
     endpoints::Deprecation,
     purl::{
-        model::{details::purl::PurlDetails, summary::purl::PurlSummary},
+        model::{
+            details::purl::PurlDetails,
+            summary::purl::PurlSummary,
+            vulnerability::{PurlVulnerabilities, PurlVulnerabilityQuery},
+        },
         service::PurlService,
     },
     Error,
 };
-use actix_web::{get, web, HttpResponse, Responder};
+use actix_web::{get, post, web, HttpResponse, Responder};
 use sea_orm::prelude::Uuid;
 use std::str::FromStr;
 use trustify_auth::{authorizer::Require, ReadSbom};
@@ -33,7 +37,8 @@ pub fn configure(config: &mut utoipa_actix_web::service_config::ServiceConfig, d
         .service(base::all_base_purls)
         .service(version::get_versioned_purl)
         .service(get)
-        .service(all);
+        .service(all)
+        .service(vulnerabilities);
 }
 
 #[utoipa::path(
@@ -88,5 +93,33 @@ pub async fn all(
     Ok(HttpResponse::Ok().json(service.purls(search, paginated, db.as_ref()).await?))
 }
 
+#[utoipa::path(
+    operation_id = "queryPurlVulnerabilities",
+    tag = "purl",
+    params(
+        Deprecation,
+    ),
+    request_body = PurlVulnerabilityQuery,
+    responses(
+        (status = 200, description = "The vulnerabilities of each purl, in the order of the query", body = Vec<PurlVulnerabilities>),
+        (status = 400, description = "A purl has no version, or there are too many purls"),
+    ),
+)]
+#[post("/v1/purl/vulnerabilities")]
+/// Look up the vulnerabilities of multiple versioned pURLs
+pub async fn vulnerabilities(
+    service: web::Data<PurlService>,
+    db: web::Data<Database>,
+    web::Json(query): web::Json<PurlVulnerabilityQuery>,
+    web::Query(Deprecation { deprecated }): web::Query<Deprecation>,
+    _: Require<ReadSbom>,
+) -> actix_web::Result<impl Responder> {
+    Ok(HttpResponse::Ok().json(
+        service
+            .purl_vulnerabilities(query.purls, deprecated, db.as_ref())
+            .await?,
+    ))
+}
+
 #[cfg(test)]
 mod test;
@@ -5,8 +5,9 @@ use crate::purl::model::summary::base_purl::BasePurlSummary;
 use crate::purl::model::summary::purl::PurlSummary;
 use crate::purl::model::summary::r#type::TypeSummary;
 use crate::test::caller;
+use actix_http::StatusCode;
 use actix_web::test::TestRequest;
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::str::FromStr;
 use test_context::test_context;
 use test_log::test;
@@ -342,3 +343,40 @@ async fn package_with_status(ctx: &TrustifyContext) -> Result<(), anyhow::Error>
 
     Ok(())
 }
+
+#[test_context(TrustifyContext)]
+#[test(actix_web::test)]
+async fn vulnerabilities(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    ctx.ingest_documents(["osv/RUSTSEC-2021-0079.json", "cve/CVE-2021-32714.json"])
+        .await?;
+
+    let app = caller(ctx).await?;
+
+    let request = TestRequest::post()
+        .uri("/api/v1/purl/vulnerabilities")
+        .set_json(json!({
+            "purls": ["pkg:cargo/hyper@0.14.1", "pkg:cargo/hyper@0.14.10"]
+        }))
+        .to_request();
+    let response: Value = app.call_and_read_body_json(request).await;
+
+    log::debug!("{response:#?}");
+
+    assert_eq!(response[0]["purl"], "pkg:cargo/hyper@0.14.1");
+    assert_eq!(response[0]["statuses"][0]["status"], "affected");
+    assert_eq!(response[0]["statuses"][0]["fixed"], json!(["0.14.10"]));
+    assert_eq!(response[1]["purl"], "pkg:cargo/hyper@0.14.10");
+    assert_eq!(response[1]["statuses"][0]["status"], "fixed");
+
+    // a version is required
+
+    let request = TestRequest::post()
+        .uri("/api/v1/purl/vulnerabilities")
+        .set_json(json!({ "purls": ["pkg:cargo/hyper"] }))
+        .to_request();
+    let response = app.call_service(request).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::{
-    advisory::model::AdvisoryHead,
+    advisory::model::{AdvisoryHead, StatusInformation},
     purl::model::{BasePurlHead, PurlHead, VersionedPurlHead},
     sbom::model::SbomHead,
     vulnerability::model::VulnerabilityHead,
@@ -261,6 +261,7 @@ impl PurlAdvisory {
                 context: advisory_cpe
                     .as_ref()
                     .map(|e| StatusContext::Cpe(e.to_string())),
+                information: product_status.product_status.into(),
             };
 
             if let Some(entry) = results
@@ -297,6 +298,8 @@ pub struct PurlStatus {
     pub status: String,
     #[schema(required)]
     pub context: Option<StatusContext>,
+    #[serde(flatten)]
+    pub information: StatusInformation,
 }
 
 #[derive(Serialize, Clone, Deserialize, Debug, ToSchema, PartialEq, Eq)]
@@ -335,6 +338,7 @@ impl PurlStatus {
             .await?,
             status,
             context: cpe.map(StatusContext::Cpe),
+            information: package_status.into(),
         })
     }
 }
@@ -8,6 +8,7 @@ use utoipa::ToSchema;
 
 pub mod details;
 pub mod summary;
+pub mod vulnerability;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema, Hash)]
 pub struct BasePurlHead {
@@ -0,0 +1,299 @@
+use crate::{
+    advisory::model::{AdvisoryHead, StatusInformation},
+    purl::model::details::purl::StatusContext,
+    vulnerability::model::{version_range_notation, VulnerabilityHead},
+    Error,
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbBackend, DbErr, EntityTrait, FromQueryResult, QueryFilter,
+    QueryResult, Select, Statement,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use trustify_common::{
+    db::multi_model::{FromQueryResultMultiModel, SelectIntoMultiModel},
+    memo::Memo,
+    purl::{Purl, PurlErr},
+};
+use trustify_cvss::{
+    cvss3::{score::Score, severity::Severity, Cvss3Base},
+    cvss4::Cvss4Base,
+};
+use trustify_entity::{
+    advisory, cpe, cvss3, cvss4, purl_status, status, version_range, vulnerability,
+    vulnerability_description,
+};
+use trustify_module_ingestor::common::Deprecation;
+use utoipa::ToSchema;
+
+/// The maximum number of purls of a single query.
+pub const MAX_PURLS: usize = 5000;
+
+/// The slug of the status providing the fixed version ranges.
+const FIXED: &str = "fixed";
+
+/// A query for the vulnerabilities of multiple purls.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PurlVulnerabilityQuery {
+    /// The purls to look up. They must have a version, qualifiers are ignored.
+    pub purls: Vec<Purl>,
+}
+
+/// The vulnerabilities of a purl.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PurlVulnerabilities {
+    /// The purl, as provided by the query
+    pub purl: Purl,
+    /// The statuses applying to the version of the purl
+    pub statuses: Vec<PurlVulnerabilityStatus>,
+}
+
+/// The status of a purl regarding a vulnerability, as reported by an advisory.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct PurlVulnerabilityStatus {
+    pub vulnerability: VulnerabilityHead,
+    pub advisory: AdvisoryHead,
+    pub status: String,
+    #[schema(required)]
+    pub context: Option<StatusContext>,
+    /// The severity, based on the scores the advisory provides for the vulnerability, if any.
+    #[schema(required)]
+    pub severity: Option<Severity>,
+    /// The score the advisory provides for the vulnerability, if any.
+    #[schema(required)]
+    pub score: Option<f64>,
+    /// The version range of the status, e.g. `[1.0.0,1.2.3)`.
+    pub version_range: String,
+    /// The version ranges the advisory reports as fixed, for the same package and vulnerability.
+    pub fixed: Vec<String>,
+    #[serde(flatten)]
+    pub information: StatusInformation,
+}
+
+impl PurlVulnerabilities {
+    /// Look up the vulnerabilities of multiple purls.
+    ///
+    /// The result has one entry per purl, in the order of the provided purls. The number of
+    /// database queries doesn't depend on the number of purls.
+    pub async fn from_purls<C: ConnectionTrait>(
+        purls: Vec<Purl>,
+        deprecation: Deprecation,
+        tx: &C,
+    ) -> Result<Vec<Self>, Error> {
+        let mut indexes = Vec::with_capacity(purls.len());
+        let mut base_purls = Vec::with_capacity(purls.len());
+        let mut versions = Vec::with_capacity(purls.len());
+
+        for (idx, purl) in purls.iter().enumerate() {
+            let Some(version) = &purl.version else {
+                return Err(Error::Purl(PurlErr::MissingVersion(format!(
+                    "A version is required to look up vulnerabilities: {purl}"
+                ))));
+            };
+
+            indexes.push(idx as i32);
+            base_purls.push(purl.package_uuid());
+            versions.push(version.clone());
+        }
+
+        // all statuses matching the version of a purl, plus all "fixed" statuses of its package
+
+        let rows = tx
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                include_str!("vulnerability.sql"),
+                [
+                    indexes.into(),
+                    base_purls.into(),
+                    versions.into(),
+                    (deprecation == Deprecation::Consider).into(),
+                ],
+            ))
+            .await?
+            .iter()
+            .map(|row| StatusCatcher::from_query_result(row, ""))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let advisory_ids = rows
+            .iter()
+            .map(|row| row.purl_status.advisory_id)
+            .collect::<BTreeSet<_>>();
+        let vulnerability_ids = rows
+            .iter()
+            .map(|row| row.purl_status.vulnerability_id.clone())
+            .collect::<BTreeSet<_>>();
+
+        // load everything related to the statuses, in batches
+
+        let advisories = advisory::Entity::find()
+            .filter(advisory::Column::Id.is_in(advisory_ids.clone()))
+            .all(tx)
+            .await?;
+        let advisories = advisories
+            .iter()
+            .map(|advisory| advisory.id)
+            .zip(AdvisoryHead::from_entities(&advisories, tx).await?)
+            .collect::<HashMap<_, _>>();
+
+        let mut descriptions = HashMap::new();
+        for description in vulnerability_description::Entity::find()
+            .filter(
+                vulnerability_description::Column::VulnerabilityId.is_in(vulnerability_ids.clone()),
+            )
+            .filter(vulnerability_description::Column::Lang.eq("en"))
+            .all(tx)
+            .await?
+        {
+            descriptions
+                .entry(description.vulnerability_id.clone())
+                .or_insert(description);
+        }
+
+        let mut vulnerabilities = HashMap::new();
+        for vulnerability in vulnerability::Entity::find()
+            .filter(vulnerability::Column::Id.is_in(vulnerability_ids.clone()))
+            .all(tx)
+            .await?
+        {
+            let description = descriptions.remove(&vulnerability.id);
+            let head = VulnerabilityHead::from_vulnerability_entity(
+                &vulnerability,
+                Memo::Provided(description),
+                tx,
+            )
+            .await?;
+            vulnerabilities.insert(vulnerability.id, head);
+        }
+
+        let mut scores = HashMap::<_, Vec<_>>::new();
+        for cvss3 in cvss3::Entity::find()
+            .filter(cvss3::Column::AdvisoryId.is_in(advisory_ids.clone()))
+            .filter(cvss3::Column::VulnerabilityId.is_in(vulnerability_ids.clone()))
+            .all(tx)
+            .await?
+        {
+            scores
+                .entry((cvss3.advisory_id, cvss3.vulnerability_id.clone()))
+                .or_default()
+                .push(Cvss3Base::from(&cvss3).score());
+        }
+        for cvss4 in cvss4::Entity::find()
+            .filter(cvss4::Column::AdvisoryId.is_in(advisory_ids))
+            .filter(cvss4::Column::VulnerabilityId.is_in(vulnerability_ids))
+            .all(tx)
+            .await?
+        {
+            scores
+                .entry((cvss4.advisory_id, cvss4.vulnerability_id.clone()))
+                .or_default()
+                .push(Cvss4Base::from(&cvss4).score());
+        }
+
+        // the fixed ranges, by purl, advisory, and vulnerability
+
+        let mut fixed = HashMap::<_, Vec<_>>::new();
+        for row in rows.iter().filter(|row| row.status.slug == FIXED) {
+            fixed
+                .entry((
+                    row.idx,
+                    row.purl_status.advisory_id,
+                    &row.purl_status.vulnerability_id,
+                ))
+                .or_default()
+                .push(version_range_notation(&row.version_range));
+        }
+
+        let mut results = purls
+            .into_iter()
+            .map(|purl| Self {
+                purl,
+                statuses: vec![],
+            })
+            .collect::<Vec<_>>();
+
+        for row in rows.iter().filter(|row| row.matches) {
+            let purl_status = &row.purl_status;
+
+            let Some(result) = results.get_mut(row.idx as usize) else {
+                continue;
+            };
+            let Some(advisory) = advisories.get(&purl_status.advisory_id) else {
+                continue;
+            };
+
+            let vulnerability = vulnerabilities
+                .get(&purl_status.vulnerability_id)
+                .cloned()
+                .unwrap_or_else(|| VulnerabilityHead {
+                    identifier: purl_status.vulnerability_id.clone(),
+                    ..Default::default()
+                });
+
+            let score = scores
+                .get(&(
+                    purl_status.advisory_id,
+                    purl_status.vulnerability_id.clone(),
+                ))
+                .map(|scores| Score::from_iter(scores.iter().copied()));
+
+            result.statuses.push(PurlVulnerabilityStatus {
+                vulnerability,
+                advisory: advisory.clone(),
+                status: row.status.slug.clone(),
+                context: row
+                    .cpe
+                    .as_ref()
+                    .map(|cpe| StatusContext::Cpe(cpe.to_string())),
+                severity: score.map(|score| score.severity()),
+                score: score.map(|score| score.value()),
+                version_range: version_range_notation(&row.version_range),
+                fixed: fixed
+                    .get(&(
+                        row.idx,
+                        purl_status.advisory_id,
+                        &purl_status.vulnerability_id,
+                    ))
+                    .cloned()
+                    .unwrap_or_default(),
+                information: purl_status.into(),
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[derive(Debug)]
+struct StatusCatcher {
+    /// The index of the purl in the query
+    idx: i32,
+    /// If the status matches the version of the purl
+    matches: bool,
+    purl_status: purl_status::Model,
+    version_range: version_range::Model,
+    status: status::Model,
+    cpe: Option<cpe::Model>,
+}
+
+impl FromQueryResult for StatusCatcher {
+    fn from_query_result(res: &QueryResult, _pre: &str) -> Result<Self, DbErr> {
+        Ok(Self {
+            idx: res.try_get("", "idx")?,
+            matches: res.try_get("", "matches")?,
+            purl_status: Self::from_query_result_multi_model(res, "", purl_status::Entity)?,
+            version_range: Self::from_query_result_multi_model(res, "", version_range::Entity)?,
+            status: Self::from_query_result_multi_model(res, "", status::Entity)?,
+            cpe: Self::from_query_result_multi_model_optional(res, "", cpe::Entity)?,
+        })
+    }
+}
+
+impl FromQueryResultMultiModel for StatusCatcher {
+    fn try_into_multi_model<E: EntityTrait>(select: Select<E>) -> Result<Select<E>, DbErr> {
+        select
+            .try_model_columns(purl_status::Entity)?
+            .try_model_columns(version_range::Entity)?
+            .try_model_columns(status::Entity)?
+            .try_model_columns(cpe::Entity)
+    }
+}
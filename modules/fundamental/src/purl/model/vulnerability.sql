@@ -0,0 +1,52 @@
+-- the statuses of a batch of purls
+--
+-- $1: the index of each purl in the query
+-- $2: the ID of the base purl of each purl
+-- $3: the version of each purl
+-- $4: if statuses of deprecated advisories should be considered
+--
+-- returns all statuses matching the version of a purl, plus all "fixed" statuses of its base purl
+SELECT
+    "query"."idx" AS "idx",
+    version_matches("query"."version", "version_range".*) AS "matches",
+    "purl_status"."id" AS "purl_status$id",
+    "purl_status"."advisory_id" AS "purl_status$advisory_id",
+    "purl_status"."vulnerability_id" AS "purl_status$vulnerability_id",
+    "purl_status"."status_id" AS "purl_status$status_id",
+    "purl_status"."base_purl_id" AS "purl_status$base_purl_id",
+    "purl_status"."version_range_id" AS "purl_status$version_range_id",
+    "purl_status"."context_cpe_id" AS "purl_status$context_cpe_id",
+    "purl_status"."justification" AS "purl_status$justification",
+    "purl_status"."impact_statement" AS "purl_status$impact_statement",
+    "purl_status"."action_statement" AS "purl_status$action_statement",
+    "version_range"."id" AS "version_range$id",
+    "version_range"."version_scheme_id" AS "version_range$version_scheme_id",
+    "version_range"."low_version" AS "version_range$low_version",
+    "version_range"."low_inclusive" AS "version_range$low_inclusive",
+    "version_range"."high_version" AS "version_range$high_version",
+    "version_range"."high_inclusive" AS "version_range$high_inclusive",
+    "status"."id" AS "status$id",
+    "status"."slug" AS "status$slug",
+    "status"."name" AS "status$name",
+    "status"."description" AS "status$description",
+    "cpe"."id" AS "cpe$id",
+    "cpe"."part" AS "cpe$part",
+    "cpe"."vendor" AS "cpe$vendor",
+    "cpe"."product" AS "cpe$product",
+    "cpe"."version" AS "cpe$version",
+    "cpe"."update" AS "cpe$update",
+    "cpe"."edition" AS "cpe$edition",
+    "cpe"."language" AS "cpe$language"
+FROM unnest($1::integer[], $2::uuid[], $3::text[]) AS "query"("idx", "base_purl_id", "version")
+JOIN "purl_status" ON "purl_status"."base_purl_id" = "query"."base_purl_id"
+JOIN "version_range" ON "purl_status"."version_range_id" = "version_range"."id"
+JOIN "status" ON "purl_status"."status_id" = "status"."id"
+JOIN "advisory" ON "purl_status"."advisory_id" = "advisory"."id"
+LEFT JOIN "cpe" ON "purl_status"."context_cpe_id" = "cpe"."id"
+WHERE
+    ($4 OR NOT "advisory"."deprecated")
+    AND (
+        "status"."slug" = 'fixed'
+        OR version_matches("query"."version", "version_range".*)
+    )
+ORDER BY "query"."idx", "purl_status"."vulnerability_id", "purl_status"."advisory_id"
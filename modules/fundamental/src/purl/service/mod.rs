@@ -4,6 +4,7 @@ use crate::{
             base_purl::BasePurlDetails, purl::PurlDetails, versioned_purl::VersionedPurlDetails,
         },
         summary::{base_purl::BasePurlSummary, purl::PurlSummary, r#type::TypeSummary},
+        vulnerability::{PurlVulnerabilities, MAX_PURLS},
     },
     Error,
 };
@@ -282,6 +283,26 @@ impl PurlService {
         }
     }
 
+    /// Look up the vulnerabilities of multiple purls at once.
+    ///
+    /// Returns one entry per purl, in the order of the provided purls.
+    #[instrument(skip(self, purls, connection), err(level=tracing::Level::INFO))]
+    pub async fn purl_vulnerabilities<C: ConnectionTrait>(
+        &self,
+        purls: Vec<Purl>,
+        deprecation: Deprecation,
+        connection: &C,
+    ) -> Result<Vec<PurlVulnerabilities>, Error> {
+        if purls.len() > MAX_PURLS {
+            return Err(Error::BadRequest(format!(
+                "Too many purls: {} (maximum: {MAX_PURLS})",
+                purls.len()
+            )));
+        }
+
+        PurlVulnerabilities::from_purls(purls, deprecation, connection).await
+    }
+
     pub async fn base_purls<C: ConnectionTrait>(
         &self,
         query: Query,
@@ -635,6 +635,66 @@ async fn statuses(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[test_context(TrustifyContext)]
+#[test(actix_web::test)]
+async fn vulnerabilities(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let service = PurlService::new();
+    ctx.ingest_documents(["osv/RUSTSEC-2021-0079.json", "cve/CVE-2021-32714.json"])
+        .await?;
+
+    let results = service
+        .purl_vulnerabilities(
+            vec![
+                Purl::from_str("pkg:cargo/hyper@0.14.1")?,
+                Purl::from_str("pkg:cargo/hyper@0.14.10?foo=bar")?,
+                Purl::from_str("pkg:cargo/unknown@1.0.0")?,
+            ],
+            Default::default(),
+            &ctx.db,
+        )
+        .await?;
+
+    log::debug!("{results:#?}");
+
+    assert_eq!(3, results.len());
+    assert_eq!("pkg:cargo/hyper@0.14.1", results[0].purl.to_string());
+
+    // affected, with the version fixing it
+
+    assert!(!results[0].statuses.is_empty());
+    assert!(results[0].statuses.iter().all(|status| {
+        status.status == "affected"
+            && status.advisory.identifier == "RUSTSEC-2021-0079"
+            && status.version_range == "[0.0.0-0,0.14.10)"
+            && status.fixed == ["0.14.10"]
+    }));
+
+    // the fixed version, ignoring qualifiers
+
+    assert!(!results[1].statuses.is_empty());
+    assert!(results[1]
+        .statuses
+        .iter()
+        .all(|status| status.status == "fixed"));
+
+    // nothing known
+
+    assert!(results[2].statuses.is_empty());
+
+    // a version is required
+
+    assert!(service
+        .purl_vulnerabilities(
+            vec![Purl::from_str("pkg:cargo/hyper")?],
+            Default::default(),
+            &ctx.db,
+        )
+        .await
+        .is_err());
+
+    Ok(())
+}
+
 #[test_context(TrustifyContext)]
 #[test(actix_web::test)]
 async fn contextual_status(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
@@ -7,8 +7,10 @@ use crate::{
     purl::service::PurlService,
     sbom::{
         model::{
-            details::SbomAdvisory, SbomPackage, SbomPackageReference, SbomPackageRelation,
-            SbomSummary, Which,
+            details::SbomAdvisory,
+            diff::SbomDiff,
+            export::{ExportFormat, ExportOptions},
+            SbomPackage, SbomPackageReference, SbomPackageRelation, SbomSummary, Which,
         },
         service::SbomService,
     },
@@ -39,6 +41,7 @@ use trustify_common::{
     purl::Purl,
 };
 use trustify_entity::{labels::Labels, relationship::Relationship};
+use trustify_module_analysis::service::invalidate_graphs;
 use trustify_module_ingestor::{
     model::IngestResult,
     service::{Format, IngestorService},
@@ -61,13 +64,17 @@ pub fn configure(
         .service(all)
         .service(all_related)
         .service(count_related)
+        .service(all_by_checksum)
         .service(get)
         .service(get_sbom_advisories)
+        .service(diff)
         .service(delete)
         .service(packages)
         .service(related)
         .service(upload)
+        .service(scan)
         .service(download)
+        .service(export)
         .service(label::set)
         .service(label::update);
 }
@@ -219,6 +226,50 @@ pub async fn count_related(
     Ok(HttpResponse::Ok().json(result))
 }
 
+#[derive(Clone, Debug, serde::Deserialize, utoipa::IntoParams)]
+struct ChecksumQuery {
+    /// The hex encoded checksum, e.g. a SHA-256 digest
+    pub checksum: String,
+    /// The algorithm of the checksum, e.g. `sha256` or `SHA-256`
+    #[serde(default)]
+    pub algorithm: Option<String>,
+}
+
+/// Find all SBOMs containing a package or file with the provided checksum.
+#[utoipa::path(
+    tag = "sbom",
+    operation_id = "listSbomsByChecksum",
+    params(
+        Query,
+        Paginated,
+        ChecksumQuery,
+    ),
+    responses(
+        (status = 200, description = "Matching SBOMs", body = PaginatedResults<SbomSummary>),
+    ),
+)]
+#[get("/v1/sbom/by-checksum")]
+pub async fn all_by_checksum(
+    sbom: web::Data<SbomService>,
+    db: web::Data<Database>,
+    web::Query(search): web::Query<Query>,
+    web::Query(paginated): web::Query<Paginated>,
+    web::Query(checksum): web::Query<ChecksumQuery>,
+    _: Require<ReadSbom>,
+) -> actix_web::Result<impl Responder> {
+    let result = sbom
+        .find_sboms_by_checksum(
+            &checksum.checksum,
+            checksum.algorithm.as_deref(),
+            paginated,
+            search,
+            db.as_ref(),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
 #[utoipa::path(
     tag = "sbom",
     operation_id = "getSbom",
@@ -271,6 +322,36 @@ pub async fn get_sbom_advisories(
 
 all!(GetSbomAdvisories -> ReadSbom, ReadAdvisory);
 
+/// Compare two SBOMs
+#[utoipa::path(
+    tag = "sbom",
+    operation_id = "diffSbom",
+    params(
+        ("left" = String, Path, description = "Digest/hash of the SBOM to compare against, prefixed by hash type, such as 'sha256:<hash>' or 'urn:uuid:<uuid>'"),
+        ("right" = String, Path, description = "Digest/hash of the SBOM to compare, prefixed by hash type, such as 'sha256:<hash>' or 'urn:uuid:<uuid>'"),
+    ),
+    responses(
+        (status = 200, description = "Differences of the SBOMs", body = SbomDiff),
+        (status = 404, description = "One of the SBOMs was not found"),
+    ),
+)]
+#[get("/v1/sbom/{left}/diff/{right}")]
+pub async fn diff(
+    fetcher: web::Data<SbomService>,
+    db: web::Data<Database>,
+    path: web::Path<(String, String)>,
+    _: Require<GetSbomAdvisories>,
+) -> actix_web::Result<impl Responder> {
+    let (left, right) = path.into_inner();
+    let left = Id::from_str(&left).map_err(Error::IdKey)?;
+    let right = Id::from_str(&right).map_err(Error::IdKey)?;
+
+    match fetcher.diff_sboms(left, right, db.as_ref()).await? {
+        Some(v) => Ok(HttpResponse::Ok().json(v)),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
 #[utoipa::path(
     tag = "sbom",
     operation_id = "deleteSbom",
@@ -301,6 +382,9 @@ pub async fn delete(
                 1 => {
                     let _ = purl_service.gc_purls(&tx).await; // ignore gc failure..
                     tx.commit().await?;
+                    // only drop the analysis graph once the deletion is visible, so that it can't
+                    // get re-loaded from the not yet deleted SBOM
+                    invalidate_graphs(&[v.head.id.to_string()]).await;
                     Ok(HttpResponse::Ok().json(v))
                 }
                 _ => Err(Internal("Unexpected number of rows affected".into())),
@@ -434,6 +518,43 @@ pub async fn upload(
     Ok(HttpResponse::Created().json(result))
 }
 
+/// Scan an SBOM for vulnerabilities, without storing it
+///
+/// The document is evaluated in a transaction which gets rolled back afterward, so that neither
+/// the document nor any of its content is persisted. As this performs the same work as an upload,
+/// it requires the same permission.
+#[utoipa::path(
+    tag = "sbom",
+    operation_id = "scanSbom",
+    request_body = Vec <u8>,
+    responses(
+        (status = 200, description = "Advisories affecting the SBOM", body = Vec<SbomAdvisory>),
+        (status = 400, description = "The file could not be parsed as an SBOM"),
+    )
+)]
+#[post("/v1/sbom/scan")]
+pub async fn scan(
+    service: web::Data<IngestorService>,
+    fetcher: web::Data<SbomService>,
+    config: web::Data<Config>,
+    content_type: Option<web::Header<header::ContentType>>,
+    bytes: web::Bytes,
+    _: Require<CreateSbom>,
+) -> Result<impl Responder, Error> {
+    let bytes = decompress_async(bytes, content_type.map(|ct| ct.0), config.upload_limit).await??;
+    let (result, tx) = service
+        .ingest_ephemeral(&bytes, Format::SBOM, Labels::default())
+        .await?;
+
+    let details = fetcher.fetch_sbom_details(result.id, &tx).await;
+    tx.rollback().await?;
+
+    match details? {
+        Some(v) => Ok(HttpResponse::Ok().json(v.advisories)),
+        None => Err(Internal("scanned SBOM not found".into())),
+    }
+}
+
 #[utoipa::path(
     tag = "sbom",
     operation_id = "downloadSbom",
@@ -478,3 +599,64 @@ pub async fn download(
         Ok(HttpResponse::NotFound().finish())
     }
 }
+
+#[derive(Clone, Debug, serde::Deserialize, utoipa::IntoParams)]
+struct ExportQuery {
+    /// The format to export the SBOM to
+    #[serde(default)]
+    #[param(inline)]
+    pub format: ExportFormat,
+    /// Include the vulnerabilities affecting the SBOM, only supported by CycloneDX
+    #[serde(default)]
+    pub vulnerabilities: bool,
+    /// Include license assertions made by other documents, such as curations
+    #[serde(default)]
+    pub curated_licenses: bool,
+}
+
+/// Export an SBOM from the stored information, in the requested format.
+#[utoipa::path(
+    tag = "sbom",
+    operation_id = "exportSbom",
+    params(
+        ("id" = String, Path, description = "Digest/hash of the document, prefixed by hash type, such as 'sha256:<hash>' or 'urn:uuid:<uuid>'"),
+        ExportQuery,
+    ),
+    responses(
+        (status = 200, description = "The exported SBOM", body = serde_json::Value),
+        (status = 400, description = "The requested options are not supported by the format"),
+        (status = 404, description = "Matching SBOM not found"),
+    ),
+)]
+#[get("/v1/sbom/{id}/export")]
+pub async fn export(
+    sbom: web::Data<SbomService>,
+    db: web::Data<Database>,
+    id: web::Path<String>,
+    web::Query(export): web::Query<ExportQuery>,
+    authorizer: web::Data<Authorizer>,
+    user: UserInformation,
+) -> actix_web::Result<impl Responder> {
+    authorizer.require(&user, Permission::ReadSbom)?;
+    if export.vulnerabilities {
+        authorizer.require(&user, Permission::ReadAdvisory)?;
+
+        if !export.format.is_cyclonedx() {
+            return Err(Error::BadRequest(
+                "Vulnerabilities can only be exported to CycloneDX".into(),
+            )
+            .into());
+        }
+    }
+
+    let id = Id::from_str(&id).map_err(Error::IdKey)?;
+    let options = ExportOptions {
+        vulnerabilities: export.vulnerabilities,
+        curated_licenses: export.curated_licenses,
+    };
+
+    match sbom.export_sbom(id, options, db.as_ref()).await? {
+        Some(v) => Ok(HttpResponse::Ok().json(v.render(export.format))),
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
@@ -4,12 +4,15 @@ use crate::{
 };
 use actix_http::StatusCode;
 use actix_web::test::TestRequest;
+use hex::ToHex;
+use sea_orm::{EntityTrait, PaginatorTrait};
 use serde_json::{json, Value};
 use test_context::test_context;
 use test_log::test;
-use trustify_common::{id::Id, model::PaginatedResults};
-use trustify_entity::labels::Labels;
+use trustify_common::{hashing::Digests, id::Id, model::PaginatedResults};
+use trustify_entity::{base_purl, labels::Labels, versioned_purl};
 use trustify_module_ingestor::model::IngestResult;
+use trustify_module_storage::service::{StorageBackend, StorageKey};
 use trustify_test_context::{call::CallService, document_bytes, TrustifyContext};
 use uuid::Uuid;
 
@@ -233,6 +236,96 @@ async fn get_advisories(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[test_context(TrustifyContext)]
+#[test(actix_web::test)]
+async fn scan_sbom(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    ctx.ingest_document("csaf/cve-2023-0044.json").await?;
+
+    let base_purls = base_purl::Entity::find().count(&ctx.db).await?;
+    let versioned_purls = versioned_purl::Entity::find().count(&ctx.db).await?;
+
+    let bytes = document_bytes("quarkus-bom-2.13.8.Final-redhat-00004.json").await?;
+    let digests = Digests::digest(&bytes);
+
+    let app = caller(ctx).await?;
+    let v: Value = app
+        .call_and_read_body_json(
+            TestRequest::post()
+                .uri("/api/v1/sbom/scan")
+                .set_payload(bytes)
+                .to_request(),
+        )
+        .await;
+
+    log::debug!("{v:#?}");
+
+    // same result as for an ingested SBOM
+    assert_eq!(v[0]["identifier"], "https://www.redhat.com/#CVE-2023-0044");
+    assert_eq!(v[0]["status"][0]["average_severity"], "high");
+
+    // but nothing was stored
+    let sboms: PaginatedResults<SbomSummary> = app
+        .call_and_read_body_json(TestRequest::get().uri("/api/v1/sbom").to_request())
+        .await;
+    assert_eq!(sboms.total, 0);
+
+    // neither its content
+    assert_eq!(base_purl::Entity::find().count(&ctx.db).await?, base_purls);
+    assert_eq!(
+        versioned_purl::Entity::find().count(&ctx.db).await?,
+        versioned_purls
+    );
+
+    // nor the document itself
+    let key = StorageKey::try_from(Id::Sha256(digests.sha256.encode_hex()))?;
+    assert!(ctx.storage.retrieve(key).await?.is_none());
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext)]
+#[test(actix_web::test)]
+async fn find_sboms_by_checksum(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let result = ctx.ingest_document("spdx/issue-552.json").await?;
+    ctx.ingest_document("cyclonedx/package-details.json")
+        .await?;
+
+    let checksum = "11b6d3ee554eedf79299905a98f9b9a04e498210b59f15094c916c91d150efcd";
+
+    let app = caller(ctx).await?;
+    let sboms: PaginatedResults<SbomSummary> = app
+        .call_and_read_body_json(
+            TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/sbom/by-checksum?checksum={checksum}&algorithm=sha256"
+                ))
+                .to_request(),
+        )
+        .await;
+
+    assert_eq!(sboms.total, 1);
+    assert_eq!(Id::Uuid(sboms.items[0].head.id), result.id);
+
+    // the checksum is part of the packages
+    let v: Value = app
+        .call_and_read_body_json(
+            TestRequest::get()
+                .uri(&format!("/api/v1/sbom/{}/packages?q=glibc", result.id))
+                .to_request(),
+        )
+        .await;
+    log::debug!("{v:#?}");
+    assert!(v["items"][0]["checksums"]
+        .as_array()
+        .expect("must be an array")
+        .contains(&json!({
+            "type": "sha256",
+            "value": checksum,
+        })));
+
+    Ok(())
+}
+
 #[test_context(TrustifyContext)]
 #[test(actix_web::test)]
 async fn query_sboms_by_ingested_time(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
@@ -270,3 +363,62 @@ async fn query_sboms_by_ingested_time(ctx: &TrustifyContext) -> Result<(), anyho
 
     Ok(())
 }
+
+#[test_context(TrustifyContext)]
+#[test(actix_web::test)]
+async fn export_sbom(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let result = ctx.ingest_document("spdx/issue-552.json").await?;
+
+    let app = caller(ctx).await?;
+
+    // SPDX, by default
+    let v: Value = app
+        .call_and_read_body_json(
+            TestRequest::get()
+                .uri(&format!("/api/v1/sbom/{}/export", result.id))
+                .to_request(),
+        )
+        .await;
+    log::debug!("{v:#?}");
+    assert_eq!(v["spdxVersion"], "SPDX-2.3");
+
+    // CycloneDX, with vulnerabilities
+    let v: Value = app
+        .call_and_read_body_json(
+            TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/sbom/{}/export?format=cyclonedx-1.5&vulnerabilities=true",
+                    result.id
+                ))
+                .to_request(),
+        )
+        .await;
+    log::debug!("{v:#?}");
+    assert_eq!(v["bomFormat"], "CycloneDX");
+    assert_eq!(v["specVersion"], "1.5");
+
+    // SPDX can't carry vulnerabilities
+    let response = app
+        .call_service(
+            TestRequest::get()
+                .uri(&format!(
+                    "/api/v1/sbom/{}/export?vulnerabilities=true",
+                    result.id
+                ))
+                .to_request(),
+        )
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // unknown SBOM
+    let response = app
+        .call_service(
+            TestRequest::get()
+                .uri(&format!("/api/v1/sbom/{}/export", Id::Uuid(Uuid::nil())))
+                .to_request(),
+        )
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use crate::{
         model::SbomPackage,
         service::{sbom::QueryCatcher, SbomService},
     },
-    vulnerability::model::VulnerabilityHead,
+    vulnerability::model::{EpssScore, KevEntry, VulnerabilityHead},
     Error,
 };
 use cpe::{cpe::Cpe, uri::OwnedUri};
@@ -25,11 +25,14 @@ use trustify_common::{
     },
     memo::Memo,
 };
-use trustify_cvss::cvss3::{score::Score, severity::Severity, Cvss3Base};
+use trustify_cvss::{
+    cvss3::{score::Score, severity::Severity, Cvss3Base},
+    cvss4::Cvss4Base,
+};
 use trustify_entity::{
-    advisory, base_purl, cvss3, product_status, product_version, purl_status, qualified_purl, sbom,
-    sbom_node, sbom_package, sbom_package_purl_ref, status, version_range, versioned_purl,
-    vulnerability,
+    advisory, base_purl, cvss3, cvss4, epss, kev, product_status, product_version, purl_status,
+    qualified_purl, sbom, sbom_node, sbom_package, sbom_package_purl_ref, status, version_range,
+    versioned_purl,
 };
 use utoipa::ToSchema;
 
@@ -79,6 +82,9 @@ impl SbomDetails {
             .join(JoinType::Join, purl_status::Relation::Advisory.def())
             .join(JoinType::Join, purl_status::Relation::Vulnerability.def())
             .select_only()
+            .column_as(purl_status::Column::Justification, "justification")
+            .column_as(purl_status::Column::ImpactStatement, "impact_statement")
+            .column_as(purl_status::Column::ActionStatement, "action_statement")
             .try_into_multi_model::<QueryCatcher>()?
             .all(tx)
             .await?;
@@ -138,7 +144,10 @@ impl SbomDetails {
                 "cpe"."version" AS "cpe$version",
                 "cpe"."update" AS "cpe$update",
                 "cpe"."edition" AS "cpe$edition",
-                "cpe"."language" AS "cpe$language"
+                "cpe"."language" AS "cpe$language",
+                "product_status"."justification" AS "justification",
+                "product_status"."impact_statement" AS "impact_statement",
+                "product_status"."action_statement" AS "action_statement"
             FROM "sbom"
             -- find statuses that matches SBOMs
             JOIN "product_version" ON "product_version"."sbom_id" = "sbom"."sbom_id"
@@ -269,6 +278,7 @@ impl SbomAdvisory {
             let sbom_status = if let Some(status) = advisory.status.iter_mut().find(|status| {
                 if status.status == each.status.slug
                     && status.vulnerability.identifier == each.vulnerability.id
+                    && status.information == each.information
                 {
                     match (&status.context, &status_cpe) {
                         (Some(StatusContext::Cpe(context_cpe)), Some(status_cpe)) => {
@@ -283,14 +293,7 @@ impl SbomAdvisory {
             }) {
                 status
             } else {
-                let status = SbomStatus::new(
-                    &each.vulnerability,
-                    each.status.slug.clone(),
-                    status_cpe,
-                    vec![],
-                    tx,
-                )
-                .await?;
+                let status = SbomStatus::new(each, status_cpe, vec![], tx).await?;
                 advisory.status.push(status);
                 if let Some(status) = advisory.status.last_mut() {
                     status
@@ -313,6 +316,11 @@ impl SbomAdvisory {
                     .await?,
                 ],
                 cpe: vec![],
+                supplier: each.sbom_package.supplier.clone(),
+                originator: each.sbom_package.originator.clone(),
+                download_location: each.sbom_package.download_location.clone(),
+                copyright_text: each.sbom_package.copyright_text.clone(),
+                ..Default::default()
             });
         }
 
@@ -325,21 +333,38 @@ pub struct SbomStatus {
     #[serde(flatten)]
     pub vulnerability: VulnerabilityHead,
     pub average_severity: Severity,
+    /// The latest EPSS score of the vulnerability, if any.
+    #[schema(required)]
+    pub epss: Option<EpssScore>,
+    /// The entry of the vulnerability in the CISA Known Exploited Vulnerabilities catalog, if any.
+    #[schema(required)]
+    pub kev: Option<KevEntry>,
     pub status: String,
     pub context: Option<StatusContext>,
+    #[serde(flatten)]
+    pub information: StatusInformation,
     pub packages: Vec<SbomPackage>,
 }
 
 impl SbomStatus {
     pub async fn new<C: ConnectionTrait>(
-        vulnerability: &vulnerability::Model,
-        status: String,
+        each: &QueryCatcher,
         cpe: Option<OwnedUri>,
         packages: Vec<SbomPackage>,
         tx: &C,
     ) -> Result<Self, Error> {
+        let vulnerability = &each.vulnerability;
         let cvss3 = vulnerability.find_related(cvss3::Entity).all(tx).await?;
-        let average_severity = Score::from_iter(cvss3.iter().map(Cvss3Base::from)).severity();
+        let cvss4 = vulnerability.find_related(cvss4::Entity).all(tx).await?;
+        let average_severity = Score::from_iter(
+            cvss3
+                .iter()
+                .map(|e| Cvss3Base::from(e).score())
+                .chain(cvss4.iter().map(|e| Cvss4Base::from(e).score())),
+        )
+        .severity();
+        let epss = vulnerability.find_related(epss::Entity).one(tx).await?;
+        let kev = vulnerability.find_related(kev::Entity).one(tx).await?;
         Ok(Self {
             vulnerability: VulnerabilityHead::from_vulnerability_entity(
                 vulnerability,
@@ -349,7 +374,10 @@ impl SbomStatus {
             .await?,
             context: cpe.as_ref().map(|e| StatusContext::Cpe(e.to_string())),
             average_severity,
-            status,
+            epss: epss.map(Into::into),
+            kev: kev.map(Into::into),
+            status: each.status.slug.clone(),
+            information: each.information.clone(),
             packages,
         })
     }
@@ -0,0 +1,69 @@
+use super::{details::SbomStatus, SbomHead, SbomPackage};
+use serde::{Deserialize, Serialize};
+use trustify_entity::relationship::Relationship;
+use utoipa::ToSchema;
+
+/// The differences between two SBOMs.
+///
+/// Changes are reported from the point of view of the left SBOM, e.g. an "added" package is
+/// only part of the right SBOM.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct SbomDiff {
+    /// The SBOM used as the base of the comparison
+    pub left: SbomHead,
+    /// The SBOM compared to the base
+    pub right: SbomHead,
+
+    /// Packages only found in the right SBOM
+    pub added: Vec<SbomPackage>,
+    /// Packages only found in the left SBOM
+    pub removed: Vec<SbomPackage>,
+    /// Packages which have a newer version in the right SBOM
+    pub upgraded: Vec<SbomPackageChange>,
+    /// Packages which have an older version in the right SBOM
+    pub downgraded: Vec<SbomPackageChange>,
+
+    /// Relationships only found in the right SBOM
+    pub added_relationships: Vec<SbomRelationship>,
+    /// Relationships only found in the left SBOM
+    pub removed_relationships: Vec<SbomRelationship>,
+
+    /// Packages of both SBOMs with different licenses
+    pub license_changes: Vec<SbomLicenseChange>,
+
+    /// Vulnerabilities affecting the right SBOM, but not the left one
+    pub introduced_vulnerabilities: Vec<SbomStatus>,
+    /// Vulnerabilities affecting the left SBOM, but not the right one
+    pub fixed_vulnerabilities: Vec<SbomStatus>,
+}
+
+/// A package found in both SBOMs, with a different version.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct SbomPackageChange {
+    /// The package in the left SBOM
+    pub left: SbomPackage,
+    /// The package in the right SBOM
+    pub right: SbomPackage,
+}
+
+/// A relationship between two packages, identified independently of their versions.
+///
+/// Packages are identified by their purl (without a version), their CPE (vendor and product), or
+/// their name.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct SbomRelationship {
+    pub left: String,
+    pub relationship: Relationship,
+    pub right: String,
+}
+
+/// The change of the licenses of a package found in both SBOMs.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct SbomLicenseChange {
+    /// The package, identified independently of its version
+    pub package: String,
+    /// Licenses only found in the right SBOM
+    pub added: Vec<String>,
+    /// Licenses only found in the left SBOM
+    pub removed: Vec<String>,
+}
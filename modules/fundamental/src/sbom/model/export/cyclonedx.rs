@@ -0,0 +1,415 @@
+use super::{algorithm, dedup, timestamp, ExportPackage, SbomChecksum, SbomExport};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+use trustify_entity::relationship::Relationship;
+
+/// External reference types of CycloneDX 1.5.
+const REFERENCE_TYPES: &[&str] = &[
+    "vcs",
+    "issue-tracker",
+    "website",
+    "advisories",
+    "bom",
+    "mailing-list",
+    "social",
+    "chat",
+    "documentation",
+    "support",
+    "distribution",
+    "distribution-intake",
+    "license",
+    "build-meta",
+    "build-system",
+    "release-notes",
+    "security-contact",
+    "model-card",
+    "log",
+    "configuration",
+    "evidence",
+    "formulation",
+    "attestation",
+    "threat-model",
+    "adversary-model",
+    "risk-assessment",
+    "vulnerability-assertion",
+    "exploitability-statement",
+    "pentest-report",
+    "static-analysis-report",
+    "dynamic-analysis-report",
+    "runtime-analysis-report",
+    "component-analysis-report",
+    "maturity-report",
+    "certification-report",
+    "codified-infrastructure",
+    "quality-metrics",
+    "poam",
+    "other",
+];
+
+/// External reference types added by CycloneDX 1.6.
+const REFERENCE_TYPES_1_6: &[&str] = &[
+    "source-distribution",
+    "electronic-signature",
+    "digital-signature",
+    "rfc-9116",
+];
+
+/// Justifications of the CycloneDX impact analysis.
+const JUSTIFICATIONS: &[&str] = &[
+    "code_not_present",
+    "code_not_reachable",
+    "requires_configuration",
+    "requires_dependency",
+    "requires_environment",
+    "protected_by_compiler",
+    "protected_at_runtime",
+    "protected_at_perimeter",
+    "protected_by_mitigating_control",
+];
+
+/// Render an SBOM as a CycloneDX JSON document of the provided spec version.
+pub fn render(sbom: &SbomExport, version: &str) -> Value {
+    // the first package describing the document becomes the metadata component
+
+    let described = sbom.described();
+    let root = described.first().copied();
+
+    let mut metadata_component = None;
+    let mut components = Vec::new();
+    let mut refs = HashSet::new();
+
+    for package in &sbom.packages {
+        refs.insert(package.package.id.as_str());
+        let component = component(package, version);
+        if Some(package.package.id.as_str()) == root && metadata_component.is_none() {
+            metadata_component = Some(component);
+        } else {
+            components.push(component);
+        }
+    }
+
+    for file in &sbom.files {
+        refs.insert(file.id.as_str());
+        components.push(json!({
+            "type": "file",
+            "bom-ref": file.id,
+            "name": file.name,
+            "hashes": hashes(&file.checksums),
+        }));
+    }
+
+    let metadata_component = metadata_component.unwrap_or_else(|| {
+        json!({
+            "type": "application",
+            "name": sbom.head.name,
+        })
+    });
+
+    // dependencies, including containment, from the point of view of the dependent
+
+    let mut dependencies = BTreeMap::<&str, Vec<&str>>::new();
+    for (left, relationship, right) in &sbom.relationships {
+        if !is_dependency(*relationship)
+            || !refs.contains(left.as_str())
+            || !refs.contains(right.as_str())
+        {
+            continue;
+        }
+
+        let depends_on = dependencies.entry(right.as_str()).or_default();
+        if !depends_on.contains(&left.as_str()) {
+            depends_on.push(left.as_str());
+        }
+    }
+
+    let dependencies = dependencies
+        .into_iter()
+        .map(|(r#ref, depends_on)| {
+            json!({
+                "ref": r#ref,
+                "dependsOn": depends_on,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let serial_number = match &sbom.head.document_id {
+        Some(id) if id.starts_with("urn:uuid:") => id.clone(),
+        _ => format!("urn:uuid:{}", sbom.head.id),
+    };
+
+    let mut value = json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": version,
+        "serialNumber": serial_number,
+        "version": 1,
+        "metadata": {
+            "timestamp": sbom.created(),
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": "trustify",
+                }],
+            },
+            "authors": sbom
+                .head
+                .authors
+                .iter()
+                .map(|author| json!({ "name": author }))
+                .collect::<Vec<_>>(),
+            "component": metadata_component,
+        },
+        "components": components,
+        "dependencies": dependencies,
+    });
+
+    if !sbom.advisories.is_empty() {
+        value["vulnerabilities"] = json!(vulnerabilities(sbom, &refs));
+    }
+
+    value
+}
+
+fn component(export: &ExportPackage, version: &str) -> Value {
+    let package = &export.package;
+
+    let mut value = json!({
+        "type": "library",
+        "bom-ref": package.id,
+        "name": package.name,
+    });
+
+    if let Some(version) = &package.version {
+        value["version"] = json!(version);
+    }
+    if let Some(supplier) = &package.supplier {
+        value["supplier"] = json!({ "name": supplier });
+    }
+    if let Some(originator) = &package.originator {
+        value["author"] = json!(originator);
+    }
+    if let Some(copyright) = &package.copyright_text {
+        value["copyright"] = json!(copyright);
+    }
+    // CycloneDX supports a single purl and CPE only
+    if let Some(purl) = package.purl.first() {
+        value["purl"] = json!(purl.head.purl.to_string());
+    }
+    if let Some(cpe) = package.cpe.first() {
+        value["cpe"] = json!(cpe);
+    }
+
+    let hashes = hashes(&package.checksums);
+    if !hashes.is_empty() {
+        value["hashes"] = json!(hashes);
+    }
+
+    let licenses = licenses(export, version);
+    if !licenses.is_empty() {
+        value["licenses"] = json!(licenses);
+    }
+
+    let mut external_references = package
+        .external_references
+        .iter()
+        .map(|reference| {
+            let known = REFERENCE_TYPES.contains(&reference.r#type.as_str())
+                || (version != "1.5" && REFERENCE_TYPES_1_6.contains(&reference.r#type.as_str()));
+            if known {
+                json!({ "type": reference.r#type, "url": reference.url })
+            } else {
+                json!({ "type": "other", "url": reference.url, "comment": reference.r#type })
+            }
+        })
+        .collect::<Vec<_>>();
+    if let Some(location) = &package.download_location {
+        if !package
+            .external_references
+            .iter()
+            .any(|reference| reference.r#type == "distribution" && reference.url == *location)
+        {
+            external_references.push(json!({ "type": "distribution", "url": location }));
+        }
+    }
+    if !external_references.is_empty() {
+        value["externalReferences"] = json!(external_references);
+    }
+
+    if !package.properties.is_empty() {
+        value["properties"] = json!(package
+            .properties
+            .iter()
+            .map(|property| json!({ "name": property.name, "value": property.value }))
+            .collect::<Vec<_>>());
+    }
+
+    value
+}
+
+fn hashes(checksums: &[SbomChecksum]) -> Vec<Value> {
+    checksums
+        .iter()
+        .filter_map(|checksum| {
+            let (_, algorithm) = algorithm(checksum)?;
+            if algorithm.is_empty() {
+                return None;
+            }
+            Some(json!({
+                "alg": algorithm,
+                "content": checksum.value,
+            }))
+        })
+        .collect()
+}
+
+/// The licenses of a package.
+///
+/// CycloneDX allows either a list of licenses, or a single expression. Starting with 1.6,
+/// licenses can be marked as declared or concluded.
+fn licenses(export: &ExportPackage, version: &str) -> Vec<Value> {
+    let declared = dedup(&export.declared_licenses);
+    let concluded = dedup(&export.concluded_licenses)
+        .into_iter()
+        .filter(|license| !declared.contains(license))
+        .collect::<Vec<_>>();
+
+    let licenses = declared
+        .into_iter()
+        .map(|license| (license, "declared"))
+        .chain(concluded.into_iter().map(|license| (license, "concluded")))
+        .collect::<Vec<_>>();
+
+    let acknowledge = |mut value: Value, acknowledgement: &str| {
+        if version != "1.5" {
+            value["acknowledgement"] = json!(acknowledgement);
+        }
+        value
+    };
+
+    if let [(license, acknowledgement)] = licenses.as_slice() {
+        if spdx::license_id(license).is_none() && spdx::Expression::parse(license).is_ok() {
+            return vec![acknowledge(
+                json!({ "expression": license }),
+                acknowledgement,
+            )];
+        }
+    }
+
+    licenses
+        .into_iter()
+        .map(|(license, acknowledgement)| {
+            let license = match spdx::license_id(license) {
+                Some(id) => json!({ "id": id.name }),
+                None => json!({ "name": license }),
+            };
+            json!({ "license": acknowledge(license, acknowledgement) })
+        })
+        .collect()
+}
+
+/// If a relationship is rendered as a dependency.
+fn is_dependency(relationship: Relationship) -> bool {
+    matches!(
+        relationship,
+        Relationship::ContainedBy
+            | Relationship::DependencyOf
+            | Relationship::DevDependencyOf
+            | Relationship::OptionalDependencyOf
+            | Relationship::ProvidedDependencyOf
+            | Relationship::TestDependencyOf
+            | Relationship::RuntimeDependencyOf
+    )
+}
+
+/// The vulnerabilities affecting the SBOM, one per vulnerability and advisory.
+fn vulnerabilities(sbom: &SbomExport, refs: &HashSet<&str>) -> Vec<Value> {
+    let mut result = Vec::new();
+
+    for advisory in &sbom.advisories {
+        let source = advisory
+            .head
+            .issuer
+            .as_ref()
+            .map(|issuer| issuer.head.name.clone())
+            .unwrap_or_else(|| advisory.head.identifier.clone());
+
+        for status in &advisory.status {
+            let vulnerability = &status.vulnerability;
+
+            let mut value = json!({
+                "id": vulnerability.identifier,
+                "source": { "name": source },
+                "ratings": [{
+                    "source": { "name": source },
+                    "severity": status.average_severity.as_str(),
+                }],
+                "affects": status
+                    .packages
+                    .iter()
+                    .filter(|package| refs.contains(package.id.as_str()))
+                    .map(|package| json!({ "ref": package.id }))
+                    .collect::<Vec<_>>(),
+            });
+
+            if let Some(description) = &vulnerability.description {
+                value["description"] = json!(description);
+            }
+            if let Some(published) = vulnerability.published {
+                value["published"] = json!(timestamp(published));
+            }
+            if let Some(recommendation) = &status.information.action_statement {
+                value["recommendation"] = json!(recommendation);
+            }
+
+            let mut analysis = serde_json::Map::new();
+            if let Some(state) = analysis_state(&status.status) {
+                analysis.insert("state".into(), json!(state));
+            }
+            if let Some(justification) = status
+                .information
+                .justification
+                .as_deref()
+                .filter(|justification| JUSTIFICATIONS.contains(justification))
+            {
+                analysis.insert("justification".into(), json!(justification));
+            }
+            if let Some(detail) = &status.information.impact_statement {
+                analysis.insert("detail".into(), json!(detail));
+            }
+            if !analysis.is_empty() {
+                value["analysis"] = Value::Object(analysis);
+            }
+
+            result.push(value);
+        }
+    }
+
+    result
+}
+
+/// The CycloneDX analysis state of a status.
+fn analysis_state(status: &str) -> Option<&'static str> {
+    Some(match status {
+        "affected" | "first_affected" | "last_affected" => "exploitable",
+        "not_affected" => "not_affected",
+        "fixed" | "first_fixed" | "recommended" => "resolved",
+        "under_investigation" => "in_triage",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Ensure the range statuses of CSAF map to the state of their plain counterpart.
+    #[test]
+    fn analysis_state_of_ranges() {
+        for status in ["affected", "first_affected", "last_affected"] {
+            assert_eq!(analysis_state(status), Some("exploitable"), "{status}");
+        }
+        for status in ["fixed", "first_fixed", "recommended"] {
+            assert_eq!(analysis_state(status), Some("resolved"), "{status}");
+        }
+        assert_eq!(analysis_state("unknown"), None);
+    }
+}
@@ -0,0 +1,139 @@
+mod cyclonedx;
+mod spdx;
+
+use super::{details::SbomAdvisory, SbomChecksum, SbomHead, SbomPackage};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
+use trustify_entity::relationship::Relationship;
+use utoipa::ToSchema;
+
+/// The format to export an SBOM to.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Default, ToSchema)]
+pub enum ExportFormat {
+    #[default]
+    #[serde(rename = "spdx-2.3")]
+    Spdx2_3,
+    #[serde(rename = "cyclonedx-1.5")]
+    CycloneDx1_5,
+    #[serde(rename = "cyclonedx-1.6")]
+    CycloneDx1_6,
+}
+
+impl ExportFormat {
+    /// If the format is a version of CycloneDX.
+    pub fn is_cyclonedx(&self) -> bool {
+        matches!(self, Self::CycloneDx1_5 | Self::CycloneDx1_6)
+    }
+}
+
+/// What to include, in addition to the content of the SBOM, when exporting it.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ExportOptions {
+    /// Include the vulnerabilities affecting the SBOM
+    pub vulnerabilities: bool,
+    /// Include license assertions made by other documents, e.g. ClearlyDefined curations
+    pub curated_licenses: bool,
+}
+
+/// Everything known about an SBOM, collected to regenerate it as a document.
+#[derive(Debug, Clone)]
+pub struct SbomExport {
+    pub head: SbomHead,
+    /// The node ID of the document itself
+    pub node_id: String,
+    pub packages: Vec<ExportPackage>,
+    pub files: Vec<ExportFile>,
+    pub relationships: Vec<(String, Relationship, String)>,
+    /// Advisories affecting the SBOM, only present if requested
+    pub advisories: Vec<SbomAdvisory>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportPackage {
+    pub package: SbomPackage,
+    /// Licenses, as asserted by the SBOM itself
+    pub declared_licenses: Vec<String>,
+    /// Licenses, as asserted by other documents after ingestion, e.g. ClearlyDefined curations
+    pub concluded_licenses: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportFile {
+    pub id: String,
+    pub name: String,
+    pub checksums: Vec<SbomChecksum>,
+}
+
+impl SbomExport {
+    /// Render the SBOM as a document of the requested format.
+    pub fn render(&self, format: ExportFormat) -> Value {
+        match format {
+            ExportFormat::Spdx2_3 => spdx::render(self),
+            ExportFormat::CycloneDx1_5 => cyclonedx::render(self, "1.5"),
+            ExportFormat::CycloneDx1_6 => cyclonedx::render(self, "1.6"),
+        }
+    }
+
+    /// The IDs of the packages describing the document.
+    fn described(&self) -> Vec<&str> {
+        self.relationships
+            .iter()
+            .filter(|(_, relationship, right)| {
+                *relationship == Relationship::DescribedBy && *right == self.node_id
+            })
+            .map(|(left, _, _)| left.as_str())
+            .collect()
+    }
+
+    /// The creation timestamp of the document, falling back to the current time.
+    fn created(&self) -> String {
+        timestamp(self.head.published.unwrap_or_else(OffsetDateTime::now_utc))
+    }
+}
+
+/// Hash algorithms, by their normalized name, with their SPDX and CycloneDX names.
+const ALGORITHMS: &[(&str, &str, &str)] = &[
+    ("md5", "MD5", "MD5"),
+    ("sha1", "SHA1", "SHA-1"),
+    ("sha224", "SHA224", ""),
+    ("sha256", "SHA256", "SHA-256"),
+    ("sha384", "SHA384", "SHA-384"),
+    ("sha512", "SHA512", "SHA-512"),
+    ("sha3256", "SHA3-256", "SHA3-256"),
+    ("sha3384", "SHA3-384", "SHA3-384"),
+    ("sha3512", "SHA3-512", "SHA3-512"),
+    ("blake2b256", "BLAKE2b-256", "BLAKE2b-256"),
+    ("blake2b384", "BLAKE2b-384", "BLAKE2b-384"),
+    ("blake2b512", "BLAKE2b-512", "BLAKE2b-512"),
+    ("blake3", "BLAKE3", "BLAKE3"),
+];
+
+/// Look up an algorithm, returning its SPDX and CycloneDX names.
+fn algorithm(checksum: &SbomChecksum) -> Option<(&'static str, &'static str)> {
+    ALGORITHMS
+        .iter()
+        .find(|(name, _, _)| *name == checksum.r#type)
+        .map(|(_, spdx, cyclonedx)| (*spdx, *cyclonedx))
+}
+
+/// Format a timestamp as UTC, with seconds precision (e.g. `2024-01-01T00:00:00Z`).
+fn timestamp(value: OffsetDateTime) -> String {
+    value
+        .to_offset(UtcOffset::UTC)
+        .replace_nanosecond(0)
+        .ok()
+        .and_then(|value| value.format(&Rfc3339).ok())
+        .unwrap_or_default()
+}
+
+/// Remove duplicates, keeping the first occurrence.
+fn dedup(values: &[String]) -> Vec<&str> {
+    let mut seen = HashSet::new();
+    values
+        .iter()
+        .filter(|value| seen.insert(value.as_str()))
+        .map(String::as_str)
+        .collect()
+}
@@ -0,0 +1,309 @@
+use super::{algorithm, dedup, SbomChecksum, SbomExport};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use trustify_entity::relationship::Relationship;
+
+const DOCUMENT_ID: &str = "SPDXRef-DOCUMENT";
+const NOASSERTION: &str = "NOASSERTION";
+
+/// Render an SBOM as an SPDX 2.3 JSON document.
+pub fn render(sbom: &SbomExport) -> Value {
+    let ids = SpdxIds::new(sbom);
+    let mut licenses = ExtractedLicenses::default();
+
+    let packages = sbom
+        .packages
+        .iter()
+        .map(|export| {
+            let package = &export.package;
+
+            let mut external_refs = Vec::new();
+            for purl in &package.purl {
+                external_refs.push(json!({
+                    "referenceCategory": "PACKAGE-MANAGER",
+                    "referenceType": "purl",
+                    "referenceLocator": purl.head.purl.to_string(),
+                }));
+            }
+            for cpe in &package.cpe {
+                let r#type = if cpe.starts_with("cpe:2.3:") {
+                    "cpe23Type"
+                } else {
+                    "cpe22Type"
+                };
+                external_refs.push(json!({
+                    "referenceCategory": "SECURITY",
+                    "referenceType": r#type,
+                    "referenceLocator": cpe,
+                }));
+            }
+            for reference in &package.external_references {
+                external_refs.push(json!({
+                    "referenceCategory": "OTHER",
+                    "referenceType": reference.r#type,
+                    "referenceLocator": reference.url,
+                }));
+            }
+
+            let mut value = json!({
+                "SPDXID": ids.get(&package.id),
+                "name": package.name,
+                "downloadLocation": package.download_location.as_deref().unwrap_or(NOASSERTION),
+                "filesAnalyzed": false,
+                "licenseDeclared": licenses.expression(&export.declared_licenses),
+                "licenseConcluded": licenses.expression(&export.concluded_licenses),
+                "copyrightText": package.copyright_text.as_deref().unwrap_or(NOASSERTION),
+                "checksums": checksums(&package.checksums),
+                "externalRefs": external_refs,
+            });
+
+            if let Some(version) = &package.version {
+                value["versionInfo"] = json!(version);
+            }
+            if let Some(supplier) = &package.supplier {
+                value["supplier"] = json!(actor(supplier, "Organization"));
+            }
+            if let Some(originator) = &package.originator {
+                value["originator"] = json!(actor(originator, "Person"));
+            }
+
+            value
+        })
+        .collect::<Vec<_>>();
+
+    let files = sbom
+        .files
+        .iter()
+        .map(|file| {
+            json!({
+                "SPDXID": ids.get(&file.id),
+                "fileName": file.name,
+                "checksums": checksums(&file.checksums),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let relationships = sbom
+        .relationships
+        .iter()
+        .filter_map(|(left, relationship, right)| {
+            let left = ids.find(left)?;
+            let right = ids.find(right)?;
+
+            // SPDX documents describe their packages
+            Some(match relationship {
+                Relationship::DescribedBy if right == DOCUMENT_ID => json!({
+                    "spdxElementId": right,
+                    "relationshipType": "DESCRIBES",
+                    "relatedSpdxElement": left,
+                }),
+                relationship => json!({
+                    "spdxElementId": left,
+                    "relationshipType": relationship_type(*relationship),
+                    "relatedSpdxElement": right,
+                }),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut creators = sbom
+        .head
+        .authors
+        .iter()
+        .map(|author| actor(author, "Person"))
+        .collect::<Vec<_>>();
+    creators.push("Tool: trustify".to_string());
+
+    json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": DOCUMENT_ID,
+        "name": sbom.head.name,
+        "documentNamespace": namespace(sbom),
+        "creationInfo": {
+            "created": sbom.created(),
+            "creators": creators,
+        },
+        "documentDescribes": sbom
+            .described()
+            .into_iter()
+            .filter_map(|id| ids.find(id))
+            .collect::<Vec<_>>(),
+        "packages": packages,
+        "files": files,
+        "relationships": relationships,
+        "hasExtractedLicensingInfos": licenses.into_infos(),
+    })
+}
+
+/// The namespace of the document, which must be a URI.
+fn namespace(sbom: &SbomExport) -> String {
+    match &sbom.head.document_id {
+        Some(id) if id.contains("://") && !id.contains('#') => id.clone(),
+        _ => format!("urn:uuid:{}", sbom.head.id),
+    }
+}
+
+/// An SPDX actor, e.g. `Organization: Red Hat`, adding the provided type if it is missing.
+fn actor(value: &str, r#type: &str) -> String {
+    if ["Person:", "Organization:", "Tool:"]
+        .iter()
+        .any(|prefix| value.starts_with(prefix))
+    {
+        value.to_string()
+    } else {
+        format!("{type}: {value}")
+    }
+}
+
+fn checksums(checksums: &[SbomChecksum]) -> Vec<Value> {
+    checksums
+        .iter()
+        .filter_map(|checksum| {
+            let (algorithm, _) = algorithm(checksum)?;
+            Some(json!({
+                "algorithm": algorithm,
+                "checksumValue": checksum.value,
+            }))
+        })
+        .collect()
+}
+
+fn relationship_type(relationship: Relationship) -> &'static str {
+    match relationship {
+        Relationship::ContainedBy => "CONTAINED_BY",
+        Relationship::DependencyOf => "DEPENDENCY_OF",
+        Relationship::DevDependencyOf => "DEV_DEPENDENCY_OF",
+        Relationship::OptionalDependencyOf => "OPTIONAL_DEPENDENCY_OF",
+        Relationship::ProvidedDependencyOf => "PROVIDED_DEPENDENCY_OF",
+        Relationship::TestDependencyOf => "TEST_DEPENDENCY_OF",
+        Relationship::RuntimeDependencyOf => "RUNTIME_DEPENDENCY_OF",
+        Relationship::ExampleOf => "EXAMPLE_OF",
+        Relationship::GeneratedFrom => "GENERATED_FROM",
+        Relationship::AncestorOf => "ANCESTOR_OF",
+        Relationship::VariantOf => "VARIANT_OF",
+        Relationship::BuildToolOf => "BUILD_TOOL_OF",
+        Relationship::DevToolOf => "DEV_TOOL_OF",
+        Relationship::DescribedBy => "DESCRIBED_BY",
+        Relationship::PackageOf => "PACKAGE_OF",
+        Relationship::Undefined => "OTHER",
+    }
+}
+
+/// SPDX identifiers of the nodes of an SBOM.
+///
+/// Node IDs of SPDX documents are kept, others (e.g. CycloneDX `bom-ref`s) are turned into
+/// valid, unique SPDX identifiers.
+struct SpdxIds {
+    ids: HashMap<String, String>,
+}
+
+impl SpdxIds {
+    fn new(sbom: &SbomExport) -> Self {
+        let mut ids = HashMap::new();
+        let mut used = HashSet::new();
+
+        ids.insert(sbom.node_id.clone(), DOCUMENT_ID.to_string());
+        used.insert(DOCUMENT_ID.to_string());
+
+        let nodes = sbom
+            .packages
+            .iter()
+            .map(|export| &export.package.id)
+            .chain(sbom.files.iter().map(|file| &file.id));
+
+        for node in nodes {
+            if ids.contains_key(node) {
+                continue;
+            }
+
+            let sanitized = node
+                .strip_prefix("SPDXRef-")
+                .unwrap_or(node)
+                .chars()
+                .map(|c| match c {
+                    'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' => c,
+                    _ => '-',
+                })
+                .collect::<String>();
+
+            let mut id = format!("SPDXRef-{sanitized}");
+            let mut n = 1;
+            while used.contains(&id) {
+                id = format!("SPDXRef-{sanitized}-{n}");
+                n += 1;
+            }
+
+            used.insert(id.clone());
+            ids.insert(node.clone(), id);
+        }
+
+        Self { ids }
+    }
+
+    fn find(&self, node_id: &str) -> Option<&str> {
+        self.ids.get(node_id).map(String::as_str)
+    }
+
+    fn get(&self, node_id: &str) -> &str {
+        self.find(node_id).unwrap_or(NOASSERTION)
+    }
+}
+
+/// Licenses which are not valid SPDX expressions, referenced as `LicenseRef-*`.
+#[derive(Default)]
+struct ExtractedLicenses {
+    /// License references, by license text
+    refs: BTreeMap<String, String>,
+}
+
+impl ExtractedLicenses {
+    /// Combine licenses into a single SPDX expression.
+    fn expression(&mut self, licenses: &[String]) -> String {
+        let licenses = dedup(licenses)
+            .into_iter()
+            .map(|license| self.reference(license))
+            .collect::<Vec<_>>();
+
+        match licenses.as_slice() {
+            [] => NOASSERTION.to_string(),
+            [license] => license.clone(),
+            licenses => licenses
+                .iter()
+                .map(|license| format!("({license})"))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        }
+    }
+
+    /// The license itself if it is a valid expression, a reference to it otherwise.
+    ///
+    /// References of the original document can't be resolved, so they are extracted as well.
+    fn reference(&mut self, license: &str) -> String {
+        if spdx::Expression::parse(license).is_ok()
+            && !license.contains("LicenseRef-")
+            && !license.contains("DocumentRef-")
+        {
+            return license.to_string();
+        }
+
+        let next = self.refs.len() + 1;
+        self.refs
+            .entry(license.to_string())
+            .or_insert_with(|| format!("LicenseRef-{next}"))
+            .clone()
+    }
+
+    fn into_infos(self) -> Vec<Value> {
+        self.refs
+            .into_iter()
+            .map(|(text, id)| {
+                json!({
+                    "licenseId": id,
+                    "name": text,
+                    "extractedText": text,
+                })
+            })
+            .collect()
+    }
+}
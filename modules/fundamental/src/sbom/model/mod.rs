@@ -1,4 +1,6 @@
 pub mod details;
+pub mod diff;
+pub mod export;
 
 use super::service::SbomService;
 use crate::{
@@ -107,6 +109,43 @@ pub struct SbomPackage {
     #[graphql(skip)]
     pub purl: Vec<PurlSummary>,
     pub cpe: Vec<String>,
+    /// The supplier of the package, e.g. `Organization: Red Hat`
+    pub supplier: Option<String>,
+    /// The originator, or author, of the package
+    pub originator: Option<String>,
+    /// The location the package can be downloaded from
+    pub download_location: Option<String>,
+    pub copyright_text: Option<String>,
+    #[graphql(skip)]
+    pub checksums: Vec<SbomChecksum>,
+    /// External references, other than purls and CPEs
+    #[graphql(skip)]
+    pub external_references: Vec<SbomExternalReference>,
+    #[graphql(skip)]
+    pub properties: Vec<SbomProperty>,
+}
+
+/// A checksum of a package or file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct SbomChecksum {
+    /// The algorithm, normalized to lowercase without separators, e.g. `sha256`
+    pub r#type: String,
+    /// The lowercase, hex encoded value
+    pub value: String,
+}
+
+/// An external reference of a package, e.g. its website or VCS.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct SbomExternalReference {
+    pub r#type: String,
+    pub url: String,
+}
+
+/// A name/value property of a package.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct SbomProperty {
+    pub name: String,
+    pub value: String,
 }
 
 // TODO: think about a way to add CPE and PURLs too
@@ -0,0 +1,316 @@
+use super::SbomService;
+use crate::{
+    sbom::model::{
+        details::{SbomDetails, SbomStatus},
+        diff::{SbomDiff, SbomLicenseChange, SbomPackageChange, SbomRelationship},
+        SbomPackage,
+    },
+    Error,
+};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect, RelationTrait};
+use sea_query::JoinType;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    str::FromStr,
+};
+use tracing::instrument;
+use trustify_common::{cpe::Cpe, db::query::Query, id::Id, model::Paginated};
+use trustify_entity::{
+    license, package_relates_to_package, purl_license_assertion, sbom_node, status::AFFECTED,
+};
+use uuid::Uuid;
+
+/// The state of an SBOM, prepared for comparing it with another one.
+struct Snapshot {
+    details: SbomDetails,
+    /// Packages, by their key
+    packages: BTreeMap<String, Vec<SbomPackage>>,
+    /// Licenses, by package key
+    licenses: BTreeMap<String, BTreeSet<String>>,
+    /// Relationships, by left key, relationship, and right key
+    relationships: BTreeMap<(String, String, String), SbomRelationship>,
+}
+
+impl SbomService {
+    /// Compare two SBOMs.
+    ///
+    /// Returns `None` if one of the SBOMs could not be found.
+    #[instrument(skip(self, connection), err(level=tracing::Level::INFO))]
+    pub async fn diff_sboms<C: ConnectionTrait>(
+        &self,
+        left: Id,
+        right: Id,
+        connection: &C,
+    ) -> Result<Option<SbomDiff>, Error> {
+        let Some(left) = self.snapshot(left, connection).await? else {
+            return Ok(None);
+        };
+        let Some(right) = self.snapshot(right, connection).await? else {
+            return Ok(None);
+        };
+
+        // packages
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut upgraded = Vec::new();
+        let mut downgraded = Vec::new();
+
+        for (key, left_packages) in &left.packages {
+            let Some(right_packages) = right.packages.get(key) else {
+                removed.extend(left_packages.iter().cloned());
+                continue;
+            };
+
+            let only_left = only_in(left_packages, right_packages);
+            let only_right = only_in(right_packages, left_packages);
+
+            match (only_left.as_slice(), only_right.as_slice()) {
+                ([left], [right]) => {
+                    let change = SbomPackageChange {
+                        left: (*left).clone(),
+                        right: (*right).clone(),
+                    };
+                    match compare_versions(left.version.as_deref(), right.version.as_deref()) {
+                        Ordering::Greater => downgraded.push(change),
+                        _ => upgraded.push(change),
+                    }
+                }
+                _ => {
+                    removed.extend(only_left.into_iter().cloned());
+                    added.extend(only_right.into_iter().cloned());
+                }
+            }
+        }
+
+        for (key, right_packages) in &right.packages {
+            if !left.packages.contains_key(key) {
+                added.extend(right_packages.iter().cloned());
+            }
+        }
+
+        // relationships
+
+        let added_relationships = right
+            .relationships
+            .iter()
+            .filter(|(key, _)| !left.relationships.contains_key(*key))
+            .map(|(_, relationship)| relationship.clone())
+            .collect();
+        let removed_relationships = left
+            .relationships
+            .iter()
+            .filter(|(key, _)| !right.relationships.contains_key(*key))
+            .map(|(_, relationship)| relationship.clone())
+            .collect();
+
+        // licenses, of packages found in both SBOMs
+
+        let mut license_changes = Vec::new();
+        for (package, left_licenses) in &left.licenses {
+            let Some(right_licenses) = right.licenses.get(package) else {
+                continue;
+            };
+
+            if left_licenses != right_licenses {
+                license_changes.push(SbomLicenseChange {
+                    package: package.clone(),
+                    added: right_licenses.difference(left_licenses).cloned().collect(),
+                    removed: left_licenses.difference(right_licenses).cloned().collect(),
+                });
+            }
+        }
+
+        // vulnerabilities
+
+        let left_vulnerabilities = affected_by(&left.details);
+        let right_vulnerabilities = affected_by(&right.details);
+
+        let introduced_vulnerabilities = right_vulnerabilities
+            .iter()
+            .filter(|(id, _)| !left_vulnerabilities.contains_key(*id))
+            .map(|(_, status)| status.clone())
+            .collect();
+        let fixed_vulnerabilities = left_vulnerabilities
+            .iter()
+            .filter(|(id, _)| !right_vulnerabilities.contains_key(*id))
+            .map(|(_, status)| status.clone())
+            .collect();
+
+        Ok(Some(SbomDiff {
+            left: left.details.summary.head,
+            right: right.details.summary.head,
+            added,
+            removed,
+            upgraded,
+            downgraded,
+            added_relationships,
+            removed_relationships,
+            license_changes,
+            introduced_vulnerabilities,
+            fixed_vulnerabilities,
+        }))
+    }
+
+    async fn snapshot<C: ConnectionTrait>(
+        &self,
+        id: Id,
+        connection: &C,
+    ) -> Result<Option<Snapshot>, Error> {
+        let Some(details) = self.fetch_sbom_details(id, connection).await? else {
+            return Ok(None);
+        };
+        let sbom_id = details.summary.head.id;
+
+        let packages = self
+            .fetch_sbom_packages(sbom_id, Query::default(), Paginated::default(), connection)
+            .await?
+            .items;
+
+        // licenses, by versioned purl
+
+        let mut purl_licenses = HashMap::<_, Vec<_>>::new();
+        for (id, text) in purl_license_assertion::Entity::find()
+            .join(
+                JoinType::Join,
+                purl_license_assertion::Relation::License.def(),
+            )
+            .filter(purl_license_assertion::Column::SbomId.eq(sbom_id))
+            .select_only()
+            .column(purl_license_assertion::Column::VersionedPurlId)
+            .column(license::Column::Text)
+            .into_tuple::<(Uuid, String)>()
+            .all(connection)
+            .await?
+        {
+            purl_licenses.entry(id).or_default().push(text);
+        }
+
+        // keys of all nodes, using the name for nodes which are not packages
+
+        let mut nodes = sbom_node::Entity::find()
+            .filter(sbom_node::Column::SbomId.eq(sbom_id))
+            .select_only()
+            .column(sbom_node::Column::NodeId)
+            .column(sbom_node::Column::Name)
+            .into_tuple::<(String, String)>()
+            .all(connection)
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let mut by_key = BTreeMap::<_, Vec<_>>::new();
+        let mut licenses = BTreeMap::<_, BTreeSet<_>>::new();
+
+        for package in packages {
+            let key = package_key(&package);
+            nodes.insert(package.id.clone(), key.clone());
+
+            let package_licenses = licenses.entry(key.clone()).or_default();
+            for purl in &package.purl {
+                package_licenses.extend(
+                    purl_licenses
+                        .get(&purl.version.uuid)
+                        .into_iter()
+                        .flatten()
+                        .cloned(),
+                );
+            }
+
+            by_key.entry(key).or_default().push(package);
+        }
+
+        let relationships = package_relates_to_package::Entity::find()
+            .filter(package_relates_to_package::Column::SbomId.eq(sbom_id))
+            .all(connection)
+            .await?
+            .into_iter()
+            .filter_map(|row| {
+                let left = nodes.get(&row.left_node_id)?.clone();
+                let right = nodes.get(&row.right_node_id)?.clone();
+                Some((
+                    (left.clone(), row.relationship.to_string(), right.clone()),
+                    SbomRelationship {
+                        left,
+                        relationship: row.relationship,
+                        right,
+                    },
+                ))
+            })
+            .collect();
+
+        Ok(Some(Snapshot {
+            details,
+            packages: by_key,
+            licenses,
+            relationships,
+        }))
+    }
+}
+
+/// The key identifying a package across SBOMs, independent of its version.
+///
+/// This is the purl without a version, the vendor and product of the CPE, or the name of the
+/// package, whichever is available first.
+fn package_key(package: &SbomPackage) -> String {
+    if let Some(purl) = package.purl.first() {
+        return purl.base.purl.to_string();
+    }
+
+    if let Some(cpe) = package.cpe.iter().find_map(|cpe| Cpe::from_str(cpe).ok()) {
+        return format!("cpe:/{}:{}", cpe.vendor().as_ref(), cpe.product().as_ref());
+    }
+
+    package.name.clone()
+}
+
+/// The packages of `packages` which have no version in `other`.
+fn only_in<'a>(packages: &'a [SbomPackage], other: &[SbomPackage]) -> Vec<&'a SbomPackage> {
+    packages
+        .iter()
+        .filter(|package| !other.iter().any(|other| other.version == package.version))
+        .collect()
+}
+
+/// Compare two versions, as semantic versions if possible, and as plain strings otherwise.
+fn compare_versions(left: Option<&str>, right: Option<&str>) -> Ordering {
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            match (lenient_semver::parse(left), lenient_semver::parse(right)) {
+                (Ok(left), Ok(right)) => left.cmp(&right),
+                _ => left.cmp(right),
+            }
+        }
+        (left, right) => left.cmp(right),
+    }
+}
+
+/// The vulnerabilities affecting an SBOM, by their identifier.
+///
+/// Statuses of the same vulnerability from different advisories are merged.
+fn affected_by(details: &SbomDetails) -> BTreeMap<String, SbomStatus> {
+    let mut result = BTreeMap::<String, SbomStatus>::new();
+
+    for status in details
+        .advisories
+        .iter()
+        .flat_map(|advisory| &advisory.status)
+        .filter(|status| AFFECTED.contains(&status.status.as_str()))
+    {
+        match result.get_mut(status.identifier()) {
+            Some(existing) => {
+                for package in &status.packages {
+                    if !existing.packages.contains(package) {
+                        existing.packages.push(package.clone());
+                    }
+                }
+            }
+            None => {
+                result.insert(status.identifier().to_string(), status.clone());
+            }
+        }
+    }
+
+    result
+}
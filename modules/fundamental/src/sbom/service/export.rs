@@ -0,0 +1,237 @@
+use super::SbomService;
+use crate::{
+    sbom::model::{
+        export::{ExportFile, ExportOptions, ExportPackage, SbomExport},
+        SbomChecksum,
+    },
+    Error,
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect, QueryTrait, RelationTrait,
+    Select,
+};
+use sea_query::JoinType;
+use std::{collections::HashMap, str::FromStr};
+use tracing::instrument;
+use trustify_common::{cpe::Cpe, db::query::Query, id::Id, model::Paginated};
+use trustify_entity::{
+    cpe_license_assertion, license, package_relates_to_package, purl_license_assertion,
+    qualified_purl, sbom, sbom_file, sbom_node, sbom_node_checksum, sbom_package_purl_ref,
+};
+use uuid::Uuid;
+
+impl SbomService {
+    /// Collect everything known about an SBOM, for regenerating it as a document.
+    ///
+    /// Returns `None` if the SBOM could not be found.
+    #[instrument(skip(self, connection), err(level=tracing::Level::INFO))]
+    pub async fn export_sbom<C: ConnectionTrait>(
+        &self,
+        id: Id,
+        options: ExportOptions,
+        connection: &C,
+    ) -> Result<Option<SbomExport>, Error> {
+        let (head, advisories) = if options.vulnerabilities {
+            let Some(details) = self.fetch_sbom_details(id, connection).await? else {
+                return Ok(None);
+            };
+            (details.summary.head, details.advisories)
+        } else {
+            let Some(summary) = self.fetch_sbom_summary(id, connection).await? else {
+                return Ok(None);
+            };
+            (summary.head, vec![])
+        };
+        let sbom_id = head.id;
+
+        let Some(sbom) = sbom::Entity::find_by_id(sbom_id).one(connection).await? else {
+            return Ok(None);
+        };
+
+        let packages = self
+            .fetch_sbom_packages(sbom_id, Query::default(), Paginated::default(), connection)
+            .await?
+            .items;
+
+        // licenses asserted by the SBOM, by versioned purl and CPE
+
+        let declared_purl_licenses = purl_licenses(
+            purl_license_assertion::Entity::find()
+                .filter(purl_license_assertion::Column::SbomId.eq(sbom_id)),
+            connection,
+        )
+        .await?;
+        let declared_cpe_licenses = cpe_licenses(sbom_id, connection).await?;
+
+        // licenses asserted by other documents, for the versioned purls of the SBOM
+
+        let curated_purl_licenses = if options.curated_licenses {
+            let versioned_purls = sbom_package_purl_ref::Entity::find()
+                .join(JoinType::Join, sbom_package_purl_ref::Relation::Purl.def())
+                .filter(sbom_package_purl_ref::Column::SbomId.eq(sbom_id))
+                .select_only()
+                .column(qualified_purl::Column::VersionedPurlId)
+                .into_query();
+
+            purl_licenses(
+                purl_license_assertion::Entity::find()
+                    .filter(purl_license_assertion::Column::SbomId.ne(sbom_id))
+                    .filter(
+                        purl_license_assertion::Column::VersionedPurlId
+                            .in_subquery(versioned_purls),
+                    ),
+                connection,
+            )
+            .await?
+        } else {
+            HashMap::new()
+        };
+
+        let packages = packages
+            .into_iter()
+            .map(|package| {
+                let mut declared_licenses = Vec::new();
+                let mut concluded_licenses = Vec::new();
+
+                for purl in &package.purl {
+                    let id = purl.version.uuid;
+                    declared_licenses.extend(declared_purl_licenses.get(&id).into_iter().flatten());
+                    concluded_licenses.extend(curated_purl_licenses.get(&id).into_iter().flatten());
+                }
+                for cpe in package.cpe.iter().filter_map(|cpe| Cpe::from_str(cpe).ok()) {
+                    declared_licenses
+                        .extend(declared_cpe_licenses.get(&cpe.uuid()).into_iter().flatten());
+                }
+
+                ExportPackage {
+                    package,
+                    declared_licenses: declared_licenses.into_iter().cloned().collect(),
+                    concluded_licenses: concluded_licenses.into_iter().cloned().collect(),
+                }
+            })
+            .collect();
+
+        // files, with their checksums
+
+        let mut names = sbom_node::Entity::find()
+            .filter(sbom_node::Column::SbomId.eq(sbom_id))
+            .select_only()
+            .column(sbom_node::Column::NodeId)
+            .column(sbom_node::Column::Name)
+            .into_tuple::<(String, String)>()
+            .all(connection)
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let mut checksums = HashMap::<_, Vec<_>>::new();
+        for checksum in sbom_node_checksum::Entity::find()
+            .filter(sbom_node_checksum::Column::SbomId.eq(sbom_id))
+            .all(connection)
+            .await?
+        {
+            checksums
+                .entry(checksum.node_id)
+                .or_default()
+                .push(SbomChecksum {
+                    r#type: checksum.r#type,
+                    value: checksum.value,
+                });
+        }
+
+        let files = sbom_file::Entity::find()
+            .filter(sbom_file::Column::SbomId.eq(sbom_id))
+            .all(connection)
+            .await?
+            .into_iter()
+            .map(|file| ExportFile {
+                name: names.remove(&file.node_id).unwrap_or_default(),
+                checksums: checksums.remove(&file.node_id).unwrap_or_default(),
+                id: file.node_id,
+            })
+            .collect();
+
+        let relationships = package_relates_to_package::Entity::find()
+            .filter(package_relates_to_package::Column::SbomId.eq(sbom_id))
+            .all(connection)
+            .await?
+            .into_iter()
+            .map(|row| (row.left_node_id, row.relationship, row.right_node_id))
+            .collect();
+
+        Ok(Some(SbomExport {
+            head,
+            node_id: sbom.node_id,
+            packages,
+            files,
+            relationships,
+            advisories,
+        }))
+    }
+}
+
+/// The license texts of purl license assertions, by versioned purl.
+async fn purl_licenses<C: ConnectionTrait>(
+    assertions: Select<purl_license_assertion::Entity>,
+    connection: &C,
+) -> Result<HashMap<Uuid, Vec<String>>, Error> {
+    let mut result = HashMap::<_, Vec<_>>::new();
+
+    for (id, text) in assertions
+        .join(
+            JoinType::Join,
+            purl_license_assertion::Relation::License.def(),
+        )
+        .select_only()
+        .column(purl_license_assertion::Column::VersionedPurlId)
+        .column(license::Column::Text)
+        .into_tuple::<(Uuid, String)>()
+        .all(connection)
+        .await?
+    {
+        result.entry(id).or_default().push(text);
+    }
+
+    Ok(result)
+}
+
+/// The license texts of the CPE license assertions of an SBOM, by CPE.
+async fn cpe_licenses<C: ConnectionTrait>(
+    sbom_id: Uuid,
+    connection: &C,
+) -> Result<HashMap<Uuid, Vec<String>>, Error> {
+    let assertions = cpe_license_assertion::Entity::find()
+        .filter(cpe_license_assertion::Column::SbomId.eq(sbom_id))
+        .all(connection)
+        .await?;
+    if assertions.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let texts = license::Entity::find()
+        .filter(
+            license::Column::Id.is_in(
+                assertions
+                    .iter()
+                    .map(|assertion| assertion.license_id)
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .all(connection)
+        .await?
+        .into_iter()
+        .map(|license| (license.id, license.text))
+        .collect::<HashMap<_, _>>();
+
+    let mut result = HashMap::<_, Vec<_>>::new();
+    for assertion in assertions {
+        if let Some(text) = texts.get(&assertion.license_id) {
+            result
+                .entry(assertion.cpe_id)
+                .or_default()
+                .push(text.clone());
+        }
+    }
+
+    Ok(result)
+}
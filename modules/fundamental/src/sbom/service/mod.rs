@@ -1,4 +1,6 @@
 pub mod assertion;
+pub mod diff;
+pub mod export;
 pub mod label;
 pub mod sbom;
 
@@ -1,17 +1,18 @@
 use super::SbomService;
 use crate::{
+    advisory::model::StatusInformation,
     purl::model::summary::purl::PurlSummary,
     sbom::model::{
-        details::SbomDetails, SbomPackage, SbomPackageReference, SbomPackageRelation, SbomSummary,
-        Which,
+        details::SbomDetails, SbomChecksum, SbomExternalReference, SbomPackage,
+        SbomPackageReference, SbomPackageRelation, SbomProperty, SbomSummary, Which,
     },
     Error,
 };
 use futures_util::{stream, StreamExt, TryStreamExt};
 use sea_orm::{
     prelude::Uuid, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult,
-    IntoSimpleExpr, QueryFilter, QueryOrder, QueryResult, QuerySelect, RelationTrait, Select,
-    SelectColumns,
+    IntoSimpleExpr, QueryFilter, QueryOrder, QueryResult, QuerySelect, QueryTrait, RelationTrait,
+    Select, SelectColumns, Statement,
 };
 use sea_query::{extension::postgres::PgExpr, Expr, Func, JoinType, SimpleExpr};
 use serde::Deserialize;
@@ -24,7 +25,7 @@ use trustify_common::{
         limiter::{limit_selector, LimiterTrait},
         multi_model::{FromQueryResultMultiModel, SelectIntoMultiModel},
         query::{Columns, Filtering, IntoColumns, Query},
-        ArrayAgg, JsonBuildObject, ToJson,
+        ArrayAgg, JsonBuildObject, ToJson, UpdateDeprecatedAdvisory,
     },
     id::{Id, TrySelectForId},
     model::{Paginated, PaginatedResults},
@@ -37,9 +38,11 @@ use trustify_entity::{
     qualified_purl::{self, CanonicalPurl, Qualifiers},
     relationship::Relationship,
     sbom::{self, SbomNodeLink},
-    sbom_node, sbom_package, sbom_package_cpe_ref, sbom_package_purl_ref, source_document, status,
-    versioned_purl, vulnerability,
+    sbom_node, sbom_node_checksum, sbom_package, sbom_package_cpe_ref,
+    sbom_package_external_reference, sbom_package_property, sbom_package_purl_ref, source_document,
+    status, versioned_purl, vulnerability,
 };
+use trustify_module_ingestor::graph::sbom::Checksum;
 
 impl SbomService {
     async fn fetch_sbom<C: ConnectionTrait>(
@@ -83,11 +86,37 @@ impl SbomService {
     }
 
     /// delete one sbom
+    ///
+    /// This also deletes the advisory created from vulnerabilities embedded into the SBOM document.
+    /// That advisory was ingested from the same document, so it shares its digest.
+    ///
+    /// Once the transaction is committed, the caller must drop the now stale analysis graph, using
+    /// [`trustify_module_analysis::service::invalidate_graphs`].
     pub async fn delete_sbom<C: ConnectionTrait>(
         &self,
         id: Uuid,
         connection: &C,
     ) -> Result<u64, Error> {
+        let stmt = Statement::from_sql_and_values(
+            connection.get_database_backend(),
+            r#"
+DELETE FROM advisory WHERE source_document_id IN (
+    SELECT advisory_document.id
+    FROM sbom
+        JOIN source_document AS sbom_document ON sbom_document.id = sbom.source_document_id
+        JOIN source_document AS advisory_document ON advisory_document.sha256 = sbom_document.sha256
+    WHERE sbom.sbom_id = $1
+)
+RETURNING identifier
+"#,
+            [id.into()],
+        );
+
+        for row in connection.query_all(stmt).await? {
+            let identifier = row.try_get_by_index::<String>(0)?;
+            UpdateDeprecatedAdvisory::execute(connection, &identifier).await?;
+        }
+
         let query = sbom::Entity::delete_by_id(id);
 
         let result = query.exec(connection).await?;
@@ -159,7 +188,7 @@ impl SbomService {
             .join(JoinType::LeftJoin, sbom_package::Relation::Purl.def())
             .join(JoinType::LeftJoin, sbom_package::Relation::Cpe.def());
 
-        query = join_purls_and_cpes(query)
+        query = select_package_information(join_purls_and_cpes(query))
             .filtering_with(
                 search,
                 sbom_package::Entity
@@ -193,6 +222,8 @@ impl SbomService {
             items.push(package_from_row(row, connection).await?);
         }
 
+        add_package_details(sbom_id, &mut items, connection).await?;
+
         Ok(PaginatedResults { items, total })
     }
 
@@ -286,6 +317,51 @@ impl SbomService {
         Ok(PaginatedResults { items, total })
     }
 
+    /// Find all SBOMs containing a package or file with the provided checksum.
+    ///
+    /// The algorithm is optional, but narrows down the search if provided, e.g. `sha256`.
+    #[instrument(skip(self, connection), err(level=tracing::Level::INFO))]
+    pub async fn find_sboms_by_checksum<C: ConnectionTrait>(
+        &self,
+        checksum: &str,
+        algorithm: Option<&str>,
+        paginated: Paginated,
+        query: Query,
+        connection: &C,
+    ) -> Result<PaginatedResults<SbomSummary>, Error> {
+        let checksum = Checksum::new(algorithm.unwrap_or_default(), checksum);
+
+        let mut nodes = sbom_node_checksum::Entity::find()
+            .select_only()
+            .column(sbom_node_checksum::Column::SbomId)
+            .filter(sbom_node_checksum::Column::Value.eq(checksum.value));
+        if algorithm.is_some() {
+            nodes = nodes.filter(sbom_node_checksum::Column::Type.eq(checksum.r#type));
+        }
+
+        let query = sbom::Entity::find()
+            .filter(sbom::Column::SbomId.in_subquery(nodes.into_query()))
+            .filtering(query)?
+            .find_also_linked(SbomNodeLink);
+
+        // limit and execute
+
+        let limiter = query.limiting(connection, paginated.offset, paginated.limit);
+
+        let total = limiter.total().await?;
+        let sboms = limiter.fetch().await?;
+
+        // collect results
+
+        let items = stream::iter(sboms.into_iter())
+            .then(|row| async { SbomSummary::from_entity(row, self, connection).await })
+            .try_filter_map(futures_util::future::ok)
+            .try_collect()
+            .await?;
+
+        Ok(PaginatedResults { items, total })
+    }
+
     /// Fetch all related packages in the context of an SBOM.
     #[allow(clippy::too_many_arguments)]
     #[instrument(skip(self, db), err(level=tracing::Level::INFO))]
@@ -340,7 +416,7 @@ impl SbomService {
 
         // collect PURLs and CPEs
 
-        query = join_purls_and_cpes(query);
+        query = select_package_information(join_purls_and_cpes(query));
 
         // filter for reference
 
@@ -390,6 +466,8 @@ impl SbomService {
             }
         }
 
+        add_package_details(sbom_id, items.iter_mut().map(|item| &mut item.package), db).await?;
+
         Ok(PaginatedResults { items, total })
     }
 
@@ -505,11 +583,110 @@ where
         )
 }
 
+/// Select the information columns of packages, which are part of the [`PackageCatcher`].
+fn select_package_information<E>(query: Select<E>) -> Select<E>
+where
+    E: EntityTrait,
+{
+    [
+        sbom_package::Column::Supplier,
+        sbom_package::Column::Originator,
+        sbom_package::Column::DownloadLocation,
+        sbom_package::Column::CopyrightText,
+    ]
+    .into_iter()
+    .fold(query, |query, column| {
+        query.select_column(column).group_by(column)
+    })
+}
+
+/// Add the checksums, external references, and properties to packages of an SBOM.
+async fn add_package_details<'a, C: ConnectionTrait>(
+    sbom_id: Uuid,
+    packages: impl IntoIterator<Item = &'a mut SbomPackage>,
+    db: &C,
+) -> Result<(), Error> {
+    let mut packages = packages.into_iter().collect::<Vec<_>>();
+    if packages.is_empty() {
+        return Ok(());
+    }
+
+    let node_ids = packages
+        .iter()
+        .map(|package| package.id.clone())
+        .collect::<Vec<_>>();
+
+    let mut checksums = HashMap::<_, Vec<_>>::new();
+    let mut external_references = HashMap::<_, Vec<_>>::new();
+    let mut properties = HashMap::<_, Vec<_>>::new();
+
+    // the number of node IDs may exceed the maximum number of parameters of a single statement,
+    // see `trustify_common::db::chunk::chunked_with`
+    for node_ids in node_ids.chunks((u16::MAX - 128) as usize) {
+        for checksum in sbom_node_checksum::Entity::find()
+            .filter(sbom_node_checksum::Column::SbomId.eq(sbom_id))
+            .filter(sbom_node_checksum::Column::NodeId.is_in(node_ids.iter().cloned()))
+            .all(db)
+            .await?
+        {
+            checksums
+                .entry(checksum.node_id)
+                .or_default()
+                .push(SbomChecksum {
+                    r#type: checksum.r#type,
+                    value: checksum.value,
+                });
+        }
+
+        for reference in sbom_package_external_reference::Entity::find()
+            .filter(sbom_package_external_reference::Column::SbomId.eq(sbom_id))
+            .filter(sbom_package_external_reference::Column::NodeId.is_in(node_ids.iter().cloned()))
+            .all(db)
+            .await?
+        {
+            external_references
+                .entry(reference.node_id)
+                .or_default()
+                .push(SbomExternalReference {
+                    r#type: reference.r#type,
+                    url: reference.url,
+                });
+        }
+
+        for property in sbom_package_property::Entity::find()
+            .filter(sbom_package_property::Column::SbomId.eq(sbom_id))
+            .filter(sbom_package_property::Column::NodeId.is_in(node_ids.iter().cloned()))
+            .all(db)
+            .await?
+        {
+            properties
+                .entry(property.node_id)
+                .or_default()
+                .push(SbomProperty {
+                    name: property.name,
+                    value: property.value,
+                });
+        }
+    }
+
+    for package in &mut packages {
+        package.checksums = checksums.remove(&package.id).unwrap_or_default();
+        package.external_references = external_references.remove(&package.id).unwrap_or_default();
+        package.properties = properties.remove(&package.id).unwrap_or_default();
+    }
+
+    Ok(())
+}
+
 #[derive(FromQueryResult)]
 struct PackageCatcher {
     id: String,
     name: String,
     version: Option<String>,
+    supplier: Option<String>,
+    originator: Option<String>,
+    download_location: Option<String>,
+    copyright_text: Option<String>,
     purls: Vec<Value>,
     cpes: Vec<Value>,
     relationship: Option<Relationship>,
@@ -587,6 +764,11 @@ async fn package_from_row<C: ConnectionTrait>(
             })
             .map(|cpe| cpe.to_string())
             .collect(),
+        supplier: row.supplier,
+        originator: row.originator,
+        download_location: row.download_location,
+        copyright_text: row.copyright_text,
+        ..Default::default()
     })
 }
 
@@ -640,6 +822,7 @@ pub struct QueryCatcher {
     pub vulnerability: vulnerability::Model,
     pub context_cpe: Option<cpe::Model>,
     pub status: status::Model,
+    pub information: StatusInformation,
 }
 
 impl FromQueryResult for QueryCatcher {
@@ -654,6 +837,11 @@ impl FromQueryResult for QueryCatcher {
             sbom_node: Self::from_query_result_multi_model(res, "", sbom_node::Entity)?,
             context_cpe: Self::from_query_result_multi_model_optional(res, "", cpe::Entity)?,
             status: Self::from_query_result_multi_model(res, "", status::Entity)?,
+            information: StatusInformation {
+                justification: res.try_get("", "justification")?,
+                impact_statement: res.try_get("", "impact_statement")?,
+                action_statement: res.try_get("", "action_statement")?,
+            },
         })
     }
 }
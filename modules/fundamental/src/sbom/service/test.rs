@@ -1,9 +1,20 @@
-use crate::sbom::service::SbomService;
+use crate::sbom::{
+    model::{
+        details::SbomStatus,
+        diff::{SbomLicenseChange, SbomRelationship},
+        export::ExportFormat,
+        SbomChecksum, SbomExternalReference, SbomPackage, SbomProperty,
+    },
+    service::SbomService,
+};
+use sea_orm::{EntityTrait, PaginatorTrait};
+use serde_json::json;
 use std::str::FromStr;
 use test_context::test_context;
 use test_log::test;
 use trustify_common::id::Id;
 use trustify_common::purl::Purl;
+use trustify_entity::{advisory, purl_status, relationship::Relationship};
 use trustify_test_context::TrustifyContext;
 
 #[test_context(TrustifyContext)]
@@ -39,6 +50,33 @@ async fn sbom_details_status(ctx: &TrustifyContext) -> Result<(), anyhow::Error>
     Ok(())
 }
 
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn delete_sbom_with_embedded_vulnerabilities(
+    ctx: &TrustifyContext,
+) -> Result<(), anyhow::Error> {
+    let result = ctx
+        .ingest_document("cyclonedx/embedded_vulnerabilities.json")
+        .await?;
+
+    assert_eq!(advisory::Entity::find().count(&ctx.db).await?, 1);
+    assert_eq!(purl_status::Entity::find().count(&ctx.db).await?, 2);
+
+    let service = SbomService::new(ctx.db.clone());
+    let sbom = service
+        .fetch_sbom_summary(result.id, &ctx.db)
+        .await?
+        .expect("must be found");
+    assert_eq!(service.delete_sbom(sbom.head.id, &ctx.db).await?, 1);
+
+    // the advisory created from the embedded vulnerabilities is gone as well
+
+    assert_eq!(advisory::Entity::find().count(&ctx.db).await?, 0);
+    assert_eq!(purl_status::Entity::find().count(&ctx.db).await?, 0);
+
+    Ok(())
+}
+
 #[test_context(TrustifyContext)]
 #[test(tokio::test)]
 async fn count_sboms(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
@@ -69,3 +107,322 @@ async fn count_sboms(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn diff_sboms(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let results = ctx
+        .ingest_documents([
+            "cyclonedx/diff/product-1.0.json",
+            "cyclonedx/diff/product-1.1.json",
+        ])
+        .await?;
+
+    let service = SbomService::new(ctx.db.clone());
+
+    let diff = service
+        .diff_sboms(results[0].id.clone(), results[1].id.clone(), &ctx.db)
+        .await?
+        .expect("must be found");
+
+    log::debug!("{diff:#?}");
+
+    let names = |packages: &[SbomPackage]| {
+        packages
+            .iter()
+            .map(|package| package.name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names(&diff.added), ["delta"]);
+    assert_eq!(names(&diff.removed), ["epsilon"]);
+
+    assert_eq!(diff.upgraded.len(), 1);
+    assert_eq!(diff.upgraded[0].left.version.as_deref(), Some("1.0.0"));
+    assert_eq!(diff.upgraded[0].right.version.as_deref(), Some("1.1.0"));
+    assert_eq!(diff.upgraded[0].right.name, "alpha");
+
+    assert_eq!(diff.downgraded.len(), 1);
+    assert_eq!(diff.downgraded[0].left.version.as_deref(), Some("2.0.0"));
+    assert_eq!(diff.downgraded[0].right.version.as_deref(), Some("1.9.0"));
+    assert_eq!(diff.downgraded[0].right.name, "beta");
+
+    assert_eq!(
+        diff.license_changes,
+        [SbomLicenseChange {
+            package: "pkg:generic/gamma".to_string(),
+            added: vec!["Apache-2.0".to_string()],
+            removed: vec!["MIT".to_string()],
+        }]
+    );
+
+    let relationships = |relationships: &[SbomRelationship]| {
+        relationships
+            .iter()
+            .map(|r| (r.left.clone(), r.relationship, r.right.clone()))
+            .collect::<Vec<_>>()
+    };
+
+    let added_relationships = relationships(&diff.added_relationships);
+    assert_eq!(added_relationships.len(), 2);
+    assert!(added_relationships
+        .iter()
+        .all(|(left, relationship, _)| left == "pkg:generic/delta"
+            && *relationship == Relationship::DependencyOf));
+    assert!(added_relationships
+        .iter()
+        .any(|(_, _, right)| right == "pkg:generic/alpha"));
+
+    let removed_relationships = relationships(&diff.removed_relationships);
+    assert_eq!(removed_relationships.len(), 1);
+    assert_eq!(removed_relationships[0].0, "pkg:generic/epsilon");
+
+    let identifiers = |statuses: &[SbomStatus]| {
+        statuses
+            .iter()
+            .map(|status| status.identifier().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        identifiers(&diff.introduced_vulnerabilities),
+        ["CVE-2024-1002"]
+    );
+    assert_eq!(identifiers(&diff.fixed_vulnerabilities), ["CVE-2024-1001"]);
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn package_details(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let results = ctx
+        .ingest_documents(["spdx/issue-552.json", "cyclonedx/package-details.json"])
+        .await?;
+
+    let service = SbomService::new(ctx.db.clone());
+
+    // SPDX
+
+    let spdx = results[0].id.try_as_uid().expect("must be a UUID");
+    let packages = service
+        .fetch_sbom_packages(spdx, Default::default(), Default::default(), &ctx.db)
+        .await?
+        .items;
+
+    let glibc = packages
+        .iter()
+        .find(|package| package.name == "glibc")
+        .expect("must be found");
+    assert_eq!(
+        glibc.supplier.as_deref(),
+        Some("Person: Jane Doe (jane.doe@example.com)")
+    );
+    assert_eq!(
+        glibc.originator.as_deref(),
+        Some("Organization: ExampleCodeInspect (contact@example.com)")
+    );
+    assert_eq!(
+        glibc.download_location.as_deref(),
+        Some("http://ftp.gnu.org/gnu/glibc/glibc-ports-2.15.tar.gz")
+    );
+    assert_eq!(
+        glibc.copyright_text.as_deref(),
+        Some("Copyright 2008-2010 John Smith")
+    );
+    assert_eq!(glibc.checksums.len(), 3);
+    assert!(glibc.checksums.contains(&SbomChecksum {
+        r#type: "sha256".to_string(),
+        value: "11b6d3ee554eedf79299905a98f9b9a04e498210b59f15094c916c91d150efcd".to_string(),
+    }));
+
+    // "NOASSERTION" is not stored
+    let jena = packages
+        .iter()
+        .find(|package| package.name == "Jena")
+        .expect("must be found");
+    assert_eq!(jena.download_location, None);
+    assert_eq!(jena.copyright_text, None);
+    assert!(jena.checksums.is_empty());
+
+    // CycloneDX
+
+    let cyclonedx = results[1].id.try_as_uid().expect("must be a UUID");
+    let packages = service
+        .fetch_sbom_packages(cyclonedx, Default::default(), Default::default(), &ctx.db)
+        .await?
+        .items;
+
+    let openssl = packages
+        .iter()
+        .find(|package| package.name == "openssl")
+        .expect("must be found");
+    assert_eq!(
+        openssl.supplier.as_deref(),
+        Some("OpenSSL Software Foundation")
+    );
+    assert_eq!(
+        openssl.originator.as_deref(),
+        Some("The OpenSSL Project Authors")
+    );
+    assert_eq!(
+        openssl.download_location.as_deref(),
+        Some("https://www.openssl.org/source/openssl-3.0.7.tar.gz")
+    );
+    assert_eq!(
+        openssl.copyright_text.as_deref(),
+        Some("Copyright 1998-2022 The OpenSSL Authors")
+    );
+    assert!(openssl.checksums.contains(&SbomChecksum {
+        r#type: "sha256".to_string(),
+        value: "83049d042a260e696f62406ac5c08bf706fd84383f945cf21bd61e9ed95c396e".to_string(),
+    }));
+    assert!(openssl
+        .external_references
+        .contains(&SbomExternalReference {
+            r#type: "website".to_string(),
+            url: "https://www.openssl.org/".to_string(),
+        }));
+    assert_eq!(
+        openssl.properties,
+        vec![SbomProperty {
+            name: "build:fips".to_string(),
+            value: "true".to_string(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn find_sboms_by_checksum(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    ctx.ingest_documents(["spdx/issue-552.json", "cyclonedx/package-details.json"])
+        .await?;
+
+    let service = SbomService::new(ctx.db.clone());
+
+    let find = |checksum: &'static str, algorithm: Option<&'static str>| {
+        let service = &service;
+        async move {
+            service
+                .find_sboms_by_checksum(
+                    checksum,
+                    algorithm,
+                    Default::default(),
+                    Default::default(),
+                    &ctx.db,
+                )
+                .await
+        }
+    };
+
+    // a package, matching case insensitive and with differently spelled algorithms
+    let result = find(
+        "83049D042A260E696F62406AC5C08BF706FD84383F945CF21BD61E9ED95C396E",
+        Some("SHA-256"),
+    )
+    .await?;
+    assert_eq!(result.total, 1);
+    assert_eq!(result.items[0].head.name, "package-details");
+
+    // a file
+    let result = find("2fd4e1c67a2d28fced849ee1bb76e7391b93eb12", None).await?;
+    assert_eq!(result.total, 1);
+
+    // the wrong algorithm
+    let result = find("2fd4e1c67a2d28fced849ee1bb76e7391b93eb12", Some("sha256")).await?;
+    assert_eq!(result.total, 0);
+
+    // unknown
+    let result = find("0000000000000000000000000000000000000000", None).await?;
+    assert_eq!(result.total, 0);
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn export_sbom(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let results = ctx
+        .ingest_documents(["spdx/issue-552.json", "cyclonedx/package-details.json"])
+        .await?;
+
+    let service = SbomService::new(ctx.db.clone());
+
+    // SPDX
+
+    let export = service
+        .export_sbom(results[0].id.clone(), Default::default(), &ctx.db)
+        .await?
+        .expect("must be found");
+    let spdx: spdx_rs::models::SPDX = serde_json::from_value(export.render(ExportFormat::Spdx2_3))?;
+
+    let glibc = spdx
+        .package_information
+        .iter()
+        .find(|package| package.package_name == "glibc")
+        .expect("must be found");
+    assert_eq!(
+        glibc.package_supplier.as_deref(),
+        Some("Person: Jane Doe (jane.doe@example.com)")
+    );
+    assert_eq!(
+        glibc.package_download_location,
+        "http://ftp.gnu.org/gnu/glibc/glibc-ports-2.15.tar.gz"
+    );
+    assert!(glibc.package_checksum.iter().any(|checksum| checksum.value
+        == "11b6d3ee554eedf79299905a98f9b9a04e498210b59f15094c916c91d150efcd"));
+
+    // CycloneDX
+
+    let export = service
+        .export_sbom(results[1].id.clone(), Default::default(), &ctx.db)
+        .await?
+        .expect("must be found");
+    let cyclonedx = export.render(ExportFormat::CycloneDx1_6);
+    serde_json::from_value::<serde_cyclonedx::cyclonedx::v_1_6::CycloneDx>(cyclonedx.clone())?;
+
+    assert_eq!(cyclonedx["specVersion"], "1.6");
+    assert_eq!(
+        cyclonedx["serialNumber"],
+        "urn:uuid:0f4d1d5c-6b8a-4f32-9e1e-5c0a2b7d3e11"
+    );
+    assert_eq!(
+        cyclonedx["metadata"]["component"]["name"],
+        "package-details"
+    );
+
+    let openssl = &cyclonedx["components"][0];
+    assert_eq!(openssl["name"], "openssl");
+    assert_eq!(openssl["purl"], "pkg:generic/openssl@3.0.7");
+    assert_eq!(openssl["supplier"]["name"], "OpenSSL Software Foundation");
+    assert_eq!(openssl["author"], "The OpenSSL Project Authors");
+    let hashes = openssl["hashes"].as_array().expect("must be an array");
+    assert_eq!(hashes.len(), 2);
+    assert!(hashes.contains(&json!({
+        "alg": "SHA-256",
+        "content": "83049d042a260e696f62406ac5c08bf706fd84383f945cf21bd61e9ed95c396e",
+    })));
+    assert_eq!(
+        openssl["properties"],
+        json!([{ "name": "build:fips", "value": "true" }])
+    );
+
+    let dependencies = cyclonedx["dependencies"]
+        .as_array()
+        .expect("must be an array");
+    assert!(dependencies.contains(&json!({
+        "ref": "package-details",
+        "dependsOn": ["openssl"],
+    })));
+
+    // unknown
+
+    let export = service
+        .export_sbom(Id::Uuid(uuid::Uuid::nil()), Default::default(), &ctx.db)
+        .await?;
+    assert!(export.is_none());
+
+    Ok(())
+}
@@ -1,13 +1,23 @@
+mod remediation;
 mod vulnerability_advisory;
 
+pub use remediation::*;
 pub use vulnerability_advisory::*;
 
-use crate::{vulnerability::model::VulnerabilityHead, Error};
-use sea_orm::{ConnectionTrait, ModelTrait};
+use crate::{
+    vulnerability::model::{EpssScore, KevEntry, VulnerabilityHead},
+    Error,
+};
+use sea_orm::{ConnectionTrait, ModelTrait, QueryOrder};
 use serde::{Deserialize, Serialize};
 use trustify_common::memo::Memo;
-use trustify_cvss::cvss3::{score::Score, severity::Severity, Cvss3Base};
-use trustify_entity::{advisory_vulnerability, cvss3, vulnerability};
+use trustify_cvss::{
+    cvss3::{score::Score, severity::Severity, Cvss3Base},
+    cvss4::Cvss4Base,
+};
+use trustify_entity::{
+    advisory_vulnerability, cvss2, cvss3, cvss4, epss, epss_history, kev, vulnerability,
+};
 use trustify_module_ingestor::common::{Deprecation, DeprecationForExt};
 use utoipa::ToSchema;
 
@@ -24,6 +34,17 @@ pub struct VulnerabilityDetails {
     #[schema(required)]
     pub average_score: Option<f64>,
 
+    /// The latest EPSS score of the vulnerability, if any.
+    #[schema(required)]
+    pub epss: Option<EpssScore>,
+
+    /// All known EPSS scores of the vulnerability, ordered by date.
+    pub epss_history: Vec<EpssScore>,
+
+    /// The entry of the vulnerability in the CISA Known Exploited Vulnerabilities catalog, if any.
+    #[schema(required)]
+    pub kev: Option<KevEntry>,
+
     /// Advisories addressing this vulnerability, if any.
     pub advisories: Vec<VulnerabilityAdvisorySummary>,
 }
@@ -40,17 +61,38 @@ impl VulnerabilityDetails {
             .all(tx)
             .await?;
 
+        let cvss2 = vulnerability
+            .find_related(cvss2::Entity)
+            .order_by_asc(cvss2::Column::Idx)
+            .all(tx)
+            .await?;
         let cvss3 = vulnerability.find_related(cvss3::Entity).all(tx).await?;
-        let score = if cvss3.is_empty() {
+        let cvss4 = vulnerability.find_related(cvss4::Entity).all(tx).await?;
+        let score = if cvss3.is_empty() && cvss4.is_empty() {
             None
         } else {
-            Some(Score::from_iter(cvss3.iter().map(Cvss3Base::from)))
+            Some(Score::from_iter(
+                cvss3
+                    .iter()
+                    .map(|e| Cvss3Base::from(e).score())
+                    .chain(cvss4.iter().map(|e| Cvss4Base::from(e).score())),
+            ))
         };
 
+        let epss = vulnerability.find_related(epss::Entity).one(tx).await?;
+        let epss_history = vulnerability
+            .find_related(epss_history::Entity)
+            .order_by_asc(epss_history::Column::Date)
+            .all(tx)
+            .await?;
+        let kev = vulnerability.find_related(kev::Entity).one(tx).await?;
+
         let advisories = VulnerabilityAdvisorySummary::from_entities(
             vulnerability,
             &advisory_vulnerabilities,
+            &cvss2,
             &cvss3,
+            &cvss4,
             tx,
         )
         .await?;
@@ -64,6 +106,9 @@ impl VulnerabilityDetails {
             .await?,
             average_severity: score.map(|v| v.severity()),
             average_score: score.map(|v| v.value()),
+            epss: epss.map(Into::into),
+            epss_history: epss_history.into_iter().map(Into::into).collect(),
+            kev: kev.map(Into::into),
             advisories,
         })
     }
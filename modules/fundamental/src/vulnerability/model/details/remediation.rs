@@ -0,0 +1,300 @@
+use crate::{purl::model::BasePurlHead, Error};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect, Related, RelationTrait,
+};
+use sea_query::JoinType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::OffsetDateTime;
+use trustify_common::purl::Purl;
+use trustify_entity::{
+    base_purl, flag, flag_product_status, flag_purl_status, product, product_status,
+    product_version_range, purl_status, remediation, remediation_product_status,
+    remediation_purl_status, threat, threat_product_status, threat_purl_status,
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A product, or a package of a product.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, ToSchema)]
+pub struct ProductReference {
+    /// The name of the product
+    pub name: String,
+    /// The package of the product, if any
+    #[schema(required)]
+    pub package: Option<String>,
+}
+
+/// Products and packages something applies to.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, ToSchema)]
+pub struct AppliesTo {
+    pub products: Vec<ProductReference>,
+    pub purls: Vec<BasePurlHead>,
+}
+
+/// How to fix a vulnerability, or deal with it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct RemediationSummary {
+    pub category: remediation::RemediationCategory,
+    pub details: String,
+    /// A URL with more information, e.g. the fix of the vendor
+    #[schema(required)]
+    pub url: Option<String>,
+    #[schema(required)]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub date: Option<OffsetDateTime>,
+    #[serde(flatten)]
+    pub applies_to: AppliesTo,
+}
+
+/// The impact, exploit status or target set of a vulnerability.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct ThreatSummary {
+    pub category: threat::ThreatCategory,
+    pub details: String,
+    #[schema(required)]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub date: Option<OffsetDateTime>,
+    #[serde(flatten)]
+    pub applies_to: AppliesTo,
+}
+
+/// The justification of why products are not affected by a vulnerability.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct FlagSummary {
+    pub label: flag::FlagLabel,
+    #[schema(required)]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub date: Option<OffsetDateTime>,
+    #[serde(flatten)]
+    pub applies_to: AppliesTo,
+}
+
+/// The remediations, threats and flags of all advisories of a vulnerability.
+#[derive(Debug, Default)]
+pub(crate) struct Remediations {
+    remediations: Vec<(Uuid, RemediationSummary)>,
+    threats: Vec<(Uuid, ThreatSummary)>,
+    flags: Vec<(Uuid, FlagSummary)>,
+}
+
+impl Remediations {
+    pub async fn load<C: ConnectionTrait>(vulnerability_id: &str, tx: &C) -> Result<Self, Error> {
+        let mut remediation_products = products::<remediation_product_status::Entity, _>(
+            remediation_product_status::Column::RemediationId,
+            vulnerability_id,
+            tx,
+        )
+        .await?;
+        let mut remediation_purls = purls::<remediation_purl_status::Entity, _>(
+            remediation_purl_status::Column::RemediationId,
+            vulnerability_id,
+            tx,
+        )
+        .await?;
+
+        let remediations = remediation::Entity::find()
+            .filter(remediation::Column::VulnerabilityId.eq(vulnerability_id))
+            .all(tx)
+            .await?
+            .into_iter()
+            .map(|remediation| {
+                (
+                    remediation.advisory_id,
+                    RemediationSummary {
+                        applies_to: AppliesTo {
+                            products: remediation_products
+                                .remove(&remediation.id)
+                                .unwrap_or_default(),
+                            purls: remediation_purls
+                                .remove(&remediation.id)
+                                .unwrap_or_default(),
+                        },
+                        category: remediation.category,
+                        details: remediation.details,
+                        url: remediation.url,
+                        date: remediation.date,
+                    },
+                )
+            })
+            .collect();
+
+        let mut threat_products = products::<threat_product_status::Entity, _>(
+            threat_product_status::Column::ThreatId,
+            vulnerability_id,
+            tx,
+        )
+        .await?;
+        let mut threat_purls = purls::<threat_purl_status::Entity, _>(
+            threat_purl_status::Column::ThreatId,
+            vulnerability_id,
+            tx,
+        )
+        .await?;
+
+        let threats = threat::Entity::find()
+            .filter(threat::Column::VulnerabilityId.eq(vulnerability_id))
+            .all(tx)
+            .await?
+            .into_iter()
+            .map(|threat| {
+                (
+                    threat.advisory_id,
+                    ThreatSummary {
+                        applies_to: AppliesTo {
+                            products: threat_products.remove(&threat.id).unwrap_or_default(),
+                            purls: threat_purls.remove(&threat.id).unwrap_or_default(),
+                        },
+                        category: threat.category,
+                        details: threat.details,
+                        date: threat.date,
+                    },
+                )
+            })
+            .collect();
+
+        let mut flag_products = products::<flag_product_status::Entity, _>(
+            flag_product_status::Column::FlagId,
+            vulnerability_id,
+            tx,
+        )
+        .await?;
+        let mut flag_purls = purls::<flag_purl_status::Entity, _>(
+            flag_purl_status::Column::FlagId,
+            vulnerability_id,
+            tx,
+        )
+        .await?;
+
+        let flags = flag::Entity::find()
+            .filter(flag::Column::VulnerabilityId.eq(vulnerability_id))
+            .all(tx)
+            .await?
+            .into_iter()
+            .map(|flag| {
+                (
+                    flag.advisory_id,
+                    FlagSummary {
+                        applies_to: AppliesTo {
+                            products: flag_products.remove(&flag.id).unwrap_or_default(),
+                            purls: flag_purls.remove(&flag.id).unwrap_or_default(),
+                        },
+                        label: flag.label,
+                        date: flag.date,
+                    },
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            remediations,
+            threats,
+            flags,
+        })
+    }
+
+    pub fn remediations(&self, advisory_id: Uuid) -> Vec<RemediationSummary> {
+        by_advisory(&self.remediations, advisory_id)
+    }
+
+    pub fn threats(&self, advisory_id: Uuid) -> Vec<ThreatSummary> {
+        by_advisory(&self.threats, advisory_id)
+    }
+
+    pub fn flags(&self, advisory_id: Uuid) -> Vec<FlagSummary> {
+        by_advisory(&self.flags, advisory_id)
+    }
+}
+
+fn by_advisory<T: Clone>(entries: &[(Uuid, T)], advisory_id: Uuid) -> Vec<T> {
+    entries
+        .iter()
+        .filter(|(id, _)| *id == advisory_id)
+        .map(|(_, entry)| entry.clone())
+        .collect()
+}
+
+/// Get the products linked through the product statuses of a link entity (like
+/// `remediation_product_status`), by the ID of the linked entry.
+async fn products<L, C>(
+    id_column: L::Column,
+    vulnerability_id: &str,
+    tx: &C,
+) -> Result<HashMap<Uuid, Vec<ProductReference>>, Error>
+where
+    L: EntityTrait + Related<product_status::Entity>,
+    C: ConnectionTrait,
+{
+    let rows = L::find()
+        .join(JoinType::Join, <L as Related<product_status::Entity>>::to())
+        .join(
+            JoinType::Join,
+            product_status::Relation::ProductVersionRange.def(),
+        )
+        .join(
+            JoinType::Join,
+            product_version_range::Relation::Product.def(),
+        )
+        .filter(product_status::Column::VulnerabilityId.eq(vulnerability_id))
+        .select_only()
+        .column(id_column)
+        .column(product::Column::Name)
+        .column(product_status::Column::Package)
+        .distinct()
+        .into_tuple::<(Uuid, String, Option<String>)>()
+        .all(tx)
+        .await?;
+
+    let mut result = HashMap::<_, Vec<_>>::new();
+    for (id, name, package) in rows {
+        result
+            .entry(id)
+            .or_default()
+            .push(ProductReference { name, package });
+    }
+
+    Ok(result)
+}
+
+/// Get the base purls linked through the purl statuses of a link entity (like
+/// `remediation_purl_status`), by the ID of the linked entry.
+async fn purls<L, C>(
+    id_column: L::Column,
+    vulnerability_id: &str,
+    tx: &C,
+) -> Result<HashMap<Uuid, Vec<BasePurlHead>>, Error>
+where
+    L: EntityTrait + Related<purl_status::Entity>,
+    C: ConnectionTrait,
+{
+    let rows = L::find()
+        .join(JoinType::Join, <L as Related<purl_status::Entity>>::to())
+        .join(JoinType::Join, purl_status::Relation::BasePurl.def())
+        .filter(purl_status::Column::VulnerabilityId.eq(vulnerability_id))
+        .select_only()
+        .column(id_column)
+        .column(base_purl::Column::Id)
+        .column(base_purl::Column::Type)
+        .column(base_purl::Column::Namespace)
+        .column(base_purl::Column::Name)
+        .distinct()
+        .into_tuple::<(Uuid, Uuid, String, Option<String>, String)>()
+        .all(tx)
+        .await?;
+
+    let mut result = HashMap::<_, Vec<_>>::new();
+    for (id, uuid, ty, namespace, name) in rows {
+        result.entry(id).or_default().push(BasePurlHead {
+            uuid,
+            purl: Purl {
+                ty,
+                namespace,
+                name,
+                version: None,
+                qualifiers: Default::default(),
+            },
+        });
+    }
+
+    Ok(result)
+}
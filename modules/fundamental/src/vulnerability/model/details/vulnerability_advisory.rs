@@ -1,7 +1,8 @@
 use crate::{
-    advisory::model::AdvisoryHead,
+    advisory::model::{AdvisoryHead, StatusInformation},
     purl::model::{details::purl::StatusContext, summary::purl::PurlSummary, BasePurlHead},
     sbom::model::SbomHead,
+    vulnerability::model::{FlagSummary, RemediationSummary, Remediations, ThreatSummary},
     Error,
 };
 use ::cpe::uri::OwnedUri;
@@ -21,9 +22,13 @@ use trustify_common::{
     memo::Memo,
     purl::Purl,
 };
-use trustify_cvss::cvss3::{score::Score, severity::Severity, Cvss3Base};
+use trustify_cvss::{
+    cvss2::vector::Cvss2Vector,
+    cvss3::{score::Score, severity::Severity, vector::Cvss3Vector, Cvss3Base},
+    cvss4::Cvss4Base,
+};
 use trustify_entity::{
-    advisory, advisory_vulnerability, base_purl, cpe, cvss3, organization,
+    advisory, advisory_vulnerability, base_purl, cpe, cvss2, cvss3, cvss4, organization,
     package_relates_to_package, purl_status, qualified_purl, sbom, sbom_node, sbom_package,
     sbom_package_purl_ref, status, version_range, versioned_purl, vulnerability,
 };
@@ -52,10 +57,21 @@ impl VulnerabilityAdvisoryHead {
             .all(tx)
             .await?;
 
-        let score = if cvss3.is_empty() {
+        let cvss4 = cvss4::Entity::find()
+            .filter(cvss4::Column::AdvisoryId.eq(advisory_vulnerability.advisory_id))
+            .filter(cvss4::Column::VulnerabilityId.eq(&vulnerability.id))
+            .all(tx)
+            .await?;
+
+        let score = if cvss3.is_empty() && cvss4.is_empty() {
             None
         } else {
-            Some(Score::from_iter(cvss3.iter().map(Cvss3Base::from)))
+            Some(Score::from_iter(
+                cvss3
+                    .iter()
+                    .map(|e| Cvss3Base::from(e).score())
+                    .chain(cvss4.iter().map(|e| Cvss4Base::from(e).score())),
+            ))
         };
 
         if let Some(advisory) = &advisory_vulnerability
@@ -76,6 +92,7 @@ impl VulnerabilityAdvisoryHead {
         vulnerability: &vulnerability::Model,
         vuln_advisories: &[advisory::Model],
         vuln_cvss3s: &[cvss3::Model],
+        vuln_cvss4s: &[cvss4::Model],
         tx: &C,
     ) -> Result<Vec<Self>, Error> {
         let mut heads = Vec::new();
@@ -89,10 +106,20 @@ impl VulnerabilityAdvisoryHead {
                 .filter(|e| e.vulnerability_id == vulnerability.id)
                 .collect::<Vec<_>>();
 
-            let score = if cvss3.is_empty() {
+            let cvss4 = vuln_cvss4s
+                .iter()
+                .filter(|e| e.vulnerability_id == vulnerability.id)
+                .collect::<Vec<_>>();
+
+            let score = if cvss3.is_empty() && cvss4.is_empty() {
                 None
             } else {
-                Some(Score::from_iter(cvss3.into_iter().map(Cvss3Base::from)))
+                Some(Score::from_iter(
+                    cvss3
+                        .into_iter()
+                        .map(|e| Cvss3Base::from(e).score())
+                        .chain(cvss4.into_iter().map(|e| Cvss4Base::from(e).score())),
+                ))
             };
 
             heads.push(VulnerabilityAdvisoryHead {
@@ -111,15 +138,32 @@ pub struct VulnerabilityAdvisorySummary {
     #[serde(flatten)]
     pub head: VulnerabilityAdvisoryHead,
 
+    /// CVSS2 scores from this advisory regarding the vulnerability.
+    #[schema(default, value_type = Vec < String >)]
+    pub cvss2_scores: Vec<String>,
+
     /// CVSS3 scores from this advisory regarding the vulnerability.
     #[schema(default, value_type = Vec < String >)]
     pub cvss3_scores: Vec<String>,
 
+    /// CVSS4 scores from this advisory regarding the vulnerability.
+    #[schema(default, value_type = Vec < String >)]
+    pub cvss4_scores: Vec<String>,
+
     pub purls: HashMap<String, Vec<VulnerabilityAdvisoryStatus>>,
 
     /// SBOMs claimed by this advisory to be addressed by this vulnerability.
     pub sboms: Vec<VulnerabilitySbomStatus>,
 
+    /// How to fix the vulnerability, or deal with it, according to this advisory.
+    pub remediations: Vec<RemediationSummary>,
+
+    /// Threats of the vulnerability, like its impact, according to this advisory.
+    pub threats: Vec<ThreatSummary>,
+
+    /// Justifications of why products are not affected by the vulnerability, according to this advisory.
+    pub flags: Vec<FlagSummary>,
+
     /// The total number of vulnerabilities described by this advisory
     pub number_of_vulnerabilities: u64,
 }
@@ -128,7 +172,9 @@ impl VulnerabilityAdvisorySummary {
     pub async fn from_entities<C: ConnectionTrait>(
         vulnerability: &vulnerability::Model,
         advisory_vulnerabilities: &[advisory_vulnerability::Model],
+        vuln_cvss2: &[cvss2::Model],
         vuln_cvss3: &[cvss3::Model],
+        vuln_cvss4: &[cvss4::Model],
         tx: &C,
     ) -> Result<Vec<Self>, Error> {
         let purl_status_query = purl_status::Entity::find()
@@ -271,6 +317,8 @@ impl VulnerabilityAdvisorySummary {
                 .await?;
         }
 
+        let remediations = Remediations::load(&vulnerability.id, tx).await?;
+
         let mut summaries = Vec::new();
 
         for advisory_vulnerability in advisory_vulnerabilities {
@@ -279,10 +327,22 @@ impl VulnerabilityAdvisorySummary {
                 .count(tx)
                 .await?;
 
+            let cvss2_scores = vuln_cvss2
+                .iter()
+                .filter(|e| e.advisory_id == advisory_vulnerability.advisory_id)
+                .map(|e| Cvss2Vector::from(e).to_string())
+                .collect();
+
             let cvss3_scores = vuln_cvss3
                 .iter()
                 .filter(|e| e.advisory_id == advisory_vulnerability.advisory_id)
-                .map(|e| Cvss3Base::from(e.clone()).to_string())
+                .map(|e| Cvss3Vector::from(e).to_string())
+                .collect();
+
+            let cvss4_scores = vuln_cvss4
+                .iter()
+                .filter(|e| e.advisory_id == advisory_vulnerability.advisory_id)
+                .map(|e| Cvss4Base::from(e).to_string())
                 .collect();
 
             let sbom_statuses = vuln_sbom_statuses
@@ -300,9 +360,14 @@ impl VulnerabilityAdvisorySummary {
                     tx,
                 )
                 .await?,
+                cvss2_scores,
                 cvss3_scores,
+                cvss4_scores,
                 purls: VulnerabilityAdvisoryStatus::from_models(purl_statuses).await?,
                 sboms: VulnerabilitySbomStatus::from_models(sbom_statuses, tx).await?,
+                remediations: remediations.remediations(advisory_vulnerability.advisory_id),
+                threats: remediations.threats(advisory_vulnerability.advisory_id),
+                flags: remediations.flags(advisory_vulnerability.advisory_id),
                 number_of_vulnerabilities,
             });
         }
@@ -356,63 +421,67 @@ impl PurlStatusCatcher {
     }
 
     pub fn version(&self) -> String {
-        match (
-            &self.version_range.low_version,
-            &self.version_range.high_version,
-        ) {
-            (Some(low), Some(high)) if low == high => low.clone(),
-            (Some(low), Some(high)) => {
-                let mut v = String::new();
-                v.push(Self::open_delim(self.version_range.low_inclusive));
-                v.push_str(low);
-                v.push(',');
-                v.push_str(high);
-                v.push(Self::close_delim(self.version_range.high_inclusive));
-                v
-            }
-
-            (Some(low), None) => {
-                let mut v = String::new();
-                v.push(Self::open_delim(self.version_range.low_inclusive));
-                v.push_str(low);
-                v.push(',');
-                v.push(Self::close_delim(self.version_range.high_inclusive));
-                v
-            }
-            (None, Some(high)) => {
-                let mut v = String::new();
-                v.push(Self::open_delim(self.version_range.low_inclusive));
-                v.push(',');
-                v.push_str(high);
-                v.push(Self::close_delim(self.version_range.high_inclusive));
-                v
-            }
-            (None, None) => "*".to_string(),
+        version_range_notation(&self.version_range)
+    }
+}
+
+/// Render a version range in interval notation, e.g. `[1.0.0,1.2.3)`.
+///
+/// A range with equal bounds is rendered as that single version, a range without bounds as `*`.
+pub(crate) fn version_range_notation(range: &version_range::Model) -> String {
+    match (&range.low_version, &range.high_version) {
+        (Some(low), Some(high)) if low == high => low.clone(),
+        (Some(low), Some(high)) => {
+            let mut v = String::new();
+            v.push(open_delim(range.low_inclusive));
+            v.push_str(low);
+            v.push(',');
+            v.push_str(high);
+            v.push(close_delim(range.high_inclusive));
+            v
+        }
+
+        (Some(low), None) => {
+            let mut v = String::new();
+            v.push(open_delim(range.low_inclusive));
+            v.push_str(low);
+            v.push(',');
+            v.push(close_delim(range.high_inclusive));
+            v
         }
+        (None, Some(high)) => {
+            let mut v = String::new();
+            v.push(open_delim(range.low_inclusive));
+            v.push(',');
+            v.push_str(high);
+            v.push(close_delim(range.high_inclusive));
+            v
+        }
+        (None, None) => "*".to_string(),
     }
+}
 
-    fn open_delim(incl: Option<bool>) -> char {
-        if let Some(incl) = incl {
-            if incl {
-                '['
-            } else {
-                '('
-            }
+fn open_delim(incl: Option<bool>) -> char {
+    if let Some(incl) = incl {
+        if incl {
+            '['
         } else {
             '('
         }
+    } else {
+        '('
     }
+}
 
-    fn close_delim(incl: Option<bool>) -> char {
-        if let Some(incl) = incl {
-            if incl {
-                ']'
-            } else {
-                ')'
-            }
+fn close_delim(incl: Option<bool>) -> char {
+    if let Some(incl) = incl {
+        if incl {
+            ']'
         } else {
             ')'
         }
+    } else {
+        ')'
     }
 }
 
@@ -422,6 +491,8 @@ pub struct VulnerabilityAdvisoryStatus {
     pub version: String,
     #[schema(required)]
     pub context: Option<StatusContext>,
+    #[serde(flatten)]
+    pub information: StatusInformation,
 }
 
 impl VulnerabilityAdvisoryStatus {
@@ -444,6 +515,7 @@ impl VulnerabilityAdvisoryStatus {
                 },
                 version: each.version(),
                 context,
+                information: (&each.purl_status).into(),
             });
         }
 
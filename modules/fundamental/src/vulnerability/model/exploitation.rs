@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use trustify_entity::{epss, epss_history, kev};
+use utoipa::ToSchema;
+
+/// A score of the Exploit Prediction Scoring System (EPSS).
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema, PartialEq)]
+pub struct EpssScore {
+    /// The probability (0..1) of exploitation activity in the next 30 days.
+    pub score: f64,
+
+    /// The proportion (0..1) of all scored vulnerabilities with the same or a lower score.
+    pub percentile: f64,
+
+    /// The date (in RFC3339 format) of when the score was calculated.
+    #[serde(with = "time::serde::rfc3339")]
+    pub date: OffsetDateTime,
+}
+
+impl From<epss::Model> for EpssScore {
+    fn from(value: epss::Model) -> Self {
+        Self {
+            score: value.score,
+            percentile: value.percentile,
+            date: value.date,
+        }
+    }
+}
+
+impl From<epss_history::Model> for EpssScore {
+    fn from(value: epss_history::Model) -> Self {
+        Self {
+            score: value.score,
+            percentile: value.percentile,
+            date: value.date,
+        }
+    }
+}
+
+/// An entry of the CISA Known Exploited Vulnerabilities (KEV) catalog.
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema, PartialEq, Eq)]
+pub struct KevEntry {
+    /// The name of the vulnerability, if any.
+    #[schema(required)]
+    pub name: Option<String>,
+
+    /// The vendor or project of the affected product, if known.
+    #[schema(required)]
+    pub vendor_project: Option<String>,
+
+    /// The affected product, if known.
+    #[schema(required)]
+    pub product: Option<String>,
+
+    /// A short description of the vulnerability, if any.
+    #[schema(required)]
+    pub short_description: Option<String>,
+
+    /// The action required to remediate the vulnerability, if any.
+    #[schema(required)]
+    pub required_action: Option<String>,
+
+    /// Additional notes, if any.
+    #[schema(required)]
+    pub notes: Option<String>,
+
+    /// The date (in RFC3339 format) of when the vulnerability was added to the catalog.
+    #[serde(with = "time::serde::rfc3339")]
+    pub date_added: OffsetDateTime,
+
+    /// The date (in RFC3339 format) by which federal agencies must remediate the vulnerability, if any.
+    #[schema(required)]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub due_date: Option<OffsetDateTime>,
+
+    /// If the vulnerability is known to be used in ransomware campaigns.
+    pub known_ransomware_campaign_use: bool,
+
+    /// Associated CWE, if any.
+    pub cwes: Vec<String>,
+}
+
+impl From<kev::Model> for KevEntry {
+    fn from(value: kev::Model) -> Self {
+        Self {
+            name: value.name,
+            vendor_project: value.vendor_project,
+            product: value.product,
+            short_description: value.short_description,
+            required_action: value.required_action,
+            notes: value.notes,
+            date_added: value.date_added,
+            due_date: value.due_date,
+            known_ransomware_campaign_use: value.known_ransomware_campaign_use,
+            cwes: value.cwes.unwrap_or_default(),
+        }
+    }
+}
@@ -1,8 +1,10 @@
 mod details;
+mod exploitation;
 mod summary;
 
 use async_graphql::SimpleObject;
 pub use details::*;
+pub use exploitation::*;
 use sea_orm::{ColumnTrait, ConnectionTrait, ModelTrait, QueryFilter};
 pub use summary::*;
 
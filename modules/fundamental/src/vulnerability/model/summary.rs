@@ -1,13 +1,15 @@
 use crate::{
-    vulnerability::model::{VulnerabilityAdvisoryHead, VulnerabilityHead},
+    vulnerability::model::{EpssScore, KevEntry, VulnerabilityAdvisoryHead, VulnerabilityHead},
     Error,
 };
+use itertools::izip;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, LoaderTrait, QueryFilter};
 use serde::{Deserialize, Serialize};
 use trustify_common::memo::Memo;
 use trustify_cvss::cvss3::severity::Severity;
 use trustify_entity::{
-    advisory, advisory_vulnerability, cvss3, vulnerability, vulnerability_description,
+    advisory, advisory_vulnerability, cvss3, cvss4, epss, kev, vulnerability,
+    vulnerability_description,
 };
 use trustify_module_ingestor::common::{Deprecation, DeprecationExt};
 use utoipa::ToSchema;
@@ -25,6 +27,14 @@ pub struct VulnerabilitySummary {
     #[schema(required)]
     pub average_score: Option<f64>,
 
+    /// The latest EPSS score of the vulnerability, if any.
+    #[schema(required)]
+    pub epss: Option<EpssScore>,
+
+    /// The entry of the vulnerability in the CISA Known Exploited Vulnerabilities catalog, if any.
+    #[schema(required)]
+    pub kev: Option<KevEntry>,
+
     // All advisories related to this vulnerability
     pub advisories: Vec<VulnerabilityAdvisoryHead>,
 }
@@ -45,6 +55,9 @@ impl VulnerabilitySummary {
             .await?;
 
         let vuln_cvss3s = vulnerabilities.load_many(cvss3::Entity, tx).await?;
+        let vuln_cvss4s = vulnerabilities.load_many(cvss4::Entity, tx).await?;
+        let vuln_epss = vulnerabilities.load_one(epss::Entity, tx).await?;
+        let vuln_kevs = vulnerabilities.load_one(kev::Entity, tx).await?;
 
         let descriptions = vulnerabilities
             .load_many(
@@ -56,14 +69,25 @@ impl VulnerabilitySummary {
 
         let mut summaries = Vec::new();
 
-        for ((((vuln, advisories), (average_score, average_severity)), vuln_cvss3s), description) in
-            vulnerabilities
-                .iter()
-                .zip(advisories.iter())
-                .zip(averages.iter())
-                .zip(vuln_cvss3s.iter())
-                .zip(descriptions.iter())
-        {
+        for (
+            vuln,
+            advisories,
+            (average_score, average_severity),
+            vuln_cvss3s,
+            vuln_cvss4s,
+            description,
+            epss,
+            kev,
+        ) in izip!(
+            vulnerabilities,
+            &advisories,
+            averages,
+            &vuln_cvss3s,
+            &vuln_cvss4s,
+            &descriptions,
+            vuln_epss,
+            vuln_kevs,
+        ) {
             summaries.push(VulnerabilitySummary {
                 head: VulnerabilityHead::from_vulnerability_entity(
                     vuln,
@@ -73,10 +97,13 @@ impl VulnerabilitySummary {
                 .await?,
                 average_severity: *average_severity,
                 average_score: *average_score,
+                epss: epss.map(Into::into),
+                kev: kev.map(Into::into),
                 advisories: VulnerabilityAdvisoryHead::from_entities(
                     vuln,
                     advisories,
                     vuln_cvss3s,
+                    vuln_cvss4s,
                     tx,
                 )
                 .await?,
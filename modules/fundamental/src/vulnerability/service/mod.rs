@@ -2,8 +2,12 @@ use crate::{
     vulnerability::model::{VulnerabilityDetails, VulnerabilitySummary},
     Error,
 };
-use sea_orm::{prelude::*, EntityTrait, FromQueryResult, IntoIdentity, QuerySelect, QueryTrait};
-use sea_query::{ColumnRef, Func, IntoColumnRef, IntoIden, SimpleExpr};
+use sea_orm::{
+    prelude::*, EntityTrait, FromQueryResult, IntoIdentity, QuerySelect, QueryTrait, RelationTrait,
+};
+use sea_query::{
+    ColumnRef, Expr, Func, IntoIden, JoinType, SelectStatement, SimpleExpr, UnionType,
+};
 use trustify_common::{
     db::{
         limiter::LimiterAsModelTrait,
@@ -14,7 +18,7 @@ use trustify_common::{
 };
 use trustify_entity::{
     cvss3::{self, Severity},
-    vulnerability,
+    cvss4, epss, kev, vulnerability,
 };
 use trustify_module_ingestor::common::Deprecation;
 
@@ -33,24 +37,60 @@ impl VulnerabilityService {
         deprecation: Deprecation,
         connection: &C,
     ) -> Result<PaginatedResults<VulnerabilitySummary>, Error> {
-        let inner_query = vulnerability::Entity::find()
-            .left_join(cvss3::Entity)
+        // all CVSS scores, regardless of their version
+        let scores = SelectStatement::new()
+            .columns([cvss3::Column::VulnerabilityId, cvss3::Column::Score])
+            .from(cvss3::Entity)
+            .union(
+                UnionType::All,
+                SelectStatement::new()
+                    .columns([cvss4::Column::VulnerabilityId, cvss4::Column::Score])
+                    .from(cvss4::Entity)
+                    .to_owned(),
+            )
+            .to_owned();
+
+        let mut inner_query = vulnerability::Entity::find()
             .expr_as_(
-                SimpleExpr::FunctionCall(Func::avg(SimpleExpr::Column(
-                    trustify_entity::cvss3::Column::Score.into_column_ref(),
-                ))),
+                SimpleExpr::FunctionCall(Func::avg(Expr::col((
+                    "cvss".into_identity(),
+                    cvss3::Column::Score,
+                )))),
                 "average_score",
             )
             .expr_as_(
                 SimpleExpr::FunctionCall(Func::cust("cvss3_severity".into_identity()).arg(
-                    SimpleExpr::FunctionCall(Func::avg(SimpleExpr::Column(
-                        trustify_entity::cvss3::Column::Score.into_column_ref(),
-                    ))),
+                    SimpleExpr::FunctionCall(Func::avg(Expr::col((
+                        "cvss".into_identity(),
+                        cvss3::Column::Score,
+                    )))),
                 )),
                 "average_severity",
             )
+            .expr_as_(
+                SimpleExpr::FunctionCall(Func::max(Expr::col((epss::Entity, epss::Column::Score)))),
+                "epss",
+            )
+            .expr_as_(
+                Expr::expr(Func::count(Expr::col((
+                    kev::Entity,
+                    kev::Column::VulnerabilityId,
+                ))))
+                .gt(0),
+                "kev",
+            )
+            .join(JoinType::LeftJoin, vulnerability::Relation::Epss.def())
+            .join(JoinType::LeftJoin, vulnerability::Relation::Kev.def())
             .group_by(vulnerability::Column::Id);
 
+        QueryTrait::query(&mut inner_query).join_subquery(
+            JoinType::LeftJoin,
+            scores,
+            "cvss".into_identity(),
+            Expr::col(("cvss".into_identity(), cvss3::Column::VulnerabilityId))
+                .equals((vulnerability::Entity, vulnerability::Column::Id)),
+        );
+
         let mut outer_query = vulnerability::Entity::find();
 
         // Alias the inner query as exactly the table the entity is expecting
@@ -91,6 +131,8 @@ impl VulnerabilityService {
                         }
                         .def(),
                     )
+                    .add_column("epss", ColumnType::Double.def())
+                    .add_column("kev", ColumnType::Boolean.def())
                     .translator(|f, op, v| match (f, v) {
                         // v = "" for all sort fields
                         ("average_severity", "") => Some(format!("average_score:{op}")),
@@ -2,12 +2,14 @@ use std::str::FromStr;
 
 use crate::purl::service::PurlService;
 use crate::sbom::service::SbomService;
+use crate::vulnerability::model::ProductReference;
 use crate::vulnerability::service::VulnerabilityService;
 use test_context::test_context;
 use test_log::test;
 use trustify_common::db::query::{q, Query};
 use trustify_common::model::Paginated;
 use trustify_common::purl::Purl;
+use trustify_entity::{flag::FlagLabel, remediation::RemediationCategory};
 use trustify_test_context::TrustifyContext;
 
 #[test_context(TrustifyContext)]
@@ -414,3 +416,99 @@ async fn vulnerability_queries(ctx: &TrustifyContext) -> Result<(), anyhow::Erro
 
     Ok(())
 }
+
+#[test_context(TrustifyContext)]
+#[test(actix_web::test)]
+async fn exploitation(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let service = VulnerabilityService::new();
+
+    // EPSS scores are only stored for known vulnerabilities
+    ctx.ingest_documents([
+        "kev/known_exploited_vulnerabilities.json",
+        "epss/epss_scores-2024-10-15.csv",
+        "epss/epss_scores-2024-10-16.csv",
+    ])
+    .await?;
+
+    let vulns = service
+        .fetch_vulnerabilities(
+            q("kev=true"),
+            Paginated::default(),
+            Default::default(),
+            &ctx.db,
+        )
+        .await?;
+    assert_eq!(3, vulns.items.len());
+    assert!(vulns.items.iter().all(|vuln| vuln.kev.is_some()));
+
+    let vulns = service
+        .fetch_vulnerabilities(
+            q("epss>0.5").sort("epss:desc"),
+            Paginated::default(),
+            Default::default(),
+            &ctx.db,
+        )
+        .await?;
+    assert_eq!(
+        vec!["CVE-2021-44228", "CVE-2014-0160", "CVE-2023-44487"],
+        vulns
+            .items
+            .iter()
+            .map(|vuln| vuln.head.identifier.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    let vuln = service
+        .fetch_vulnerability("CVE-2021-44228", Default::default(), &ctx.db)
+        .await?
+        .expect("must be found");
+    assert_eq!(vuln.epss.map(|epss| epss.score), Some(0.97565));
+    assert_eq!(2, vuln.epss_history.len());
+    assert!(vuln.epss_history[0].date < vuln.epss_history[1].date);
+    assert!(
+        vuln.kev
+            .expect("must be known")
+            .known_ransomware_campaign_use
+    );
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext)]
+#[test(actix_web::test)]
+async fn remediations(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    let service = VulnerabilityService::new();
+
+    ctx.ingest_document("csaf/cve-2023-0044.json").await?;
+
+    let vuln = service
+        .fetch_vulnerability("CVE-2023-0044", Default::default(), &ctx.db)
+        .await?
+        .expect("must be found");
+    assert_eq!(1, vuln.advisories.len());
+    let advisory = &vuln.advisories[0];
+
+    assert_eq!(4, advisory.remediations.len());
+    let fix = advisory
+        .remediations
+        .iter()
+        .find(|r| r.url.as_deref() == Some("https://access.redhat.com/errata/RHSA-2023:1006"))
+        .expect("must be found");
+    assert_eq!(fix.category, RemediationCategory::VendorFix);
+    assert_eq!(
+        fix.applies_to.products,
+        vec![ProductReference {
+            name: "Red Hat build of Quarkus 2.7.7".to_string(),
+            package: None,
+        }]
+    );
+
+    assert_eq!(1, advisory.threats.len());
+    assert_eq!(advisory.threats[0].details, "Low");
+
+    assert_eq!(1, advisory.flags.len());
+    assert_eq!(advisory.flags[0].label, FlagLabel::VulnerableCodeNotPresent);
+    assert_eq!(9, advisory.flags[0].applies_to.products.len());
+
+    Ok(())
+}
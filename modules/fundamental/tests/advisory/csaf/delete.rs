@@ -1,11 +1,11 @@
 #![allow(clippy::expect_used)]
 
-use super::prepare_ps_state_change;
+use super::{prepare_ps_state_change, ACTION_STATEMENT, IMPACT_STATEMENT};
 use test_context::test_context;
 use test_log::test;
 use trustify_common::purl::Purl;
 use trustify_module_fundamental::{
-    advisory::service::AdvisoryService,
+    advisory::{model::StatusInformation, service::AdvisoryService},
     purl::{
         model::details::purl::{PurlStatus, StatusContext},
         service::PurlService,
@@ -135,6 +135,11 @@ async fn delete_check_vulns(ctx: &TrustifyContext) -> anyhow::Result<()> {
             context: Some(StatusContext::Cpe(
                 "cpe:/a:redhat:jboss_enterprise_application_platform:7.4:*:el9:*".to_string()
             )),
+            information: StatusInformation {
+                justification: None,
+                impact_statement: Some(IMPACT_STATEMENT.to_string()),
+                action_statement: Some(ACTION_STATEMENT.to_string()),
+            },
         }]
     );
 
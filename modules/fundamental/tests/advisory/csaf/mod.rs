@@ -9,6 +9,12 @@ use csaf::Csaf;
 use trustify_module_ingestor::model::IngestResult;
 use trustify_test_context::{document_bytes, TrustifyContext};
 
+/// The impact of CVE-2023-33201 on the product changed by [`prepare_ps_state_change`].
+const IMPACT_STATEMENT: &str = "Moderate";
+
+/// The remediation of CVE-2023-33201 for the product changed by [`prepare_ps_state_change`].
+const ACTION_STATEMENT: &str = "Before applying this update, make sure all previously released errata relevant to your system have been applied.\n\nFor details on how to apply this update, refer to:\n\nhttps://access.redhat.com/articles/11258";
+
 /// Ingest a document twice, mutating it using the provided closure.
 async fn twice<M1, M2>(
     ctx: &TrustifyContext,
@@ -1,10 +1,11 @@
 #![allow(clippy::expect_used)]
 
-use super::{prepare_ps_state_change, twice};
+use super::{prepare_ps_state_change, twice, ACTION_STATEMENT, IMPACT_STATEMENT};
 use test_context::test_context;
 use test_log::test;
 use trustify_common::purl::Purl;
 use trustify_module_fundamental::{
+    advisory::model::StatusInformation,
     purl::{
         model::details::purl::{PurlStatus, StatusContext},
         service::PurlService,
@@ -147,6 +148,11 @@ async fn change_ps_list_vulns(ctx: &TrustifyContext) -> anyhow::Result<()> {
             context: Some(StatusContext::Cpe(
                 "cpe:/a:redhat:jboss_enterprise_application_platform:7.4:*:el9:*".to_string()
             )),
+            information: StatusInformation {
+                justification: None,
+                impact_statement: Some(IMPACT_STATEMENT.to_string()),
+                action_statement: Some(ACTION_STATEMENT.to_string()),
+            },
         }]
     );
 
@@ -237,6 +243,11 @@ async fn change_ps_list_vulns_all(ctx: &TrustifyContext) -> anyhow::Result<()> {
             context: Some(StatusContext::Cpe(
                 "cpe:/a:redhat:jboss_enterprise_application_platform:7.4:*:el9:*".to_string()
             )),
+            information: StatusInformation {
+                justification: None,
+                impact_statement: Some(IMPACT_STATEMENT.to_string()),
+                action_statement: Some(ACTION_STATEMENT.to_string()),
+            },
         }]
     );
     assert_eq!(
@@ -251,6 +262,11 @@ async fn change_ps_list_vulns_all(ctx: &TrustifyContext) -> anyhow::Result<()> {
             context: Some(StatusContext::Cpe(
                 "cpe:/a:redhat:jboss_enterprise_application_platform:7.4:*:el9:*".to_string()
             )),
+            information: StatusInformation {
+                justification: None,
+                impact_statement: Some(IMPACT_STATEMENT.to_string()),
+                action_statement: Some(ACTION_STATEMENT.to_string()),
+            },
         }]
     );
 
@@ -1,7 +1,16 @@
+use osv::schema::{Ecosystem, Event, RangeType, Severity, SeverityType};
+use std::str::FromStr;
 use test_context::test_context;
 use test_log::test;
-use trustify_module_ingestor::service::Format;
-use trustify_test_context::TrustifyContext;
+use trustify_common::purl::Purl;
+use trustify_module_fundamental::{
+    purl::service::PurlService, vulnerability::service::VulnerabilityService,
+};
+use trustify_module_ingestor::service::{
+    advisory::osv::{from_yaml, to_yaml},
+    Format,
+};
+use trustify_test_context::{document_bytes, TrustifyContext};
 
 /// Test for <https://github.com/gcmurphy/osv/pull/51>
 #[test_context(TrustifyContext)]
@@ -12,3 +21,238 @@ async fn pypa_yaml(ctx: &TrustifyContext) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Affected packages which can't be mapped to a purl must be reported.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn unmapped_ecosystem(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let data = document_bytes("osv/PYSEC-2024-55.yaml").await?;
+    let mut osv = from_yaml(&data)?;
+
+    let package = osv.affected[0]
+        .package
+        .as_mut()
+        .expect("must be expected test data");
+    package.ecosystem = Ecosystem::Android;
+    package.purl = None;
+
+    let result = ctx.ingest_read(to_yaml(&osv)?.as_bytes()).await?;
+
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0].contains("cipherbcrypt"));
+
+    Ok(())
+}
+
+/// Ensure that `ECOSYSTEM` ranges get evaluated using the version scheme of the ecosystem, even
+/// without a list of affected versions.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn ecosystem_range(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let data = document_bytes("osv/RSEC-2023-6.yaml").await?;
+    let mut osv = from_yaml(&data)?;
+
+    let affected = &mut osv.affected[0];
+    affected
+        .package
+        .as_mut()
+        .expect("must be expected test data")
+        .ecosystem = Ecosystem::PyPI;
+    affected.versions = None;
+    affected
+        .ranges
+        .as_mut()
+        .expect("must be expected test data")[0]
+        .events = vec![
+        Event::Introduced("0.2".into()),
+        Event::LastAffected("1.7".into()),
+    ];
+
+    ctx.ingest_read(to_yaml(&osv)?.as_bytes()).await?;
+
+    assert_affected(
+        ctx,
+        "pkg:pypi/commonmark",
+        [
+            ("0.1", false),
+            ("1.0rc1", true),
+            ("1.7", true),
+            ("1.7.1", false),
+        ],
+    )
+    .await
+}
+
+/// Ensure that the listed versions (tags) of a `GIT` range are marked affected, as the commits of
+/// the events can't be compared with them.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn git_range(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let data = document_bytes("osv/RSEC-2023-6.yaml").await?;
+    let mut osv = from_yaml(&data)?;
+
+    let range = &mut osv.affected[0]
+        .ranges
+        .as_mut()
+        .expect("must be expected test data")[0];
+    range.range_type = RangeType::Git;
+    range.repo = Some("https://github.com/r-lib/commonmark".into());
+    range.events = vec![
+        Event::Introduced("3ef9ea5e5e3ba6a5ee1d5e6c1fe1e18d9a5e4d4b".into()),
+        Event::Fixed("c4bd2b6b9a3d7a2cc1b06ee1a1c3e8f45d1d8b0c".into()),
+    ];
+
+    ctx.ingest_read(to_yaml(&osv)?.as_bytes()).await?;
+
+    assert_affected(
+        ctx,
+        "pkg:cran/commonmark",
+        [("0.2", true), ("1.0", true), ("1.7", true), ("1.8", false)],
+    )
+    .await
+}
+
+/// Ecosystems without a strict version scheme must still mark the listed versions affected.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn ecosystem_range_without_scheme(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let data = document_bytes("osv/RSEC-2023-6.yaml").await?;
+    let mut osv = from_yaml(&data)?;
+
+    let affected = &mut osv.affected[0];
+    affected
+        .package
+        .as_mut()
+        .expect("must be expected test data")
+        .ecosystem = Ecosystem::RubyGems;
+    affected.versions = Some(vec!["2.0.0.beta1".into(), "2.0.0".into(), "2.0.1".into()]);
+    affected
+        .ranges
+        .as_mut()
+        .expect("must be expected test data")[0]
+        .events = vec![
+        Event::Introduced("2.0.0.beta1".into()),
+        Event::Fixed("2.0.1".into()),
+    ];
+
+    ctx.ingest_read(to_yaml(&osv)?.as_bytes()).await?;
+
+    assert_affected(
+        ctx,
+        "pkg:gem/commonmark",
+        [("2.0.0.beta1", true), ("2.0.0", true), ("2.0.1", false)],
+    )
+    .await
+}
+
+/// Assert if versions of a package are affected by any ingested advisory.
+async fn assert_affected(
+    ctx: &TrustifyContext,
+    purl: &str,
+    versions: impl IntoIterator<Item = (&str, bool)>,
+) -> anyhow::Result<()> {
+    let service = PurlService::new();
+
+    for (version, affected) in versions {
+        let purl = Purl::from_str(&format!("{purl}@{version}"))?;
+        ctx.ingestor
+            .graph()
+            .ingest_qualified_package(&purl, &ctx.db)
+            .await?;
+
+        let details = service
+            .purl_by_purl(&purl, Default::default(), &ctx.db)
+            .await?
+            .expect("must be found");
+
+        assert_eq!(
+            details
+                .advisories
+                .iter()
+                .flat_map(|advisory| &advisory.status)
+                .any(|status| status.status == "affected"),
+            affected,
+            "{version}"
+        );
+    }
+
+    Ok(())
+}
+
+/// CVSS v4 scores must be ingested, and be part of the average score of the vulnerability.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn cvss4_score(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let data = document_bytes("osv/RSEC-2023-6.yaml").await?;
+    let mut osv = from_yaml(&data)?;
+
+    osv.severity = Some(vec![
+        Severity {
+            severity_type: SeverityType::CVSSv3,
+            score: "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H".into(),
+        },
+        Severity {
+            severity_type: SeverityType::CVSSv4,
+            score: "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N".into(),
+        },
+    ]);
+
+    ctx.ingest_read(to_yaml(&osv)?.as_bytes()).await?;
+
+    let vuln = VulnerabilityService::new()
+        .fetch_vulnerability("CVE-2020-5238", Default::default(), &ctx.db)
+        .await?
+        .expect("must be found");
+
+    // average of 9.8 (v3) and 9.3 (v4)
+    assert_eq!(vuln.average_score, Some(9.55));
+    assert_eq!(
+        vuln.average_severity,
+        Some(trustify_cvss::cvss3::severity::Severity::Critical)
+    );
+    assert_eq!(
+        vuln.advisories[0].cvss4_scores,
+        vec!["CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N".to_string()]
+    );
+
+    Ok(())
+}
+
+/// CVSS v2 scores and CVSS v3 temporal metrics must be retained, but not change the average score.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn cvss2_score(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let data = document_bytes("osv/RSEC-2023-6.yaml").await?;
+    let mut osv = from_yaml(&data)?;
+
+    osv.severity = Some(vec![
+        Severity {
+            severity_type: SeverityType::CVSSv2,
+            score: "AV:N/AC:L/Au:N/C:P/I:P/A:P".into(),
+        },
+        Severity {
+            severity_type: SeverityType::CVSSv3,
+            score: "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C".into(),
+        },
+    ]);
+
+    ctx.ingest_read(to_yaml(&osv)?.as_bytes()).await?;
+
+    let vuln = VulnerabilityService::new()
+        .fetch_vulnerability("CVE-2020-5238", Default::default(), &ctx.db)
+        .await?
+        .expect("must be found");
+
+    // only the v3 base score
+    assert_eq!(vuln.average_score, Some(9.8));
+    assert_eq!(
+        vuln.advisories[0].cvss2_scores,
+        vec!["AV:N/AC:L/Au:N/C:P/I:P/A:P".to_string()]
+    );
+    assert_eq!(
+        vuln.advisories[0].cvss3_scores,
+        vec!["CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C".to_string()]
+    );
+
+    Ok(())
+}
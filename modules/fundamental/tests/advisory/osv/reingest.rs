@@ -125,6 +125,7 @@ async fn withdrawn(ctx: &TrustifyContext) -> anyhow::Result<()> {
             },
             status: "affected".to_string(),
             context: None,
+            information: Default::default(),
         }]
     );
     assert_eq!(
@@ -137,6 +138,7 @@ async fn withdrawn(ctx: &TrustifyContext) -> anyhow::Result<()> {
             },
             status: "affected".to_string(),
             context: None,
+            information: Default::default(),
         }]
     );
 
@@ -25,7 +25,8 @@ pub fn configure(svc: &mut utoipa_actix_web::service_config::ServiceConfig, db:
         .service(delete)
         .service(get_reports)
         .service(set_enabled)
-        .service(force);
+        .service(force)
+        .service(cancel);
 }
 
 #[utoipa::path(
@@ -248,6 +249,38 @@ async fn force(
     Ok(HttpResponse::NoContent().finish())
 }
 
+#[utoipa::path(
+    tag = "importer",
+    operation_id = "cancelImporter",
+    params(
+        ("name", Path, description = "The name of the importer"),
+        ("if-match"=Option<String>, Header, description = "The revision to update"),
+    ),
+    responses(
+        (status = 204, description = "Requested the current run to be canceled"),
+        (status = 404, description = "An importer with that name does not exist"),
+        (status = 409, description = "The importer is not running"),
+        (status = 412, description = "The provided if-match header did not match the stored revision"),
+    )
+)]
+#[post("/v1/importer/{name}/cancel")]
+/// Cancel the current run of an importer
+async fn cancel(
+    service: web::Data<ImporterService>,
+    name: web::Path<String>,
+    web::Header(if_match): web::Header<IfMatch>,
+    _: Require<UpdateImporter>,
+) -> Result<impl Responder, Error> {
+    let revision = match &if_match {
+        IfMatch::Any => None,
+        IfMatch::Items(items) => items.first().map(|etag| etag.tag()),
+    };
+
+    service.cancel(&name, revision).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 #[utoipa::path(
     tag = "importer",
     operation_id = "deleteImporter",
@@ -0,0 +1,44 @@
+use super::*;
+
+/// Importing the daily scores of the FIRST Exploit Prediction Scoring System (EPSS).
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct EpssImporter {
+    #[serde(flatten)]
+    pub common: CommonImporter,
+
+    /// The HTTP(S) URL of the file.
+    #[serde(default = "default::source")]
+    pub source: String,
+}
+
+pub const DEFAULT_SOURCE_EPSS: &str = "https://epss.cyentia.com/epss_scores-current.csv.gz";
+
+mod default {
+    pub fn source() -> String {
+        super::DEFAULT_SOURCE_EPSS.into()
+    }
+}
+
+impl Deref for EpssImporter {
+    type Target = CommonImporter;
+
+    fn deref(&self) -> &Self::Target {
+        &self.common
+    }
+}
+
+impl DerefMut for EpssImporter {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.common
+    }
+}
@@ -4,16 +4,24 @@ mod clearly_defined;
 mod csaf;
 mod cve;
 mod cwe;
+mod epss;
+mod kev;
+mod openvex;
 mod osv;
 mod sbom;
+mod schedule;
 
 pub use clearly_defined::*;
 pub use clearly_defined_curation::*;
 pub use csaf::*;
 pub use cve::*;
 pub use cwe::*;
+pub use epss::*;
+pub use kev::*;
+pub use openvex::*;
 pub use osv::*;
 pub use sbom::*;
+pub use schedule::*;
 
 use crate::runner::report::Report;
 use std::{
@@ -21,7 +29,7 @@ use std::{
     time::Duration,
 };
 use time::OffsetDateTime;
-use trustify_common::model::Revisioned;
+use trustify_common::{model::Revisioned, serde::is_default};
 use trustify_entity::{
     importer::{self, Model},
     importer_report,
@@ -63,6 +71,35 @@ impl From<importer::State> for State {
     }
 }
 
+/// The outcome of an importer run
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Outcome {
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl From<importer_report::Outcome> for Outcome {
+    fn from(value: importer_report::Outcome) -> Self {
+        match value {
+            importer_report::Outcome::Succeeded => Self::Succeeded,
+            importer_report::Outcome::Failed => Self::Failed,
+            importer_report::Outcome::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+impl From<Outcome> for importer_report::Outcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Succeeded => Self::Succeeded,
+            Outcome::Failed => Self::Failed,
+            Outcome::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
 impl From<State> for importer::State {
     fn from(value: State) -> Self {
         match value {
@@ -105,6 +142,37 @@ pub struct ImporterData {
     /// The continuation token of the importer.
     #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
     pub continuation: serde_json::Value,
+
+    /// The next time the importer is scheduled to run, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub next_run: Option<OffsetDateTime>,
+
+    /// The lease of the current run, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease: Option<Lease>,
+
+    /// A request to cancel the current run is pending.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub cancel_requested: bool,
+}
+
+/// The claim of a node on an importer run.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Lease {
+    /// The node holding the lease
+    pub owner: String,
+    /// The point in time the lease expires, unless renewed
+    #[serde(with = "time::serde::rfc3339")]
+    pub expires: OffsetDateTime,
+}
+
+impl Lease {
+    /// Check if the lease expired at the provided point in time
+    pub fn is_expired(&self, now: OffsetDateTime) -> bool {
+        self.expires <= now
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -157,6 +225,9 @@ pub enum ImporterConfiguration {
     ClearlyDefined(ClearlyDefinedImporter),
     ClearlyDefinedCuration(ClearlyDefinedCurationImporter),
     Cwe(CweImporter),
+    Epss(EpssImporter),
+    Kev(KevImporter),
+    OpenVex(OpenVexImporter),
 }
 
 impl Deref for ImporterConfiguration {
@@ -171,6 +242,9 @@ impl Deref for ImporterConfiguration {
             Self::ClearlyDefined(importer) => &importer.common,
             Self::ClearlyDefinedCuration(importer) => &importer.common,
             Self::Cwe(importer) => &importer.common,
+            Self::Epss(importer) => &importer.common,
+            Self::Kev(importer) => &importer.common,
+            Self::OpenVex(importer) => &importer.common,
         }
     }
 }
@@ -185,6 +259,9 @@ impl DerefMut for ImporterConfiguration {
             Self::ClearlyDefined(importer) => &mut importer.common,
             Self::ClearlyDefinedCuration(importer) => &mut importer.common,
             Self::Cwe(importer) => &mut importer.common,
+            Self::Epss(importer) => &mut importer.common,
+            Self::Kev(importer) => &mut importer.common,
+            Self::OpenVex(importer) => &mut importer.common,
         }
     }
 }
@@ -210,6 +287,16 @@ pub struct CommonImporter {
     #[schemars(with = "HumantimeSerde")]
     pub period: Duration,
 
+    /// A cron expression (evaluated in UTC) defining when to run the importer, taking precedence over the period.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    #[schemars(with = "Option<String>")]
+    pub schedule: Option<Schedule>,
+
+    /// Time windows during which the importer must not be started.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blackout_windows: Vec<BlackoutWindow>,
+
     /// A description for users.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -238,27 +325,45 @@ impl TryFrom<Model> for Importer {
             progress_current,
             progress_total,
             progress_message,
+            lease_owner,
+            lease_expires,
+            cancel_requested,
             continuation,
             revision: _,
         }: Model,
     ) -> Result<Self, Self::Error> {
+        let now = OffsetDateTime::now_utc();
+        let state = State::from(state);
+        let configuration: ImporterConfiguration = serde_json::from_value(configuration)?;
+
+        // while running, the next run can only be evaluated once the current one is finished
+        let next_run = match state {
+            State::Waiting => configuration.next_run(last_run, now),
+            State::Running => None,
+        };
+
         Ok(Self {
             name,
             data: ImporterData {
-                state: state.into(),
+                state,
                 last_change,
                 last_success,
                 last_run,
                 last_error,
                 progress: into_progress(
                     last_change,
-                    OffsetDateTime::now_utc(),
+                    now,
                     progress_current,
                     progress_total,
                     progress_message,
                 ),
                 continuation: continuation.unwrap_or_default(),
-                configuration: serde_json::from_value(configuration)?,
+                next_run,
+                lease: lease_owner
+                    .zip(lease_expires)
+                    .map(|(owner, expires)| Lease { owner, expires }),
+                cancel_requested,
+                configuration,
             },
         })
     }
@@ -279,6 +384,8 @@ pub struct ImporterReport {
     /// Errors captured by the report
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// The outcome of the run
+    pub outcome: Outcome,
     /// Detailed report information
     pub report: Option<Report>,
 }
@@ -290,6 +397,7 @@ impl From<importer_report::Model> for ImporterReport {
             importer,
             creation,
             error,
+            outcome,
             report,
         } = value;
         Self {
@@ -297,6 +405,7 @@ impl From<importer_report::Model> for ImporterReport {
             importer,
             creation,
             error,
+            outcome: outcome.into(),
             report: serde_json::from_value(report).ok(),
         }
     }
@@ -72,6 +72,8 @@ mod test {
                 common: CommonImporter {
                     disabled: false,
                     period: Duration::from_secs(30),
+                    schedule: None,
+                    blackout_windows: vec![],
                     description: None,
                     labels: Default::default(),
                 },
@@ -0,0 +1,234 @@
+use super::*;
+use chrono::{DateTime, Utc};
+use croner::Cron;
+use std::fmt;
+
+/// The maximum number of blackout windows to skip when looking for the next run.
+const MAX_BLACKOUT_SKIPS: usize = 100;
+
+/// A cron expression (e.g. `0 2 * * *`), evaluated in UTC.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Schedule {
+    expression: String,
+    cron: Cron,
+}
+
+impl Schedule {
+    /// The first occurrence after the provided point in time.
+    pub fn next_after(&self, time: OffsetDateTime) -> Option<OffsetDateTime> {
+        let next = self
+            .cron
+            .find_next_occurrence(&to_chrono(time)?, false)
+            .ok()?;
+        from_chrono(next)
+    }
+}
+
+impl TryFrom<String> for Schedule {
+    type Error = String;
+
+    fn try_from(expression: String) -> Result<Self, Self::Error> {
+        let cron = Cron::new(&expression)
+            .parse()
+            .map_err(|err| format!("invalid cron expression '{expression}': {err}"))?;
+        Ok(Self { expression, cron })
+    }
+}
+
+impl From<Schedule> for String {
+    fn from(value: Schedule) -> Self {
+        value.expression
+    }
+}
+
+impl PartialEq for Schedule {
+    fn eq(&self, other: &Self) -> bool {
+        self.expression == other.expression
+    }
+}
+
+impl Eq for Schedule {}
+
+impl fmt::Debug for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Schedule").field(&self.expression).finish()
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+/// A time window during which an importer must not be started.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BlackoutWindow {
+    /// A cron expression (evaluated in UTC) for the start of the window.
+    #[schema(value_type = String)]
+    #[schemars(with = "String")]
+    pub start: Schedule,
+
+    /// The duration of the window.
+    #[serde(with = "humantime_serde")]
+    #[schemars(with = "HumantimeSerde")]
+    pub duration: Duration,
+}
+
+impl BlackoutWindow {
+    /// If the point in time is inside the window, return the end of the window.
+    fn end_of(&self, time: OffsetDateTime) -> Option<OffsetDateTime> {
+        // the window contains `time` if it started less than `duration` before `time`
+        let start = self.start.next_after(time - self.duration)?;
+        (start <= time).then(|| start + self.duration)
+    }
+}
+
+impl CommonImporter {
+    /// Evaluate the next point in time the importer should run.
+    ///
+    /// If the importer has a schedule, it is used instead of the period. Importers which never ran
+    /// are due immediately. A run which is due in a blackout window gets postponed to the end of
+    /// the window.
+    ///
+    /// Returns `None` if the importer is disabled, or no next run could be found.
+    pub fn next_run(
+        &self,
+        last_run: Option<OffsetDateTime>,
+        now: OffsetDateTime,
+    ) -> Option<OffsetDateTime> {
+        if self.disabled {
+            return None;
+        }
+
+        let due = match (last_run, &self.schedule) {
+            (None, _) => now,
+            (Some(last_run), Some(schedule)) => schedule.next_after(last_run)?,
+            (Some(last_run), None) => last_run + self.period,
+        };
+
+        let mut next = due.max(now);
+
+        for _ in 0..MAX_BLACKOUT_SKIPS {
+            let Some(end) = self
+                .blackout_windows
+                .iter()
+                .filter_map(|window| window.end_of(next))
+                .max()
+            else {
+                return Some(next);
+            };
+            next = end;
+        }
+
+        log::warn!("Unable to find a next run outside of the blackout windows");
+        None
+    }
+}
+
+fn to_chrono(time: OffsetDateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(time.unix_timestamp(), time.nanosecond())
+}
+
+fn from_chrono(time: DateTime<Utc>) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp_nanos(time.timestamp_nanos_opt()?.into()).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::macros::datetime;
+
+    fn common(schedule: Option<&str>, blackout_windows: Vec<BlackoutWindow>) -> CommonImporter {
+        CommonImporter {
+            disabled: false,
+            period: Duration::from_secs(60 * 60),
+            schedule: schedule.map(|schedule| schedule.to_string().try_into().unwrap()),
+            blackout_windows,
+            description: None,
+            labels: Default::default(),
+        }
+    }
+
+    fn business_hours() -> BlackoutWindow {
+        BlackoutWindow {
+            start: "0 8 * * 1-5".to_string().try_into().unwrap(),
+            duration: Duration::from_secs(10 * 60 * 60),
+        }
+    }
+
+    #[test]
+    fn invalid_schedule() {
+        assert!(Schedule::try_from("foo".to_string()).is_err());
+        assert!(serde_json::from_str::<Schedule>(r#""61 * * * *""#).is_err());
+    }
+
+    #[test]
+    fn next_run_period() {
+        let now = datetime!(2024-11-04 12:00 UTC);
+        let common = common(None, vec![]);
+
+        assert_eq!(common.next_run(None, now), Some(now));
+        assert_eq!(
+            common.next_run(Some(datetime!(2024-11-04 11:30 UTC)), now),
+            Some(datetime!(2024-11-04 12:30 UTC))
+        );
+        // overdue runs are due now
+        assert_eq!(
+            common.next_run(Some(datetime!(2024-11-04 10:00 UTC)), now),
+            Some(now)
+        );
+    }
+
+    #[test]
+    fn next_run_schedule() {
+        let now = datetime!(2024-11-04 12:00 UTC);
+        let common = common(Some("0 2 * * *"), vec![]);
+
+        assert_eq!(
+            common.next_run(Some(datetime!(2024-11-04 02:00 UTC)), now),
+            Some(datetime!(2024-11-05 02:00 UTC))
+        );
+    }
+
+    #[test]
+    fn next_run_blackout() {
+        // a monday, during business hours
+        let now = datetime!(2024-11-04 12:00 UTC);
+        let common = common(None, vec![business_hours()]);
+
+        assert_eq!(
+            common.next_run(None, now),
+            Some(datetime!(2024-11-04 18:00 UTC))
+        );
+        // outside the window
+        assert_eq!(
+            common.next_run(None, datetime!(2024-11-04 19:00 UTC)),
+            Some(datetime!(2024-11-04 19:00 UTC))
+        );
+        // no window on a saturday
+        assert_eq!(
+            common.next_run(None, datetime!(2024-11-09 12:00 UTC)),
+            Some(datetime!(2024-11-09 12:00 UTC))
+        );
+    }
+
+    #[test]
+    fn next_run_disabled() {
+        let mut common = common(None, vec![]);
+        common.disabled = true;
+
+        assert_eq!(common.next_run(None, OffsetDateTime::now_utc()), None);
+    }
+}
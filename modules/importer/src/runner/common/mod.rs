@@ -3,6 +3,7 @@ use trustify_common::id::IdError;
 
 pub mod filter;
 pub mod processing_error;
+pub mod source;
 pub mod storage;
 pub mod validation;
 pub mod walker;
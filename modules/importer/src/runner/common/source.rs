@@ -0,0 +1,226 @@
+//! Importing whole documents, from a remote URL.
+
+use crate::runner::{
+    common::Error,
+    context::RunContext,
+    report::{Phase, ReportBuilder, ScannerError},
+    ImportRunner, RunOutput,
+};
+use anyhow::anyhow;
+use flate2::read::GzDecoder;
+use std::{io::Read, sync::Arc};
+use tokio::sync::Mutex;
+use tracing::instrument;
+use trustify_entity::labels::Labels;
+use trustify_module_ingestor::{
+    graph::Graph,
+    service::{Format, IngestorService},
+};
+
+/// The last modification of a source, used as continuation to skip unmodified sources.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LastModified(Option<String>);
+
+/// The HTTP(S) URL of a document.
+///
+/// Importers are configured through the API, so a source must never be a path on the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Source(String);
+
+impl Source {
+    pub fn new(source: &str) -> Result<Self, Error> {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            Ok(Self(source.to_string()))
+        } else {
+            Err(Error::Processing(anyhow!(
+                "Unsupported source, must be an HTTP(S) URL: {source}"
+            )))
+        }
+    }
+
+    /// Fetch the document, unless it wasn't modified since the last run.
+    #[instrument(ret, err)]
+    pub async fn fetch(
+        &self,
+        since: &LastModified,
+    ) -> Result<Option<(Document, LastModified)>, Error> {
+        let response = reqwest::get(&self.0).await?.error_for_status()?;
+
+        let last_modified = response
+            .headers()
+            .get("Last-Modified")
+            .map(|inner| inner.to_str())
+            .transpose()?
+            .map(|inner| inner.to_string());
+
+        if last_modified.is_some() && since.0 == last_modified {
+            return Ok(None);
+        }
+
+        let document = Document {
+            name: self.0.clone(),
+            data: response.bytes().await?.into(),
+        };
+
+        Ok(Some((document, LastModified(last_modified))))
+    }
+}
+
+/// A document retrieved from a [`Source`].
+pub struct Document {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl std::fmt::Debug for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Document")
+            .field("name", &self.name)
+            .field("len", &self.data.len())
+            .finish()
+    }
+}
+
+impl Document {
+    /// Get the content, decompressed if the name indicates it's gzipped.
+    pub fn content(self) -> Result<Vec<u8>, Error> {
+        if self.name.ends_with(".gz") {
+            let mut content = Vec::new();
+            GzDecoder::new(self.data.as_slice()).read_to_end(&mut content)?;
+            Ok(content)
+        } else {
+            Ok(self.data)
+        }
+    }
+}
+
+/// Ingest the document of a source with a fixed format.
+pub struct SourceWalker {
+    continuation: LastModified,
+    source: String,
+    format: Format,
+    labels: Labels,
+    ingestor: IngestorService,
+    report: Arc<Mutex<ReportBuilder>>,
+}
+
+impl SourceWalker {
+    pub fn new(
+        source: impl Into<String>,
+        format: Format,
+        ingestor: IngestorService,
+        report: Arc<Mutex<ReportBuilder>>,
+    ) -> Self {
+        Self {
+            continuation: LastModified(None),
+            source: source.into(),
+            format,
+            labels: Labels::new(),
+            ingestor,
+            report,
+        }
+    }
+
+    /// Set a continuation token from a previous run.
+    pub fn continuation(mut self, continuation: LastModified) -> Self {
+        self.continuation = continuation;
+        self
+    }
+
+    /// Set the labels to apply to all ingested documents.
+    pub fn labels(mut self, labels: Labels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Run the walker
+    #[instrument(skip(self, context), ret)]
+    pub async fn run(self, context: &impl RunContext) -> Result<LastModified, Error> {
+        let Some((document, last_modified)) =
+            Source::new(&self.source)?.fetch(&self.continuation).await?
+        else {
+            // no change, just keep the same continuation
+            return Ok(self.continuation);
+        };
+
+        context.check_canceled(|| Error::Canceled).await?;
+
+        let name = document.name.clone();
+        self.report.lock().await.tick();
+
+        let result = match document.content() {
+            Ok(content) => self
+                .ingestor
+                .ingest(
+                    &content,
+                    self.format,
+                    self.labels
+                        .clone()
+                        .add("source", &self.source)
+                        .add("file", &name),
+                    None,
+                )
+                .await
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+
+        match result {
+            Ok(()) => Ok(last_modified),
+            Err(err) => {
+                self.report.lock().await.add_error(Phase::Upload, name, err);
+                // had an error, keep the old continuation as active.
+                Ok(self.continuation)
+            }
+        }
+    }
+}
+
+impl ImportRunner {
+    /// Run an importer ingesting all documents of a source, using a fixed format.
+    #[instrument(skip(self, context), ret)]
+    pub(crate) async fn run_once_source(
+        &self,
+        context: impl RunContext + 'static,
+        format: Format,
+        source: &str,
+        labels: &Labels,
+        continuation: serde_json::Value,
+    ) -> Result<RunOutput, ScannerError> {
+        let ingestor = IngestorService::new(Graph::new(self.db.clone()), self.storage.clone());
+
+        let report = Arc::new(Mutex::new(ReportBuilder::new()));
+        let continuation = serde_json::from_value(continuation).unwrap_or_default();
+
+        let walker = SourceWalker::new(source, format, ingestor, report.clone())
+            .labels(
+                Labels::new()
+                    .add("importer", context.name())
+                    .extend(&labels.0),
+            )
+            .continuation(continuation);
+
+        match walker.run(&context).await {
+            Ok(continuation) => {
+                // extract the report
+                let report = match Arc::try_unwrap(report) {
+                    Ok(report) => report.into_inner(),
+                    Err(report) => report.lock().await.clone(),
+                }
+                .build();
+                Ok(RunOutput {
+                    report,
+                    continuation: serde_json::to_value(continuation).ok(),
+                })
+            }
+            Err(err) => Err(ScannerError::Normal {
+                err: err.into(),
+                output: RunOutput {
+                    report: report.lock().await.clone().build(),
+                    continuation: None,
+                },
+            }),
+        }
+    }
+}
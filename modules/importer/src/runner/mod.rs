@@ -6,6 +6,7 @@ pub mod context;
 pub mod csaf;
 pub mod cve;
 pub mod cwe;
+pub mod openvex;
 pub mod osv;
 pub mod progress;
 pub mod report;
@@ -20,6 +21,7 @@ use std::path::PathBuf;
 use time::OffsetDateTime;
 use tracing::instrument;
 use trustify_common::db::Database;
+use trustify_module_ingestor::service::Format;
 use trustify_module_storage::service::dispatch::DispatchBackend;
 
 pub struct ImportRunner {
@@ -59,6 +61,23 @@ impl ImportRunner {
             ImporterConfiguration::Cwe(cwe) => {
                 self.run_once_cwe_catalog(context, cwe, continuation).await
             }
+            ImporterConfiguration::Epss(epss) => {
+                self.run_once_source(
+                    context,
+                    Format::Epss,
+                    &epss.source,
+                    &epss.labels,
+                    continuation,
+                )
+                .await
+            }
+            ImporterConfiguration::Kev(kev) => {
+                self.run_once_source(context, Format::Kev, &kev.source, &kev.labels, continuation)
+                    .await
+            }
+            ImporterConfiguration::OpenVex(openvex) => {
+                self.run_once_openvex(context, openvex, continuation).await
+            }
         }
     }
 
@@ -0,0 +1,217 @@
+mod handler;
+
+use crate::{
+    model::OpenVexImporter,
+    runner::{
+        common::walker::{CallbackError, Callbacks, GitWalker},
+        context::RunContext,
+        report::{Phase, ReportBuilder, ScannerError},
+        RunOutput,
+    },
+};
+use handler::OpenVexHandler;
+use parking_lot::Mutex;
+use std::{path::Path, path::PathBuf, sync::Arc};
+use tokio::runtime::Handle;
+use tracing::instrument;
+use trustify_entity::labels::Labels;
+use trustify_module_ingestor::{
+    graph::Graph,
+    service::{Format, IngestorService},
+};
+
+struct Context<C: RunContext + 'static> {
+    context: C,
+    source: String,
+    labels: Labels,
+    report: Arc<Mutex<ReportBuilder>>,
+    ingestor: IngestorService,
+}
+
+impl<C: RunContext> Context<C> {
+    fn store(&self, path: &Path, data: Vec<u8>) -> anyhow::Result<()> {
+        self.report.lock().tick();
+
+        Handle::current().block_on(async {
+            self.ingestor
+                .ingest(
+                    &data,
+                    Format::OpenVex,
+                    Labels::new()
+                        .add("source", &self.source)
+                        .add("importer", self.context.name())
+                        .add("file", path.to_string_lossy())
+                        .extend(&self.labels.0),
+                    None,
+                )
+                .await
+        })?;
+
+        Ok(())
+    }
+}
+
+impl<C: RunContext> Callbacks<Vec<u8>> for Context<C> {
+    fn loading_error(&mut self, path: PathBuf, message: String) {
+        self.report
+            .lock()
+            .add_error(Phase::Validation, path.to_string_lossy(), message);
+    }
+
+    fn process(&mut self, path: &Path, vex: Vec<u8>) -> Result<(), CallbackError> {
+        if let Err(err) = self.store(path, vex) {
+            self.report
+                .lock()
+                .add_error(Phase::Upload, path.to_string_lossy(), err.to_string());
+        }
+
+        self.context.check_canceled_sync(|| CallbackError::Canceled)
+    }
+}
+
+impl super::ImportRunner {
+    #[instrument(skip(self), ret)]
+    pub async fn run_once_openvex(
+        &self,
+        context: impl RunContext + 'static,
+        openvex: OpenVexImporter,
+        continuation: serde_json::Value,
+    ) -> Result<RunOutput, ScannerError> {
+        let ingestor = IngestorService::new(Graph::new(self.db.clone()), self.storage.clone());
+
+        let report = Arc::new(Mutex::new(ReportBuilder::new()));
+
+        // progress reporting
+
+        let progress = context.progress(format!("Import OpenVEX: {}", openvex.source));
+
+        let handler = OpenVexHandler(Context {
+            context,
+            source: openvex.source.clone(),
+            labels: openvex.common.labels,
+            report: report.clone(),
+            ingestor,
+        });
+
+        // a git repository, which we clone or update. The source is provided through the API, so
+        // it must not be used as a path on the server.
+
+        let continuation = serde_json::from_value(continuation).unwrap_or_default();
+        let working_dir = self.create_working_dir("openvex", &openvex.source).await?;
+
+        let walker = GitWalker::new(openvex.source, handler)
+            .continuation(continuation)
+            .branch(openvex.branch)
+            .path(openvex.path)
+            .progress(progress);
+
+        let continuation = match working_dir {
+            Some(working_dir) => walker.working_dir(working_dir).run().await,
+            None => walker.run().await,
+        }
+        .map_err(|err| ScannerError::Critical(err.into()))?;
+
+        // extract the report
+
+        let report = match Arc::try_unwrap(report) {
+            Ok(report) => report.into_inner(),
+            Err(report) => report.lock().clone(),
+        }
+        .build();
+
+        // return
+
+        Ok(RunOutput {
+            report,
+            continuation: serde_json::to_value(continuation).ok(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{model::CommonImporter, runner::ImportRunner};
+    use git2::{Repository, Signature};
+    use sea_orm::{EntityTrait, PaginatorTrait};
+    use std::time::Duration;
+    use test_context::test_context;
+    use test_log::test;
+    use trustify_entity::advisory;
+    use trustify_module_storage::service::dispatch::DispatchBackend;
+    use trustify_test_context::{document_bytes, TrustifyContext};
+
+    #[derive(Debug)]
+    struct TestContext;
+
+    impl RunContext for TestContext {
+        fn name(&self) -> &str {
+            "test"
+        }
+
+        async fn is_canceled(&self) -> bool {
+            false
+        }
+    }
+
+    /// Create a git repository, containing one OpenVEX document.
+    async fn create_repository(path: &Path) -> anyhow::Result<()> {
+        let repo = Repository::init(path)?;
+
+        tokio::fs::create_dir(path.join("vex")).await?;
+        tokio::fs::write(
+            path.join("vex/git.openvex.json"),
+            document_bytes("openvex/git.openvex.json").await?,
+        )
+        .await?;
+
+        let mut index = repo.index()?;
+        index.add_path(Path::new("vex/git.openvex.json"))?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+        let signature = Signature::now("test", "test@example.com")?;
+        repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])?;
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn run_git(ctx: &TrustifyContext) -> anyhow::Result<()> {
+        let repository = tempfile::tempdir()?;
+        create_repository(repository.path()).await?;
+
+        let runner = ImportRunner {
+            db: ctx.db.clone(),
+            storage: DispatchBackend::Filesystem(ctx.storage.clone()),
+            working_dir: None,
+        };
+
+        let importer = OpenVexImporter {
+            common: CommonImporter {
+                disabled: false,
+                period: Duration::from_secs(300),
+                schedule: None,
+                blackout_windows: vec![],
+                description: None,
+                labels: Default::default(),
+            },
+            source: format!("file://{}", repository.path().display()),
+            branch: None,
+            path: Some("vex".into()),
+        };
+
+        let output = runner
+            .run_once_openvex(TestContext, importer, serde_json::Value::Null)
+            .await
+            .expect("must run");
+
+        assert_eq!(output.report.number_of_items, 1);
+        assert!(output.report.messages.is_empty());
+        // the commit, to continue from
+        assert!(output.continuation.is_some_and(|value| !value.is_null()));
+
+        assert_eq!(advisory::Entity::find().count(&ctx.db).await?, 1);
+
+        Ok(())
+    }
+}
@@ -23,13 +23,15 @@ pub struct ServiceRunContext {
 }
 
 impl ServiceRunContext {
-    pub fn new(service: ImporterService, name: String) -> Self {
+    /// Create a new context for a run, holding the lease of `owner`.
+    pub fn new(service: ImporterService, name: String, owner: String) -> Self {
         Self {
             name: name.clone(),
             state: Mutex::new(CheckCancellation::new(
                 service.clone(),
                 name,
-                Duration::from_secs(60),
+                owner,
+                Duration::from_secs(10),
             )),
             service,
         }
@@ -54,6 +56,7 @@ impl RunContext for ServiceRunContext {
 struct CheckCancellation {
     service: ImporterService,
     importer_name: String,
+    owner: String,
 
     canceled: bool,
     last_check: Instant,
@@ -61,10 +64,16 @@ struct CheckCancellation {
 }
 
 impl CheckCancellation {
-    pub fn new(service: ImporterService, importer_name: String, period: Duration) -> Self {
+    pub fn new(
+        service: ImporterService,
+        importer_name: String,
+        owner: String,
+        period: Duration,
+    ) -> Self {
         Self {
             service,
             importer_name,
+            owner,
             canceled: false,
             last_check: Instant::now(),
             period,
@@ -92,10 +101,19 @@ impl CheckCancellation {
     async fn perform_check(&self) -> anyhow::Result<bool> {
         let importer = self.service.read(&self.importer_name).await?;
 
-        // If we have a record, return its state.
+        // If we have a record, return its state. If a cancellation was requested, or another node
+        // took over the lease, we're canceled too.
         // If we don't have a record, we must have been deleted. Which also means we're canceled.
         Ok(importer
-            .map(|importer| importer.value.data.configuration.disabled)
+            .map(|importer| {
+                importer.value.data.configuration.disabled
+                    || importer.value.data.cancel_requested
+                    || importer
+                        .value
+                        .data
+                        .lease
+                        .is_none_or(|lease| lease.owner != self.owner)
+            })
             .unwrap_or(true))
     }
 }
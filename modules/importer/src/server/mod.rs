@@ -10,23 +10,48 @@ use crate::{
     server::context::ServiceRunContext,
     service::ImporterService,
 };
-use std::{path::PathBuf, time::Duration};
+use futures::{stream::FuturesUnordered, StreamExt};
+use std::{collections::HashSet, path::PathBuf, time::Duration};
 use time::OffsetDateTime;
 use tokio::time::MissedTickBehavior;
 use tracing::instrument;
-use trustify_common::db::Database;
+use trustify_common::{db::Database, model::Revisioned};
 use trustify_module_storage::service::dispatch::DispatchBackend;
+use uuid::Uuid;
+
+/// Configuration of the importer loop
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    /// The ID of this node, used to claim importer runs
+    pub node_id: String,
+    /// The maximum number of importers this node runs in parallel
+    pub concurrency: usize,
+    /// The time after which a claim on a run expires, unless it gets renewed
+    pub lease_duration: Duration,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            node_id: Uuid::new_v4().to_string(),
+            concurrency: 1,
+            lease_duration: Duration::from_secs(60),
+        }
+    }
+}
 
 /// run the importer loop
 pub async fn importer(
     db: Database,
     storage: DispatchBackend,
     working_dir: Option<PathBuf>,
+    config: ServerConfig,
 ) -> anyhow::Result<()> {
     Server {
         db,
         storage,
         working_dir,
+        config,
     }
     .run()
     .await
@@ -47,128 +72,281 @@ impl From<Report> for RunOutput {
     }
 }
 
-/// Single node, single process importer processor.
+/// Importer processor, coordinating with other nodes through leases on importer runs.
+///
+/// A node claims a run by updating the importer using its current revision, so that only one
+/// node can succeed. While running, the node renews its lease. If a node crashes, its lease
+/// expires and another node will pick up the importer.
 struct Server {
     db: Database,
     storage: DispatchBackend,
     working_dir: Option<PathBuf>,
+    config: ServerConfig,
 }
 
 impl Server {
-    #[instrument(skip_all, ret)]
+    #[instrument(skip_all, fields(node_id = %self.config.node_id), ret)]
     async fn run(&self) -> anyhow::Result<()> {
         let service = ImporterService::new(self.db.clone());
 
-        self.reset_all_jobs(&service).await?;
+        log::info!(
+            "Running importers as node {} (concurrency: {})",
+            self.config.node_id,
+            self.config.concurrency
+        );
 
         let mut interval = tokio::time::interval(Duration::from_secs(1));
         interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
+        let mut running = HashSet::new();
+        let mut runs = FuturesUnordered::new();
+
         loop {
-            interval.tick().await;
+            tokio::select! {
+                _ = interval.tick() => {}
+                Some((name, result)) = runs.next() => {
+                    running.remove(&name);
+                    if let Err(err) = result {
+                        // a failing importer must not stop the others
+                        log::warn!("Failed to run importer {name}: {err}");
+                    }
+                    continue;
+                }
+            }
+
             log::debug!("checking importers");
 
             let importers = service.list().await?;
             for importer in importers {
+                if running.len() >= self.config.concurrency {
+                    break;
+                }
+
                 // FIXME: could add that to the query/list operation
-                if importer.data.configuration.disabled || can_wait(&importer) {
+                if importer.data.configuration.disabled
+                    || running.contains(&importer.name)
+                    || can_wait(&importer)
+                    || is_leased(&importer)
+                {
                     continue;
                 }
 
-                log::debug!("  {}: {:?}", importer.name, importer.data.configuration);
+                let Some(importer) = self.claim(&service, &importer.name).await? else {
+                    continue;
+                };
 
-                service.update_start(&importer.name, None).await?;
+                running.insert(importer.name.clone());
+                runs.push(async {
+                    let name = importer.name.clone();
+                    (name, self.run_importer(&service, importer).await)
+                });
+            }
+        }
+    }
 
-                // record timestamp before processing, so that we can use it as "since" marker
-                let last_run = OffsetDateTime::now_utc();
+    /// Try to claim the run of an importer.
+    ///
+    /// Returns the importer if the claim was successful, `None` if it is not due anymore, or some
+    /// other node was faster.
+    async fn claim(
+        &self,
+        service: &ImporterService,
+        name: &str,
+    ) -> anyhow::Result<Option<Importer>> {
+        // re-read the importer, to ensure we decide on the latest state
+        let Some(Revisioned {
+            value: importer,
+            revision,
+        }) = service.read(name).await?
+        else {
+            return Ok(None);
+        };
 
-                log::info!("Starting run: {}", importer.name);
+        if importer.data.configuration.disabled || can_wait(&importer) || is_leased(&importer) {
+            return Ok(None);
+        }
 
-                let context = ServiceRunContext::new(service.clone(), importer.name.clone());
+        if !service
+            .try_claim(
+                name,
+                &revision,
+                &self.config.node_id,
+                self.config.lease_duration,
+            )
+            .await?
+        {
+            log::debug!("Importer {name} was claimed by another node");
+            return Ok(None);
+        }
 
-                let runner = ImportRunner {
-                    db: self.db.clone(),
-                    storage: self.storage.clone(),
-                    working_dir: self.working_dir.clone(),
-                };
+        if let Some(lease) = &importer.data.lease {
+            log::info!(
+                "Taking over importer {name} from node {}, lease expired: {}",
+                lease.owner,
+                lease.expires
+            );
+        }
 
-                let (last_error, report, continuation) = match runner
-                    .run_once(
-                        context,
-                        importer.data.configuration,
-                        importer.data.last_success,
-                        importer.data.continuation,
-                    )
-                    .await
-                {
-                    Ok(RunOutput {
+        Ok(Some(importer))
+    }
+
+    /// Perform a claimed run of an importer, renewing the lease until it is finished.
+    async fn run_importer(
+        &self,
+        service: &ImporterService,
+        importer: Importer,
+    ) -> anyhow::Result<()> {
+        // record timestamp before processing, so that we can use it as "since" marker
+        let last_run = OffsetDateTime::now_utc();
+
+        log::info!("Starting run: {}", importer.name);
+
+        let context = ServiceRunContext::new(
+            service.clone(),
+            importer.name.clone(),
+            self.config.node_id.clone(),
+        );
+
+        let runner = ImportRunner {
+            db: self.db.clone(),
+            storage: self.storage.clone(),
+            working_dir: self.working_dir.clone(),
+        };
+
+        // keep the continuation, in case the run gets canceled
+        let previous_continuation =
+            Some(importer.data.continuation.clone()).filter(|value| !value.is_null());
+
+        let run = runner.run_once(
+            context,
+            importer.data.configuration,
+            importer.data.last_success,
+            importer.data.continuation,
+        );
+
+        let result = tokio::select! {
+            result = run => result,
+            _ = self.renew_lease(service, &importer.name) => {
+                // dropping the run cancels it, another node took over
+                log::warn!("Lost lease of importer {}, canceled the run", importer.name);
+                return Ok(());
+            }
+        };
+
+        let (last_error, report, continuation) = match result {
+            Ok(RunOutput {
+                report,
+                continuation,
+            }) => (None, Some(report), continuation),
+            Err(ScannerError::Normal {
+                err,
+                output:
+                    RunOutput {
                         report,
                         continuation,
-                    }) => (None, Some(report), continuation),
-                    Err(ScannerError::Normal {
-                        err,
-                        output:
-                            RunOutput {
-                                report,
-                                continuation,
-                            },
-                    }) => (Some(err.to_string()), Some(report), continuation),
-                    Err(ScannerError::Critical(err)) => (Some(err.to_string()), None, None),
-                };
+                    },
+            }) => (Some(err.to_string()), Some(report), continuation),
+            Err(ScannerError::Critical(err)) => (Some(err.to_string()), None, None),
+        };
 
-                log::info!("Import run complete: {last_error:?}");
+        log::info!("Import run complete: {last_error:?}");
 
-                service
-                    .update_finish(
-                        &importer.name,
-                        None,
-                        last_run,
-                        last_error,
-                        continuation,
-                        report.and_then(|report| serde_json::to_value(report).ok()),
-                    )
-                    .await?;
-            }
+        // ensure we still own the run, otherwise another node took over
+        if !service
+            .renew_lease(
+                &importer.name,
+                &self.config.node_id,
+                self.config.lease_duration,
+            )
+            .await?
+        {
+            log::warn!(
+                "Lost lease of importer {}, discarding the result of the run",
+                importer.name
+            );
+            return Ok(());
         }
-    }
 
-    /// Reset all jobs back into non-running state.
-    ///
-    /// This is intended when the application starts up, to reset stale job states. Making it
-    /// possible to re-run them when they are due.
-    ///
-    /// **NOTE:** we can only do this as we're intended to be a single-process worker.
-    async fn reset_all_jobs(&self, service: &ImporterService) -> anyhow::Result<()> {
-        for importer in service.list().await? {
-            if importer.data.state == State::Running {
-                log::info!(
-                    "Cleaning up stale importer job during startup: {} (since: {})",
-                    importer.name,
-                    importer.data.last_change
-                );
-                service
-                    .update_finish(
-                        &importer.name,
-                        None,
-                        // either use the last run, or fall back to the last time the state changed
-                        importer.data.last_run.unwrap_or(importer.data.last_change),
-                        Some("Import cancelled".into()),
-                        None,
-                        None,
-                    )
-                    .await?;
+        let cancelled = service
+            .read(&importer.name)
+            .await?
+            .is_some_and(|importer| importer.value.data.cancel_requested);
+
+        let (last_error, report, continuation) = match cancelled {
+            true => {
+                log::info!("Import run was cancelled: {}", importer.name);
+                (
+                    Some(last_error.unwrap_or_else(|| "Import cancelled".into())),
+                    // always record the outcome of a cancelled run
+                    Some(report.unwrap_or_else(|| Report {
+                        start_date: last_run,
+                        end_date: OffsetDateTime::now_utc(),
+                        number_of_items: 0,
+                        messages: Default::default(),
+                    })),
+                    // resume from where we stopped, or started
+                    continuation.or(previous_continuation),
+                )
             }
-        }
+            false => (last_error, report, continuation),
+        };
+
+        service
+            .update_finish(
+                &importer.name,
+                None,
+                last_run,
+                last_error,
+                cancelled,
+                continuation,
+                report.and_then(|report| serde_json::to_value(report).ok()),
+            )
+            .await?;
 
         Ok(())
     }
+
+    /// Periodically renew the lease of a run, returns once the lease was lost.
+    async fn renew_lease(&self, service: &ImporterService, name: &str) {
+        let mut interval = tokio::time::interval(self.config.lease_duration / 3);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // the first tick completes immediately, and we just claimed the lease
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            match service
+                .renew_lease(name, &self.config.node_id, self.config.lease_duration)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => return,
+                Err(err) => {
+                    log::warn!("Failed to renew lease of importer {name}: {err}");
+                }
+            }
+        }
+    }
+}
+
+/// check if another node holds an active lease on the importer
+fn is_leased(importer: &Importer) -> bool {
+    importer.data.state == State::Running
+        && importer
+            .data
+            .lease
+            .as_ref()
+            .is_some_and(|lease| !lease.is_expired(OffsetDateTime::now_utc()))
 }
 
 /// check if we need to run or skip the importer
 fn can_wait(importer: &Importer) -> bool {
-    let Some(last) = importer.data.last_run else {
-        return false;
-    };
+    let now = OffsetDateTime::now_utc();
 
-    (OffsetDateTime::now_utc() - last) < importer.data.configuration.period
+    importer
+        .data
+        .configuration
+        .next_run(importer.data.last_run, now)
+        .is_none_or(|next_run| next_run > now)
 }
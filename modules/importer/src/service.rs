@@ -5,7 +5,10 @@ use sea_orm::{
     QueryFilter, QueryOrder, TransactionTrait,
 };
 use sea_query::{Alias, Expr, Nullable, SimpleExpr};
-use std::fmt::{Debug, Display};
+use std::{
+    fmt::{Debug, Display},
+    time::Duration,
+};
 use time::OffsetDateTime;
 use tracing::instrument;
 use trustify_common::{
@@ -24,6 +27,8 @@ pub enum Error {
     NotFound(String),
     #[error("mid air collision")]
     MidAirCollision,
+    #[error("importer '{0}' is not running")]
+    NotRunning(String),
     #[error("database error: {0}")]
     Database(#[from] sea_orm::DbErr),
     #[error(transparent)]
@@ -68,6 +73,11 @@ impl ResponseError for Error {
                 message: self.to_string(),
                 details: None,
             }),
+            Error::NotRunning(_) => HttpResponse::Conflict().json(ErrorInformation {
+                error: "NotRunning".into(),
+                message: self.to_string(),
+                details: None,
+            }),
             _ => HttpResponse::InternalServerError().json(ErrorInformation {
                 error: "Internal".into(),
                 message: self.to_string(),
@@ -134,6 +144,10 @@ impl ImporterService {
             progress_total: Set(None),
             progress_message: Set(None),
 
+            lease_owner: Set(None),
+            lease_expires: Set(None),
+            cancel_requested: Set(false),
+
             continuation: Set(None),
 
             configuration: Set(serde_json::to_value(configuration)?),
@@ -242,11 +256,80 @@ impl ImporterService {
                     importer::Column::State,
                     Expr::value(importer::State::Running),
                 ),
+                (importer::Column::CancelRequested, Expr::value(false)),
             ],
         )
         .await
     }
 
+    /// Try to claim a run of the importer for a node, starting the run.
+    ///
+    /// The claim only succeeds if the importer still has the revision the decision to run it was
+    /// based on. With several nodes competing for the same importer, only one of them will win.
+    ///
+    /// Returns `false` if the importer was modified in the meantime.
+    #[instrument(skip(self), ret)]
+    pub async fn try_claim(
+        &self,
+        name: &str,
+        expected_revision: &str,
+        owner: &str,
+        lease: Duration,
+    ) -> Result<bool, Error> {
+        let now = OffsetDateTime::now_utc();
+
+        let result = self
+            .update(
+                &self.db,
+                name,
+                Some(expected_revision),
+                vec![
+                    (importer::Column::LastChange, Expr::value(now)),
+                    (
+                        importer::Column::State,
+                        Expr::value(importer::State::Running),
+                    ),
+                    (importer::Column::LeaseOwner, Expr::value(owner)),
+                    (importer::Column::LeaseExpires, Expr::value(now + lease)),
+                    (importer::Column::CancelRequested, Expr::value(false)),
+                ],
+            )
+            .await;
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(Error::MidAirCollision) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Renew the lease of a run, extending it from now on.
+    ///
+    /// This doesn't change the revision, as it isn't a change of the importer a user made. So it
+    /// must not fail an update of the configuration. If another node took over an expired lease in
+    /// the meantime, renewing fails, and the run gets discarded.
+    ///
+    /// Returns `false` if the node doesn't hold the lease anymore.
+    #[instrument(skip(self), ret)]
+    pub async fn renew_lease(
+        &self,
+        name: &str,
+        owner: &str,
+        lease: Duration,
+    ) -> Result<bool, Error> {
+        let result = importer::Entity::update_many()
+            .col_expr(
+                importer::Column::LeaseExpires,
+                Expr::value(OffsetDateTime::now_utc() + lease),
+            )
+            .filter(importer::Column::Name.eq(name))
+            .filter(importer::Column::LeaseOwner.eq(owner))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
     #[instrument(skip(self, report), ret)]
     pub async fn update_finish(
         &self,
@@ -254,13 +337,21 @@ impl ImporterService {
         expected_revision: Option<&str>,
         start: OffsetDateTime,
         last_error: Option<String>,
+        cancelled: bool,
         continuation: Option<serde_json::Value>,
         report: Option<serde_json::Value>,
     ) -> Result<(), Error> {
         let tx = self.db.begin().await?;
 
         let now = OffsetDateTime::now_utc();
-        let successful = last_error.is_none();
+        let successful = last_error.is_none() && !cancelled;
+        let outcome = if cancelled {
+            importer_report::Outcome::Cancelled
+        } else if successful {
+            importer_report::Outcome::Succeeded
+        } else {
+            importer_report::Outcome::Failed
+        };
         let mut updates = vec![
             (importer::Column::LastError, Expr::value(last_error.clone())),
             (importer::Column::LastRun, Expr::value(start)),
@@ -276,6 +367,12 @@ impl ImporterService {
             ),
             (importer::Column::LastChange, Expr::value(now)),
             (importer::Column::Continuation, Expr::value(continuation)),
+            (importer::Column::LeaseOwner, Expr::value(String::null())),
+            (
+                importer::Column::LeaseExpires,
+                Expr::value(None::<OffsetDateTime>),
+            ),
+            (importer::Column::CancelRequested, Expr::value(false)),
         ];
         if successful {
             // we use the `start` marker, so that `last_success` can be used as the next `since`
@@ -292,6 +389,7 @@ impl ImporterService {
                 importer: Set(name.to_string()),
                 creation: Set(OffsetDateTime::now_utc()),
                 error: Set(last_error),
+                outcome: Set(outcome),
                 report: Set(report),
             };
             entity.insert(&tx).await?;
@@ -304,6 +402,44 @@ impl ImporterService {
         Ok(())
     }
 
+    /// Request to cancel the current run of an importer.
+    ///
+    /// The run is canceled cooperatively, the next time the importer checks for cancellation.
+    #[instrument(skip(self))]
+    pub async fn cancel(&self, name: &str, expected_revision: Option<&str>) -> Result<(), Error> {
+        let mut update = importer::Entity::update_many()
+            .col_expr(importer::Column::Revision, Expr::value(Uuid::new_v4()))
+            .col_expr(importer::Column::CancelRequested, Expr::value(true))
+            .filter(importer::Column::Name.eq(name))
+            .filter(importer::Column::State.eq(importer::State::Running));
+
+        if let Some(revision) = expected_revision {
+            update = update.filter(
+                importer::Column::Revision
+                    .into_expr()
+                    .cast_as(Alias::new("text"))
+                    .eq(revision),
+            );
+        }
+
+        let result = update.exec(&self.db).await?;
+
+        if result.rows_affected > 0 {
+            return Ok(());
+        }
+
+        // figure out why we couldn't update
+        match self.read(name).await? {
+            None => Err(Error::NotFound(name.to_string())),
+            Some(current)
+                if expected_revision.is_some_and(|expected| expected != current.revision) =>
+            {
+                Err(Error::MidAirCollision)
+            }
+            Some(_) => Err(Error::NotRunning(name.to_string())),
+        }
+    }
+
     async fn update<C>(
         &self,
         db: &C,
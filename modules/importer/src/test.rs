@@ -1,7 +1,10 @@
 #![cfg(test)]
 
-use super::model::{
-    CommonImporter, Importer, ImporterConfiguration, ImporterData, SbomImporter, State,
+use super::{
+    model::{
+        CommonImporter, Importer, ImporterConfiguration, ImporterData, Outcome, SbomImporter, State,
+    },
+    service::ImporterService,
 };
 use actix_web::{
     http::{header, StatusCode},
@@ -11,6 +14,7 @@ use serde_json::json;
 use std::time::Duration;
 use test_context::test_context;
 use test_log::test;
+use time::OffsetDateTime;
 use trustify_test_context::{app::TestApp, TrustifyContext};
 use utoipa_actix_web::AppExt;
 
@@ -19,6 +23,8 @@ fn mock_configuration(source: impl Into<String>) -> ImporterConfiguration {
         common: CommonImporter {
             disabled: false,
             period: Duration::from_secs(30),
+            schedule: None,
+            blackout_windows: vec![],
             description: None,
             labels: Default::default(),
         },
@@ -46,6 +52,9 @@ fn mock_importer(result: &Importer, source: impl Into<String>) -> Importer {
             last_run: None,
             progress: Default::default(),
             continuation: serde_json::Value::Null,
+            next_run: result.data.next_run, // we can't predict timestamps
+            lease: None,
+            cancel_requested: false,
         },
     }
 }
@@ -99,6 +108,9 @@ async fn default(ctx: TrustifyContext) {
                 last_error: None,
                 progress: Default::default(),
                 continuation: serde_json::Value::Null,
+                next_run: result[0].data.next_run, // we can't predict timestamps
+                lease: None,
+                cancel_requested: false,
             }
         }]
     );
@@ -389,3 +401,139 @@ async fn patch(ctx: TrustifyContext) {
     let resp = actix::call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::NOT_FOUND);
 }
+
+#[test_context(TrustifyContext, skip_teardown)]
+#[test(tokio::test)]
+async fn lease(ctx: TrustifyContext) -> anyhow::Result<()> {
+    let service = ImporterService::new(ctx.db);
+    let lease = Duration::from_secs(60);
+
+    service
+        .create("foo".into(), mock_configuration("bar"))
+        .await?;
+
+    let revision = service.read("foo").await?.expect("must be found").revision;
+
+    // the first node wins
+
+    assert!(service.try_claim("foo", &revision, "a", lease).await?);
+    assert!(!service.try_claim("foo", &revision, "b", lease).await?);
+
+    let result = service.read("foo").await?.expect("must be found").value;
+    assert_eq!(result.data.state, State::Running);
+    assert_eq!(result.data.lease.map(|lease| lease.owner), Some("a".into()));
+
+    // only the owner can renew the lease
+
+    let revision = service.read("foo").await?.expect("must be found").revision;
+    assert!(service.renew_lease("foo", "a", lease).await?);
+    assert!(!service.renew_lease("foo", "b", lease).await?);
+
+    // renewing doesn't change the revision, so it doesn't conflict with updates
+
+    let result = service.read("foo").await?.expect("must be found");
+    assert_eq!(result.revision, revision);
+
+    // finishing the run releases the lease
+
+    service
+        .update_finish(
+            "foo",
+            None,
+            OffsetDateTime::now_utc(),
+            None,
+            false,
+            None,
+            None,
+        )
+        .await?;
+
+    let result = service.read("foo").await?.expect("must be found").value;
+    assert_eq!(result.data.state, State::Waiting);
+    assert_eq!(result.data.lease, None);
+    assert!(!service.renew_lease("foo", "a", lease).await?);
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext, skip_teardown)]
+#[test(actix_web::test)]
+async fn cancel(ctx: TrustifyContext) -> anyhow::Result<()> {
+    let service = ImporterService::new(ctx.db.clone());
+    let app = actix::init_service(
+        App::new()
+            .into_utoipa_app()
+            .add_test_authorizer()
+            .service(
+                utoipa_actix_web::scope("/api")
+                    .configure(|svc| super::endpoints::configure(svc, ctx.db.clone())),
+            )
+            .into_app(),
+    )
+    .await;
+
+    service
+        .create("foo".into(), mock_configuration("bar"))
+        .await?;
+
+    // not running, can't be cancelled
+
+    let req = actix::TestRequest::post()
+        .uri("/api/v1/importer/foo/cancel")
+        .to_request();
+
+    let resp = actix::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    // start it, and cancel it
+
+    service.update_start("foo", None).await?;
+
+    let req = actix::TestRequest::post()
+        .uri("/api/v1/importer/foo/cancel")
+        .to_request();
+
+    let resp = actix::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let result = service.read("foo").await?.expect("must be found").value;
+    assert!(result.data.cancel_requested);
+
+    // finish as cancelled, keeping the continuation
+
+    service
+        .update_finish(
+            "foo",
+            None,
+            OffsetDateTime::now_utc(),
+            Some("Import cancelled".into()),
+            true,
+            Some(json!("abc")),
+            Some(json!({
+                "startDate": "2024-01-01T00:00:00Z",
+                "endDate": "2024-01-01T00:00:00Z",
+            })),
+        )
+        .await?;
+
+    let result = service.read("foo").await?.expect("must be found").value;
+    assert_eq!(result.data.state, State::Waiting);
+    assert!(!result.data.cancel_requested);
+    assert_eq!(result.data.last_success, None);
+    assert_eq!(result.data.continuation, json!("abc"));
+
+    let reports = service.get_reports("foo", Default::default()).await?;
+    assert_eq!(reports.total, 1);
+    assert_eq!(reports.items[0].outcome, Outcome::Cancelled);
+
+    // unknown importer
+
+    let req = actix::TestRequest::post()
+        .uri("/api/v1/importer/bar/cancel")
+        .to_request();
+
+    let resp = actix::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
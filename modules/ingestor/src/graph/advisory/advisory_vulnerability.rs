@@ -1,13 +1,17 @@
 use crate::graph::{advisory::AdvisoryContext, error::Error, vulnerability::VulnerabilityContext};
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoIdentity, NotSet, QueryFilter,
-    Set,
+    QueryOrder, Set,
 };
 use sea_query::{Condition, Expr, IntoCondition};
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 use trustify_common::{cpe::Cpe, purl::Purl};
-use trustify_cvss::cvss3::Cvss3Base;
+use trustify_cvss::{
+    cvss2::vector::Cvss2Vector,
+    cvss3::{vector::Cvss3Vector, Cvss3Base},
+    cvss4::Cvss4Base,
+};
 use trustify_entity::{
     self as entity, cvss3::Severity, purl_status, status, version_range,
     version_scheme::VersionScheme, vulnerability,
@@ -32,6 +36,32 @@ pub enum Version {
     Unbounded,
 }
 
+/// Additional information on a status, as provided by VEX documents.
+#[derive(Clone, Default, Eq, Hash, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatusInformation {
+    /// Why a product is not affected, e.g. `vulnerable_code_not_present`
+    pub justification: Option<String>,
+    /// The impact of the vulnerability on a product
+    pub impact_statement: Option<String>,
+    /// What to do about the vulnerability
+    pub action_statement: Option<String>,
+}
+
+impl StatusInformation {
+    /// Fill in the information which is missing, keeping what is already present.
+    pub fn merge(&mut self, other: &StatusInformation) {
+        fn merge(value: &mut Option<String>, other: &Option<String>) {
+            if value.is_none() {
+                value.clone_from(other);
+            }
+        }
+
+        merge(&mut self.justification, &other.justification);
+        merge(&mut self.impact_statement, &other.impact_statement);
+        merge(&mut self.action_statement, &other.action_statement);
+    }
+}
+
 impl VersionInfo {
     pub fn into_active_model(self) -> version_range::ActiveModel {
         version_range::ActiveModel {
@@ -290,6 +320,28 @@ impl<'g> AdvisoryVulnerabilityContext<'g> {
         status: &str,
         info: VersionInfo,
         connection: &C,
+    ) -> Result<(), Error> {
+        self.ingest_package_status_with_information(
+            cpe_context,
+            purl,
+            status,
+            info,
+            StatusInformation::default(),
+            connection,
+        )
+        .await
+    }
+
+    /// Ingest a package status, along with the additional information of a VEX statement.
+    #[instrument(skip(self, connection), ret)]
+    pub async fn ingest_package_status_with_information<C: ConnectionTrait>(
+        &self,
+        cpe_context: Option<Cpe>,
+        purl: &Purl,
+        status: &str,
+        info: VersionInfo,
+        information: StatusInformation,
+        connection: &C,
     ) -> Result<(), Error> {
         let status = status::Entity::find()
             .filter(status::Column::Slug.eq(status))
@@ -302,6 +354,10 @@ impl<'g> AdvisoryVulnerabilityContext<'g> {
         let package_status = purl_status::Entity::find()
             .filter(purl_status::Column::BasePurlId.eq(package.base_purl.id))
             .filter(purl_status::Column::AdvisoryId.eq(self.advisory.advisory.id))
+            .filter(
+                purl_status::Column::VulnerabilityId
+                    .eq(self.advisory_vulnerability.vulnerability_id.clone()),
+            )
             .filter(purl_status::Column::StatusId.eq(status.id))
             .left_join(version_range::Entity)
             .filter(info.clone().into_condition())
@@ -324,6 +380,9 @@ impl<'g> AdvisoryVulnerabilityContext<'g> {
             base_purl_id: Set(package.base_purl.id),
             version_range_id: Set(version_range.id),
             context_cpe_id: NotSet,
+            justification: Set(information.justification),
+            impact_statement: Set(information.impact_statement),
+            action_statement: Set(information.action_statement),
         };
 
         package_status.insert(connection).await?;
@@ -390,6 +449,17 @@ impl<'g> AdvisoryVulnerabilityContext<'g> {
         minor_version: u8,
         connection: &C,
     ) -> Result<Option<Cvss3Base>, Error> {
+        Ok(self
+            .get_cvss3_vector(minor_version, connection)
+            .await?
+            .map(|vector| vector.base))
+    }
+
+    pub async fn get_cvss3_vector<C: ConnectionTrait>(
+        &self,
+        minor_version: u8,
+        connection: &C,
+    ) -> Result<Option<Cvss3Vector>, Error> {
         Ok(entity::cvss3::Entity::find()
             .filter(entity::cvss3::Column::AdvisoryId.eq(self.advisory_vulnerability.advisory_id))
             .filter(
@@ -408,27 +478,193 @@ impl<'g> AdvisoryVulnerabilityContext<'g> {
         cvss3: Cvss3Base,
         connection: &C,
     ) -> Result<Cvss3Base, Error> {
+        Ok(self
+            .ingest_cvss3_vector(cvss3.into(), connection)
+            .await?
+            .base)
+    }
+
+    /// Ingest a CVSS v3 vector, including its temporal and environmental metrics.
+    #[instrument(skip(self, connection), err)]
+    pub async fn ingest_cvss3_vector<C: ConnectionTrait>(
+        &self,
+        cvss3: Cvss3Vector,
+        connection: &C,
+    ) -> Result<Cvss3Vector, Error> {
         if let Some(found) = self
-            .get_cvss3_score(cvss3.minor_version, connection)
+            .get_cvss3_vector(cvss3.base.minor_version, connection)
             .await?
         {
             return Ok(found);
         }
 
+        let base = &cvss3.base;
+        let temporal = &cvss3.temporal;
+        let environmental = &cvss3.environmental;
+
         let model = entity::cvss3::ActiveModel {
             advisory_id: Set(self.advisory_vulnerability.advisory_id),
             vulnerability_id: Set(self.advisory_vulnerability.vulnerability_id.clone()),
-            minor_version: sea_orm::ActiveValue::Set(cvss3.minor_version as i32),
-            av: Set(cvss3.av.into()),
-            ac: Set(cvss3.ac.into()),
-            pr: Set(cvss3.pr.into()),
-            ui: Set(cvss3.ui.into()),
-            s: Set(cvss3.s.into()),
-            c: Set(cvss3.c.into()),
-            i: Set(cvss3.i.into()),
-            a: Set(cvss3.a.into()),
-            score: Set(cvss3.score().roundup().value()),
-            severity: Set(Severity::from(cvss3.score().roundup().severity())),
+            minor_version: sea_orm::ActiveValue::Set(base.minor_version as i32),
+            av: Set(base.av.into()),
+            ac: Set(base.ac.into()),
+            pr: Set(base.pr.into()),
+            ui: Set(base.ui.into()),
+            s: Set(base.s.into()),
+            c: Set(base.c.into()),
+            i: Set(base.i.into()),
+            a: Set(base.a.into()),
+            score: Set(base.score().roundup().value()),
+            severity: Set(Severity::from(base.score().roundup().severity())),
+            e: Set(temporal.e.map(Into::into)),
+            rl: Set(temporal.rl.map(Into::into)),
+            rc: Set(temporal.rc.map(Into::into)),
+            cr: Set(environmental.cr.map(Into::into)),
+            ir: Set(environmental.ir.map(Into::into)),
+            ar: Set(environmental.ar.map(Into::into)),
+            mav: Set(environmental.mav.map(Into::into)),
+            mac: Set(environmental.mac.map(Into::into)),
+            mpr: Set(environmental.mpr.map(Into::into)),
+            mui: Set(environmental.mui.map(Into::into)),
+            ms: Set(environmental.ms.map(Into::into)),
+            mc: Set(environmental.mc.map(Into::into)),
+            mi: Set(environmental.mi.map(Into::into)),
+            ma: Set(environmental.ma.map(Into::into)),
+            temporal_score: Set(cvss3.temporal_score().map(|score| score.value())),
+            environmental_score: Set(cvss3.environmental_score().map(|score| score.value())),
+        };
+
+        Ok(model.insert(connection).await?.into())
+    }
+
+    pub async fn cvss2_scores<C: ConnectionTrait>(
+        &self,
+        connection: &C,
+    ) -> Result<Vec<Cvss2Vector>, Error> {
+        Ok(entity::cvss2::Entity::find()
+            .filter(entity::cvss2::Column::AdvisoryId.eq(self.advisory_vulnerability.advisory_id))
+            .filter(
+                entity::cvss2::Column::VulnerabilityId
+                    .eq(self.advisory_vulnerability.vulnerability_id.clone()),
+            )
+            .order_by_asc(entity::cvss2::Column::MinorVersion)
+            .order_by_asc(entity::cvss2::Column::Idx)
+            .all(connection)
+            .await?
+            .drain(..)
+            .map(|e| e.into())
+            .collect())
+    }
+
+    /// Ingest a CVSS v2 vector. An advisory may provide more than one vector for a
+    /// vulnerability, each distinct vector is stored with the next index.
+    #[instrument(skip(self, connection), err)]
+    pub async fn ingest_cvss2_score<C: ConnectionTrait>(
+        &self,
+        cvss2: Cvss2Vector,
+        connection: &C,
+    ) -> Result<Cvss2Vector, Error> {
+        let existing = self.cvss2_scores(connection).await?;
+        if existing.contains(&cvss2) {
+            return Ok(cvss2);
+        }
+
+        let base = &cvss2.base;
+        let temporal = &cvss2.temporal;
+        let environmental = &cvss2.environmental;
+
+        let model = entity::cvss2::ActiveModel {
+            advisory_id: Set(self.advisory_vulnerability.advisory_id),
+            vulnerability_id: Set(self.advisory_vulnerability.vulnerability_id.clone()),
+            minor_version: Set(0),
+            idx: Set(existing.len() as i32),
+            av: Set(base.av.into()),
+            ac: Set(base.ac.into()),
+            au: Set(base.au.into()),
+            c: Set(base.c.into()),
+            i: Set(base.i.into()),
+            a: Set(base.a.into()),
+            e: Set(temporal.e.map(Into::into)),
+            rl: Set(temporal.rl.map(Into::into)),
+            rc: Set(temporal.rc.map(Into::into)),
+            cdp: Set(environmental.cdp.map(Into::into)),
+            td: Set(environmental.td.map(Into::into)),
+            cr: Set(environmental.cr.map(Into::into)),
+            ir: Set(environmental.ir.map(Into::into)),
+            ar: Set(environmental.ar.map(Into::into)),
+            score: Set(base.score().value()),
+            severity: Set(Severity::from(base.severity())),
+            temporal_score: Set(cvss2.temporal_score().map(|score| score.value())),
+            environmental_score: Set(cvss2.environmental_score().map(|score| score.value())),
+        };
+
+        Ok(model.insert(connection).await?.into())
+    }
+
+    pub async fn cvss4_scores<C: ConnectionTrait>(
+        &self,
+        connection: &C,
+    ) -> Result<Vec<Cvss4Base>, Error> {
+        Ok(entity::cvss4::Entity::find()
+            .filter(entity::cvss4::Column::AdvisoryId.eq(self.advisory_vulnerability.advisory_id))
+            .filter(
+                entity::cvss4::Column::VulnerabilityId
+                    .eq(self.advisory_vulnerability.vulnerability_id.clone()),
+            )
+            .all(connection)
+            .await?
+            .drain(..)
+            .map(|e| e.into())
+            .collect())
+    }
+
+    pub async fn get_cvss4_score<C: ConnectionTrait>(
+        &self,
+        minor_version: u8,
+        connection: &C,
+    ) -> Result<Option<Cvss4Base>, Error> {
+        Ok(entity::cvss4::Entity::find()
+            .filter(entity::cvss4::Column::AdvisoryId.eq(self.advisory_vulnerability.advisory_id))
+            .filter(
+                entity::cvss4::Column::VulnerabilityId
+                    .eq(self.advisory_vulnerability.vulnerability_id.clone()),
+            )
+            .filter(entity::cvss4::Column::MinorVersion.eq(minor_version as i32))
+            .one(connection)
+            .await?
+            .map(|cvss| cvss.into()))
+    }
+
+    #[instrument(skip(self, connection), err)]
+    pub async fn ingest_cvss4_score<C: ConnectionTrait>(
+        &self,
+        cvss4: Cvss4Base,
+        connection: &C,
+    ) -> Result<Cvss4Base, Error> {
+        if let Some(found) = self
+            .get_cvss4_score(cvss4.minor_version, connection)
+            .await?
+        {
+            return Ok(found);
+        }
+
+        let model = entity::cvss4::ActiveModel {
+            advisory_id: Set(self.advisory_vulnerability.advisory_id),
+            vulnerability_id: Set(self.advisory_vulnerability.vulnerability_id.clone()),
+            minor_version: Set(cvss4.minor_version as i32),
+            av: Set(cvss4.av.into()),
+            ac: Set(cvss4.ac.into()),
+            at: Set(cvss4.at.into()),
+            pr: Set(cvss4.pr.into()),
+            ui: Set(cvss4.ui.into()),
+            vc: Set(cvss4.vc.into()),
+            vi: Set(cvss4.vi.into()),
+            va: Set(cvss4.va.into()),
+            sc: Set(cvss4.sc.into()),
+            si: Set(cvss4.si.into()),
+            sa: Set(cvss4.sa.into()),
+            score: Set(cvss4.score().value()),
+            severity: Set(Severity::from(cvss4.severity())),
         };
 
         Ok(model.insert(connection).await?.into())
@@ -0,0 +1,85 @@
+use sea_orm::{ActiveValue::Set, ConnectionTrait, DbErr, EntityTrait};
+use sea_query::OnConflict;
+use trustify_common::db::chunk::EntityChunkedIter;
+use trustify_entity::sbom_node_checksum;
+use uuid::Uuid;
+
+/// A checksum of a package or file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Checksum {
+    /// The normalized algorithm, e.g. `sha256`
+    pub r#type: String,
+    /// The lowercase, hex encoded value
+    pub value: String,
+}
+
+impl Checksum {
+    /// Create a new checksum, normalizing the algorithm and value.
+    ///
+    /// SPDX (`SHA256`, `SHA3_256`) and CycloneDX (`SHA-256`, `SHA3-256`) spell algorithms
+    /// differently, so they are lowercased and stripped of separators, e.g. `sha256`.
+    pub fn new(algorithm: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        Self {
+            r#type: normalize_algorithm(algorithm.as_ref()),
+            value: value.as_ref().trim().to_lowercase(),
+        }
+    }
+}
+
+/// Normalize the name of a hash algorithm, e.g. `SHA-256` to `sha256`.
+pub fn normalize_algorithm(algorithm: &str) -> String {
+    algorithm
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Collects checksums of SBOM nodes.
+#[derive(Default)]
+pub(crate) struct ChecksumCreator {
+    checksums: Vec<sbom_node_checksum::ActiveModel>,
+}
+
+impl ChecksumCreator {
+    pub fn add(
+        &mut self,
+        sbom_id: Uuid,
+        node_id: &str,
+        checksums: impl IntoIterator<Item = Checksum>,
+    ) {
+        for checksum in checksums {
+            self.checksums.push(sbom_node_checksum::ActiveModel {
+                sbom_id: Set(sbom_id),
+                node_id: Set(node_id.to_string()),
+                r#type: Set(checksum.r#type),
+                value: Set(checksum.value),
+            });
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.checksums.len()
+    }
+
+    pub async fn create(self, db: &impl ConnectionTrait) -> Result<(), DbErr> {
+        for batch in &self.checksums.into_iter().chunked() {
+            sbom_node_checksum::Entity::insert_many(batch)
+                .on_conflict(
+                    OnConflict::columns([
+                        sbom_node_checksum::Column::SbomId,
+                        sbom_node_checksum::Column::NodeId,
+                        sbom_node_checksum::Column::Type,
+                        sbom_node_checksum::Column::Value,
+                    ])
+                    .do_nothing()
+                    .to_owned(),
+                )
+                .do_nothing()
+                .exec(db)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
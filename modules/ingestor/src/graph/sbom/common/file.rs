@@ -1,4 +1,4 @@
-use crate::graph::sbom::ReferenceSource;
+use crate::graph::sbom::{Checksum, ChecksumCreator, ReferenceSource};
 use sea_orm::{ActiveValue::Set, ConnectionTrait, DbErr, EntityTrait};
 use sea_query::OnConflict;
 use tracing::instrument;
@@ -11,6 +11,7 @@ pub struct FileCreator {
     sbom_id: Uuid,
     nodes: Vec<sbom_node::ActiveModel>,
     files: Vec<sbom_file::ActiveModel>,
+    checksums: ChecksumCreator,
 }
 
 impl FileCreator {
@@ -19,6 +20,7 @@ impl FileCreator {
             sbom_id,
             nodes: Vec::new(),
             files: Vec::new(),
+            checksums: Default::default(),
         }
     }
 
@@ -27,10 +29,18 @@ impl FileCreator {
             sbom_id,
             nodes: Vec::with_capacity(capacity_files),
             files: Vec::with_capacity(capacity_files),
+            checksums: Default::default(),
         }
     }
 
-    pub fn add(&mut self, node_id: String, name: String) {
+    pub fn add(
+        &mut self,
+        node_id: String,
+        name: String,
+        checksums: impl IntoIterator<Item = Checksum>,
+    ) {
+        self.checksums.add(self.sbom_id, &node_id, checksums);
+
         self.nodes.push(sbom_node::ActiveModel {
             sbom_id: Set(self.sbom_id),
             node_id: Set(node_id.clone()),
@@ -69,6 +79,8 @@ impl FileCreator {
                 .await?;
         }
 
+        self.checksums.create(db).await?;
+
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+mod checksum;
 mod file;
 mod package;
 mod relationship;
@@ -6,6 +7,7 @@ mod license;
 
 pub use license::*;
 
+pub use checksum::*;
 pub use file::*;
 pub use package::*;
 pub use relationship::*;
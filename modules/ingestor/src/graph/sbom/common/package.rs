@@ -1,11 +1,11 @@
-use crate::graph::sbom::{LicenseInfo, ReferenceSource};
+use crate::graph::sbom::{Checksum, ChecksumCreator, LicenseInfo, ReferenceSource};
 use sea_orm::{ActiveValue::Set, ConnectionTrait, DbErr, EntityTrait};
 use sea_query::OnConflict;
 use tracing::instrument;
 use trustify_common::db::chunk::EntityChunkedIter;
 use trustify_entity::{
     cpe_license_assertion, purl_license_assertion, sbom_node, sbom_package, sbom_package_cpe_ref,
-    sbom_package_purl_ref,
+    sbom_package_external_reference, sbom_package_property, sbom_package_purl_ref,
 };
 use uuid::Uuid;
 
@@ -18,6 +18,9 @@ pub struct PackageCreator {
     cpe_refs: Vec<sbom_package_cpe_ref::ActiveModel>,
     purl_license_assertions: Vec<purl_license_assertion::ActiveModel>,
     cpe_license_assertions: Vec<cpe_license_assertion::ActiveModel>,
+    checksums: ChecksumCreator,
+    external_references: Vec<sbom_package_external_reference::ActiveModel>,
+    properties: Vec<sbom_package_property::ActiveModel>,
 }
 
 pub enum PackageReference {
@@ -28,6 +31,20 @@ pub enum PackageReference {
     Cpe(Uuid),
 }
 
+/// Information about a package, in addition to its name, version, and references.
+#[derive(Clone, Debug, Default)]
+pub struct PackageInformation {
+    pub supplier: Option<String>,
+    pub originator: Option<String>,
+    pub download_location: Option<String>,
+    pub copyright_text: Option<String>,
+    pub checksums: Vec<Checksum>,
+    /// External references, other than purls and CPEs, as pairs of type and URL
+    pub external_references: Vec<(String, String)>,
+    /// Properties, as pairs of name and value
+    pub properties: Vec<(String, String)>,
+}
+
 impl PackageCreator {
     pub fn new(sbom_id: Uuid) -> Self {
         Self {
@@ -38,6 +55,9 @@ impl PackageCreator {
             cpe_refs: Vec::new(),
             purl_license_assertions: Vec::new(),
             cpe_license_assertions: Vec::new(),
+            checksums: Default::default(),
+            external_references: Vec::new(),
+            properties: Vec::new(),
         }
     }
 
@@ -50,6 +70,9 @@ impl PackageCreator {
             cpe_refs: Vec::new(), // most packages won't have a CPE, so we start with a low number
             purl_license_assertions: Vec::new(),
             cpe_license_assertions: Vec::new(),
+            checksums: Default::default(),
+            external_references: Vec::new(),
+            properties: Vec::new(),
         }
     }
 
@@ -60,6 +83,7 @@ impl PackageCreator {
         version: Option<String>,
         refs: impl IntoIterator<Item = PackageReference>,
         license_refs: impl IntoIterator<Item = LicenseInfo> + Clone,
+        info: PackageInformation,
     ) {
         for r#ref in refs {
             match r#ref {
@@ -101,6 +125,27 @@ impl PackageCreator {
             }
         }
 
+        self.checksums.add(self.sbom_id, &node_id, info.checksums);
+
+        for (r#type, url) in info.external_references {
+            self.external_references
+                .push(sbom_package_external_reference::ActiveModel {
+                    sbom_id: Set(self.sbom_id),
+                    node_id: Set(node_id.clone()),
+                    r#type: Set(r#type),
+                    url: Set(url),
+                });
+        }
+
+        for (name, value) in info.properties {
+            self.properties.push(sbom_package_property::ActiveModel {
+                sbom_id: Set(self.sbom_id),
+                node_id: Set(node_id.clone()),
+                name: Set(name),
+                value: Set(value),
+            });
+        }
+
         self.nodes.push(sbom_node::ActiveModel {
             sbom_id: Set(self.sbom_id),
             node_id: Set(node_id.clone()),
@@ -111,6 +156,10 @@ impl PackageCreator {
             sbom_id: Set(self.sbom_id),
             node_id: Set(node_id),
             version: Set(version),
+            supplier: Set(info.supplier),
+            originator: Set(info.originator),
+            download_location: Set(info.download_location),
+            copyright_text: Set(info.copyright_text),
         });
     }
 
@@ -121,6 +170,7 @@ impl PackageCreator {
             num_packages=self.packages.len(),
             num_purl_refs=self.purl_refs.len(),
             num_cpe_refs=self.cpe_refs.len(),
+            num_checksums=self.checksums.len(),
         ),
         err
     )]
@@ -216,6 +266,42 @@ impl PackageCreator {
                 .await?;
         }
 
+        self.checksums.create(db).await?;
+
+        for batch in &self.external_references.into_iter().chunked() {
+            sbom_package_external_reference::Entity::insert_many(batch)
+                .on_conflict(
+                    OnConflict::columns([
+                        sbom_package_external_reference::Column::SbomId,
+                        sbom_package_external_reference::Column::NodeId,
+                        sbom_package_external_reference::Column::Type,
+                        sbom_package_external_reference::Column::Url,
+                    ])
+                    .do_nothing()
+                    .to_owned(),
+                )
+                .do_nothing()
+                .exec(db)
+                .await?;
+        }
+
+        for batch in &self.properties.into_iter().chunked() {
+            sbom_package_property::Entity::insert_many(batch)
+                .on_conflict(
+                    OnConflict::columns([
+                        sbom_package_property::Column::SbomId,
+                        sbom_package_property::Column::NodeId,
+                        sbom_package_property::Column::Name,
+                        sbom_package_property::Column::Value,
+                    ])
+                    .do_nothing()
+                    .to_owned(),
+                )
+                .do_nothing()
+                .exec(db)
+                .await?;
+        }
+
         Ok(())
     }
 }
@@ -3,8 +3,8 @@ use crate::graph::{
     product::ProductInformation,
     purl::creator::PurlCreator,
     sbom::{
-        LicenseCreator, LicenseInfo, PackageCreator, PackageReference, RelationshipCreator,
-        SbomContext, SbomInformation,
+        Checksum, LicenseCreator, LicenseInfo, PackageCreator, PackageInformation,
+        PackageReference, RelationshipCreator, SbomContext, SbomInformation,
     },
 };
 use sea_orm::ConnectionTrait;
@@ -303,6 +303,7 @@ impl<'a> Creator<'a> {
                 comp.version.as_ref().map(|v| v.to_string()),
                 refs,
                 license_refs,
+                information(comp),
             );
         }
 
@@ -318,3 +319,45 @@ impl<'a> Creator<'a> {
         Ok(())
     }
 }
+
+/// The information of a component, in addition to its name, version, and references.
+fn information(component: &Component) -> PackageInformation {
+    let external_references = component
+        .external_references
+        .iter()
+        .flatten()
+        .map(|reference| (reference.type_.to_string(), reference.url.to_string()))
+        .collect::<Vec<_>>();
+
+    PackageInformation {
+        supplier: component
+            .supplier
+            .as_ref()
+            .and_then(|supplier| supplier.name.as_ref())
+            .map(ToString::to_string),
+        originator: component.author.as_ref().map(ToString::to_string),
+        download_location: external_references
+            .iter()
+            .find(|(r#type, _)| r#type == "distribution")
+            .map(|(_, url)| url.clone()),
+        copyright_text: component.copyright.as_ref().map(ToString::to_string),
+        checksums: component
+            .hashes
+            .iter()
+            .flatten()
+            .map(|hash| Checksum::new(hash.alg.to_string(), hash.content.to_string()))
+            .collect(),
+        external_references,
+        properties: component
+            .properties
+            .iter()
+            .flatten()
+            .filter_map(|property| {
+                Some((
+                    property.name.to_string(),
+                    property.value.as_ref()?.to_string(),
+                ))
+            })
+            .collect(),
+    }
+}
@@ -710,7 +710,14 @@ impl SbomContext {
                 qualified_purl,
             })
             .chain(cpes.into_iter().map(PackageReference::Cpe));
-        creator.add(node_id, name, version, refs, iter::empty());
+        creator.add(
+            node_id,
+            name,
+            version,
+            refs,
+            iter::empty(),
+            Default::default(),
+        );
 
         creator.create(connection).await?;
 
@@ -4,8 +4,8 @@ use crate::{
         product::ProductInformation,
         purl::creator::PurlCreator,
         sbom::{
-            FileCreator, LicenseCreator, LicenseInfo, PackageCreator, PackageReference, References,
-            RelationshipCreator, SbomContext, SbomInformation,
+            Checksum, FileCreator, LicenseCreator, LicenseInfo, PackageCreator, PackageInformation,
+            PackageReference, References, RelationshipCreator, SbomContext, SbomInformation,
         },
     },
     service::Error,
@@ -13,7 +13,7 @@ use crate::{
 use sbom_walker::report::{check, ReportSink};
 use sea_orm::ConnectionTrait;
 use serde_json::Value;
-use spdx_rs::models::{RelationshipType, SPDX};
+use spdx_rs::models::{Algorithm, RelationshipType, SPDX};
 use std::{collections::HashMap, str::FromStr};
 use time::OffsetDateTime;
 use tracing::instrument;
@@ -142,6 +142,7 @@ impl SbomContext {
             }
 
             let mut product_cpe = None;
+            let mut external_references = Vec::new();
 
             for r in &package.external_reference {
                 match &*r.reference_type {
@@ -171,7 +172,10 @@ impl SbomContext {
                             log::info!("Failed to parse CPE ({}): {err}", r.reference_locator);
                         }
                     },
-                    _ => {}
+                    _ => {
+                        external_references
+                            .push((r.reference_type.clone(), r.reference_locator.clone()));
+                    }
                 }
             }
 
@@ -181,6 +185,15 @@ impl SbomContext {
                 package.package_version.clone(),
                 refs,
                 license_refs,
+                PackageInformation {
+                    supplier: assertion(package.package_supplier.as_deref()),
+                    originator: assertion(package.package_originator.as_deref()),
+                    download_location: assertion(Some(&package.package_download_location)),
+                    copyright_text: assertion(package.copyright_text.as_deref()),
+                    checksums: checksums(&package.package_checksum),
+                    external_references,
+                    properties: vec![],
+                },
             );
 
             if product_packages.contains(&package.package_spdx_identifier) {
@@ -209,7 +222,8 @@ impl SbomContext {
             FileCreator::with_capacity(self.sbom.sbom_id, sbom_data.file_information.len());
 
         for file in sbom_data.file_information {
-            files.add(file.file_spdx_identifier, file.file_name);
+            let checksums = checksums(&file.file_checksum);
+            files.add(file.file_spdx_identifier, file.file_name, checksums);
         }
 
         // create all purls and CPEs
@@ -242,6 +256,46 @@ impl SbomContext {
     }
 }
 
+/// The value of an optional SPDX field, unless it is `NOASSERTION` or `NONE`.
+fn assertion(value: Option<&str>) -> Option<String> {
+    value
+        .filter(|value| !matches!(*value, "NOASSERTION" | "NONE"))
+        .map(ToString::to_string)
+}
+
+fn checksums(checksums: &[spdx_rs::models::Checksum]) -> Vec<Checksum> {
+    checksums
+        .iter()
+        .map(|checksum| Checksum::new(algorithm(&checksum.algorithm), &checksum.value))
+        .collect()
+}
+
+/// The normalized name of an SPDX checksum algorithm.
+///
+/// This is mapped explicitly, as the name is part of the stored checksum, and so must not change
+/// with the `Debug` representation of the enum.
+fn algorithm(algorithm: &Algorithm) -> &'static str {
+    match algorithm {
+        Algorithm::SHA1 => "sha1",
+        Algorithm::SHA224 => "sha224",
+        Algorithm::SHA256 => "sha256",
+        Algorithm::SHA384 => "sha384",
+        Algorithm::SHA512 => "sha512",
+        Algorithm::MD2 => "md2",
+        Algorithm::MD4 => "md4",
+        Algorithm::MD5 => "md5",
+        Algorithm::MD6 => "md6",
+        Algorithm::SHA3256 => "sha3256",
+        Algorithm::SHA3384 => "sha3384",
+        Algorithm::SHA3512 => "sha3512",
+        Algorithm::BLAKE2B256 => "blake2b256",
+        Algorithm::BLAKE2B384 => "blake2b384",
+        Algorithm::BLAKE2B512 => "blake2b512",
+        Algorithm::BLAKE3 => "blake3",
+        Algorithm::ADLER32 => "adler32",
+    }
+}
+
 pub struct SpdxRelationship<'spdx>(pub &'spdx str, pub Relationship, pub &'spdx str);
 
 impl<'spdx> TryFrom<(&'spdx str, &'spdx RelationshipType, &'spdx str)> for SpdxRelationship<'spdx> {
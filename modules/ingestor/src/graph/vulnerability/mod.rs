@@ -94,6 +94,34 @@ impl Graph {
         Ok(VulnerabilityContext::new(self, result))
     }
 
+    /// Ensure that vulnerabilities exist, without altering the information of existing ones.
+    #[instrument(skip(self, identifiers, db), err(level=tracing::Level::INFO))]
+    pub async fn ingest_vulnerability_ids<C: ConnectionTrait>(
+        &self,
+        identifiers: impl IntoIterator<Item = impl Into<String>>,
+        db: &C,
+    ) -> Result<(), Error> {
+        let entities = identifiers
+            .into_iter()
+            .map(|id| vulnerability::ActiveModel {
+                id: Set(id.into()),
+                ..Default::default()
+            });
+
+        for chunk in &entities.chunked() {
+            vulnerability::Entity::insert_many(chunk)
+                .on_conflict(
+                    OnConflict::column(vulnerability::Column::Id)
+                        .do_nothing()
+                        .to_owned(),
+                )
+                .exec_without_returning(db)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     #[instrument(skip(self, connection), err(level=tracing::Level::INFO))]
     pub async fn get_vulnerability<C: ConnectionTrait>(
         &self,
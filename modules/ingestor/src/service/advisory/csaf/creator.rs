@@ -1,6 +1,6 @@
 use crate::{
     graph::{
-        advisory::advisory_vulnerability::{Version, VersionInfo, VersionSpec},
+        advisory::advisory_vulnerability::{StatusInformation, VersionInfo},
         cpe::CpeCreator,
         organization::{OrganizationContext, OrganizationInformation},
         product::ProductInformation,
@@ -8,18 +8,27 @@ use crate::{
         Graph,
     },
     service::{
-        advisory::csaf::{product_status::ProductStatus, util::ResolveProductIdCache},
+        advisory::{
+            csaf::{
+                product_status::ProductStatus, remediation::flag_label, util::ResolveProductIdCache,
+            },
+            purl_version_info,
+        },
         Error,
     },
 };
-use csaf::{definitions::ProductIdT, Csaf};
+use csaf::{
+    definitions::{ProductGroupIdT, ProductIdT},
+    vulnerability::{ThreatCategory, Vulnerability},
+    Csaf,
+};
 use sea_orm::{ActiveValue::Set, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
 use std::collections::{hash_map::Entry, HashMap, HashSet};
 use tracing::instrument;
 use trustify_common::{cpe::Cpe, db::chunk::EntityChunkedIter, purl::Purl};
 use trustify_entity::{
     organization, product, product_status, product_version_range, purl_status, status,
-    version_range, version_scheme::VersionScheme,
+    version_range,
 };
 use uuid::Uuid;
 
@@ -31,13 +40,35 @@ struct PurlStatus {
     info: VersionInfo,
 }
 
+/// The IDs of product and purl statuses.
+#[derive(Debug, Default)]
+pub struct StatusIds {
+    pub product_status: HashSet<Uuid>,
+    pub purl_status: HashSet<Uuid>,
+}
+
+impl StatusIds {
+    pub fn is_empty(&self) -> bool {
+        self.product_status.is_empty() && self.purl_status.is_empty()
+    }
+}
+
 #[derive(Debug)]
 pub struct StatusCreator<'a> {
     cache: ResolveProductIdCache<'a>,
     advisory_id: Uuid,
     vulnerability_id: String,
-    entries: HashSet<PurlStatus>,
+    /// The purl statuses, with their ID and the merged information of all products
+    entries: HashMap<PurlStatus, (Uuid, StatusInformation)>,
     products: HashSet<ProductStatus>,
+    /// The products, by CSAF product ID
+    product_ids: HashMap<String, HashSet<ProductStatus>>,
+    /// The IDs of the product statuses created for a product
+    product_status_ids: HashMap<ProductStatus, Vec<Uuid>>,
+    /// The IDs of the purl statuses created for a product
+    purl_status_ids: HashMap<ProductStatus, Vec<Uuid>>,
+    /// The justification, impact and action statements, by CSAF product ID
+    statements: HashMap<String, StatusInformation>,
 }
 
 impl<'a> StatusCreator<'a> {
@@ -47,15 +78,82 @@ impl<'a> StatusCreator<'a> {
             cache,
             advisory_id,
             vulnerability_id: vulnerability_identifier,
-            entries: HashSet::new(),
+            entries: HashMap::new(),
             products: HashSet::new(),
+            product_ids: HashMap::new(),
+            product_status_ids: HashMap::new(),
+            purl_status_ids: HashMap::new(),
+            statements: HashMap::new(),
+        }
+    }
+
+    /// Record the justification, impact and action statements of the products of a
+    /// vulnerability.
+    ///
+    /// The justification is taken from the flags, the impact statement from the threats of
+    /// category "impact" and the action statement from the remediations. If there is more than
+    /// one, the first one wins. This must be called before adding products using
+    /// [`Self::add_all`].
+    pub fn add_statements(&mut self, vulnerability: &Vulnerability) {
+        for flag in vulnerability.flags.iter().flatten() {
+            let justification = flag_label(&flag.label).to_string();
+            for id in self.resolve_ids(&flag.product_ids, &flag.group_ids) {
+                self.statements
+                    .entry(id)
+                    .or_default()
+                    .justification
+                    .get_or_insert_with(|| justification.clone());
+            }
+        }
+
+        for threat in vulnerability.threats.iter().flatten() {
+            if !matches!(threat.category, ThreatCategory::Impact) {
+                continue;
+            }
+            for id in self.resolve_ids(&threat.product_ids, &threat.group_ids) {
+                self.statements
+                    .entry(id)
+                    .or_default()
+                    .impact_statement
+                    .get_or_insert_with(|| threat.details.clone());
+            }
+        }
+
+        for remediation in vulnerability.remediations.iter().flatten() {
+            for id in self.resolve_ids(&remediation.product_ids, &remediation.group_ids) {
+                self.statements
+                    .entry(id)
+                    .or_default()
+                    .action_statement
+                    .get_or_insert_with(|| remediation.details.clone());
+            }
         }
     }
 
+    /// Get the CSAF product IDs, referenced directly or through a product group.
+    fn resolve_ids(
+        &self,
+        product_ids: &Option<Vec<ProductIdT>>,
+        group_ids: &Option<Vec<ProductGroupIdT>>,
+    ) -> Vec<String> {
+        let grouped = group_ids
+            .iter()
+            .flatten()
+            .flat_map(|id| self.cache.get_product_group(id));
+
+        product_ids
+            .iter()
+            .flatten()
+            .chain(grouped)
+            .map(|id| id.0.clone())
+            .collect()
+    }
+
     pub fn add_all(&mut self, ps: &Option<Vec<ProductIdT>>, status: &'static str) {
         for r in ps.iter().flatten() {
             let mut product = ProductStatus {
                 status,
+                information: self.statements.get(&r.0).cloned().unwrap_or_default(),
                 ..Default::default()
             };
             let mut product_ids = vec![];
@@ -83,10 +181,39 @@ impl<'a> StatusCreator<'a> {
                     },
                 );
             }
+            self.product_ids
+                .entry(r.0.clone())
+                .or_default()
+                .insert(product.clone());
             self.products.insert(product);
         }
     }
 
+    /// Get the IDs of the statuses created for CSAF products, referenced directly or through a
+    /// product group.
+    ///
+    /// This requires the statuses to be created first, using [`Self::create`].
+    pub fn status_ids(
+        &self,
+        product_ids: &Option<Vec<ProductIdT>>,
+        group_ids: &Option<Vec<ProductGroupIdT>>,
+    ) -> StatusIds {
+        let mut result = StatusIds::default();
+
+        for product_id in self.resolve_ids(product_ids, group_ids) {
+            for product in self.product_ids.get(&product_id).into_iter().flatten() {
+                result
+                    .product_status
+                    .extend(self.product_status_ids.get(product).into_iter().flatten());
+                result
+                    .purl_status
+                    .extend(self.purl_status_ids.get(product).into_iter().flatten());
+            }
+        }
+
+        result
+    }
+
     async fn check_status(
         status: &str,
         connection: &impl ConnectionTrait,
@@ -206,14 +333,23 @@ impl<'a> StatusCreator<'a> {
                 };
 
                 for package in packages {
+                    let id = Uuid::now_v7();
+                    self.product_status_ids
+                        .entry(product.clone())
+                        .or_default()
+                        .push(id);
+
                     let base_product = product_status::ActiveModel {
-                        id: Default::default(),
+                        id: Set(id),
                         product_version_range_id: range.clone().id,
                         advisory_id: Set(self.advisory_id),
                         vulnerability_id: Set(self.vulnerability_id.clone()),
                         package: Set(package),
                         context_cpe_id: Set(product.cpe.as_ref().map(Cpe::uuid)),
                         status_id: Set(status_id.id),
+                        justification: Set(product.information.justification.clone()),
+                        impact_statement: Set(product.information.impact_statement.clone()),
+                        action_statement: Set(product.information.action_statement.clone()),
                     };
 
                     if let Some(cpe) = &product.cpe {
@@ -227,16 +363,7 @@ impl<'a> StatusCreator<'a> {
             for purl in &product.purls {
                 let purl = purl.clone();
                 // Ingest purl status
-                let info = match purl.version.clone() {
-                    Some(version) => VersionInfo {
-                        scheme: VersionScheme::Generic,
-                        spec: VersionSpec::Exact(version),
-                    },
-                    None => VersionInfo {
-                        spec: VersionSpec::Range(Version::Unbounded, Version::Unbounded),
-                        scheme: VersionScheme::Semver,
-                    },
-                };
+                let info = purl_version_info(&purl);
 
                 let purl_status = PurlStatus {
                     cpe: product.cpe.clone(),
@@ -245,11 +372,20 @@ impl<'a> StatusCreator<'a> {
                     info,
                 };
 
-                self.entries.insert(purl_status);
+                let (id, information) = self
+                    .entries
+                    .entry(purl_status)
+                    .or_insert_with(|| (Uuid::now_v7(), StatusInformation::default()));
+                information.merge(&product.information);
+                let id = *id;
+                self.purl_status_ids
+                    .entry(product.clone())
+                    .or_default()
+                    .push(id);
             }
         }
 
-        for ps in &self.entries {
+        for ps in self.entries.keys() {
             // add to PURL creator
             purls.add(ps.purl.clone());
 
@@ -306,7 +442,7 @@ impl<'a> StatusCreator<'a> {
         let mut version_ranges = Vec::new();
         let mut package_statuses = Vec::new();
 
-        for ps in &self.entries {
+        for (ps, (id, information)) in &self.entries {
             let status = checked.get(&ps.status).ok_or_else(|| {
                 Error::Graph(crate::graph::error::Error::InvalidStatus(
                     ps.status.to_string(),
@@ -322,13 +458,16 @@ impl<'a> StatusCreator<'a> {
             version_ranges.push(version_range);
 
             let package_status = purl_status::ActiveModel {
-                id: Default::default(),
+                id: Set(*id),
                 advisory_id: Set(self.advisory_id),
                 vulnerability_id: Set(self.vulnerability_id.clone()),
                 status_id: Set(status.id),
                 base_purl_id: Set(package_id),
                 context_cpe_id: Set(cpe_id),
                 version_range_id: Set(version_range_id),
+                justification: Set(information.justification.clone()),
+                impact_statement: Set(information.impact_statement.clone()),
+                action_statement: Set(information.action_statement.clone()),
             };
 
             package_statuses.push(package_status);
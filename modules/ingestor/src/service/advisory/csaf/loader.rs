@@ -8,14 +8,11 @@ use crate::{
     },
     model::IngestResult,
     service::{
-        advisory::csaf::{util::gen_identifier, StatusCreator},
+        advisory::csaf::{util::gen_identifier, RemediationCreator, StatusCreator},
         Error, Warnings,
     },
 };
-use csaf::{
-    vulnerability::{ProductStatus, Vulnerability},
-    Csaf,
-};
+use csaf::{vulnerability::Vulnerability, Csaf};
 use hex::ToHex;
 use sbom_walker::report::ReportSink;
 use sea_orm::{ConnectionTrait, TransactionTrait};
@@ -24,7 +21,7 @@ use std::{fmt::Debug, str::FromStr};
 use time::OffsetDateTime;
 use tracing::instrument;
 use trustify_common::{hashing::Digests, id::Id};
-use trustify_cvss::cvss3::Cvss3Base;
+use trustify_cvss::{cvss2::vector::Cvss2Vector, cvss3::vector::Cvss3Vector};
 use trustify_entity::labels::Labels;
 
 struct Information<'a>(&'a Csaf);
@@ -167,18 +164,36 @@ impl<'g> CsafLoader<'g> {
             )
             .await?;
 
-        if let Some(product_status) = &vulnerability.product_status {
-            self.ingest_product_statuses(csaf, &advisory_vulnerability, product_status, connection)
-                .await?;
-        }
+        self.ingest_product_statuses(csaf, &advisory_vulnerability, vulnerability, connection)
+            .await?;
 
         for score in vulnerability.scores.iter().flatten() {
+            if let Some(vector) = score
+                .cvss_v2
+                .as_ref()
+                .and_then(|v2| v2["vectorString"].as_str())
+            {
+                match Cvss2Vector::from_str(vector) {
+                    Ok(cvss2) => {
+                        log::debug!("{cvss2:?}");
+                        advisory_vulnerability
+                            .ingest_cvss2_score(cvss2, connection)
+                            .await?;
+                    }
+                    Err(err) => {
+                        let msg = format!("Unable to parse CVSS2: {:#?}", err);
+                        log::info!("{msg}");
+                        report.error(msg);
+                    }
+                }
+            }
+
             if let Some(v3) = &score.cvss_v3 {
-                match Cvss3Base::from_str(&v3.to_string()) {
+                match Cvss3Vector::from_str(&v3.to_string()) {
                     Ok(cvss3) => {
                         log::debug!("{cvss3:?}");
                         advisory_vulnerability
-                            .ingest_cvss3_score(cvss3, connection)
+                            .ingest_cvss3_vector(cvss3, connection)
                             .await?;
                     }
                     Err(err) => {
@@ -198,24 +213,40 @@ impl<'g> CsafLoader<'g> {
         &self,
         csaf: &Csaf,
         advisory_vulnerability: &AdvisoryVulnerabilityContext<'_>,
-        product_status: &ProductStatus,
+        vulnerability: &Vulnerability,
         connection: &C,
     ) -> Result<(), Error> {
-        let mut creator = StatusCreator::new(
-            csaf,
-            advisory_vulnerability.advisory_vulnerability.advisory_id,
-            advisory_vulnerability
-                .advisory_vulnerability
-                .vulnerability_id
-                .clone(),
-        );
+        let advisory_id = advisory_vulnerability.advisory_vulnerability.advisory_id;
+        let vulnerability_id = &advisory_vulnerability
+            .advisory_vulnerability
+            .vulnerability_id;
 
-        creator.add_all(&product_status.fixed, "fixed");
-        creator.add_all(&product_status.known_not_affected, "not_affected");
-        creator.add_all(&product_status.known_affected, "affected");
+        let mut creator = StatusCreator::new(csaf, advisory_id, vulnerability_id.clone());
+        creator.add_statements(vulnerability);
+
+        if let Some(product_status) = &vulnerability.product_status {
+            creator.add_all(&product_status.first_affected, "first_affected");
+            creator.add_all(&product_status.first_fixed, "first_fixed");
+            creator.add_all(&product_status.fixed, "fixed");
+            creator.add_all(&product_status.known_affected, "affected");
+            creator.add_all(&product_status.known_not_affected, "not_affected");
+            creator.add_all(&product_status.last_affected, "last_affected");
+            creator.add_all(&product_status.recommended, "recommended");
+            creator.add_all(&product_status.under_investigation, "under_investigation");
+        }
 
         creator.create(self.graph, connection).await?;
 
+        // remediations, threats and flags refer to the statuses of the products
+
+        let mut remediations = RemediationCreator::new(advisory_id, vulnerability_id.clone());
+
+        remediations.add_remediations(vulnerability.remediations.iter().flatten(), &creator);
+        remediations.add_threats(vulnerability.threats.iter().flatten(), &creator);
+        remediations.add_flags(vulnerability.flags.iter().flatten(), &creator);
+
+        remediations.create(connection).await?;
+
         Ok(())
     }
 }
@@ -226,8 +257,17 @@ mod test {
     use hex::ToHex;
 
     use crate::graph::Graph;
+    use sea_orm::{ColumnTrait, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter};
+    use std::collections::HashSet;
     use test_context::test_context;
     use test_log::test;
+    use trustify_entity::{
+        flag::{self, FlagLabel},
+        product_status, purl_status,
+        remediation::{self, RemediationCategory},
+        threat::{self, ThreatCategory},
+        version_range,
+    };
     use trustify_test_context::{document, TrustifyContext};
 
     #[test_context(TrustifyContext)]
@@ -385,6 +425,76 @@ mod test {
             "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:L/I:L/A:L"
         );
 
+        let remediations = remediation::Entity::find()
+            .filter(remediation::Column::VulnerabilityId.eq("CVE-2023-0044"))
+            .all(&ctx.db)
+            .await?;
+        assert_eq!(4, remediations.len());
+
+        let fix = remediations
+            .iter()
+            .find(|r| r.url.as_deref() == Some("https://access.redhat.com/errata/RHSA-2023:1006"))
+            .expect("must be found");
+        assert_eq!(fix.category, RemediationCategory::VendorFix);
+        assert_eq!(
+            1,
+            fix.find_related(product_status::Entity)
+                .count(&ctx.db)
+                .await?
+        );
+
+        let threats = threat::Entity::find().all(&ctx.db).await?;
+        assert_eq!(1, threats.len());
+        assert_eq!(threats[0].category, ThreatCategory::Impact);
+        assert_eq!(threats[0].details, "Low");
+
+        let flags = flag::Entity::find().all(&ctx.db).await?;
+        assert_eq!(1, flags.len());
+        assert_eq!(flags[0].label, FlagLabel::VulnerableCodeNotPresent);
+        // all the products known to be not affected
+        assert_eq!(
+            9,
+            flags[0]
+                .find_related(product_status::Entity)
+                .count(&ctx.db)
+                .await?
+        );
+
+        // the flag is the justification of the product statuses
+        let not_affected = product_status::Entity::find()
+            .filter(product_status::Column::Justification.eq("vulnerable_code_not_present"))
+            .all(&ctx.db)
+            .await?;
+        assert_eq!(9, not_affected.len());
+        assert!(not_affected
+            .iter()
+            .all(|status| status.action_statement.is_some() && status.impact_statement.is_none()));
+
+        // products with different statements don't lead to duplicate purl statuses
+        let purl_statuses = purl_status::Entity::find()
+            .find_also_related(version_range::Entity)
+            .all(&ctx.db)
+            .await?;
+        let unique = purl_statuses
+            .iter()
+            .map(|(status, range)| {
+                (
+                    status.base_purl_id,
+                    status.status_id,
+                    status.context_cpe_id,
+                    range.as_ref().map(|range| {
+                        (
+                            range.low_version.clone(),
+                            range.low_inclusive,
+                            range.high_version.clone(),
+                            range.high_inclusive,
+                        )
+                    }),
+                )
+            })
+            .collect::<HashSet<_>>();
+        assert_eq!(unique.len(), purl_statuses.len());
+
         Ok(())
     }
 }
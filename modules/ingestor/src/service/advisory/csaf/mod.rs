@@ -3,4 +3,6 @@ mod product_status;
 mod util;
 
 mod creator;
+mod remediation;
 pub use creator::*;
+pub use remediation::*;
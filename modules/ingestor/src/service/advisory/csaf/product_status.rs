@@ -1,5 +1,7 @@
 use super::util::branch_purl;
-use crate::graph::advisory::advisory_vulnerability::{Version, VersionInfo, VersionSpec};
+use crate::graph::advisory::advisory_vulnerability::{
+    StatusInformation, Version, VersionInfo, VersionSpec,
+};
 use cpe::cpe::Cpe;
 use csaf::definitions::{Branch, BranchCategory, FullProductName};
 use trustify_common::purl::Purl;
@@ -14,6 +16,7 @@ pub struct ProductStatus {
     pub status: &'static str,
     pub purls: Vec<Purl>,
     pub packages: Vec<String>,
+    pub information: StatusInformation,
 }
 
 impl ProductStatus {
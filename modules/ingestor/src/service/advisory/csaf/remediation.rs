@@ -0,0 +1,261 @@
+use crate::service::{
+    advisory::csaf::{StatusCreator, StatusIds},
+    Error,
+};
+use csaf::vulnerability::{
+    Flag, FlagLabel, Remediation, RemediationCategory, Threat, ThreatCategory,
+};
+use sea_orm::{ActiveValue::Set, ConnectionTrait, EntityTrait};
+use time::OffsetDateTime;
+use tracing::instrument;
+use trustify_common::db::chunk::EntityChunkedIter;
+use trustify_entity::{
+    flag, flag_product_status, flag_purl_status, remediation, remediation_product_status,
+    remediation_purl_status, threat, threat_product_status, threat_purl_status,
+};
+use uuid::Uuid;
+
+/// Creates the remediations, threats and flags of a vulnerability, linked to the statuses of the
+/// products they apply to.
+#[derive(Debug)]
+pub struct RemediationCreator {
+    advisory_id: Uuid,
+    vulnerability_id: String,
+    remediations: Vec<(Uuid, remediation::ActiveModel, StatusIds)>,
+    threats: Vec<(Uuid, threat::ActiveModel, StatusIds)>,
+    flags: Vec<(Uuid, flag::ActiveModel, StatusIds)>,
+}
+
+impl RemediationCreator {
+    pub fn new(advisory_id: Uuid, vulnerability_id: String) -> Self {
+        Self {
+            advisory_id,
+            vulnerability_id,
+            remediations: Vec::new(),
+            threats: Vec::new(),
+            flags: Vec::new(),
+        }
+    }
+
+    pub fn add_remediations<'r>(
+        &mut self,
+        remediations: impl IntoIterator<Item = &'r Remediation>,
+        statuses: &StatusCreator,
+    ) {
+        for remediation in remediations {
+            let id = Uuid::now_v7();
+            let entity = remediation::ActiveModel {
+                id: Set(id),
+                advisory_id: Set(self.advisory_id),
+                vulnerability_id: Set(self.vulnerability_id.clone()),
+                category: Set(remediation_category(&remediation.category)),
+                details: Set(remediation.details.clone()),
+                url: Set(remediation.url.as_ref().map(|url| url.to_string())),
+                date: Set(remediation
+                    .date
+                    .and_then(|date| OffsetDateTime::from_unix_timestamp(date.timestamp()).ok())),
+            };
+
+            let ids = statuses.status_ids(&remediation.product_ids, &remediation.group_ids);
+            self.remediations.push((id, entity, ids));
+        }
+    }
+
+    pub fn add_threats<'t>(
+        &mut self,
+        threats: impl IntoIterator<Item = &'t Threat>,
+        statuses: &StatusCreator,
+    ) {
+        for threat in threats {
+            let id = Uuid::now_v7();
+            let entity = threat::ActiveModel {
+                id: Set(id),
+                advisory_id: Set(self.advisory_id),
+                vulnerability_id: Set(self.vulnerability_id.clone()),
+                category: Set(threat_category(&threat.category)),
+                details: Set(threat.details.clone()),
+                date: Set(threat
+                    .date
+                    .and_then(|date| OffsetDateTime::from_unix_timestamp(date.timestamp()).ok())),
+            };
+
+            let ids = statuses.status_ids(&threat.product_ids, &threat.group_ids);
+            self.threats.push((id, entity, ids));
+        }
+    }
+
+    pub fn add_flags<'f>(
+        &mut self,
+        flags: impl IntoIterator<Item = &'f Flag>,
+        statuses: &StatusCreator,
+    ) {
+        for flag in flags {
+            let id = Uuid::now_v7();
+            let entity = flag::ActiveModel {
+                id: Set(id),
+                advisory_id: Set(self.advisory_id),
+                vulnerability_id: Set(self.vulnerability_id.clone()),
+                label: Set(flag_label(&flag.label)),
+                date: Set(flag
+                    .date
+                    .and_then(|date| OffsetDateTime::from_unix_timestamp(date.timestamp()).ok())),
+            };
+
+            let ids = statuses.status_ids(&flag.product_ids, &flag.group_ids);
+            self.flags.push((id, entity, ids));
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    pub async fn create<C: ConnectionTrait>(self, connection: &C) -> Result<(), Error> {
+        let mut remediations = Vec::new();
+        let mut remediation_product_statuses = Vec::new();
+        let mut remediation_purl_statuses = Vec::new();
+
+        for (id, entity, ids) in self.remediations {
+            remediation_product_statuses.extend(ids.product_status.into_iter().map(|status| {
+                remediation_product_status::ActiveModel {
+                    remediation_id: Set(id),
+                    product_status_id: Set(status),
+                }
+            }));
+            remediation_purl_statuses.extend(ids.purl_status.into_iter().map(|status| {
+                remediation_purl_status::ActiveModel {
+                    remediation_id: Set(id),
+                    purl_status_id: Set(status),
+                }
+            }));
+            remediations.push(entity);
+        }
+
+        let mut threats = Vec::new();
+        let mut threat_product_statuses = Vec::new();
+        let mut threat_purl_statuses = Vec::new();
+
+        for (id, entity, ids) in self.threats {
+            threat_product_statuses.extend(ids.product_status.into_iter().map(|status| {
+                threat_product_status::ActiveModel {
+                    threat_id: Set(id),
+                    product_status_id: Set(status),
+                }
+            }));
+            threat_purl_statuses.extend(ids.purl_status.into_iter().map(|status| {
+                threat_purl_status::ActiveModel {
+                    threat_id: Set(id),
+                    purl_status_id: Set(status),
+                }
+            }));
+            threats.push(entity);
+        }
+
+        let mut flags = Vec::new();
+        let mut flag_product_statuses = Vec::new();
+        let mut flag_purl_statuses = Vec::new();
+
+        for (id, entity, ids) in self.flags {
+            flag_product_statuses.extend(ids.product_status.into_iter().map(|status| {
+                flag_product_status::ActiveModel {
+                    flag_id: Set(id),
+                    product_status_id: Set(status),
+                }
+            }));
+            flag_purl_statuses.extend(ids.purl_status.into_iter().map(|status| {
+                flag_purl_status::ActiveModel {
+                    flag_id: Set(id),
+                    purl_status_id: Set(status),
+                }
+            }));
+            flags.push(entity);
+        }
+
+        // the entries first, then the links to the statuses
+
+        for batch in &remediations.chunked() {
+            remediation::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &threats.chunked() {
+            threat::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &flags.chunked() {
+            flag::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &remediation_product_statuses.chunked() {
+            remediation_product_status::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &remediation_purl_statuses.chunked() {
+            remediation_purl_status::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &threat_product_statuses.chunked() {
+            threat_product_status::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &threat_purl_statuses.chunked() {
+            threat_purl_status::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &flag_product_statuses.chunked() {
+            flag_product_status::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        for batch in &flag_purl_statuses.chunked() {
+            flag_purl_status::Entity::insert_many(batch)
+                .exec_without_returning(connection)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn remediation_category(category: &RemediationCategory) -> remediation::RemediationCategory {
+    match category {
+        RemediationCategory::VendorFix => remediation::RemediationCategory::VendorFix,
+        RemediationCategory::Workaround => remediation::RemediationCategory::Workaround,
+        RemediationCategory::Mitigation => remediation::RemediationCategory::Mitigation,
+        RemediationCategory::NoneAvailable => remediation::RemediationCategory::NoneAvailable,
+        RemediationCategory::NoFixPlanned => remediation::RemediationCategory::NoFixPlanned,
+    }
+}
+
+fn threat_category(category: &ThreatCategory) -> threat::ThreatCategory {
+    match category {
+        ThreatCategory::Impact => threat::ThreatCategory::Impact,
+        ThreatCategory::ExploitStatus => threat::ThreatCategory::ExploitStatus,
+        ThreatCategory::TargetSet => threat::ThreatCategory::TargetSet,
+    }
+}
+
+pub(super) fn flag_label(label: &FlagLabel) -> flag::FlagLabel {
+    match label {
+        FlagLabel::ComponentNotPresent => flag::FlagLabel::ComponentNotPresent,
+        FlagLabel::VulnerableCodeNotPresent => flag::FlagLabel::VulnerableCodeNotPresent,
+        FlagLabel::VulnerableCodeNotInExecutePath => {
+            flag::FlagLabel::VulnerableCodeNotInExecutePath
+        }
+        FlagLabel::VulnerableCodeCannotBeControlledByAdversary => {
+            flag::FlagLabel::VulnerableCodeCannotBeControlledByAdversary
+        }
+        FlagLabel::InlineMitigationsAlreadyExist => flag::FlagLabel::InlineMitigationsAlreadyExist,
+    }
+}
@@ -1,5 +1,5 @@
 use csaf::{
-    definitions::{Branch, BranchesT, ProductIdT},
+    definitions::{Branch, BranchesT, ProductGroupIdT, ProductIdT},
     product_tree::{ProductTree, Relationship},
     Csaf,
 };
@@ -102,6 +102,8 @@ pub struct ResolveProductIdCache<'a> {
     full_product_name_to_backtrace: HashMap<&'a str, Vec<&'a Branch>>,
     /// Lookup from product IDs to relationships
     product_id_to_relationship: HashMap<&'a str, &'a Relationship>,
+    /// Lookup from product group IDs to the IDs of their products
+    product_group_to_product_ids: HashMap<&'a str, &'a [ProductIdT]>,
 }
 
 impl<'a> ResolveProductIdCache<'a> {
@@ -127,11 +129,22 @@ impl<'a> ResolveProductIdCache<'a> {
             .map(|rel| (rel.full_product_name.product_id.0.as_str(), rel))
             .collect();
 
+        // product groups
+
+        let groups = csaf
+            .product_tree
+            .iter()
+            .flat_map(|pt| &pt.product_groups)
+            .flatten()
+            .map(|group| (group.group_id.0.as_str(), group.product_ids.as_slice()))
+            .collect();
+
         // done
 
         Self {
             full_product_name_to_backtrace: cache,
             product_id_to_relationship: rels,
+            product_group_to_product_ids: groups,
         }
     }
 
@@ -147,6 +160,14 @@ impl<'a> ResolveProductIdCache<'a> {
     pub fn get_relationship(&self, product_id: &str) -> Option<&'a Relationship> {
         self.product_id_to_relationship.get(product_id).copied()
     }
+
+    /// Get the IDs of the products of a product group (by ID).
+    pub fn get_product_group(&self, group_id: &ProductGroupIdT) -> &'a [ProductIdT] {
+        self.product_group_to_product_ids
+            .get(group_id.0.as_str())
+            .copied()
+            .unwrap_or_default()
+    }
 }
 
 pub fn gen_identifier(csaf: &Csaf) -> String {
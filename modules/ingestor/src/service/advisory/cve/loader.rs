@@ -8,17 +8,19 @@ use crate::{
         Graph,
     },
     model::IngestResult,
-    service::{advisory::cve::divination::divine_purl, Error},
+    service::{advisory::cve::divination::divine_purl, Error, Warnings},
 };
 use cve::{
     common::{Description, Product, Status, VersionRange},
+    published::Metric,
     Cve, Timestamp,
 };
 use sea_orm::TransactionTrait;
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr};
 use time::OffsetDateTime;
 use tracing::instrument;
 use trustify_common::{hashing::Digests, id::Id};
+use trustify_cvss::{cvss2::vector::Cvss2Vector, cvss4::Cvss4Base};
 use trustify_entity::{labels::Labels, version_scheme::VersionScheme};
 
 /// Loader capable of parsing a CVE Record JSON file
@@ -49,6 +51,7 @@ impl<'g> CveLoader<'g> {
     ) -> Result<IngestResult, Error> {
         let id = cve.id();
         let labels = labels.into().add("type", "cve");
+        let warnings = Warnings::new();
 
         let tx = self.graph.db.begin().await?;
 
@@ -104,6 +107,32 @@ impl<'g> CveLoader<'g> {
             )
             .await?;
 
+        for vector in Self::cvss_vectors(&cve, |metric| metric.cvss_v2_0.as_ref()) {
+            match Cvss2Vector::from_str(vector) {
+                Ok(cvss2) => {
+                    advisory_vuln.ingest_cvss2_score(cvss2, &tx).await?;
+                }
+                Err(err) => {
+                    let msg = format!("Unable to parse CVSS2: {:#?}", err);
+                    log::info!("{msg}");
+                    warnings.error(msg)
+                }
+            }
+        }
+
+        for vector in Self::cvss_vectors(&cve, |metric| metric.cvss_v4_0.as_ref()) {
+            match Cvss4Base::from_str(vector) {
+                Ok(cvss4) => {
+                    advisory_vuln.ingest_cvss4_score(cvss4, &tx).await?;
+                }
+                Err(err) => {
+                    let msg = format!("Unable to parse CVSS4: {:#?}", err);
+                    log::info!("{msg}");
+                    warnings.error(msg)
+                }
+            }
+        }
+
         if let Some(affected) = affected {
             for product in affected {
                 if let Some(purl) = divine_purl(product) {
@@ -174,10 +203,25 @@ impl<'g> CveLoader<'g> {
         Ok(IngestResult {
             id: Id::Uuid(advisory.advisory.id),
             document_id: Some(id.to_string()),
-            warnings: vec![],
+            warnings: warnings.into(),
         })
     }
 
+    /// Collect the CVSS vector strings of one version from the metrics of the CNA container.
+    fn cvss_vectors(cve: &Cve, version: fn(&Metric) -> Option<&serde_json::Value>) -> Vec<&str> {
+        match cve {
+            Cve::Rejected(_) => vec![],
+            Cve::Published(published) => published
+                .containers
+                .cna
+                .metrics
+                .iter()
+                .filter_map(version)
+                .filter_map(|cvss| cvss["vectorString"].as_str())
+                .collect(),
+        }
+    }
+
     /// Build descriptions,
     fn build_descriptions(descriptions: &[Description]) -> (Vec<(&str, &str)>, Option<&str>) {
         let mut english_description = None;
@@ -379,6 +423,102 @@ mod test {
         Ok(())
     }
 
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn cvss4_metrics(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let graph = Graph::new(ctx.db.clone());
+
+        let (mut cve, digests): (serde_json::Value, _) =
+            document("mitre/CVE-2024-28111.json").await?;
+        cve["containers"]["cna"]["metrics"]
+            .as_array_mut()
+            .expect("must be expected test data")
+            .push(serde_json::json!({
+                "cvssV4_0": {
+                    "baseScore": 9.3,
+                    "baseSeverity": "CRITICAL",
+                    "vectorString": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+                    "version": "4.0"
+                }
+            }));
+        let cve: Cve = serde_json::from_value(cve)?;
+
+        let loader = CveLoader::new(&graph);
+        let result = loader
+            .load(("file", "CVE-2024-28111.json"), cve, &digests)
+            .await?;
+        assert!(result.warnings.is_empty());
+
+        let advisory_vuln = graph
+            .get_advisory_by_digest(&digests.sha256.encode_hex::<String>(), &ctx.db)
+            .await?
+            .expect("advisory must be found")
+            .get_vulnerability("CVE-2024-28111", &ctx.db)
+            .await?
+            .expect("vulnerability must be linked");
+
+        let scores = advisory_vuln.cvss4_scores(&ctx.db).await?;
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].score().value(), 9.3);
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn cvss2_metrics(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let graph = Graph::new(ctx.db.clone());
+
+        let (mut cve, digests): (serde_json::Value, _) =
+            document("mitre/CVE-2024-28111.json").await?;
+        let metrics = cve["containers"]["cna"]["metrics"]
+            .as_array_mut()
+            .expect("must be expected test data");
+        // the same vector twice, and another one
+        for vector in [
+            "AV:N/AC:L/Au:N/C:P/I:N/A:P/E:F/RL:OF/RC:C",
+            "AV:N/AC:L/Au:N/C:P/I:N/A:P/E:F/RL:OF/RC:C",
+            "AV:N/AC:H/Au:N/C:P/I:N/A:N",
+        ] {
+            metrics.push(serde_json::json!({
+                "cvssV2_0": {
+                    "baseScore": 6.4,
+                    "vectorString": vector,
+                    "version": "2.0"
+                }
+            }));
+        }
+        let cve: Cve = serde_json::from_value(cve)?;
+
+        let loader = CveLoader::new(&graph);
+        let result = loader
+            .load(("file", "CVE-2024-28111.json"), cve, &digests)
+            .await?;
+        assert!(result.warnings.is_empty());
+
+        let advisory_vuln = graph
+            .get_advisory_by_digest(&digests.sha256.encode_hex::<String>(), &ctx.db)
+            .await?
+            .expect("advisory must be found")
+            .get_vulnerability("CVE-2024-28111", &ctx.db)
+            .await?
+            .expect("vulnerability must be linked");
+
+        let scores = advisory_vuln.cvss2_scores(&ctx.db).await?;
+        assert_eq!(scores.len(), 2);
+
+        let score = &scores[0];
+        assert_eq!(score.score().value(), 6.4);
+        assert_eq!(score.temporal_score().map(|s| s.value()), Some(5.3));
+        assert_eq!(
+            score.to_string(),
+            "AV:N/AC:L/Au:N/C:P/I:N/A:P/E:F/RL:OF/RC:C"
+        );
+        assert_eq!(scores[1].to_string(), "AV:N/AC:H/Au:N/C:P/I:N/A:N");
+
+        Ok(())
+    }
+
     #[test_context(TrustifyContext)]
     #[test(tokio::test)]
     async fn divine_purls(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
@@ -0,0 +1,240 @@
+use crate::{
+    graph::{
+        advisory::{
+            advisory_vulnerability::StatusInformation, AdvisoryContext, AdvisoryInformation,
+            AdvisoryVulnerabilityInformation,
+        },
+        Graph,
+    },
+    model::IngestResult,
+    service::{
+        advisory::{
+            cyclonedx::{parse_timestamp, CycloneDxVex, Vulnerability},
+            purl_version_info,
+        },
+        Error, Warnings,
+    },
+};
+use hex::ToHex;
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use std::fmt::Debug;
+use tracing::instrument;
+use trustify_common::{hashing::Digests, id::Id};
+use trustify_entity::labels::Labels;
+
+/// Loader capable of turning the vulnerabilities of a CycloneDX document into the statuses of an
+/// advisory.
+pub struct CyclonedxVexLoader<'g> {
+    graph: &'g Graph,
+}
+
+impl<'g> CyclonedxVexLoader<'g> {
+    pub fn new(graph: &'g Graph) -> Self {
+        Self { graph }
+    }
+
+    /// Load a standalone CycloneDX VEX document.
+    #[instrument(skip(self, vex), ret)]
+    pub async fn load(
+        &self,
+        labels: impl Into<Labels> + Debug,
+        vex: CycloneDxVex,
+        digests: &Digests,
+    ) -> Result<IngestResult, Error> {
+        let warnings = Warnings::new();
+
+        let tx = self.graph.db.begin().await?;
+        let advisory = self.ingest(labels, &vex, digests, &warnings, &tx).await?;
+        tx.commit().await?;
+
+        Ok(IngestResult {
+            id: Id::Uuid(advisory.advisory.id),
+            document_id: Some(advisory.advisory.document_id),
+            warnings: warnings.into(),
+        })
+    }
+
+    /// Ingest the vulnerabilities of a CycloneDX document as an advisory.
+    ///
+    /// This is also used for vulnerabilities embedded into an SBOM, as part of ingesting the SBOM.
+    #[instrument(skip(self, vex, warnings, connection), err)]
+    pub(crate) async fn ingest<C: ConnectionTrait>(
+        &self,
+        labels: impl Into<Labels> + Debug,
+        vex: &CycloneDxVex,
+        digests: &Digests,
+        warnings: &Warnings,
+        connection: &C,
+    ) -> Result<AdvisoryContext<'g>, Error> {
+        let labels = labels.into().add("type", "cyclonedx");
+
+        let id = vex
+            .serial_number
+            .clone()
+            .unwrap_or_else(|| digests.sha256.encode_hex());
+
+        let metadata = vex.metadata.as_ref();
+        let information = AdvisoryInformation {
+            id: id.clone(),
+            title: None,
+            version: vex
+                .version
+                .map(|version| semver::Version::new(version, 0, 0)),
+            issuer: metadata
+                .and_then(|metadata| metadata.supplier.as_ref())
+                .and_then(|supplier| supplier.name.clone()),
+            published: metadata
+                .and_then(|metadata| metadata.timestamp.as_deref())
+                .and_then(parse_timestamp),
+            modified: None,
+            withdrawn: None,
+        };
+        let advisory = self
+            .graph
+            .ingest_advisory(&id, labels, digests, information, connection)
+            .await?;
+
+        let purls = vex.purls();
+
+        for vulnerability in &vex.vulnerabilities {
+            let ids = vulnerability.ids();
+            if ids.is_empty() {
+                warnings.add("Vulnerability without an ID".to_string());
+                continue;
+            }
+
+            let status = vulnerability.status();
+            let information = status_information(vulnerability);
+
+            for id in ids {
+                self.graph.ingest_vulnerability(&id, (), connection).await?;
+                let advisory_vuln = advisory
+                    .link_to_vulnerability(
+                        &id,
+                        Some(AdvisoryVulnerabilityInformation {
+                            title: None,
+                            summary: None,
+                            description: vulnerability.description.clone(),
+                            reserved_date: None,
+                            discovery_date: vulnerability
+                                .created
+                                .as_deref()
+                                .and_then(parse_timestamp),
+                            release_date: vulnerability
+                                .published
+                                .as_deref()
+                                .and_then(parse_timestamp),
+                            cwes: (!vulnerability.cwes.is_empty()).then(|| {
+                                vulnerability
+                                    .cwes
+                                    .iter()
+                                    .map(|cwe| format!("CWE-{cwe}"))
+                                    .collect()
+                            }),
+                        }),
+                        connection,
+                    )
+                    .await?;
+
+                for affects in &vulnerability.affects {
+                    let Some(purl) = affects.purl(&purls) else {
+                        let msg = format!(
+                            "Unable to map affected component to a purl: {}",
+                            affects.r#ref
+                        );
+                        log::info!("{msg}");
+                        warnings.add(msg);
+                        continue;
+                    };
+
+                    advisory_vuln
+                        .ingest_package_status_with_information(
+                            None,
+                            &purl,
+                            status,
+                            purl_version_info(&purl),
+                            information.clone(),
+                            connection,
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        Ok(advisory)
+    }
+}
+
+/// The justification and statements of a vulnerability, as reported by its analysis.
+fn status_information(vulnerability: &Vulnerability) -> StatusInformation {
+    let analysis = vulnerability.analysis.as_ref();
+
+    StatusInformation {
+        justification: analysis.and_then(|analysis| analysis.justification.clone()),
+        impact_statement: analysis
+            .and_then(|analysis| analysis.detail.clone())
+            .or_else(|| vulnerability.detail.clone()),
+        action_statement: vulnerability
+            .recommendation
+            .clone()
+            .or_else(|| vulnerability.workaround.clone()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+    use test_context::test_context;
+    use test_log::test;
+    use trustify_entity::{purl_status, status};
+    use trustify_test_context::{document, TrustifyContext};
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn loader(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let graph = Graph::new(ctx.db.clone());
+        let loader = CyclonedxVexLoader::new(&graph);
+
+        let (vex, digests): (CycloneDxVex, _) = document("cyclonedx/vex/log4j.cdx.json").await?;
+        let result = loader.load(("source", "test"), vex, &digests).await?;
+
+        assert_eq!(
+            result.document_id.as_deref(),
+            Some("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79")
+        );
+        // one component can't be mapped to a purl
+        assert_eq!(result.warnings.len(), 1);
+
+        let advisory = graph
+            .get_advisory_by_digest(&digests.sha256.encode_hex::<String>(), &ctx.db)
+            .await?
+            .expect("must be found");
+        assert_eq!(advisory.vulnerabilities(&ctx.db).await?.len(), 2);
+
+        let affected = purl_status::Entity::find()
+            .inner_join(status::Entity)
+            .filter(status::Column::Slug.eq("affected"))
+            .filter(purl_status::Column::VulnerabilityId.eq("CVE-2021-44228"))
+            .all(&ctx.db)
+            .await?;
+        assert_eq!(affected.len(), 1);
+        assert_eq!(
+            affected[0].action_statement.as_deref(),
+            Some("Upgrade to log4j-core 2.17.1 or later")
+        );
+
+        let not_affected = purl_status::Entity::find()
+            .inner_join(status::Entity)
+            .filter(status::Column::Slug.eq("not_affected"))
+            .filter(purl_status::Column::VulnerabilityId.eq("CVE-2021-45046"))
+            .all(&ctx.db)
+            .await?;
+        assert_eq!(not_affected.len(), 2);
+        assert!(not_affected
+            .iter()
+            .all(|status| status.justification.as_deref() == Some("code_not_reachable")));
+
+        Ok(())
+    }
+}
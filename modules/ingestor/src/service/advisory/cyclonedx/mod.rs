@@ -0,0 +1,206 @@
+//! Support for the vulnerabilities of CycloneDX documents, either as standalone VEX documents or
+//! embedded into an SBOM.
+//!
+//! This only covers the parts of the document required for ingesting the vulnerabilities. The
+//! SBOM itself is processed by the CycloneDX SBOM loader.
+//!
+//! See: <https://cyclonedx.org/capabilities/vex/>
+
+pub mod loader;
+
+use std::collections::HashMap;
+use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use trustify_common::purl::Purl;
+
+/// The vulnerability related parts of a CycloneDX document.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxVex {
+    #[serde(default)]
+    pub serial_number: Option<String>,
+    #[serde(default)]
+    pub version: Option<u64>,
+    #[serde(default)]
+    pub metadata: Option<Metadata>,
+    #[serde(default)]
+    pub components: Option<Vec<Component>>,
+    #[serde(default)]
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+impl CycloneDxVex {
+    /// A standalone VEX document, which doesn't describe any components.
+    pub fn is_standalone(&self) -> bool {
+        self.components.is_none() && !self.vulnerabilities.is_empty()
+    }
+
+    /// The purls of all components, by their `bom-ref`.
+    pub fn purls(&self) -> HashMap<&str, Purl> {
+        fn collect<'a>(components: &'a [Component], result: &mut HashMap<&'a str, Purl>) {
+            for component in components {
+                if let (Some(bom_ref), Some(purl)) = (&component.bom_ref, &component.purl) {
+                    if let Ok(purl) = Purl::try_from(purl.as_str()) {
+                        result.insert(bom_ref, purl);
+                    }
+                }
+                collect(&component.components, result);
+            }
+        }
+
+        let mut result = HashMap::new();
+        if let Some(component) = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+        {
+            collect(std::slice::from_ref(component), &mut result);
+        }
+        collect(self.components.as_deref().unwrap_or_default(), &mut result);
+
+        result
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct Metadata {
+    #[serde(default)]
+    pub timestamp: Option<String>,
+    #[serde(default)]
+    pub supplier: Option<OrganizationalEntity>,
+    #[serde(default)]
+    pub component: Option<Component>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct OrganizationalEntity {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct Component {
+    #[serde(rename = "bom-ref", default)]
+    pub bom_ref: Option<String>,
+    #[serde(default)]
+    pub purl: Option<String>,
+    #[serde(default)]
+    pub components: Vec<Component>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct Vulnerability {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub references: Vec<Reference>,
+    #[serde(default)]
+    pub cwes: Vec<u32>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub detail: Option<String>,
+    #[serde(default)]
+    pub recommendation: Option<String>,
+    #[serde(default)]
+    pub workaround: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub published: Option<String>,
+    #[serde(default)]
+    pub analysis: Option<Analysis>,
+    #[serde(default)]
+    pub affects: Vec<Affects>,
+}
+
+impl Vulnerability {
+    /// The IDs of the vulnerability: its ID, plus any CVE references.
+    pub fn ids(&self) -> Vec<String> {
+        let mut ids = Vec::new();
+        ids.extend(self.id.clone());
+        for reference in &self.references {
+            if reference.id.starts_with("CVE-") && !ids.contains(&reference.id) {
+                ids.push(reference.id.clone());
+            }
+        }
+
+        ids
+    }
+
+    /// The status of the affected components, derived from the analysis.
+    ///
+    /// Without an analysis, the listed components are considered affected.
+    pub fn status(&self) -> &'static str {
+        match self.analysis.as_ref().and_then(|analysis| analysis.state) {
+            Some(state) => state.slug(),
+            None => "affected",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct Reference {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct Analysis {
+    #[serde(default)]
+    pub state: Option<ImpactAnalysisState>,
+    #[serde(default)]
+    pub justification: Option<String>,
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpactAnalysisState {
+    Resolved,
+    ResolvedWithPedigree,
+    Exploitable,
+    InTriage,
+    FalsePositive,
+    NotAffected,
+}
+
+impl ImpactAnalysisState {
+    /// The slug of the matching status.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Self::Resolved | Self::ResolvedWithPedigree => "fixed",
+            Self::Exploitable => "affected",
+            Self::InTriage => "under_investigation",
+            Self::FalsePositive | Self::NotAffected => "not_affected",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct Affects {
+    #[serde(rename = "ref")]
+    pub r#ref: String,
+}
+
+impl Affects {
+    /// The purl of the affected component.
+    ///
+    /// The reference is either the `bom-ref` of a component of the document, a BOM-Link to a
+    /// component of another document, or the purl itself.
+    pub fn purl(&self, purls: &HashMap<&str, Purl>) -> Option<Purl> {
+        let r#ref = match self.r#ref.strip_prefix("urn:cdx:") {
+            Some(link) => link.split_once('#').map(|(_, r#ref)| r#ref)?,
+            None => self.r#ref.as_str(),
+        };
+
+        purls
+            .get(r#ref)
+            .cloned()
+            .or_else(|| Purl::try_from(r#ref).ok())
+    }
+}
+
+/// Parse a CycloneDX timestamp.
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(timestamp, &Iso8601::DEFAULT).ok()
+}
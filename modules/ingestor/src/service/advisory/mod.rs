@@ -1,3 +1,36 @@
 pub mod csaf;
 pub mod cve;
+pub mod cyclonedx;
+pub mod openvex;
 pub mod osv;
+
+use crate::graph::advisory::advisory_vulnerability::{Version, VersionInfo, VersionSpec};
+use trustify_common::purl::Purl;
+use trustify_entity::version_scheme::VersionScheme;
+
+/// The version information of a status for a purl.
+///
+/// A purl with a version refers to exactly that version, one without a version to all versions.
+pub(crate) fn purl_version_info(purl: &Purl) -> VersionInfo {
+    match &purl.version {
+        Some(version) => VersionInfo {
+            scheme: exact_version_scheme(purl),
+            spec: VersionSpec::Exact(version.clone()),
+        },
+        None => VersionInfo {
+            spec: VersionSpec::Range(Version::Unbounded, Version::Unbounded),
+            scheme: VersionScheme::Semver,
+        },
+    }
+}
+
+/// The scheme to compare an exact version of a purl with.
+///
+/// Distribution versions may be written differently, yet be equal (e.g. with or without the epoch).
+fn exact_version_scheme(purl: &Purl) -> VersionScheme {
+    match purl.ty.as_str() {
+        "deb" => VersionScheme::Deb,
+        "apk" => VersionScheme::Alpine,
+        _ => VersionScheme::Generic,
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    graph::{
+        advisory::{
+            advisory_vulnerability::StatusInformation, AdvisoryInformation,
+            AdvisoryVulnerabilityInformation,
+        },
+        Graph,
+    },
+    model::IngestResult,
+    service::{
+        advisory::{
+            openvex::{Component, OpenVex, Statement},
+            purl_version_info,
+        },
+        Error, Warnings,
+    },
+};
+use sea_orm::TransactionTrait;
+use std::{collections::HashMap, fmt::Debug};
+use tracing::instrument;
+use trustify_common::{hashing::Digests, id::Id};
+use trustify_entity::labels::Labels;
+
+/// Loader capable of parsing an OpenVEX document and turning its statements into the statuses
+/// of an advisory.
+pub struct OpenVexLoader<'g> {
+    graph: &'g Graph,
+}
+
+impl<'g> OpenVexLoader<'g> {
+    pub fn new(graph: &'g Graph) -> Self {
+        Self { graph }
+    }
+
+    #[instrument(skip(self, vex), fields(id = %vex.id), ret)]
+    pub async fn load(
+        &self,
+        labels: impl Into<Labels> + Debug,
+        vex: OpenVex,
+        digests: &Digests,
+    ) -> Result<IngestResult, Error> {
+        let warnings = Warnings::new();
+
+        let labels = labels.into().add("type", "openvex");
+
+        let tx = self.graph.db.begin().await?;
+
+        let information = AdvisoryInformation {
+            id: vex.id.clone(),
+            title: None,
+            version: vex.version.as_semver(),
+            issuer: Some(vex.author.clone()),
+            published: Some(vex.timestamp),
+            modified: vex.last_updated.or(Some(vex.timestamp)),
+            withdrawn: None,
+        };
+        let advisory = self
+            .graph
+            .ingest_advisory(&vex.id, labels, digests, information, &tx)
+            .await?;
+
+        // statements of the same vulnerability share the link to the advisory
+
+        let mut vulnerabilities = HashMap::new();
+
+        for statement in &vex.statements {
+            for id in vulnerability_ids(statement) {
+                if vulnerabilities.contains_key(&id) {
+                    continue;
+                }
+
+                self.graph.ingest_vulnerability(&id, (), &tx).await?;
+                let advisory_vuln = advisory
+                    .link_to_vulnerability(
+                        &id,
+                        Some(AdvisoryVulnerabilityInformation {
+                            title: None,
+                            summary: None,
+                            description: statement
+                                .vulnerability
+                                .description()
+                                .map(ToString::to_string),
+                            reserved_date: None,
+                            discovery_date: None,
+                            release_date: None,
+                            cwes: None,
+                        }),
+                        &tx,
+                    )
+                    .await?;
+                vulnerabilities.insert(id, advisory_vuln);
+            }
+        }
+
+        for statement in &vex.statements {
+            let information = StatusInformation {
+                justification: statement.justification.clone(),
+                impact_statement: statement.impact_statement.clone(),
+                action_statement: statement.action_statement.clone(),
+            };
+
+            for component in components(statement) {
+                let Some(purl) = component.purl() else {
+                    let msg = format!("Unable to map product to a purl: {component}");
+                    log::info!("{msg}");
+                    warnings.add(msg);
+                    continue;
+                };
+
+                for id in vulnerability_ids(statement) {
+                    let Some(advisory_vuln) = vulnerabilities.get(&id) else {
+                        continue;
+                    };
+
+                    advisory_vuln
+                        .ingest_package_status_with_information(
+                            None,
+                            &purl,
+                            statement.status.slug(),
+                            purl_version_info(&purl),
+                            information.clone(),
+                            &tx,
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(IngestResult {
+            id: Id::Uuid(advisory.advisory.id),
+            document_id: Some(vex.id),
+            warnings: warnings.into(),
+        })
+    }
+}
+
+/// The IDs of the vulnerabilities of a statement: its name, plus any CVE aliases.
+fn vulnerability_ids(statement: &Statement) -> Vec<String> {
+    let name = statement.vulnerability.name();
+
+    let mut ids = vec![name.to_string()];
+    ids.extend(
+        statement
+            .vulnerability
+            .aliases()
+            .iter()
+            .filter(|alias| alias.starts_with("CVE-") && alias.as_str() != name)
+            .cloned(),
+    );
+
+    ids
+}
+
+/// The components a statement applies to.
+///
+/// If a product lists subcomponents, the statement is about those subcomponents. Otherwise, it is
+/// about the product itself.
+fn components(statement: &Statement) -> Vec<&Component> {
+    let mut result = Vec::new();
+
+    for product in &statement.products {
+        let subcomponents = product.subcomponents();
+        if !subcomponents.is_empty() {
+            result.extend(subcomponents);
+        } else if !statement.subcomponents.is_empty() {
+            result.extend(&statement.subcomponents);
+        } else {
+            result.push(product);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex::ToHex;
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+    use test_context::test_context;
+    use test_log::test;
+    use trustify_entity::{purl_status, status};
+    use trustify_test_context::{document, TrustifyContext};
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn loader(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let graph = Graph::new(ctx.db.clone());
+        let loader = OpenVexLoader::new(&graph);
+
+        let (vex, digests): (OpenVex, _) = document("openvex/git.openvex.json").await?;
+        let result = loader.load(("source", "test"), vex, &digests).await?;
+
+        assert_eq!(
+            result.document_id.as_deref(),
+            Some("https://openvex.dev/docs/example/vex-9fb3463de1b57")
+        );
+        // one product can't be mapped to a purl
+        assert_eq!(result.warnings.len(), 1);
+
+        let advisory = graph
+            .get_advisory_by_digest(&digests.sha256.encode_hex::<String>(), &ctx.db)
+            .await?
+            .expect("must be found");
+        assert_eq!(advisory.vulnerabilities(&ctx.db).await?.len(), 2);
+
+        let not_affected = purl_status::Entity::find()
+            .inner_join(status::Entity)
+            .filter(status::Column::Slug.eq("not_affected"))
+            .filter(purl_status::Column::VulnerabilityId.eq("CVE-2023-25652"))
+            .all(&ctx.db)
+            .await?;
+        // the statement is about the subcomponents of the product
+        assert_eq!(not_affected.len(), 2);
+        assert!(not_affected.iter().all(|status| {
+            status.justification.as_deref() == Some("vulnerable_code_not_in_execute_path")
+        }));
+
+        let affected = purl_status::Entity::find()
+            .inner_join(status::Entity)
+            .filter(status::Column::Slug.eq("affected"))
+            .filter(purl_status::Column::VulnerabilityId.eq("CVE-2023-29007"))
+            .all(&ctx.db)
+            .await?;
+        assert_eq!(affected.len(), 1);
+        assert_eq!(
+            affected[0].action_statement.as_deref(),
+            Some("Update to version 2.40.1")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+//! Support for OpenVEX documents.
+//!
+//! See: <https://github.com/openvex/spec/blob/main/OPENVEX-SPEC.md>
+
+pub mod loader;
+
+use std::fmt::{Display, Formatter};
+use time::OffsetDateTime;
+use trustify_common::purl::Purl;
+
+/// The prefix of the `@context` of OpenVEX documents.
+pub const CONTEXT_PREFIX: &str = "https://openvex.dev/ns";
+
+/// An OpenVEX document.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct OpenVex {
+    #[serde(rename = "@context")]
+    pub context: String,
+    #[serde(rename = "@id")]
+    pub id: String,
+    pub author: String,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub last_updated: Option<OffsetDateTime>,
+    pub version: DocumentVersion,
+    #[serde(default)]
+    pub tooling: Option<String>,
+    #[serde(default)]
+    pub statements: Vec<Statement>,
+}
+
+/// The version of a document.
+///
+/// This is a number, but older versions of the specification used a string.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum DocumentVersion {
+    Number(u64),
+    String(String),
+}
+
+impl DocumentVersion {
+    /// The version as a semantic version, using the version number as major version.
+    pub fn as_semver(&self) -> Option<semver::Version> {
+        match self {
+            Self::Number(version) => Some(semver::Version::new(*version, 0, 0)),
+            Self::String(version) => version
+                .parse()
+                .ok()
+                .map(|version| semver::Version::new(version, 0, 0)),
+        }
+    }
+}
+
+/// A statement on the status of products regarding a vulnerability.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Statement {
+    pub vulnerability: VulnerabilityReference,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub timestamp: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub products: Vec<Component>,
+    /// Subcomponents of all products, as used by older versions of the specification
+    #[serde(default)]
+    pub subcomponents: Vec<Component>,
+    pub status: Status,
+    #[serde(default)]
+    pub status_notes: Option<String>,
+    #[serde(default)]
+    pub justification: Option<String>,
+    #[serde(default)]
+    pub impact_statement: Option<String>,
+    #[serde(default)]
+    pub action_statement: Option<String>,
+}
+
+/// The vulnerability of a statement.
+///
+/// This is an object, but older versions of the specification used the plain name.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum VulnerabilityReference {
+    Name(String),
+    Vulnerability(Vulnerability),
+}
+
+impl VulnerabilityReference {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Name(name) => name,
+            Self::Vulnerability(vulnerability) => &vulnerability.name,
+        }
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            Self::Name(_) => None,
+            Self::Vulnerability(vulnerability) => vulnerability.description.as_deref(),
+        }
+    }
+
+    pub fn aliases(&self) -> &[String] {
+        match self {
+            Self::Name(_) => &[],
+            Self::Vulnerability(vulnerability) => &vulnerability.aliases,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Vulnerability {
+    #[serde(rename = "@id", default)]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// A product or subcomponent.
+///
+/// This is an object, but older versions of the specification used the plain identifier.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Component {
+    Id(String),
+    Component(ComponentDetails),
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct ComponentDetails {
+    #[serde(rename = "@id", default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub identifiers: Option<Identifiers>,
+    #[serde(default)]
+    pub subcomponents: Vec<Component>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct Identifiers {
+    #[serde(default)]
+    pub purl: Option<String>,
+    #[serde(default)]
+    pub cpe22: Option<String>,
+    #[serde(default)]
+    pub cpe23: Option<String>,
+}
+
+impl Component {
+    /// The purl of the component, from its identifiers or its ID.
+    pub fn purl(&self) -> Option<Purl> {
+        let (id, purl) = match self {
+            Self::Id(id) => (Some(id), None),
+            Self::Component(component) => (
+                component.id.as_ref(),
+                component
+                    .identifiers
+                    .as_ref()
+                    .and_then(|identifiers| identifiers.purl.as_ref()),
+            ),
+        };
+
+        purl.into_iter()
+            .chain(id.filter(|id| id.starts_with("pkg:")))
+            .find_map(|purl| Purl::try_from(purl.as_str()).ok())
+    }
+
+    pub fn subcomponents(&self) -> &[Component] {
+        match self {
+            Self::Id(_) => &[],
+            Self::Component(component) => &component.subcomponents,
+        }
+    }
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id(id) => f.write_str(id),
+            Self::Component(ComponentDetails { id: Some(id), .. }) => f.write_str(id),
+            Self::Component(_) => f.write_str("<unnamed>"),
+        }
+    }
+}
+
+/// The status of products.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    NotAffected,
+    Affected,
+    Fixed,
+    UnderInvestigation,
+}
+
+impl Status {
+    /// The slug of the matching status.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Self::NotAffected => "not_affected",
+            Self::Affected => "affected",
+            Self::Fixed => "fixed",
+            Self::UnderInvestigation => "under_investigation",
+        }
+    }
+}
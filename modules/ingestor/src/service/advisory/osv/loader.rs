@@ -15,13 +15,13 @@ use crate::{
         Error, Warnings,
     },
 };
-use osv::schema::{Event, Range, RangeType, ReferenceType, SeverityType, Vulnerability};
+use osv::schema::{Event, Package, Range, RangeType, ReferenceType, SeverityType, Vulnerability};
 use sbom_walker::report::ReportSink;
 use sea_orm::{ConnectionTrait, TransactionTrait};
 use std::{fmt::Debug, str::FromStr};
 use tracing::instrument;
 use trustify_common::{hashing::Digests, id::Id, purl::Purl, time::ChronoExt};
-use trustify_cvss::cvss3::Cvss3Base;
+use trustify_cvss::{cvss2::vector::Cvss2Vector, cvss3::vector::Cvss3Vector, cvss4::Cvss4Base};
 use trustify_entity::{labels::Labels, version_scheme::VersionScheme};
 
 pub struct OsvLoader<'g> {
@@ -78,6 +78,23 @@ impl<'g> OsvLoader<'g> {
                 .await?;
         }
 
+        // affected packages which we can't map to a purl get dropped, report them
+
+        for package in osv
+            .affected
+            .iter()
+            .filter_map(|affected| affected.package.as_ref())
+        {
+            if to_purls(package).is_empty() {
+                let msg = format!(
+                    "Unable to map affected package to a purl: {:?} / {}",
+                    package.ecosystem, package.name
+                );
+                log::info!("{msg}");
+                warnings.error(msg);
+            }
+        }
+
         let mut purl_creator = PurlCreator::new();
 
         for cve_id in cve_ids {
@@ -100,17 +117,38 @@ impl<'g> OsvLoader<'g> {
                 .await?;
 
             for severity in osv.severity.iter().flatten() {
-                if matches!(severity.severity_type, SeverityType::CVSSv3) {
-                    match Cvss3Base::from_str(&severity.score) {
+                match severity.severity_type {
+                    SeverityType::CVSSv2 => match Cvss2Vector::from_str(&severity.score) {
+                        Ok(cvss2) => {
+                            advisory_vuln.ingest_cvss2_score(cvss2, &tx).await?;
+                        }
+                        Err(err) => {
+                            let msg = format!("Unable to parse CVSS2: {:#?}", err);
+                            log::info!("{msg}");
+                            warnings.error(msg)
+                        }
+                    },
+                    SeverityType::CVSSv3 => match Cvss3Vector::from_str(&severity.score) {
                         Ok(cvss3) => {
-                            advisory_vuln.ingest_cvss3_score(cvss3, &tx).await?;
+                            advisory_vuln.ingest_cvss3_vector(cvss3, &tx).await?;
                         }
                         Err(err) => {
                             let msg = format!("Unable to parse CVSS3: {:#?}", err);
                             log::info!("{msg}");
                             warnings.error(msg)
                         }
-                    }
+                    },
+                    SeverityType::CVSSv4 => match Cvss4Base::from_str(&severity.score) {
+                        Ok(cvss4) => {
+                            advisory_vuln.ingest_cvss4_score(cvss4, &tx).await?;
+                        }
+                        Err(err) => {
+                            let msg = format!("Unable to parse CVSS4: {:#?}", err);
+                            log::info!("{msg}");
+                            warnings.error(msg)
+                        }
+                    },
+                    _ => {}
                 }
             }
 
@@ -125,15 +163,10 @@ impl<'g> OsvLoader<'g> {
                     continue;
                 };
 
-                // extract PURLs
+                // GIT ranges can only be evaluated using the explicit list of versions, if present
+                let has_versions = affected.versions.iter().flatten().next().is_some();
 
-                let mut purls = vec![];
-                purls.extend(translate::to_purl(package).map(Purl::from));
-                if let Some(purl) = &package.purl {
-                    purls.extend(Purl::from_str(purl).ok());
-                }
-
-                for purl in purls {
+                for purl in to_purls(package) {
                     // iterate through the known versions, apply the version, and create them
                     for version in affected.versions.iter().flatten() {
                         let mut purl = purl.clone();
@@ -142,12 +175,27 @@ impl<'g> OsvLoader<'g> {
                     }
 
                     for range in affected.ranges.iter().flatten() {
-                        match range.range_type {
-                            RangeType::Semver => {
-                                create_package_status_semver(&advisory_vuln, &purl, range, &tx)
-                                    .await?;
+                        let scheme = match range.range_type {
+                            RangeType::Semver => Some(VersionScheme::Semver),
+                            RangeType::Ecosystem => {
+                                translate::to_version_scheme(&package.ecosystem)
+                            }
+                            RangeType::Git if !has_versions => Some(VersionScheme::Git),
+                            _ => None,
+                        };
+
+                        match scheme {
+                            Some(scheme) => {
+                                create_package_status_range(
+                                    &advisory_vuln,
+                                    &purl,
+                                    range,
+                                    scheme,
+                                    &tx,
+                                )
+                                .await?;
                             }
-                            _ => {
+                            None => {
                                 create_package_status_versions(
                                     &advisory_vuln,
                                     &purl,
@@ -175,6 +223,16 @@ impl<'g> OsvLoader<'g> {
     }
 }
 
+/// extract the purls of an affected package, translated as well as explicitly provided ones
+fn to_purls(package: &Package) -> Vec<Purl> {
+    let mut purls = vec![];
+    purls.extend(translate::to_purl(package).map(Purl::from));
+    if let Some(purl) = &package.purl {
+        purls.extend(Purl::from_str(purl).ok());
+    }
+    purls
+}
+
 /// create package statues based on listed versions
 async fn create_package_status_versions<C: ConnectionTrait>(
     advisory_vuln: &AdvisoryVulnerabilityContext<'_>,
@@ -186,45 +244,32 @@ async fn create_package_status_versions<C: ConnectionTrait>(
     // the list of versions, sorted by the range type
     let versions = versions.into_iter().cloned().collect::<Vec<_>>();
 
-    let mut start = None;
-    for event in &range.events {
-        match event {
-            Event::Introduced(version) => {
-                start = Some(version);
-            }
-            Event::Fixed(version) | Event::LastAffected(version) => {
-                if let Some(start) = start.take() {
-                    ingest_range_from(
-                        advisory_vuln,
-                        purl,
-                        "affected",
-                        start,
-                        Some(version),
-                        &versions,
-                        connection,
-                    )
-                    .await?;
-                }
-
-                ingest_exact(advisory_vuln, purl, "fixed", version, connection).await?;
-            }
-            Event::Limit(_) => {}
-            // for non_exhaustive
-            _ => {}
+    if matches!(range.range_type, RangeType::Git) {
+        // the events are commits, which can't be located in the list of versions (tags). So the
+        // list is the set of affected versions.
+        for version in &versions {
+            ingest_exact(advisory_vuln, purl, "affected", version, connection).await?;
+        }
+    } else {
+        for (start, end) in events_to_ranges(&range.events) {
+            let Version::Inclusive(start) = start else {
+                continue;
+            };
+            ingest_range_from(
+                advisory_vuln,
+                purl,
+                "affected",
+                &start,
+                &end,
+                &versions,
+                connection,
+            )
+            .await?;
         }
     }
 
-    if let Some(start) = start {
-        ingest_range_from(
-            advisory_vuln,
-            purl,
-            "affected",
-            start,
-            None,
-            &versions,
-            connection,
-        )
-        .await?;
+    for fixed in events_to_fixed(&range.events) {
+        ingest_exact(advisory_vuln, purl, "fixed", fixed, connection).await?;
     }
 
     Ok(())
@@ -236,8 +281,7 @@ async fn ingest_range_from<C: ConnectionTrait>(
     purl: &Purl,
     status: &str,
     start: &str,
-    // exclusive end
-    end: Option<&str>,
+    end: &Version,
     versions: &[impl AsRef<str>],
     connection: &C,
 ) -> Result<(), Error> {
@@ -252,27 +296,28 @@ async fn ingest_range_from<C: ConnectionTrait>(
 
 /// Extract a list of versions according to OSV
 ///
-/// The idea for ECOSYSTEM and GIT is that the user provides an explicit list of versions, in the
-/// right order. So we search through this list, by start and end events. Translating this into
-/// exact version matches.
+/// The idea for ECOSYSTEM is that the user provides an explicit list of versions, in the right
+/// order. So we search through this list, by start and end events. Translating this into exact
+/// version matches. A start of `0` matches from the first version on.
 ///
 /// See: <https://ossf.github.io/osv-schema/#affectedrangestype-field>
-fn match_versions<'v>(
-    versions: &'v [impl AsRef<str>],
-    start: &str,
-    end: Option<&str>,
-) -> Vec<&'v str> {
+fn match_versions<'v>(versions: &'v [impl AsRef<str>], start: &str, end: &Version) -> Vec<&'v str> {
     let mut matches = None;
 
     for version in versions {
         let version = version.as_ref();
         match (&mut matches, end) {
-            (None, _) if version == start => {
+            (None, _) if version == start || start == "0" => {
                 matches = Some(vec![version]);
             }
             (None, _) => {}
-            (Some(_), Some(end)) if end == version => {
-                // reached the exclusive env
+            (Some(_), Version::Exclusive(end)) if end == version => {
+                // reached the exclusive end
+                break;
+            }
+            (Some(matches), Version::Inclusive(end)) if end == version => {
+                // reached the inclusive end
+                matches.push(version);
                 break;
             }
             (Some(matches), _) => {
@@ -306,55 +351,46 @@ async fn ingest_exact<C: ConnectionTrait>(
         .await?)
 }
 
-/// create a package status from a semver range
-async fn create_package_status_semver<C: ConnectionTrait>(
+/// create a package status from a range, evaluated by the version scheme
+async fn create_package_status_range<C: ConnectionTrait>(
     advisory_vuln: &AdvisoryVulnerabilityContext<'_>,
     purl: &Purl,
     range: &Range,
+    scheme: VersionScheme,
     connection: &C,
 ) -> Result<(), Error> {
-    let parsed_range = events_to_range(&range.events);
-
-    let spec = match &parsed_range {
-        (Some(start), None) => Some(VersionSpec::Range(
-            Version::Inclusive(start.clone()),
-            Version::Unbounded,
-        )),
-        (None, Some(end)) => Some(VersionSpec::Range(
-            Version::Unbounded,
-            Version::Exclusive(end.clone()),
-        )),
-        (Some(start), Some(end)) => Some(VersionSpec::Range(
-            Version::Inclusive(start.clone()),
-            Version::Exclusive(end.clone()),
-        )),
-        (None, None) => None,
-    };
-
-    if let Some(spec) = spec {
+    for (start, end) in events_to_ranges(&range.events) {
+        let start = match start {
+            // there is no commit before all others
+            Version::Inclusive(start) if scheme == VersionScheme::Git && start == "0" => {
+                Version::Unbounded
+            }
+            start => start,
+        };
+
         advisory_vuln
             .ingest_package_status(
                 None,
                 purl,
                 "affected",
                 VersionInfo {
-                    scheme: VersionScheme::Semver,
-                    spec,
+                    scheme,
+                    spec: VersionSpec::Range(start, end),
                 },
                 connection,
             )
             .await?;
     }
 
-    if let (_, Some(fixed)) = &parsed_range {
+    for fixed in events_to_fixed(&range.events) {
         advisory_vuln
             .ingest_package_status(
                 None,
                 purl,
                 "fixed",
                 VersionInfo {
-                    scheme: VersionScheme::Semver,
-                    spec: VersionSpec::Exact(fixed.clone()),
+                    scheme,
+                    spec: VersionSpec::Exact(fixed.to_string()),
                 },
                 connection,
             )
@@ -378,24 +414,69 @@ fn detect_organization(osv: &Vulnerability) -> Option<String> {
     None
 }
 
-fn events_to_range(events: &[Event]) -> (Option<String>, Option<String>) {
-    let start = events.iter().find_map(|e| {
-        if let Event::Introduced(version) = e {
+/// Convert the events of a range into a list of affected ranges.
+///
+/// Each `introduced` event opens a range, which is closed by the following `fixed` (exclusive)
+/// or `last_affected` (inclusive) event. A range still open at the end is closed by the `limit`
+/// (exclusive), if there is one, or is unbounded otherwise. A closing event without any previous
+/// range, affects everything before it.
+///
+/// See: <https://ossf.github.io/osv-schema/#evaluation>
+fn events_to_ranges(events: &[Event]) -> Vec<(Version, Version)> {
+    let limit = events.iter().find_map(|e| {
+        if let Event::Limit(version) = e {
             Some(version.clone())
         } else {
             None
         }
     });
 
-    let end = events.iter().find_map(|e| {
+    let mut result = vec![];
+    let mut start = None;
+
+    for event in events {
+        match event {
+            Event::Introduced(version) => {
+                start.get_or_insert_with(|| version.clone());
+            }
+            Event::Fixed(version) | Event::LastAffected(version) => {
+                let end = match event {
+                    Event::Fixed(_) => Version::Exclusive(version.clone()),
+                    _ => Version::Inclusive(version.clone()),
+                };
+                match start.take() {
+                    Some(start) => result.push((Version::Inclusive(start), end)),
+                    // without a previous range, everything before is affected
+                    None if result.is_empty() => result.push((Version::Unbounded, end)),
+                    None => {}
+                }
+            }
+            Event::Limit(_) => {}
+            // for non_exhaustive
+            _ => {}
+        }
+    }
+
+    if let Some(start) = start {
+        let end = match limit {
+            Some(limit) if limit != "*" => Version::Exclusive(limit),
+            _ => Version::Unbounded,
+        };
+        result.push((Version::Inclusive(start), end));
+    }
+
+    result
+}
+
+/// The versions of all `fixed` events.
+fn events_to_fixed(events: &[Event]) -> impl Iterator<Item = &str> {
+    events.iter().filter_map(|e| {
         if let Event::Fixed(version) = e {
-            Some(version.clone())
+            Some(version.as_str())
         } else {
             None
         }
-    });
-
-    (start, end)
+    })
 }
 
 #[cfg(test)]
@@ -471,13 +552,53 @@ mod test {
     }
 
     #[rstest]
-    #[case("b", Some("d"), vec!["b", "c"])]
-    #[case("e", None, vec!["e", "f", "g"])]
-    #[case("x", None, vec![])]
-    #[case("e", Some("a"), vec!["e", "f", "g"])]
+    #[case("b", Version::Exclusive("d".into()), vec!["b", "c"])]
+    #[case("e", Version::Unbounded, vec!["e", "f", "g"])]
+    #[case("x", Version::Unbounded, vec![])]
+    #[case("e", Version::Exclusive("a".into()), vec!["e", "f", "g"])]
+    #[case("b", Version::Inclusive("d".into()), vec!["b", "c", "d"])]
+    #[case("0", Version::Exclusive("c".into()), vec!["a", "b"])]
     #[test_log::test]
-    fn test_matches(#[case] start: &str, #[case] end: Option<&str>, #[case] result: Vec<&str>) {
+    fn test_matches(#[case] start: &str, #[case] end: Version, #[case] result: Vec<&str>) {
         const INPUT: &[&str] = &["a", "b", "c", "d", "e", "f", "g"];
-        assert_eq!(match_versions(INPUT, start, end), result);
+        assert_eq!(match_versions(INPUT, start, &end), result);
+    }
+
+    #[rstest]
+    #[case(
+        vec![Event::Introduced("1.0".into()), Event::Fixed("1.2".into())],
+        vec![(Version::Inclusive("1.0".into()), Version::Exclusive("1.2".into()))]
+    )]
+    #[case(
+        vec![Event::Introduced("1.0".into()), Event::LastAffected("1.1".into())],
+        vec![(Version::Inclusive("1.0".into()), Version::Inclusive("1.1".into()))]
+    )]
+    #[case(
+        vec![
+            Event::Introduced("0".into()),
+            Event::Fixed("1.2".into()),
+            Event::Introduced("2.0".into()),
+            Event::Fixed("2.1".into()),
+        ],
+        vec![
+            (Version::Inclusive("0".into()), Version::Exclusive("1.2".into())),
+            (Version::Inclusive("2.0".into()), Version::Exclusive("2.1".into())),
+        ]
+    )]
+    #[case(
+        vec![Event::Introduced("1.0".into()), Event::Limit("3.0".into())],
+        vec![(Version::Inclusive("1.0".into()), Version::Exclusive("3.0".into()))]
+    )]
+    #[case(
+        vec![Event::Introduced("1.0".into()), Event::Limit("*".into())],
+        vec![(Version::Inclusive("1.0".into()), Version::Unbounded)]
+    )]
+    #[case(
+        vec![Event::Fixed("1.2".into())],
+        vec![(Version::Unbounded, Version::Exclusive("1.2".into()))]
+    )]
+    #[test_log::test]
+    fn test_events_to_ranges(#[case] events: Vec<Event>, #[case] result: Vec<(Version, Version)>) {
+        assert_eq!(events_to_ranges(&events), result);
     }
 }
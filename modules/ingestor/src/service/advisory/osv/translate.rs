@@ -1,5 +1,7 @@
 use osv::schema::{Ecosystem, Package};
 use packageurl::PackageUrl;
+use std::borrow::Cow;
+use trustify_entity::version_scheme::VersionScheme;
 
 const MAVEN_DEFAULT_REPO: &str = "https://repo.maven.apache.org/maven2";
 
@@ -14,12 +16,53 @@ pub fn to_purl(
     translate(ecosystem, name)
 }
 
-/// Also see: <https://ossf.github.io/osv-schema/#affectedpackage-field>
+/// Also see: <https://ossf.github.io/osv-schema/#affectedpackage-field> and
+/// <https://github.com/package-url/purl-spec/blob/master/PURL-TYPES.rst>
 fn translate<'a>(ecosystem: &Ecosystem, name: &'a str) -> Option<PackageUrl<'a>> {
     match ecosystem {
-        Ecosystem::CRAN => PackageUrl::new("cran", name).ok(),
-        Ecosystem::CratesIO => PackageUrl::new("cargo", name).ok(),
-        Ecosystem::Npm => PackageUrl::new("npm", name).ok(),
+        Ecosystem::CRAN => purl("cran", None, name),
+        Ecosystem::CratesIO => purl("cargo", None, name),
+        Ecosystem::Npm => purl("npm", None, name),
+        Ecosystem::PyPI => purl("pypi", None, normalize_pypi_name(name)),
+        Ecosystem::NuGet => purl("nuget", None, name),
+        Ecosystem::RubyGems => purl("gem", None, name),
+        Ecosystem::Hex => purl("hex", None, name.to_lowercase()),
+        Ecosystem::Pub => purl("pub", None, name.to_lowercase()),
+        Ecosystem::Hackage => purl("hackage", None, name),
+        Ecosystem::ConanCenter => purl("conan", None, name),
+        Ecosystem::Bitnami => purl("bitnami", None, name),
+        // the module path, the last segment being the name
+        Ecosystem::Go => {
+            let (namespace, name) = split_last(name);
+            purl("golang", namespace, name)
+        }
+        // the repository URL, without the scheme
+        Ecosystem::SwiftURL => {
+            let (namespace, name) = split_last(name);
+            purl("swift", namespace, name)
+        }
+        // vendor/package
+        Ecosystem::Packagist => {
+            let (namespace, name) = split_last(name);
+            purl("composer", namespace, name)
+        }
+        // owner/repository
+        Ecosystem::GitHubActions => {
+            let (namespace, name) = split_last(name);
+            purl("github", namespace, name)
+        }
+        Ecosystem::Debian { .. } => purl("deb", Some("debian"), name),
+        Ecosystem::Ubuntu { .. } => purl("deb", Some("ubuntu"), name),
+        Ecosystem::Alpine { .. } => purl("apk", Some("alpine"), name),
+        Ecosystem::Wolfi => purl("apk", Some("wolfi"), name),
+        Ecosystem::Chainguard => purl("apk", Some("chainguard"), name),
+        Ecosystem::RedHat { .. } => purl("rpm", Some("redhat"), name),
+        Ecosystem::RockyLinux { .. } => purl("rpm", Some("rocky-linux"), name),
+        Ecosystem::AlmaLinux { .. } => purl("rpm", Some("almalinux"), name),
+        Ecosystem::SUSE { .. } => purl("rpm", Some("suse"), name),
+        Ecosystem::OpenSUSE { .. } => purl("rpm", Some("opensuse"), name),
+        Ecosystem::Mageia { .. } => purl("rpm", Some("mageia"), name),
+        Ecosystem::PhotonOS { .. } => purl("rpm", Some("photon"), name),
         Ecosystem::Maven(repo) => {
             let split = name.split(':').collect::<Vec<_>>();
             if split.len() == 2 {
@@ -42,6 +85,59 @@ fn translate<'a>(ecosystem: &Ecosystem, name: &'a str) -> Option<PackageUrl<'a>>
     }
 }
 
+fn purl<'a>(
+    ty: &'static str,
+    namespace: Option<&'a str>,
+    name: impl Into<Cow<'a, str>>,
+) -> Option<PackageUrl<'a>> {
+    let mut purl = PackageUrl::new(ty, name).ok()?;
+    if let Some(namespace) = namespace {
+        purl.with_namespace(namespace);
+    }
+    Some(purl)
+}
+
+/// Split a path like name into namespace and name, at the last slash.
+fn split_last(name: &str) -> (Option<&str>, &str) {
+    match name.trim_end_matches('/').rsplit_once('/') {
+        Some((namespace, name)) => (Some(namespace), name),
+        None => (None, name),
+    }
+}
+
+/// Normalize a python package name, see: <https://packaging.python.org/en/latest/specifications/name-normalization/>
+fn normalize_pypi_name(name: &str) -> String {
+    name.split(['-', '_', '.'])
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
+
+/// The version scheme of `ECOSYSTEM` ranges, if we can evaluate them.
+///
+/// Ecosystems without a matching scheme need to list the affected versions explicitly.
+pub fn to_version_scheme(ecosystem: &Ecosystem) -> Option<VersionScheme> {
+    match ecosystem {
+        Ecosystem::PyPI => Some(VersionScheme::Python),
+        Ecosystem::Maven(_) => Some(VersionScheme::Maven),
+        // other ecosystems, like Go or RubyGems, use versions which are not strictly semver
+        Ecosystem::CratesIO | Ecosystem::Npm => Some(VersionScheme::Semver),
+        Ecosystem::Debian { .. } | Ecosystem::Ubuntu { .. } => Some(VersionScheme::Deb),
+        Ecosystem::Alpine { .. } | Ecosystem::Wolfi | Ecosystem::Chainguard => {
+            Some(VersionScheme::Alpine)
+        }
+        Ecosystem::RedHat { .. }
+        | Ecosystem::RockyLinux { .. }
+        | Ecosystem::AlmaLinux { .. }
+        | Ecosystem::SUSE { .. }
+        | Ecosystem::OpenSUSE { .. }
+        | Ecosystem::Mageia { .. }
+        | Ecosystem::PhotonOS { .. } => Some(VersionScheme::Rpm),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -59,6 +155,34 @@ mod test {
         "groupid:artifactid",
         Some("pkg:maven/groupid/artifactid?repository_url=http://other/repo")
     )]
+    #[case(Ecosystem::PyPI, "Django", Some("pkg:pypi/django"))]
+    #[case(Ecosystem::PyPI, "zope.interface", Some("pkg:pypi/zope-interface"))]
+    #[case(Ecosystem::Debian(None), "curl", Some("pkg:deb/debian/curl"))]
+    #[case(
+        Ecosystem::Go,
+        "github.com/gin-gonic/gin",
+        Some("pkg:golang/github.com/gin-gonic/gin")
+    )]
+    #[case(Ecosystem::Go, "stdlib", Some("pkg:golang/stdlib"))]
+    #[case(
+        Ecosystem::Packagist,
+        "symfony/http-kernel",
+        Some("pkg:composer/symfony/http-kernel")
+    )]
+    #[case(Ecosystem::NuGet, "Newtonsoft.Json", Some("pkg:nuget/Newtonsoft.Json"))]
+    #[case(Ecosystem::RubyGems, "rails", Some("pkg:gem/rails"))]
+    #[case(Ecosystem::Hex, "Plug", Some("pkg:hex/plug"))]
+    #[case(
+        Ecosystem::SwiftURL,
+        "github.com/apple/swift-nio",
+        Some("pkg:swift/github.com/apple/swift-nio")
+    )]
+    #[case(
+        Ecosystem::RockyLinux(None),
+        "openssl",
+        Some("pkg:rpm/rocky-linux/openssl")
+    )]
+    #[case(Ecosystem::Alpine(Some("v3.16".to_string())), "curl", Some("pkg:apk/alpine/curl"))]
     fn test_translate(
         #[case] ecosystem: Ecosystem,
         #[case] name: &str,
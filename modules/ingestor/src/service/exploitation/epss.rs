@@ -0,0 +1,261 @@
+use super::parse_date;
+use crate::{graph::Graph, model::IngestResult, service::Error};
+use hex::ToHex;
+use sea_orm::{EntityTrait, QuerySelect, Set, TransactionTrait};
+use sea_query::{Alias, Expr, OnConflict};
+use std::collections::HashSet;
+use time::OffsetDateTime;
+use tracing::instrument;
+use trustify_common::{db::chunk::EntityChunkedIter, hashing::Digests, id::Id};
+use trustify_entity::{epss, epss_history, labels::Labels, vulnerability};
+
+/// The scores of the FIRST Exploit Prediction Scoring System (EPSS), published daily.
+///
+/// See: <https://www.first.org/epss/data_stats>
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpssScores {
+    /// The day the scores were calculated.
+    pub date: OffsetDateTime,
+    pub scores: Vec<EpssScore>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct EpssScore {
+    pub cve: String,
+    pub epss: f64,
+    pub percentile: f64,
+}
+
+impl EpssScores {
+    /// Parse the CSV file, which starts with a comment line carrying the model version and the
+    /// date of the scores:
+    ///
+    /// ```csv
+    /// #model_version:v2023.03.01,score_date:2024-10-16T00:00:00+0000
+    /// cve,epss,percentile
+    /// CVE-1999-0001,0.01141,0.84752
+    /// ```
+    pub fn parse(buffer: &[u8]) -> Result<Self, Error> {
+        let (header, data) = match buffer.iter().position(|c| *c == b'\n') {
+            Some(pos) if buffer.starts_with(b"#") => (&buffer[..pos], &buffer[pos + 1..]),
+            _ => {
+                return Err(Error::UnsupportedFormat(
+                    "EPSS scores are missing the score date".into(),
+                ))
+            }
+        };
+
+        let date = std::str::from_utf8(header)?
+            .trim_start_matches('#')
+            .split(',')
+            .filter_map(|entry| entry.split_once(':'))
+            .find_map(|(key, value)| (key.trim() == "score_date").then_some(value.trim()))
+            .ok_or_else(|| {
+                Error::UnsupportedFormat("EPSS scores are missing the score date".into())
+            })?;
+
+        let scores = csv::Reader::from_reader(data)
+            .deserialize()
+            .collect::<Result<Vec<EpssScore>, _>>()?;
+
+        Ok(Self {
+            date: parse_date(date)?,
+            scores,
+        })
+    }
+}
+
+pub struct EpssLoader<'d> {
+    graph: &'d Graph,
+}
+
+impl<'d> EpssLoader<'d> {
+    pub fn new(graph: &'d Graph) -> Self {
+        Self { graph }
+    }
+
+    #[instrument(skip(self, buffer), ret)]
+    pub async fn load_bytes(
+        &self,
+        labels: Labels,
+        buffer: &[u8],
+        digests: &Digests,
+    ) -> Result<IngestResult, Error> {
+        let scores = EpssScores::parse(buffer)?;
+        self.load(labels, scores, digests).await
+    }
+
+    /// Store the scores of a day, and update the current score of a vulnerability, unless
+    /// newer scores have already been loaded.
+    ///
+    /// The feed covers all CVEs, so only scores of already known vulnerabilities are stored.
+    /// Vulnerabilities becoming known later on will get their scores with the next day's feed.
+    #[instrument(skip(self, scores), ret)]
+    pub async fn load(
+        &self,
+        _labels: Labels,
+        scores: EpssScores,
+        digests: &Digests,
+    ) -> Result<IngestResult, Error> {
+        let EpssScores { date, scores } = scores;
+
+        let tx = self.graph.db.begin().await?;
+
+        let known = vulnerability::Entity::find()
+            .select_only()
+            .column(vulnerability::Column::Id)
+            .into_tuple::<String>()
+            .all(&tx)
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        let total = scores.len();
+        let scores = scores
+            .into_iter()
+            .filter(|score| known.contains(&score.cve))
+            .collect::<Vec<_>>();
+        log::debug!("Storing {} of {total} scores", scores.len());
+
+        let history = scores.iter().map(|score| epss_history::ActiveModel {
+            vulnerability_id: Set(score.cve.clone()),
+            date: Set(date),
+            score: Set(score.epss),
+            percentile: Set(score.percentile),
+        });
+
+        for chunk in &history.chunked() {
+            epss_history::Entity::insert_many(chunk)
+                .on_conflict(
+                    OnConflict::columns([
+                        epss_history::Column::VulnerabilityId,
+                        epss_history::Column::Date,
+                    ])
+                    .update_columns([
+                        epss_history::Column::Score,
+                        epss_history::Column::Percentile,
+                    ])
+                    .to_owned(),
+                )
+                .exec_without_returning(&tx)
+                .await?;
+        }
+
+        let current = scores.into_iter().map(|score| epss::ActiveModel {
+            vulnerability_id: Set(score.cve),
+            date: Set(date),
+            score: Set(score.epss),
+            percentile: Set(score.percentile),
+        });
+
+        for chunk in &current.chunked() {
+            epss::Entity::insert_many(chunk)
+                .on_conflict(
+                    OnConflict::column(epss::Column::VulnerabilityId)
+                        .update_columns([
+                            epss::Column::Date,
+                            epss::Column::Score,
+                            epss::Column::Percentile,
+                        ])
+                        // loading the scores of a previous day must not replace the current ones
+                        .action_and_where(
+                            Expr::col((epss::Entity, epss::Column::Date))
+                                .lte(Expr::col((Alias::new("excluded"), epss::Column::Date))),
+                        )
+                        .to_owned(),
+                )
+                .exec_without_returning(&tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(IngestResult {
+            id: Id::Sha512(digests.sha512.encode_hex()),
+            document_id: Some("EPSS".to_string()),
+            warnings: vec![],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sea_orm::PaginatorTrait;
+    use test_context::test_context;
+    use test_log::test;
+    use time::macros::datetime;
+    use trustify_test_context::{document_bytes, TrustifyContext};
+
+    #[test]
+    fn parse() -> Result<(), anyhow::Error> {
+        let scores = EpssScores::parse(
+            b"#model_version:v2023.03.01,score_date:2024-10-16T00:00:00+0000\ncve,epss,percentile\nCVE-1999-0001,0.01141,0.84752\n",
+        )?;
+
+        assert_eq!(scores.date, datetime!(2024-10-16 0:00 UTC));
+        assert_eq!(
+            scores.scores,
+            vec![EpssScore {
+                cve: "CVE-1999-0001".into(),
+                epss: 0.01141,
+                percentile: 0.84752,
+            }]
+        );
+
+        // the date is required
+        assert!(
+            EpssScores::parse(b"cve,epss,percentile\nCVE-1999-0001,0.01141,0.84752\n").is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn history(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let graph = Graph::new(ctx.db.clone());
+        let loader = EpssLoader::new(&graph);
+
+        // scores of unknown vulnerabilities must be skipped, leave out one
+        graph
+            .ingest_vulnerability_ids(
+                [
+                    "CVE-2014-0160",
+                    "CVE-2021-44228",
+                    "CVE-2023-44487",
+                    "CVE-2024-27088",
+                ],
+                &ctx.db,
+            )
+            .await?;
+
+        let newer = document_bytes("epss/epss_scores-2024-10-16.csv").await?;
+        let older = document_bytes("epss/epss_scores-2024-10-15.csv").await?;
+
+        loader
+            .load_bytes(Labels::default(), &newer, &Digests::digest(&newer))
+            .await?;
+        // loading an older file must only add to the history
+        loader
+            .load_bytes(Labels::default(), &older, &Digests::digest(&older))
+            .await?;
+
+        let current = epss::Entity::find_by_id("CVE-2021-44228")
+            .one(&ctx.db)
+            .await?
+            .expect("must be found");
+        assert_eq!(current.date, datetime!(2024-10-16 0:00 UTC));
+        assert_eq!(current.score, 0.97565);
+
+        assert_eq!(epss::Entity::find().count(&ctx.db).await?, 4);
+        assert_eq!(epss_history::Entity::find().count(&ctx.db).await?, 8);
+        assert!(epss::Entity::find_by_id("CVE-2024-28111")
+            .one(&ctx.db)
+            .await?
+            .is_none());
+        assert_eq!(vulnerability::Entity::find().count(&ctx.db).await?, 4);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use super::{non_empty, parse_date};
+use crate::{graph::Graph, model::IngestResult, service::Error};
+use hex::ToHex;
+use sea_orm::{EntityTrait, Set, TransactionTrait};
+use tracing::instrument;
+use trustify_common::{db::chunk::EntityChunkedIter, hashing::Digests, id::Id};
+use trustify_entity::{kev, labels::Labels};
+
+/// The CISA Known Exploited Vulnerabilities (KEV) catalog.
+///
+/// See: <https://www.cisa.gov/known-exploited-vulnerabilities-catalog>
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KevCatalog {
+    pub catalog_version: String,
+    pub vulnerabilities: Vec<KevEntry>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KevEntry {
+    #[serde(rename = "cveID")]
+    pub cve_id: String,
+    pub vendor_project: Option<String>,
+    pub product: Option<String>,
+    pub vulnerability_name: Option<String>,
+    pub date_added: String,
+    pub short_description: Option<String>,
+    pub required_action: Option<String>,
+    pub due_date: Option<String>,
+    /// Either `Known` or `Unknown`.
+    pub known_ransomware_campaign_use: Option<String>,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub cwes: Vec<String>,
+}
+
+pub struct KevLoader<'d> {
+    graph: &'d Graph,
+}
+
+impl<'d> KevLoader<'d> {
+    pub fn new(graph: &'d Graph) -> Self {
+        Self { graph }
+    }
+
+    /// Replace the known exploited vulnerabilities with the content of the catalog.
+    ///
+    /// The catalog is always published as a whole, so entries which are no longer part of it
+    /// get removed.
+    #[instrument(skip(self, catalog), fields(version = %catalog.catalog_version), ret)]
+    pub async fn load(
+        &self,
+        _labels: Labels,
+        catalog: KevCatalog,
+        digests: &Digests,
+    ) -> Result<IngestResult, Error> {
+        let ids = catalog
+            .vulnerabilities
+            .iter()
+            .map(|entry| entry.cve_id.clone())
+            .collect::<Vec<_>>();
+
+        let entries = catalog
+            .vulnerabilities
+            .into_iter()
+            .map(|entry| {
+                Ok(kev::ActiveModel {
+                    vulnerability_id: Set(entry.cve_id),
+                    vendor_project: Set(non_empty(entry.vendor_project)),
+                    product: Set(non_empty(entry.product)),
+                    name: Set(non_empty(entry.vulnerability_name)),
+                    short_description: Set(non_empty(entry.short_description)),
+                    required_action: Set(non_empty(entry.required_action)),
+                    notes: Set(non_empty(entry.notes)),
+                    date_added: Set(parse_date(&entry.date_added)?),
+                    due_date: Set(non_empty(entry.due_date)
+                        .map(|date| parse_date(&date))
+                        .transpose()?),
+                    known_ransomware_campaign_use: Set(entry
+                        .known_ransomware_campaign_use
+                        .is_some_and(|known| known.eq_ignore_ascii_case("known"))),
+                    cwes: Set((!entry.cwes.is_empty()).then_some(entry.cwes)),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let tx = self.graph.db.begin().await?;
+
+        self.graph.ingest_vulnerability_ids(ids, &tx).await?;
+
+        kev::Entity::delete_many().exec(&tx).await?;
+
+        for chunk in &entries.chunked() {
+            kev::Entity::insert_many(chunk)
+                .exec_without_returning(&tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(IngestResult {
+            id: Id::Sha512(digests.sha512.encode_hex()),
+            document_id: Some("KEV".to_string()),
+            warnings: vec![],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sea_orm::PaginatorTrait;
+    use test_context::test_context;
+    use test_log::test;
+    use time::macros::datetime;
+    use trustify_test_context::{document_bytes, TrustifyContext};
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn load(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let graph = Graph::new(ctx.db.clone());
+        let loader = KevLoader::new(&graph);
+
+        let data = document_bytes("kev/known_exploited_vulnerabilities.json").await?;
+        let digests = Digests::digest(&data);
+        let catalog: KevCatalog = serde_json::from_slice(&data)?;
+
+        // should work twice without error/conflict.
+        loader
+            .load(Labels::default(), catalog.clone(), &digests)
+            .await?;
+        loader
+            .load(Labels::default(), catalog.clone(), &digests)
+            .await?;
+
+        let log4shell = kev::Entity::find_by_id("CVE-2021-44228")
+            .one(&ctx.db)
+            .await?
+            .expect("must be found");
+        assert_eq!(log4shell.date_added, datetime!(2021-12-10 0:00 UTC));
+        assert_eq!(log4shell.due_date, Some(datetime!(2021-12-24 0:00 UTC)));
+        assert!(log4shell.known_ransomware_campaign_use);
+
+        let heartbleed = kev::Entity::find_by_id("CVE-2014-0160")
+            .one(&ctx.db)
+            .await?
+            .expect("must be found");
+        assert_eq!(heartbleed.notes, None);
+        assert!(!heartbleed.known_ransomware_campaign_use);
+
+        // entries dropped from the catalog get removed
+        let mut catalog = catalog;
+        catalog.vulnerabilities.truncate(1);
+        loader.load(Labels::default(), catalog, &digests).await?;
+        assert_eq!(kev::Entity::find().count(&ctx.db).await?, 1);
+
+        Ok(())
+    }
+}
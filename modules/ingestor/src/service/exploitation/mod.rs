@@ -0,0 +1,20 @@
+//! Information on the exploitation of vulnerabilities, not bound to a specific advisory.
+
+pub mod epss;
+pub mod kev;
+
+use crate::service::Error;
+use time::{macros::format_description, Date, OffsetDateTime};
+
+/// Parse the date part (`YYYY-MM-DD`) of a timestamp, as midnight UTC.
+fn parse_date(s: &str) -> Result<OffsetDateTime, Error> {
+    let date = s.get(..10).unwrap_or(s);
+    Date::parse(date, format_description!("[year]-[month]-[day]"))
+        .map(|date| date.midnight().assume_utc())
+        .map_err(|err| Error::Generic(anyhow::anyhow!("invalid date '{s}': {err}")))
+}
+
+/// Treat empty strings as absent.
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty())
+}
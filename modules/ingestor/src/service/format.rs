@@ -3,7 +3,17 @@ use crate::{
     graph::{sbom::clearly_defined::Curation, Graph},
     model::IngestResult,
     service::{
-        advisory::{csaf::loader::CsafLoader, cve::loader::CveLoader, osv::loader::OsvLoader},
+        advisory::{
+            csaf::loader::CsafLoader,
+            cve::loader::CveLoader,
+            cyclonedx::{loader::CyclonedxVexLoader, CycloneDxVex},
+            openvex::{loader::OpenVexLoader, OpenVex, CONTEXT_PREFIX},
+            osv::loader::OsvLoader,
+        },
+        exploitation::{
+            epss::EpssLoader,
+            kev::{KevCatalog, KevLoader},
+        },
         sbom::{
             clearly_defined_curation::ClearlyDefinedCurationLoader, cyclonedx::CyclonedxLoader,
             spdx::SpdxLoader,
@@ -28,11 +38,15 @@ pub enum Format {
     OSV,
     CSAF,
     CVE,
+    OpenVex,
     SPDX,
     CycloneDX,
+    CycloneDxVex,
     ClearlyDefinedCuration,
     ClearlyDefined,
     CweCatalog,
+    Epss,
+    Kev,
     // These should be resolved to one of the above before loading
     Advisory,
     SBOM,
@@ -40,6 +54,14 @@ pub enum Format {
 }
 
 impl<'g> Format {
+    /// If the original document gets kept in the storage.
+    ///
+    /// Feeds, like the daily EPSS scores, are neither referenced nor retrieved later on. Keeping
+    /// each run would grow the storage without bound.
+    pub fn is_stored(&self) -> bool {
+        !matches!(self, Self::Epss | Self::Kev)
+    }
+
     #[instrument(skip(self, graph, buffer))]
     pub async fn load(
         &self,
@@ -68,6 +90,12 @@ impl<'g> Format {
                 let cve: Cve = serde_json::from_slice(buffer)?;
                 loader.load(labels, cve, digests).await
             }
+            Format::OpenVex => {
+                // issuer is the author of the document
+                let loader = OpenVexLoader::new(graph);
+                let vex: OpenVex = serde_json::from_slice(buffer)?;
+                loader.load(labels, vex, digests).await
+            }
             Format::SPDX => {
                 let loader = SpdxLoader::new(graph);
                 let v: Value = serde_json::from_slice(buffer)?;
@@ -79,6 +107,11 @@ impl<'g> Format {
 
                 loader.load(labels, v, digests).await
             }
+            Format::CycloneDxVex => {
+                let loader = CyclonedxVexLoader::new(graph);
+                let vex: CycloneDxVex = serde_json::from_slice(buffer)?;
+                loader.load(labels, vex, digests).await
+            }
             Format::ClearlyDefined => {
                 let loader = ClearlyDefinedLoader::new(graph);
                 let item: Value = serde_json::from_slice(buffer)?;
@@ -93,6 +126,15 @@ impl<'g> Format {
                 let loader = CweCatalogLoader::new(graph);
                 loader.load_bytes(labels, buffer, digests).await
             }
+            Format::Epss => {
+                let loader = EpssLoader::new(graph);
+                loader.load_bytes(labels, buffer, digests).await
+            }
+            Format::Kev => {
+                let loader = KevLoader::new(graph);
+                let catalog: KevCatalog = serde_json::from_slice(buffer)?;
+                loader.load(labels, catalog, digests).await
+            }
             f => Err(Error::UnsupportedFormat(format!(
                 "Must resolve {f:?} to an actual format"
             ))),
@@ -103,10 +145,17 @@ impl<'g> Format {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         match Self::advisory_from_bytes(bytes) {
             Err(Error::UnsupportedFormat(ea)) => match Self::sbom_from_bytes(bytes) {
-                Err(Error::UnsupportedFormat(es)) => match Self::is_cwe_catalog(bytes) {
-                    Ok(_) => Ok(Self::CweCatalog),
-                    Err(_) => Err(Error::UnsupportedFormat(format!("{ea}\n{es}"))),
-                },
+                Err(Error::UnsupportedFormat(es)) => {
+                    if let Ok(true) = Self::is_cwe_catalog(bytes) {
+                        Ok(Self::CweCatalog)
+                    } else if let Ok(true) = Self::is_epss(bytes) {
+                        Ok(Self::Epss)
+                    } else if let Ok(true) = Self::is_kev(bytes) {
+                        Ok(Self::Kev)
+                    } else {
+                        Err(Error::UnsupportedFormat(format!("{ea}\n{es}")))
+                    }
+                }
                 x => x,
             },
             x => x,
@@ -119,11 +168,16 @@ impl<'g> Format {
             Ok(Format::CSAF)
         } else if Self::is_cve(bytes)? {
             Ok(Format::CVE)
+        } else if Self::is_openvex(bytes)? {
+            Ok(Format::OpenVex)
+        } else if Self::is_cyclonedx_vex(bytes)? {
+            Ok(Format::CycloneDxVex)
         } else if Self::is_osv(bytes)? {
             Ok(Format::OSV)
         } else {
             Err(Error::UnsupportedFormat(
-                "Unable to detect advisory format; only CSAF, CVE, and OSV are supported".into(),
+                "Unable to detect advisory format; only CSAF, CVE, OpenVEX, CycloneDX VEX, and OSV are supported"
+                    .into(),
             ))
         }
     }
@@ -160,6 +214,13 @@ impl<'g> Format {
         }
     }
 
+    pub fn is_openvex(bytes: &[u8]) -> Result<bool, Error> {
+        match masked(depth(1).and(key("@context")), bytes) {
+            Ok(Some(x)) => Ok(x.starts_with(CONTEXT_PREFIX)),
+            Err(_) | Ok(None) => Ok(false),
+        }
+    }
+
     pub fn is_osv(bytes: &[u8]) -> Result<bool, Error> {
         Ok(Self::is_osv_json(bytes)? || Self::is_osv_yaml(bytes)?)
     }
@@ -196,6 +257,15 @@ impl<'g> Format {
         }
     }
 
+    /// A CycloneDX document with vulnerabilities, but without any components.
+    pub fn is_cyclonedx_vex(bytes: &[u8]) -> Result<bool, Error> {
+        if !matches!(Self::is_cyclonedx(bytes), Ok(true)) {
+            return Ok(false);
+        }
+
+        Ok(serde_json::from_slice::<CycloneDxVex>(bytes).is_ok_and(|vex| vex.is_standalone()))
+    }
+
     pub fn is_clearly_defined(bytes: &[u8]) -> Result<bool, Error> {
         // first just try to get some YAML.
         if let Ok(candidate) = serde_yml::from_slice::<'_, serde_yml::Value>(bytes) {
@@ -208,6 +278,20 @@ impl<'g> Format {
         Ok(false)
     }
 
+    /// EPSS scores start with a comment line, followed by the CSV header.
+    ///
+    /// Without the comment line, we don't know the date of the scores. So we can't load them.
+    pub fn is_epss(bytes: &[u8]) -> Result<bool, Error> {
+        Ok(bytes.starts_with(b"#model_version:"))
+    }
+
+    pub fn is_kev(bytes: &[u8]) -> Result<bool, Error> {
+        match masked(depth(1).and(key("catalogVersion")), bytes) {
+            Ok(Some(_)) => Ok(true),
+            Err(_) | Ok(None) => Ok(false),
+        }
+    }
+
     pub fn is_cwe_catalog(bytes: &[u8]) -> Result<bool, Error> {
         let xml = Cursor::new(bytes);
         let mut reader = Reader::from_reader(xml);
@@ -284,6 +368,9 @@ mod test {
         let cve = document_bytes("mitre/CVE-2024-27088.json").await?;
         assert!(matches!(Format::from_bytes(&cve), Ok(Format::CVE)));
 
+        let vex = document_bytes("openvex/git.openvex.json").await?;
+        assert!(matches!(Format::from_bytes(&vex), Ok(Format::OpenVex)));
+
         let cyclone = document_bytes("zookeeper-3.9.2-cyclonedx.json").await?;
         assert!(matches!(
             Format::from_bytes(&cyclone),
@@ -296,6 +383,9 @@ mod test {
             Ok(Format::CycloneDX)
         ));
 
+        let vex = document_bytes("cyclonedx/vex/log4j.cdx.json").await?;
+        assert!(matches!(Format::from_bytes(&vex), Ok(Format::CycloneDxVex)));
+
         let spdx = document_bytes("ubi9-9.2-755.1697625012.json").await?;
         assert!(matches!(Format::from_bytes(&spdx), Ok(Format::SPDX)));
 
@@ -306,6 +396,16 @@ mod test {
         cwe.read_to_end(&mut xml)?;
         assert!(matches!(Format::from_bytes(&xml), Ok(Format::CweCatalog)));
 
+        let epss = document_bytes("epss/epss_scores-2024-10-16.csv").await?;
+        assert!(matches!(Format::from_bytes(&epss), Ok(Format::Epss)));
+        // without the header line, there's no date of the scores
+        assert!(!Format::is_epss(
+            b"cve,epss,percentile\nCVE-1999-0001,0.01141,0.84752\n"
+        )?);
+
+        let kev = document_bytes("kev/known_exploited_vulnerabilities.json").await?;
+        assert!(matches!(Format::from_bytes(&kev), Ok(Format::Kev)));
+
         Ok(())
     }
 }
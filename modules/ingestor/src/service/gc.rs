@@ -0,0 +1,140 @@
+use super::{Error, IngestorService};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime},
+};
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::instrument;
+use trustify_entity::{advisory, sbom, source_document};
+use trustify_module_storage::service::StorageBackend;
+
+/// The outcome of a garbage collection run.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GarbageCollectionReport {
+    /// If the run only reported, but didn't delete anything
+    pub dry_run: bool,
+    /// Number of entries found in the storage
+    pub total: usize,
+    /// Number of unreferenced entries, skipped because they are too recent or have no timestamp
+    pub skipped: usize,
+    /// Keys of the unreferenced entries
+    pub unreferenced: Vec<String>,
+    /// Number of entries which got deleted
+    pub deleted: usize,
+    /// Errors encountered while deleting entries
+    pub errors: Vec<String>,
+}
+
+impl IngestorService {
+    /// Delete all documents from the storage which are no longer referenced by an SBOM or advisory.
+    ///
+    /// Entries modified less than `min_age` ago are kept, as they might belong to an ingestion
+    /// which is still in progress. When running with `dry_run`, nothing gets deleted, but the
+    /// report lists what would be.
+    #[instrument(skip(self), ret, err)]
+    pub async fn collect_garbage(
+        &self,
+        dry_run: bool,
+        min_age: Duration,
+    ) -> Result<GarbageCollectionReport, Error> {
+        let entries = self.storage.list().await.map_err(Error::Storage)?;
+
+        // deleting an SBOM or advisory keeps its source document, so we only consider the ones
+        // still in use
+
+        let referenced: HashSet<String> = source_document::Entity::find()
+            .select_only()
+            .column(source_document::Column::Sha256)
+            .filter(
+                Condition::any()
+                    .add(
+                        source_document::Column::Id.in_subquery(
+                            sbom::Entity::find()
+                                .select_only()
+                                .column(sbom::Column::SourceDocumentId)
+                                .into_query(),
+                        ),
+                    )
+                    .add(
+                        source_document::Column::Id.in_subquery(
+                            advisory::Entity::find()
+                                .select_only()
+                                .column(advisory::Column::SourceDocumentId)
+                                .into_query(),
+                        ),
+                    ),
+            )
+            .distinct()
+            .into_tuple::<String>()
+            .all(&self.graph.db)
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut report = GarbageCollectionReport {
+            dry_run,
+            total: entries.len(),
+            ..Default::default()
+        };
+
+        let now = SystemTime::now();
+
+        for entry in entries {
+            if referenced.contains(&entry.key.to_string()) {
+                continue;
+            }
+
+            let old_enough = entry
+                .modified
+                .and_then(|modified| now.duration_since(modified).ok())
+                .is_some_and(|age| age >= min_age);
+            if !old_enough {
+                report.skipped += 1;
+                continue;
+            }
+
+            report.unreferenced.push(entry.key.to_string());
+
+            if dry_run {
+                continue;
+            }
+
+            log::debug!("Deleting unreferenced document: {}", entry.key);
+
+            match self.storage.delete(entry.key.clone()).await {
+                Ok(()) => report.deleted += 1,
+                Err(err) => {
+                    log::warn!("Failed to delete document {}: {err}", entry.key);
+                    report.errors.push(format!("{}: {err}", entry.key));
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Run the garbage collection every `interval`, for as long as the future is polled.
+    ///
+    /// A failing run is only logged, the next one will try again.
+    pub async fn collect_garbage_periodically(&self, interval: Duration, min_age: Duration) {
+        // don't run right away, the server is just starting up
+        let mut interval = tokio::time::interval_at(Instant::now() + interval, interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            match self.collect_garbage(false, min_age).await {
+                Ok(report) => log::info!(
+                    "Garbage collection deleted {} of {} documents ({} errors)",
+                    report.deleted,
+                    report.total,
+                    report.errors.len()
+                ),
+                Err(err) => log::warn!("Garbage collection failed: {err}"),
+            }
+        }
+    }
+}
@@ -1,24 +1,29 @@
 pub mod advisory;
 pub mod dataset;
+pub mod exploitation;
+pub mod gc;
 pub mod sbom;
 pub mod weakness;
 
 mod format;
 pub use format::Format;
 
-use crate::service::dataset::{DatasetIngestResult, DatasetLoader};
+use crate::service::{
+    dataset::{DatasetIngestResult, DatasetLoader},
+    sbom::{cyclonedx::CyclonedxLoader, spdx::SpdxLoader},
+};
 use crate::{graph::Graph, model::IngestResult};
 use actix_web::{body::BoxBody, HttpResponse, ResponseError};
 use anyhow::anyhow;
 use parking_lot::Mutex;
 use sbom_walker::report::ReportSink;
-use sea_orm::error::DbErr;
+use sea_orm::{error::DbErr, DatabaseTransaction, TransactionTrait};
 use std::sync::Arc;
 use std::{fmt::Debug, time::Instant};
 use tokio::task::JoinError;
 use tokio_util::io::ReaderStream;
 use tracing::instrument;
-use trustify_common::{error::ErrorInformation, id::IdError};
+use trustify_common::{error::ErrorInformation, hashing::Digests, id::IdError};
 use trustify_entity::labels::Labels;
 use trustify_module_analysis::service::AnalysisService;
 use trustify_module_storage::service::{dispatch::DispatchBackend, StorageBackend};
@@ -34,6 +39,8 @@ pub enum Error {
     #[error(transparent)]
     Json(#[from] serde_json::Error),
     #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[error(transparent)]
     JsonPath(#[from] jsonpath_rust::parser::JsonPathParserError),
     #[error(transparent)]
     Xml(#[from] roxmltree::Error),
@@ -65,6 +72,11 @@ impl ResponseError for Error {
                 message: err.to_string(),
                 details: None,
             }),
+            Self::Csv(err) => HttpResponse::BadRequest().json(ErrorInformation {
+                error: "CsvParse".into(),
+                message: err.to_string(),
+                details: None,
+            }),
             Self::JsonPath(err) => HttpResponse::BadRequest().json(ErrorInformation {
                 error: "JsonPath".into(),
                 message: err.to_string(),
@@ -184,24 +196,29 @@ impl IngestorService {
             Format::Unknown => Format::from_bytes(bytes)?,
             v => v,
         };
-        let stream = ReaderStream::new(bytes);
+        let digests = if fmt.is_stored() {
+            let stream = ReaderStream::new(bytes);
 
-        let result = self
-            .storage
-            .store(stream)
-            .await
-            .map_err(|err| Error::Storage(anyhow!("{err}")))?;
+            self.storage
+                .store(stream)
+                .await
+                .map_err(|err| Error::Storage(anyhow!("{err}")))?
+                .digests
+        } else {
+            Digests::digest(bytes)
+        };
 
         let result = fmt
-            .load(&self.graph, labels.into(), issuer, &result.digests, bytes)
+            .load(&self.graph, labels.into(), issuer, &digests, bytes)
             .await?;
 
         match fmt {
             Format::SPDX | Format::CycloneDX => {
                 let analysis_service = AnalysisService::new();
                 if result.id.to_string().starts_with("urn:uuid:") {
+                    // (re-)build the graph, as a previously ingested document might have changed
                     match analysis_service // TODO: today we chop off 'urn:uuid:' prefix using .split_off on result.id
-                        .load_graphs(
+                        .reload_graphs(
                             vec![result.id.to_string().split_off("urn:uuid:".len())],
                             &self.graph.db,
                         )
@@ -233,6 +250,53 @@ impl IngestorService {
         Ok(result)
     }
 
+    /// Ingest an SBOM into a transaction, without storing the document and without committing.
+    ///
+    /// This allows evaluating an SBOM, e.g. looking up the vulnerabilities affecting it, without
+    /// persisting it. The caller is responsible for rolling back the returned transaction once it
+    /// is no longer needed.
+    #[instrument(skip(self, bytes), err)]
+    pub async fn ingest_ephemeral(
+        &self,
+        bytes: &[u8],
+        format: Format,
+        labels: impl Into<Labels> + Debug,
+    ) -> Result<(IngestResult, DatabaseTransaction), Error> {
+        let fmt = match format {
+            Format::SBOM | Format::Unknown => Format::sbom_from_bytes(bytes)?,
+            v => v,
+        };
+
+        let digests = Digests::digest(bytes);
+        let value: serde_json::Value = serde_json::from_slice(bytes)?;
+
+        let tx = self.graph.db.begin().await?;
+
+        let result = match fmt {
+            Format::SPDX => {
+                SpdxLoader::new(&self.graph)
+                    .ingest(labels.into(), value, &digests, &tx)
+                    .await
+            }
+            Format::CycloneDX => {
+                CyclonedxLoader::new(&self.graph)
+                    .ingest(labels.into(), value, &digests, &tx)
+                    .await
+            }
+            f => Err(Error::UnsupportedFormat(format!(
+                "{f:?} is not supported; only SPDX and CycloneDX are supported"
+            ))),
+        };
+
+        match result {
+            Ok(result) => Ok((result, tx)),
+            Err(err) => {
+                tx.rollback().await?;
+                Err(err)
+            }
+        }
+    }
+
     /// Ingest a dataset archive
     #[instrument(skip(self, bytes), ret, err)]
     pub async fn ingest_dataset(
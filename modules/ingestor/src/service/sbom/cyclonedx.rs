@@ -1,9 +1,13 @@
 use crate::{
     graph::{sbom::cyclonedx, Graph},
     model::IngestResult,
-    service::Error,
+    service::{
+        advisory::cyclonedx::{loader::CyclonedxVexLoader, CycloneDxVex},
+        Error, Warnings,
+    },
 };
-use sea_orm::TransactionTrait;
+use sea_orm::{ConnectionTrait, TransactionTrait};
+use serde::Deserialize;
 use serde_json::Value;
 use tracing::instrument;
 use trustify_common::{hashing::Digests, id::Id};
@@ -25,6 +29,38 @@ impl<'g> CyclonedxLoader<'g> {
         value: Value,
         digests: &Digests,
     ) -> Result<IngestResult, Error> {
+        let tx = self.graph.db.begin().await?;
+        let result = self.ingest(labels, value, digests, &tx).await?;
+        tx.commit().await?;
+
+        Ok(result)
+    }
+
+    /// Ingest a CycloneDX document, using the provided connection.
+    ///
+    /// This doesn't commit anything, which allows ingesting into a transaction which gets rolled
+    /// back later on.
+    #[instrument(skip(self, value, connection), err)]
+    pub(crate) async fn ingest<C: ConnectionTrait>(
+        &self,
+        labels: Labels,
+        value: Value,
+        digests: &Digests,
+        connection: &C,
+    ) -> Result<IngestResult, Error> {
+        let warnings = Warnings::new();
+
+        // the vulnerabilities, which the SBOM model doesn't cover. Failing to process them must
+        // not fail ingesting the SBOM.
+        let vex = match value.get("vulnerabilities") {
+            Some(_) => CycloneDxVex::deserialize(&value)
+                .inspect_err(|err| {
+                    warnings.add(format!("Failed to parse vulnerabilities, skipping: {err}"))
+                })
+                .ok(),
+            None => None,
+        };
+
         let sbom: serde_cyclonedx::cyclonedx::v_1_6::CycloneDx = serde_json::from_value(value)
             .map_err(|err| Error::UnsupportedFormat(format!("Failed to parse: {err}")))?;
 
@@ -36,8 +72,6 @@ impl<'g> CyclonedxLoader<'g> {
             sbom.serial_number,
         );
 
-        let tx = self.graph.db.begin().await?;
-
         let document_id = sbom
             .serial_number
             .clone()
@@ -46,24 +80,28 @@ impl<'g> CyclonedxLoader<'g> {
         let ctx = self
             .graph
             .ingest_sbom(
-                labels,
+                labels.clone(),
                 digests,
                 document_id.clone(),
                 cyclonedx::Information(&sbom),
-                &tx,
+                connection,
             )
             .await?;
 
-        ctx.ingest_cyclonedx(sbom, &tx)
+        ctx.ingest_cyclonedx(sbom, connection)
             .await
             .map_err(Error::Generic)?;
 
-        tx.commit().await?;
+        if let Some(vex) = vex.filter(|vex| !vex.vulnerabilities.is_empty()) {
+            CyclonedxVexLoader::new(self.graph)
+                .ingest(labels, &vex, digests, &warnings, connection)
+                .await?;
+        }
 
         Ok(IngestResult {
             id: Id::Uuid(ctx.sbom.sbom_id),
             document_id,
-            warnings: vec![],
+            warnings: warnings.into(),
         })
     }
 }
@@ -72,8 +110,11 @@ impl<'g> CyclonedxLoader<'g> {
 mod test {
     use crate::service::IngestorService;
     use crate::{graph::Graph, service::Format};
+    use sea_orm::PaginatorTrait;
+    use sea_orm::{EntityTrait, QuerySelect};
     use test_context::test_context;
     use test_log::test;
+    use trustify_entity::{advisory, purl_status, sbom, status};
     use trustify_test_context::{document_bytes, TrustifyContext};
 
     #[test_context(TrustifyContext)]
@@ -92,4 +133,72 @@ mod test {
 
         Ok(())
     }
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn ingest_cyclonedx_vulnerabilities(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+        let db = &ctx.db;
+        let graph = Graph::new(db.clone());
+        let data = document_bytes("cyclonedx/embedded_vulnerabilities.json").await?;
+
+        let ingestor = IngestorService::new(graph, ctx.storage.clone());
+
+        ingestor
+            .ingest(&data, Format::CycloneDX, ("source", "test"), None)
+            .await
+            .expect("must ingest");
+
+        // the embedded vulnerabilities are ingested as an advisory
+
+        let statuses = purl_status::Entity::find()
+            .inner_join(status::Entity)
+            .select_only()
+            .column(purl_status::Column::VulnerabilityId)
+            .column(status::Column::Slug)
+            .column(purl_status::Column::Justification)
+            .into_tuple::<(String, String, Option<String>)>()
+            .all(db)
+            .await?;
+
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses.contains(&("CVE-2023-0286".to_string(), "fixed".to_string(), None)));
+        assert!(statuses.contains(&(
+            "CVE-2022-37434".to_string(),
+            "not_affected".to_string(),
+            Some("code_not_present".to_string())
+        )));
+
+        Ok(())
+    }
+
+    #[test_context(TrustifyContext)]
+    #[test(tokio::test)]
+    async fn ingest_cyclonedx_invalid_vulnerabilities(
+        ctx: &TrustifyContext,
+    ) -> Result<(), anyhow::Error> {
+        let db = &ctx.db;
+        let graph = Graph::new(db.clone());
+        let data = document_bytes("cyclonedx/embedded_vulnerabilities.json").await?;
+
+        // valid for the SBOM, but not for processing the vulnerabilities
+        let mut value: serde_json::Value = serde_json::from_slice(&data)?;
+        value["vulnerabilities"][0]["affects"] = serde_json::Value::Null;
+        let data = serde_json::to_vec(&value)?;
+
+        let ingestor = IngestorService::new(graph, ctx.storage.clone());
+
+        let result = ingestor
+            .ingest(&data, Format::CycloneDX, ("source", "test"), None)
+            .await
+            .expect("must ingest");
+
+        // the SBOM is still ingested, only the vulnerabilities are skipped
+
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(sbom::Entity::find().count(db).await?, 1);
+        assert_eq!(advisory::Entity::find().count(db).await?, 0);
+        assert_eq!(purl_status::Entity::find().count(db).await?, 0);
+
+        Ok(())
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
     model::IngestResult,
     service::{Error, Warnings},
 };
-use sea_orm::TransactionTrait;
+use sea_orm::{ConnectionTrait, TransactionTrait};
 use serde_json::Value;
 use tracing::instrument;
 use trustify_common::{hashing::Digests, id::Id};
@@ -27,6 +27,25 @@ impl<'g> SpdxLoader<'g> {
         labels: Labels,
         json: Value,
         digests: &Digests,
+    ) -> Result<IngestResult, Error> {
+        let tx = self.graph.db.begin().await?;
+        let result = self.ingest(labels, json, digests, &tx).await?;
+        tx.commit().await?;
+
+        Ok(result)
+    }
+
+    /// Ingest an SPDX document, using the provided connection.
+    ///
+    /// This doesn't commit anything, which allows ingesting into a transaction which gets rolled
+    /// back later on.
+    #[instrument(skip(self, json, connection), err)]
+    pub(crate) async fn ingest<C: ConnectionTrait>(
+        &self,
+        labels: Labels,
+        json: Value,
+        digests: &Digests,
+        connection: &C,
     ) -> Result<IngestResult, Error> {
         let warnings = Warnings::default();
 
@@ -37,8 +56,6 @@ impl<'g> SpdxLoader<'g> {
             spdx.document_creation_information.document_name
         );
 
-        let tx = self.graph.db.begin().await?;
-
         let labels = labels.add("type", "spdx");
 
         let document_id = spdx
@@ -53,13 +70,11 @@ impl<'g> SpdxLoader<'g> {
                 digests,
                 Some(document_id.clone()),
                 spdx::Information(&spdx),
-                &tx,
+                connection,
             )
             .await?;
 
-        sbom.ingest_spdx(spdx, &warnings, &tx).await?;
-
-        tx.commit().await?;
+        sbom.ingest_spdx(spdx, &warnings, connection).await?;
 
         Ok(IngestResult {
             id: Id::Uuid(sbom.sbom.sbom_id),
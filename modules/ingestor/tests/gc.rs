@@ -0,0 +1,128 @@
+use sea_orm::EntityTrait;
+use std::time::Duration;
+use test_context::test_context;
+use test_log::test;
+use tokio_util::io::ReaderStream;
+use trustify_entity::sbom;
+use trustify_module_ingestor::service::IngestorService;
+use trustify_module_storage::service::{fs::FileSystemBackend, StorageBackend};
+use trustify_test_context::TrustifyContext;
+
+/// Ensure that only unreferenced documents get collected, and only if not running dry.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn collect_garbage(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    ctx.ingest_document("zookeeper-3.9.2-cyclonedx.json")
+        .await?;
+
+    // store a document which is not referenced by any source document
+
+    let orphan = ctx
+        .storage
+        .store(ReaderStream::new(&b"orphan"[..]))
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))?
+        .key();
+
+    // a recent document must be kept
+
+    let report = ctx
+        .ingestor
+        .collect_garbage(false, Duration::from_secs(60 * 60))
+        .await?;
+    assert_eq!(report.total, 2);
+    assert_eq!(report.skipped, 1);
+    assert!(report.unreferenced.is_empty());
+    assert_eq!(report.deleted, 0);
+
+    // a dry run must only report
+
+    let report = ctx.ingestor.collect_garbage(true, Duration::ZERO).await?;
+    assert!(report.dry_run);
+    assert_eq!(report.unreferenced, vec![orphan.to_string()]);
+    assert_eq!(report.deleted, 0);
+    assert!(ctx.storage.retrieve(orphan.clone()).await?.is_some());
+
+    // now delete
+
+    let report = ctx.ingestor.collect_garbage(false, Duration::ZERO).await?;
+    assert_eq!(report.unreferenced, vec![orphan.to_string()]);
+    assert_eq!(report.deleted, 1);
+    assert!(report.errors.is_empty());
+    assert!(ctx.storage.retrieve(orphan).await?.is_none());
+
+    // the ingested document must still be available
+
+    let report = ctx.ingestor.collect_garbage(false, Duration::ZERO).await?;
+    assert_eq!(report.total, 1);
+    assert!(report.unreferenced.is_empty());
+
+    Ok(())
+}
+
+/// Ensure that the document of a deleted SBOM gets collected.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn collect_deleted(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let result = ctx
+        .ingest_document("zookeeper-3.9.2-cyclonedx.json")
+        .await?;
+
+    let report = ctx.ingestor.collect_garbage(false, Duration::ZERO).await?;
+    assert_eq!(report.total, 1);
+    assert!(report.unreferenced.is_empty());
+
+    // delete the SBOM, keeping its source document
+
+    let id = result.id.try_as_uid().expect("must be a UUID");
+    sbom::Entity::delete_by_id(id).exec(&ctx.db).await?;
+
+    let report = ctx.ingestor.collect_garbage(false, Duration::ZERO).await?;
+    assert_eq!(report.unreferenced.len(), 1);
+    assert_eq!(report.deleted, 1);
+    assert!(report.errors.is_empty());
+
+    // the document must be gone
+
+    let report = ctx.ingestor.collect_garbage(false, Duration::ZERO).await?;
+    assert_eq!(report.total, 0);
+
+    Ok(())
+}
+
+/// Ensure that content not written by the storage backend is never collected.
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn keep_foreign(ctx: &TrustifyContext) -> anyhow::Result<()> {
+    let (storage, dir) = FileSystemBackend::for_test().await?;
+    let ingestor = IngestorService::new(ctx.graph.clone(), storage.clone());
+
+    let orphan = storage
+        .store(ReaderStream::new(&b"orphan"[..]))
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))?
+        .key();
+
+    // place foreign files next to the stored content
+
+    let level = dir.path().join("content").join("ab").join("cd");
+    tokio::fs::create_dir_all(&level).await?;
+    let foreign = [
+        level.join("backup.tar"),
+        level.join("A591A6D40BF420404A011733CFB7B190D62C65BF0BCDA32B57B277D9AD9F146E"),
+    ];
+    for file in &foreign {
+        tokio::fs::write(file, b"foreign").await?;
+    }
+
+    let report = ingestor.collect_garbage(false, Duration::ZERO).await?;
+    assert_eq!(report.total, 1);
+    assert_eq!(report.unreferenced, vec![orphan.to_string()]);
+    assert_eq!(report.deleted, 1);
+
+    for file in &foreign {
+        assert!(file.exists(), "{} must be kept", file.display());
+    }
+
+    Ok(())
+}
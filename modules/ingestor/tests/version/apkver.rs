@@ -0,0 +1,126 @@
+use crate::version::common::{version_matches, Version, VersionRange};
+use sea_orm::{ConnectionTrait, Statement};
+use test_context::test_context;
+use test_log::test;
+use trustify_common::db::Database;
+use trustify_test_context::TrustifyContext;
+
+async fn apkver_cmp(db: &Database, left: &str, right: &str) -> Result<Option<i32>, anyhow::Error> {
+    let result = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            format!(
+                r#"
+        SELECT * FROM apkver_cmp( '{left}', '{right}' )
+        "#,
+            ),
+        ))
+        .await?;
+
+    if let Some(result) = result {
+        Ok(result.try_get_by_index(0)?)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Versions in ascending order
+const ORDERED: &[&str] = &[
+    "1.0_alpha",
+    "1.0_alpha1",
+    "1.0_beta",
+    "1.0_pre2",
+    "1.0_rc1",
+    "1.0",
+    "1.0-r0",
+    "1.0-r1",
+    "1.0_cvs",
+    "1.0_git20240101",
+    "1.0_p1",
+    "1.0_p2-r3",
+    "1.0a",
+    "1.0b",
+    "1.0.1",
+    "1.0.9",
+    "1.0.10",
+    "1.1",
+    "2.0",
+];
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn test_apkver_cmp_ordering(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    for (i, left) in ORDERED.iter().enumerate() {
+        for right in &ORDERED[i + 1..] {
+            assert_eq!(
+                Some(-1),
+                apkver_cmp(&ctx.db, left, right).await?,
+                "{left} < {right}"
+            );
+            assert_eq!(
+                Some(1),
+                apkver_cmp(&ctx.db, right, left).await?,
+                "{right} > {left}"
+            );
+        }
+        assert_eq!(Some(0), apkver_cmp(&ctx.db, left, left).await?);
+    }
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn test_apkver_cmp(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    // components with a leading zero compare as fractions
+    assert_eq!(Some(-1), apkver_cmp(&ctx.db, "1.01", "1.1").await?);
+    // the hash is ignored
+    assert_eq!(
+        Some(0),
+        apkver_cmp(&ctx.db, "1.0~abc123-r1", "1.0-r1").await?
+    );
+    assert_eq!(Some(1), apkver_cmp(&ctx.db, "1.0_rc1_p1", "1.0_rc1").await?);
+    // invalid
+    assert_eq!(None, apkver_cmp(&ctx.db, "foo", "1.0").await?);
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext, skip_teardown)]
+#[test(tokio::test)]
+async fn test_version_matches(ctx: TrustifyContext) -> Result<(), anyhow::Error> {
+    let db = ctx.db;
+
+    assert!(
+        version_matches(
+            &db,
+            "3.0.12-r1",
+            VersionRange::Range(Version::Inclusive("3.0"), Version::Exclusive("3.1")),
+            "alpine"
+        )
+        .await?
+    );
+
+    // a pre-release of the fixed version is still affected
+    assert!(
+        version_matches(
+            &db,
+            "3.1_rc1-r0",
+            VersionRange::Range(Version::Inclusive("3.0"), Version::Exclusive("3.1")),
+            "alpine"
+        )
+        .await?
+    );
+
+    assert!(
+        !version_matches(
+            &db,
+            "3.1-r0",
+            VersionRange::Range(Version::Inclusive("3.0"), Version::Exclusive("3.1")),
+            "alpine"
+        )
+        .await?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use crate::version::common::{version_matches, Version, VersionRange};
+use sea_orm::{ConnectionTrait, Statement};
+use test_context::test_context;
+use test_log::test;
+use trustify_common::db::Database;
+use trustify_test_context::TrustifyContext;
+
+async fn debver_cmp(db: &Database, left: &str, right: &str) -> Result<Option<i32>, anyhow::Error> {
+    let result = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            format!(
+                r#"
+        SELECT * FROM debver_cmp( '{left}', '{right}' )
+        "#,
+            ),
+        ))
+        .await?;
+
+    if let Some(result) = result {
+        Ok(result.try_get_by_index(0)?)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Versions in ascending order
+const ORDERED: &[&str] = &[
+    "1.0~~",
+    "1.0~~a",
+    "1.0~",
+    "1.0",
+    "1.0-0.1",
+    "1.0-1",
+    "1.0-1ubuntu1",
+    "1.0-2",
+    "1.0a",
+    "1.0+b1",
+    "1.0.1",
+    "1.2.3",
+    "1.10",
+    "1:0.1",
+];
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn test_debver_cmp_ordering(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    for (i, left) in ORDERED.iter().enumerate() {
+        for right in &ORDERED[i + 1..] {
+            assert_eq!(
+                Some(-1),
+                debver_cmp(&ctx.db, left, right).await?,
+                "{left} < {right}"
+            );
+            assert_eq!(
+                Some(1),
+                debver_cmp(&ctx.db, right, left).await?,
+                "{right} > {left}"
+            );
+        }
+        assert_eq!(Some(0), debver_cmp(&ctx.db, left, left).await?);
+    }
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn test_debver_cmp(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    // the epoch defaults to zero
+    assert_eq!(Some(0), debver_cmp(&ctx.db, "0:1.0", "1.0").await?);
+    // a missing revision is equal to zero
+    assert_eq!(Some(0), debver_cmp(&ctx.db, "1.0", "1.0-0").await?);
+    // the revision starts after the last hyphen
+    assert_eq!(Some(-1), debver_cmp(&ctx.db, "1.0-a-b", "1.0-a-c").await?);
+    // the upstream version must start with a digit
+    assert_eq!(None, debver_cmp(&ctx.db, "abc", "1.0").await?);
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext, skip_teardown)]
+#[test(tokio::test)]
+async fn test_version_matches(ctx: TrustifyContext) -> Result<(), anyhow::Error> {
+    let db = ctx.db;
+
+    assert!(
+        version_matches(
+            &db,
+            "1:2.0-1",
+            VersionRange::Range(Version::Inclusive("1.0"), Version::Exclusive("1:2.0-2")),
+            "deb"
+        )
+        .await?
+    );
+
+    // a backport of the fixed version is still before it
+    assert!(
+        version_matches(
+            &db,
+            "2.0~bpo11+1",
+            VersionRange::Range(Version::Inclusive("1.0"), Version::Exclusive("2.0")),
+            "deb"
+        )
+        .await?
+    );
+
+    assert!(
+        !version_matches(
+            &db,
+            "2.0-1",
+            VersionRange::Range(Version::Inclusive("1.0"), Version::Exclusive("2.0")),
+            "deb"
+        )
+        .await?
+    );
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
+mod apkver;
 mod common;
+mod debver;
 mod mavenver;
+mod pythonver;
 mod rpmver;
 mod semver;
 
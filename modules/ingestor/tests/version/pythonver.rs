@@ -0,0 +1,169 @@
+use crate::version::common::{version_matches, Version, VersionRange};
+use rstest::rstest;
+use sea_orm::{ConnectionTrait, Statement};
+use test_context::{test_context, AsyncTestContext};
+use test_log::test;
+use trustify_common::db::Database;
+use trustify_test_context::TrustifyContext;
+
+async fn pythonver_cmp(
+    db: &Database,
+    left: &str,
+    right: &str,
+) -> Result<Option<i32>, anyhow::Error> {
+    let result = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            format!(
+                r#"
+        SELECT * FROM pythonver_cmp( '{left}', '{right}' )
+        "#,
+            ),
+        ))
+        .await?;
+
+    if let Some(result) = result {
+        Ok(result.try_get_by_index(0)?)
+    } else {
+        Ok(None)
+    }
+}
+
+/// The ordering example of the specification, see:
+/// <https://peps.python.org/pep-0440/#summary-of-permitted-suffixes-and-relative-ordering>
+const ORDERED: &[&str] = &[
+    "1.dev0",
+    "1.0.dev456",
+    "1.0a1",
+    "1.0a2.dev456",
+    "1.0a12.dev456",
+    "1.0a12",
+    "1.0b1.dev456",
+    "1.0b2",
+    "1.0b2.post345.dev456",
+    "1.0b2.post345",
+    "1.0rc1.dev456",
+    "1.0rc1",
+    "1.0",
+    "1.0+abc.5",
+    "1.0+abc.7",
+    "1.0+5",
+    "1.0.post456.dev34",
+    "1.0.post456",
+    "1.0.15",
+    "1.1.dev1",
+];
+
+#[test_context(TrustifyContext)]
+#[test(tokio::test)]
+async fn test_pythonver_cmp_ordering(ctx: &TrustifyContext) -> Result<(), anyhow::Error> {
+    for (i, left) in ORDERED.iter().enumerate() {
+        for right in &ORDERED[i + 1..] {
+            assert_eq!(
+                Some(-1),
+                pythonver_cmp(&ctx.db, left, right).await?,
+                "{left} < {right}"
+            );
+            assert_eq!(
+                Some(1),
+                pythonver_cmp(&ctx.db, right, left).await?,
+                "{right} > {left}"
+            );
+        }
+        assert_eq!(Some(0), pythonver_cmp(&ctx.db, left, left).await?);
+    }
+
+    Ok(())
+}
+
+#[rstest]
+// trailing zeros
+#[case("1.0", "1.0.0", Some(0))]
+#[case("1.10", "1.9", Some(1))]
+// epochs
+#[case("1!1.0", "2.0", Some(1))]
+#[case("0!1.0", "1.0", Some(0))]
+// normalization
+#[case("v1.0", "1.0", Some(0))]
+#[case("1.0alpha1", "1.0a1", Some(0))]
+#[case("1.0-beta.2", "1.0b2", Some(0))]
+#[case("1.0c1", "1.0rc1", Some(0))]
+#[case("1.0pre1", "1.0rc1", Some(0))]
+#[case("1.0a", "1.0a0", Some(0))]
+#[case("1.0-1", "1.0.post1", Some(0))]
+#[case("1.0rev1", "1.0.post1", Some(0))]
+#[case("1.0.DEV1", "1.0.dev1", Some(0))]
+#[case("1.0+ubuntu-1", "1.0+ubuntu.1", Some(0))]
+// local versions
+#[case("1.0+1", "1.0+abc", Some(1))]
+#[case("1.0+abc", "1.0+abc.1", Some(-1))]
+// invalid
+#[case("foo", "1.0", None)]
+#[case("1.0", "1.0+", None)]
+#[test_log::test(tokio::test)]
+async fn test_pythonver_cmp(
+    #[case] left: &str,
+    #[case] right: &str,
+    #[case] expected: Option<i32>,
+) -> Result<(), anyhow::Error> {
+    let ctx = TrustifyContext::setup().await;
+
+    assert_eq!(expected, pythonver_cmp(&ctx.db, left, right).await?);
+
+    Ok(())
+}
+
+#[test_context(TrustifyContext, skip_teardown)]
+#[test(tokio::test)]
+async fn test_version_matches(ctx: TrustifyContext) -> Result<(), anyhow::Error> {
+    let db = ctx.db;
+
+    assert!(version_matches(&db, "1.0.0", VersionRange::Exact("1.0"), "python").await?);
+    assert!(!version_matches(&db, "1.0.1", VersionRange::Exact("1.0"), "python").await?);
+
+    // pre-releases are before the final release
+    assert!(
+        version_matches(
+            &db,
+            "2.0rc1",
+            VersionRange::Range(Version::Inclusive("1.0"), Version::Exclusive("2.0")),
+            "python"
+        )
+        .await?
+    );
+
+    // post-releases are after the final release
+    assert!(
+        !version_matches(
+            &db,
+            "2.0.post1",
+            VersionRange::Range(Version::Inclusive("1.0"), Version::Exclusive("2.0")),
+            "python"
+        )
+        .await?
+    );
+
+    // the "introduced: 0" of OSV
+    assert!(
+        version_matches(
+            &db,
+            "0.0.1.dev1",
+            VersionRange::Range(Version::Inclusive("0"), Version::Unbounded),
+            "pypi"
+        )
+        .await?
+    );
+
+    // invalid versions never match
+    assert!(
+        !version_matches(
+            &db,
+            "foo",
+            VersionRange::Range(Version::Inclusive("0"), Version::Unbounded),
+            "python"
+        )
+        .await?
+    );
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::service::{
-    compression::Compression, temp::TempFile, StorageBackend, StorageKey, StorageResult, StoreError,
+    compression::Compression, temp::TempFile, StorageBackend, StorageEntry, StorageKey,
+    StorageResult, StoreError,
 };
 use anyhow::Context;
 use bytes::Bytes;
@@ -13,7 +14,7 @@ use std::{
 use strum::IntoEnumIterator;
 use tempfile::{tempdir, TempDir};
 use tokio::{
-    fs::{create_dir_all, File},
+    fs::{create_dir_all, read_dir, remove_file, File},
     io::AsyncWriteExt,
 };
 use tokio_util::io::ReaderStream;
@@ -162,6 +163,73 @@ impl StorageBackend for FileSystemBackend {
 
         Ok(None)
     }
+
+    async fn list(&self) -> Result<Vec<StorageEntry>, Self::Error> {
+        let mut result = vec![];
+
+        // walk down the levels, collecting all directories
+        let mut dirs = vec![self.content.clone()];
+        for _ in 0..NUM_LEVELS {
+            let mut next = vec![];
+            for dir in dirs {
+                let mut entries = match read_dir(&dir).await {
+                    Ok(entries) => entries,
+                    Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                };
+                while let Some(entry) = entries.next_entry().await? {
+                    if entry.file_type().await?.is_dir() {
+                        next.push(entry.path());
+                    }
+                }
+            }
+            dirs = next;
+        }
+
+        // the files of the lowest level are the content, named by the hash plus extension
+        for dir in dirs {
+            let mut entries = read_dir(&dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let metadata = entry.metadata().await?;
+                if !metadata.is_file() {
+                    continue;
+                }
+                let name = entry.file_name();
+                let Some(key) = name
+                    .to_str()
+                    .and_then(|name| name.split('.').next())
+                    .and_then(StorageKey::from_name)
+                else {
+                    continue;
+                };
+                result.push(StorageEntry {
+                    key,
+                    modified: metadata.modified().ok(),
+                });
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn delete(&self, StorageKey(hash): StorageKey) -> Result<(), Self::Error> {
+        // remove all compression types, as we might have multiple
+        for compression in &self.read_compressions {
+            let target = level_dir(&self.content, &hash, NUM_LEVELS);
+            let mut target = target.join(&hash);
+            target.set_extension(compression.extension());
+
+            log::debug!("Deleting file: {}", target.display());
+
+            match remove_file(&target).await {
+                Ok(()) => {}
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
 }
 
 fn level_dir(base: impl AsRef<Path>, hash: &str, levels: usize) -> PathBuf {
@@ -320,4 +388,64 @@ mod test {
 
         drop(backend);
     }
+
+    /// Ensure deleted content is no longer listed or retrieved.
+    #[test(tokio::test)]
+    #[rstest]
+    #[case(Compression::None)]
+    #[case(Compression::Zstd)]
+    async fn test_list_and_delete(#[case] compression: Compression) {
+        let dir = tempdir().unwrap();
+        let backend = FileSystemBackend::new(dir.path(), compression)
+            .await
+            .unwrap();
+
+        let hello = backend
+            .store(ReaderStream::new(&b"Hello World"[..]))
+            .await
+            .expect("store must succeed");
+        let bye = backend
+            .store(ReaderStream::new(&b"Bye World"[..]))
+            .await
+            .expect("store must succeed");
+
+        let mut keys = backend
+            .list()
+            .await
+            .expect("list must succeed")
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect::<Vec<_>>();
+        keys.sort();
+        let mut expected = vec![hello.key(), bye.key()];
+        expected.sort();
+        assert_eq!(keys, expected);
+
+        backend
+            .delete(hello.key())
+            .await
+            .expect("delete must succeed");
+        // deleting again must succeed too
+        backend
+            .delete(hello.key())
+            .await
+            .expect("delete must succeed");
+
+        let keys = backend
+            .list()
+            .await
+            .expect("list must succeed")
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec![bye.key()]);
+
+        assert!(backend
+            .retrieve(hello.key())
+            .await
+            .expect("retrieve must succeed")
+            .is_none());
+
+        drop(backend);
+    }
 }
@@ -13,6 +13,7 @@ use futures::Stream;
 use hex::ToHex;
 use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
+use std::time::SystemTime;
 use trustify_common::hashing::Digests;
 use trustify_common::id::Id;
 
@@ -39,6 +40,17 @@ impl Display for StorageKey {
     }
 }
 
+impl StorageKey {
+    /// Get the key of a stored object by its name, if it could have been written by a backend.
+    ///
+    /// Backends only write objects named by the lowercase hex encoded SHA256 digest of the content.
+    /// Everything else belongs to someone else, and must be left alone.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        (name.len() == 64 && name.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')))
+            .then(|| StorageKey(name.to_string()))
+    }
+}
+
 #[derive(Copy, Clone, Debug, thiserror::Error)]
 pub enum StorageKeyError {
     #[error("Storage key must be of type SHA256")]
@@ -81,6 +93,14 @@ impl StorageResult {
     }
 }
 
+/// An entry of the storage
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageEntry {
+    pub key: StorageKey,
+    /// The time the content was last modified, if known
+    pub modified: Option<SystemTime>,
+}
+
 pub trait StorageBackend {
     type Error: Debug;
 
@@ -100,4 +120,10 @@ pub trait StorageBackend {
     ) -> impl Future<
         Output = Result<Option<impl Stream<Item = Result<Bytes, Self::Error>> + 'a>, Self::Error>,
     >;
+
+    /// List all entries of the storage
+    fn list(&self) -> impl Future<Output = Result<Vec<StorageEntry>, Self::Error>>;
+
+    /// Delete the content, succeeds if the content doesn't exist
+    fn delete(&self, key: StorageKey) -> impl Future<Output = Result<(), Self::Error>>;
 }
@@ -1,8 +1,8 @@
 use crate::{
     config::S3Config,
     service::{
-        compression::Compression, temp::TempFile, StorageBackend, StorageKey, StorageResult,
-        StoreError,
+        compression::Compression, temp::TempFile, StorageBackend, StorageEntry, StorageKey,
+        StorageResult, StoreError,
     },
 };
 use bytes::Bytes;
@@ -96,6 +96,30 @@ impl StorageBackend for S3Backend {
             Err(e) => Err(e.into()),
         }
     }
+
+    async fn list(&self) -> Result<Vec<StorageEntry>, Self::Error> {
+        Ok(self
+            .bucket
+            .list(String::new(), None)
+            .await?
+            .into_iter()
+            .flat_map(|page| page.contents)
+            // the bucket might be shared, only report what we could have stored
+            .filter_map(|object| {
+                Some(StorageEntry {
+                    modified: humantime::parse_rfc3339_weak(&object.last_modified).ok(),
+                    key: StorageKey::from_name(&object.key)?,
+                })
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self), err(Debug, level=tracing::Level::INFO))]
+    async fn delete(&self, StorageKey(key): StorageKey) -> Result<(), Self::Error> {
+        // S3 reports success, even if the object doesn't exist
+        self.bucket.delete_object(&key).await?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -34,7 +34,7 @@ use trustify_infrastructure::{
 };
 use trustify_module_graphql::RootQuery;
 use trustify_module_importer::server::importer;
-use trustify_module_ingestor::graph::Graph;
+use trustify_module_ingestor::{graph::Graph, service::IngestorService};
 use trustify_module_storage::{
     config::{StorageConfig, StorageStrategy},
     service::{dispatch::DispatchBackend, fs::FileSystemBackend, s3::S3Backend},
@@ -92,6 +92,18 @@ pub struct Run {
     )]
     pub dataset_entry_limit: BinaryByteSize,
 
+    /// A directory to persist analysis graph snapshots to, speeding up loading graphs after a restart.
+    #[arg(long, env = "TRUSTD_ANALYSIS_SNAPSHOT_DIR")]
+    pub analysis_snapshot_dir: Option<PathBuf>,
+
+    /// Periodically delete documents from the storage which are no longer referenced, disabled if not set.
+    #[arg(long, env = "TRUSTD_STORAGE_GC_INTERVAL")]
+    pub storage_gc_interval: Option<humantime::Duration>,
+
+    /// Minimum age of a document before the periodic garbage collection deletes it.
+    #[arg(long, env = "TRUSTD_STORAGE_GC_MIN_AGE", default_value = "1h")]
+    pub storage_gc_min_age: humantime::Duration,
+
     // flattened commands must go last
     //
     /// Database configuration
@@ -168,6 +180,7 @@ struct InitData {
     ui: UI,
     with_graphql: bool,
     config: ModuleConfig,
+    storage_gc: Option<(Duration, Duration)>,
 }
 
 /// Groups all module configurations.
@@ -175,6 +188,7 @@ struct InitData {
 pub(crate) struct ModuleConfig {
     fundamental: trustify_module_fundamental::endpoints::Config,
     ingestor: trustify_module_ingestor::endpoints::Config,
+    analysis: trustify_module_analysis::endpoints::Config,
 }
 
 impl Run {
@@ -287,6 +301,9 @@ impl InitData {
             ingestor: trustify_module_ingestor::endpoints::Config {
                 dataset_entry_limit: run.dataset_entry_limit.into(),
             },
+            analysis: trustify_module_analysis::endpoints::Config {
+                snapshot_dir: run.analysis_snapshot_dir,
+            },
         };
 
         Ok(InitData {
@@ -302,6 +319,9 @@ impl InitData {
             embedded_oidc,
             ui,
             with_graphql: run.with_graphql,
+            storage_gc: run
+                .storage_gc_interval
+                .map(|interval| (interval.into(), run.storage_gc_min_age.into())),
         })
     }
 
@@ -348,6 +368,20 @@ impl InitData {
             );
         }
 
+        // periodically clean up the storage
+        if let Some((interval, min_age)) = self.storage_gc {
+            let service = IngestorService::new(Graph::new(db), storage);
+            tasks.push(
+                async move {
+                    service
+                        .collect_garbage_periodically(interval, min_age)
+                        .await;
+                    Ok::<_, anyhow::Error>(())
+                }
+                .boxed_local(),
+            );
+        }
+
         let (result, _, _) = futures::future::select_all(tasks).await;
 
         log::info!("one of the server tasks returned, exiting: {result:?}");
@@ -377,10 +411,12 @@ pub(crate) struct Config {
 
 pub(crate) fn configure(svc: &mut utoipa_actix_web::service_config::ServiceConfig, config: Config) {
     let Config {
-        config: ModuleConfig {
-            ingestor,
-            fundamental,
-        },
+        config:
+            ModuleConfig {
+                ingestor,
+                fundamental,
+                analysis,
+            },
         db,
         storage,
         auth,
@@ -436,7 +472,7 @@ pub(crate) fn configure(svc: &mut utoipa_actix_web::service_config::ServiceConfi
                         db.clone(),
                         storage,
                     );
-                    trustify_module_analysis::endpoints::configure(svc, db.clone());
+                    trustify_module_analysis::endpoints::configure(svc, analysis, db.clone());
                     trustify_module_user::endpoints::configure(svc, db.clone());
                 }),
         );
@@ -33,7 +33,7 @@ use trustify_infrastructure::{
     Infrastructure, InfrastructureConfig, InitContext, Metrics,
 };
 use trustify_module_graphql::RootQuery;
-use trustify_module_importer::server::importer;
+use trustify_module_importer::server::{importer, ServerConfig};
 use trustify_module_ingestor::graph::Graph;
 use trustify_module_storage::{
     config::{StorageConfig, StorageStrategy},
@@ -52,6 +52,18 @@ pub struct Run {
     #[arg(long, env)]
     pub working_dir: Option<PathBuf>,
 
+    /// The ID of this node, used to claim importer runs. Random if not set.
+    #[arg(long, env = "TRUSTD_IMPORTER_NODE_ID")]
+    pub node_id: Option<String>,
+
+    /// The maximum number of importers this node runs in parallel
+    #[arg(long, env = "TRUSTD_IMPORTER_CONCURRENCY", default_value_t = 1)]
+    pub concurrency: usize,
+
+    /// The time after which the claim of a node on an importer run expires, unless renewed
+    #[arg(long, env = "TRUSTD_IMPORTER_LEASE_DURATION", default_value = "1m")]
+    pub lease_duration: humantime::Duration,
+
     // flattened commands must go last
     //
     /// Database configuration
@@ -73,6 +85,7 @@ struct InitData {
     storage: DispatchBackend,
     tracing: Tracing,
     working_dir: Option<PathBuf>,
+    config: ServerConfig,
 }
 
 impl Run {
@@ -126,6 +139,13 @@ impl InitData {
             tracing: run.infra.tracing,
             storage,
             working_dir: run.working_dir,
+            config: ServerConfig {
+                node_id: run
+                    .node_id
+                    .unwrap_or_else(|| ServerConfig::default().node_id),
+                concurrency: run.concurrency.max(1),
+                lease_duration: run.lease_duration.into(),
+            },
         })
     }
 
@@ -133,7 +153,8 @@ impl InitData {
         let db = self.db;
         let storage = self.storage;
 
-        let importer = async { importer(db, storage, self.working_dir).await }.boxed_local();
+        let importer =
+            async { importer(db, storage, self.working_dir, self.config).await }.boxed_local();
 
         let tasks = vec![importer];
 
@@ -1,8 +1,9 @@
 use std::{collections::HashSet, time::Duration};
 use trustify_common::config::Database;
 use trustify_module_importer::model::{
-    ClearlyDefinedImporter, ClearlyDefinedPackageType, CveImporter, CweImporter,
-    DEFAULT_SOURCE_CLEARLY_DEFINED_CURATION, DEFAULT_SOURCE_CVEPROJECT, DEFAULT_SOURCE_CWE_CATALOG,
+    ClearlyDefinedImporter, ClearlyDefinedPackageType, CveImporter, CweImporter, EpssImporter,
+    KevImporter, DEFAULT_SOURCE_CLEARLY_DEFINED_CURATION, DEFAULT_SOURCE_CVEPROJECT,
+    DEFAULT_SOURCE_CWE_CATALOG, DEFAULT_SOURCE_EPSS, DEFAULT_SOURCE_KEV,
 };
 use trustify_module_importer::{
     model::{
@@ -42,6 +43,8 @@ async fn add_osv(
             common: CommonImporter {
                 disabled: true,
                 period: Duration::from_secs(300),
+                schedule: None,
+                blackout_windows: vec![],
                 description: Some(description.into()),
                 labels: Default::default(),
             },
@@ -68,6 +71,8 @@ async fn add_cve(
             common: CommonImporter {
                 disabled: true,
                 period: Duration::from_secs(300),
+                schedule: None,
+                blackout_windows: vec![],
                 description: Some(description.into()),
                 labels: Default::default(),
             },
@@ -92,6 +97,8 @@ async fn add_clearly_defined_curations(
                 disabled: true,
                 // once an hour is plenty
                 period: Duration::from_secs(60 * 60),
+                schedule: None,
+                blackout_windows: vec![],
                 description: Some(description.into()),
                 labels: Default::default(),
             },
@@ -115,6 +122,8 @@ async fn add_clearly_defined(
                 disabled: true,
                 // once an hour is plenty
                 period: Duration::from_secs(60 * 60),
+                schedule: None,
+                blackout_windows: vec![],
                 description: Some(description.into()),
                 labels: Default::default(),
             },
@@ -134,6 +143,8 @@ async fn add_cwe(importer: &ImporterService, name: &str, description: &str) -> a
                 disabled: true,
                 // once a day is plenty
                 period: Duration::from_secs(60 * 60 * 24),
+                schedule: None,
+                blackout_windows: vec![],
                 description: Some(description.into()),
                 labels: Default::default(),
             },
@@ -143,6 +154,46 @@ async fn add_cwe(importer: &ImporterService, name: &str, description: &str) -> a
     .await
 }
 
+async fn add_epss(importer: &ImporterService, name: &str, description: &str) -> anyhow::Result<()> {
+    add(
+        importer,
+        name,
+        ImporterConfiguration::Epss(EpssImporter {
+            common: CommonImporter {
+                disabled: true,
+                // scores get published once a day
+                period: Duration::from_secs(60 * 60 * 24),
+                schedule: None,
+                blackout_windows: vec![],
+                description: Some(description.into()),
+                labels: Default::default(),
+            },
+            source: DEFAULT_SOURCE_EPSS.into(),
+        }),
+    )
+    .await
+}
+
+async fn add_kev(importer: &ImporterService, name: &str, description: &str) -> anyhow::Result<()> {
+    add(
+        importer,
+        name,
+        ImporterConfiguration::Kev(KevImporter {
+            common: CommonImporter {
+                disabled: true,
+                // once a day is plenty
+                period: Duration::from_secs(60 * 60 * 24),
+                schedule: None,
+                blackout_windows: vec![],
+                description: Some(description.into()),
+                labels: Default::default(),
+            },
+            source: DEFAULT_SOURCE_KEV.into(),
+        }),
+    )
+    .await
+}
+
 pub async fn sample_data(db: trustify_common::db::Database) -> anyhow::Result<()> {
     let importer = ImporterService::new(db);
 
@@ -150,6 +201,8 @@ pub async fn sample_data(db: trustify_common::db::Database) -> anyhow::Result<()
         common: CommonImporter {
             disabled: true,
             period: Duration::from_secs(300),
+            schedule: None,
+            blackout_windows: vec![],
             description: Some("All Red Hat SBOMs".into()),
             labels: Default::default(),
         },
@@ -171,6 +224,8 @@ pub async fn sample_data(db: trustify_common::db::Database) -> anyhow::Result<()
             common: CommonImporter {
                 disabled: true,
                 period: Duration::from_secs(300),
+                schedule: None,
+                blackout_windows: vec![],
                 description: Some("All Red Hat CSAF data".into()),
                 labels: Default::default(),
             },
@@ -190,6 +245,8 @@ pub async fn sample_data(db: trustify_common::db::Database) -> anyhow::Result<()
             common: CommonImporter {
                 disabled: true,
                 period: Duration::from_secs(300),
+                schedule: None,
+                blackout_windows: vec![],
                 description: Some("Red Hat VEX files from 2024".into()),
                 labels: Default::default(),
             },
@@ -204,6 +261,9 @@ pub async fn sample_data(db: trustify_common::db::Database) -> anyhow::Result<()
 
     add_cwe(&importer, "cwe", "Common Weakness Enumeration").await?;
 
+    add_epss(&importer, "epss", "Exploit Prediction Scoring System").await?;
+    add_kev(&importer, "kev", "CISA Known Exploited Vulnerabilities").await?;
+
     add_cve(&importer, "cve", None, "CVE List V5").await?;
     add_cve(
         &importer,
@@ -8,6 +8,7 @@ use tokio::task::{spawn_local, LocalSet};
 
 mod db;
 mod openapi;
+mod storage;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(clap::Subcommand, Debug)]
@@ -20,6 +21,8 @@ pub enum Command {
     Db(db::Run),
     /// Access OpenAPI related information of the API server
     Openapi(openapi::Run),
+    /// Manage the document storage
+    Storage(storage::Run),
 }
 
 #[derive(clap::Parser, Debug)]
@@ -41,6 +44,7 @@ impl Trustd {
             Some(Command::Importer(run)) => run.run().await,
             Some(Command::Db(run)) => run.run().await,
             Some(Command::Openapi(run)) => run.run().await,
+            Some(Command::Storage(run)) => run.run().await,
             None => pm_mode().await,
         }
     }
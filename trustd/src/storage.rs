@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use trustify_common::config::Database;
+use trustify_common::db;
+use trustify_infrastructure::tracing::{init_tracing, Tracing};
+use trustify_module_ingestor::{graph::Graph, service::IngestorService};
+use trustify_module_storage::{
+    config::{StorageConfig, StorageStrategy},
+    service::{dispatch::DispatchBackend, fs::FileSystemBackend, s3::S3Backend},
+};
+
+#[derive(clap::Args, Debug)]
+pub struct Run {
+    #[command(subcommand)]
+    pub(crate) command: Command,
+    #[command(flatten)]
+    pub(crate) database: Database,
+    #[command(flatten)]
+    pub(crate) storage: StorageConfig,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+    /// Delete documents which are no longer referenced
+    Gc {
+        /// Only report which documents would be deleted
+        #[arg(long)]
+        dry_run: bool,
+        /// Minimum age of a document before it gets deleted
+        #[arg(long, env = "TRUSTD_STORAGE_GC_MIN_AGE", default_value = "1h")]
+        min_age: humantime::Duration,
+    },
+}
+
+impl Run {
+    pub async fn run(self) -> anyhow::Result<ExitCode> {
+        init_tracing("storage-run", Tracing::Disabled);
+        match self.command {
+            Command::Gc { dry_run, min_age } => {
+                let db = db::Database::new(&self.database).await?;
+                let storage = storage(self.storage).await?;
+                let service = IngestorService::new(Graph::new(db), storage);
+
+                let report = service.collect_garbage(dry_run, min_age.into()).await?;
+                println!("{}", serde_json::to_string_pretty(&report)?);
+
+                Ok(if report.errors.is_empty() {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::FAILURE
+                })
+            }
+        }
+    }
+}
+
+async fn storage(config: StorageConfig) -> anyhow::Result<DispatchBackend> {
+    Ok(match config.storage_strategy {
+        StorageStrategy::Fs => {
+            let storage = config
+                .fs_path
+                .unwrap_or_else(|| PathBuf::from("./.trustify/storage"));
+            DispatchBackend::Filesystem(FileSystemBackend::new(storage, config.compression).await?)
+        }
+        StorageStrategy::S3 => {
+            DispatchBackend::S3(S3Backend::new(config.s3_config, config.compression).await?)
+        }
+    })
+}
@@ -164,6 +164,8 @@ fn default_common(description: impl Into<String>) -> CommonImporter {
     CommonImporter {
         disabled: false,
         period: Default::default(),
+        schedule: None,
+        blackout_windows: vec![],
         description: Some(description.into()),
         labels: Default::default(),
     }